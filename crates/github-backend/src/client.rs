@@ -1,10 +1,11 @@
-use std::sync::OnceLock;
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::Duration;
 use ureq::Agent;
 
 use crate::convert::convert_query_to_github;
 use crate::error::{GitHubError, Result};
 use crate::models::*;
+use crate::org::RepoFilter;
 use crate::wiki::WikiManager;
 
 /// GitHub REST API client
@@ -15,6 +16,12 @@ pub struct GitHubClient {
     repo: String,
     token: String,
     wiki_manager: OnceLock<WikiManager>,
+    /// Projects (v2) board number under `owner`, if configured
+    project_number: Option<u64>,
+    project_cache: Arc<Mutex<Option<ProjectV2>>>,
+    /// Repositories spanned in org scope; `None` for a single repository
+    org: Option<RepoFilter>,
+    org_repos: Arc<Mutex<Option<Vec<GitHubRepo>>>>,
 }
 
 impl GitHubClient {
@@ -44,9 +51,70 @@ impl GitHubClient {
             repo: repo.to_string(),
             token: token.to_string(),
             wiki_manager: OnceLock::new(),
+            project_number: None,
+            project_cache: Arc::new(Mutex::new(None)),
+            org: None,
+            org_repos: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// Span every repository of the owner that `filter` admits instead of a
+    /// single one. The configured repo, if any, stays the default for
+    /// unqualified issue numbers, labels and the wiki.
+    pub fn with_org_scope(mut self, filter: RepoFilter) -> Self {
+        self.org = Some(filter);
+        self
+    }
+
+    /// The repository filter when the client is org-scoped
+    pub fn org_filter(&self) -> Option<&RepoFilter> {
+        self.org.as_ref()
+    }
+
+    pub(crate) fn org_repos_cache(&self) -> &Mutex<Option<Vec<GitHubRepo>>> {
+        &self.org_repos
+    }
+
+    /// A client for another repository of this connection, sharing its
+    /// HTTP agent and caches.
+    pub(crate) fn in_repo(&self, owner: &str, repo: &str) -> GitHubClient {
+        Self {
+            agent: self.agent.clone(),
+            base_url: self.base_url.clone(),
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            token: self.token.clone(),
+            wiki_manager: OnceLock::new(),
+            project_number: self.project_number,
+            project_cache: Arc::clone(&self.project_cache),
+            org: self.org.clone(),
+            org_repos: Arc::clone(&self.org_repos),
+        }
+    }
+
+    /// Use the owner's Projects (v2) board with this number for board fields
+    pub fn with_project_number(mut self, number: Option<u64>) -> Self {
+        self.project_number = number;
+        self
+    }
+
+    /// Get the configured Projects (v2) board number
+    pub fn project_number(&self) -> Option<u64> {
+        self.project_number
+    }
+
+    pub(crate) fn project_cache(&self) -> &Mutex<Option<ProjectV2>> {
+        &self.project_cache
+    }
+
+    pub(crate) fn agent(&self) -> &Agent {
+        &self.agent
+    }
+
+    pub(crate) fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
     /// Get the owner for this client
     pub fn owner(&self) -> &str {
         &self.owner
@@ -66,12 +134,12 @@ impl GitHubClient {
     }
 
     /// Build the Authorization header value
-    fn auth_header(&self) -> String {
+    pub(crate) fn auth_header(&self) -> String {
         format!("Bearer {}", self.token)
     }
 
     /// Check response status and return error if not successful
-    fn check_response(
+    pub(crate) fn check_response(
         &self,
         mut response: ureq::http::Response<ureq::Body>,
     ) -> Result<ureq::http::Response<ureq::Body>> {
@@ -173,14 +241,23 @@ impl GitHubClient {
 
     /// Search issues using GitHub search syntax
     ///
-    /// The query is automatically scoped to this repository.
+    /// The query is automatically scoped to this repository, or in org scope
+    /// to the owner's (filtered) repositories.
     pub fn search_issues(
         &self,
         query: &str,
         per_page: usize,
         page: usize,
     ) -> Result<GitHubSearchResult> {
-        let scoped_query = format!("repo:{}/{} {}", self.owner, self.repo, query);
+        let Some(scope) = self.search_scope(query)? else {
+            // No repository passes the org filter
+            return Ok(GitHubSearchResult {
+                total_count: 0,
+                incomplete_results: false,
+                items: Vec::new(),
+            });
+        };
+        let scoped_query = format!("{} {}", scope, query).trim().to_string();
         let url = format!(
             "{}/search/issues?q={}&per_page={}&page={}",
             self.base_url,
@@ -277,6 +354,171 @@ impl GitHubClient {
         Ok(())
     }
 
+    /// Detach a sub-issue from its parent.
+    ///
+    /// `child_id` is the **global numeric ID** of the sub-issue.
+    pub fn remove_sub_issue(&self, parent_number: u64, child_id: u64) -> Result<()> {
+        let url = self.repo_url(&format!("/issues/{}/sub_issue", parent_number));
+
+        let body = serde_json::json!({ "sub_issue_id": child_id });
+
+        let response = self
+            .agent
+            .delete(&url)
+            .header("Authorization", &self.auth_header())
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .force_send_body()
+            .send_json(&body)
+            .map_err(GitHubError::Http)?;
+
+        self.check_response(response)?;
+        Ok(())
+    }
+
+    /// List the sub-issues of an issue (GitHub allows at most 100 per parent)
+    pub fn list_sub_issues(&self, number: u64) -> Result<Vec<GitHubIssue>> {
+        self.list_related_issues(&format!("/issues/{}/sub_issues", number))
+    }
+
+    /// Get the parent of a sub-issue, or `None` if the issue has no parent
+    pub fn get_parent_issue(&self, number: u64) -> Result<Option<GitHubIssue>> {
+        let url = self.repo_url(&format!("/issues/{}/parent", number));
+
+        let response = self
+            .agent
+            .get(&url)
+            .header("Authorization", &self.auth_header())
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .call()
+            .map_err(GitHubError::Http)?;
+
+        match self.check_response(response) {
+            Ok(mut response) => Ok(Some(response.body_mut().read_json()?)),
+            Err(GitHubError::Api { status: 404, .. }) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    // ==================== Dependency Operations ====================
+
+    /// List the issues blocking an issue
+    pub fn list_blocked_by(&self, number: u64) -> Result<Vec<GitHubIssue>> {
+        self.list_related_issues(&format!("/issues/{}/dependencies/blocked_by", number))
+    }
+
+    /// List the issues an issue is blocking
+    pub fn list_blocking(&self, number: u64) -> Result<Vec<GitHubIssue>> {
+        self.list_related_issues(&format!("/issues/{}/dependencies/blocking", number))
+    }
+
+    /// Mark an issue as blocked by another.
+    ///
+    /// `blocker_id` is the **global numeric ID** of the blocking issue.
+    pub fn add_blocked_by(&self, number: u64, blocker_id: u64) -> Result<()> {
+        let url = self.repo_url(&format!("/issues/{}/dependencies/blocked_by", number));
+
+        let body = serde_json::json!({ "issue_id": blocker_id });
+
+        let response = self
+            .agent
+            .post(&url)
+            .header("Authorization", &self.auth_header())
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .send_json(&body)
+            .map_err(GitHubError::Http)?;
+
+        self.check_response(response)?;
+        Ok(())
+    }
+
+    /// Remove a "blocked by" dependency.
+    ///
+    /// `blocker_id` is the **global numeric ID** of the blocking issue.
+    pub fn remove_blocked_by(&self, number: u64, blocker_id: u64) -> Result<()> {
+        let url = self.repo_url(&format!(
+            "/issues/{}/dependencies/blocked_by/{}",
+            number, blocker_id
+        ));
+
+        let response = self
+            .agent
+            .delete(&url)
+            .header("Authorization", &self.auth_header())
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .call()
+            .map_err(GitHubError::Http)?;
+
+        self.check_response(response)?;
+        Ok(())
+    }
+
+    /// Fetch a list of issues related to an issue (sub-issues, dependencies)
+    fn list_related_issues(&self, path: &str) -> Result<Vec<GitHubIssue>> {
+        let url = format!("{}?per_page=100", self.repo_url(path));
+
+        let response = self
+            .agent
+            .get(&url)
+            .header("Authorization", &self.auth_header())
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .call()
+            .map_err(GitHubError::Http)?;
+
+        let mut response = self.check_response(response)?;
+        let issues: Vec<GitHubIssue> = response.body_mut().read_json()?;
+        Ok(issues)
+    }
+
+    // ==================== Issue Type Operations ====================
+
+    /// List the owner's issue types.
+    ///
+    /// Issue types are an organization feature: personal accounts and
+    /// organizations without them yield an empty list.
+    pub fn list_issue_types(&self) -> Result<Vec<GitHubIssueType>> {
+        let url = format!("{}/orgs/{}/issue-types", self.base_url, self.owner);
+
+        let response = self
+            .agent
+            .get(&url)
+            .header("Authorization", &self.auth_header())
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .call()
+            .map_err(GitHubError::Http)?;
+
+        match self.check_response(response) {
+            Ok(mut response) => Ok(response.body_mut().read_json()?),
+            Err(GitHubError::Api { status: 404, .. }) => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Remove a label from an issue, leaving the label itself in place
+    pub fn remove_issue_label(&self, number: u64, name: &str) -> Result<()> {
+        let encoded_name = urlencoding::encode(name);
+        let url = self.repo_url(&format!("/issues/{}/labels/{}", number, encoded_name));
+
+        let response = self
+            .agent
+            .delete(&url)
+            .header("Authorization", &self.auth_header())
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .call()
+            .map_err(GitHubError::Http)?;
+
+        self.check_response(response)?;
+        Ok(())
+    }
+
     // ==================== Repository Operations ====================
 
     /// List repositories for the authenticated user
@@ -315,6 +557,24 @@ impl GitHubClient {
         Ok(repo)
     }
 
+    /// List users that can be assigned to issues in the repository
+    pub fn list_assignees(&self) -> Result<Vec<GitHubUser>> {
+        let url = format!("{}?per_page=100", self.repo_url("/assignees"));
+
+        let response = self
+            .agent
+            .get(&url)
+            .header("Authorization", &self.auth_header())
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .call()
+            .map_err(GitHubError::Http)?;
+
+        let mut response = self.check_response(response)?;
+        let users: Vec<GitHubUser> = response.body_mut().read_json()?;
+        Ok(users)
+    }
+
     // ==================== Label Operations ====================
 
     /// List labels for the repository
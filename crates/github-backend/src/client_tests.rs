@@ -3,8 +3,10 @@
 #[cfg(test)]
 mod tests {
     use crate::client::GitHubClient;
-    use tracker_core::IssueTracker;
-    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use tracker_core::{CustomField, CustomFieldUpdate, IssueTracker, UpdateIssue};
+    use wiremock::matchers::{
+        body_json, body_partial_json, body_string_contains, header, method, path, query_param,
+    };
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     /// Helper to create a mock GitHub issue response
@@ -250,6 +252,7 @@ mod tests {
             labels: Some(vec!["bug".to_string()]),
             assignees: None,
             milestone: None,
+            issue_type: None,
         };
 
         let issue = client.create_issue(&create).unwrap();
@@ -280,6 +283,7 @@ mod tests {
             labels: None,
             assignees: None,
             milestone: None,
+            issue_type: None,
         };
 
         let issue = client.update_issue(42, &update).unwrap();
@@ -359,6 +363,27 @@ mod tests {
         assert_eq!(labels[2].name, "documentation");
     }
 
+    #[tokio::test]
+    async fn test_list_assignees() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/assignees"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"login": "octocat", "id": 1},
+                {"login": "hubot", "id": 2}
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let client = GitHubClient::with_base_url(&mock_server.uri(), "owner", "repo", "test-token");
+        let users = client.list_assignees().unwrap();
+
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0].login, "octocat");
+        assert_eq!(users[1].id, 2);
+    }
+
     #[tokio::test]
     async fn test_create_label() {
         let mock_server = MockServer::start().await;
@@ -754,4 +779,621 @@ mod tests {
             other => panic!("Expected Api error, got: {:?}", other),
         }
     }
+
+    #[tokio::test]
+    async fn test_get_issue_links_reads_sub_issues_and_dependencies() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues/42/parent"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(mock_github_issue(10, "Epic")))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues/42/sub_issues"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!([mock_github_issue(43, "Child")])),
+            )
+            .mount(&mock_server)
+            .await;
+        let mut blocker = mock_github_issue(7, "Upstream fix");
+        blocker["repository_url"] = serde_json::json!("https://api.github.com/repos/owner/lib");
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues/42/dependencies/blocked_by"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([blocker])))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues/42/dependencies/blocking"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&mock_server)
+            .await;
+
+        let client = GitHubClient::with_base_url(&mock_server.uri(), "owner", "repo", "test-token");
+        let links = IssueTracker::get_issue_links(&client, "42").unwrap();
+
+        let summary: Vec<_> = links
+            .iter()
+            .map(|l| {
+                (
+                    l.id.as_str(),
+                    l.link_type.name.as_str(),
+                    l.direction.as_deref().unwrap(),
+                    l.issues[0].id.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("parent:10", "Parent", "INWARD", "10"),
+                ("subtask:1043", "Subtask", "OUTWARD", "43"),
+                ("blocked-by:1007", "Blocks", "INWARD", "lib#7"),
+            ]
+        );
+        assert_eq!(
+            links[1].issues[0].id_readable.as_deref(),
+            Some("owner/repo#43")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_links_without_relations_apis() {
+        // Servers without sub-issues or dependencies answer 404
+        let mock_server = MockServer::start().await;
+
+        let client = GitHubClient::with_base_url(&mock_server.uri(), "owner", "repo", "test-token");
+        let links = IssueTracker::get_issue_links(&client, "42").unwrap();
+
+        assert!(links.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_link_issues_depends_adds_blocked_by() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues/7"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(mock_github_issue(7, "Blocker")))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/owner/repo/issues/42/dependencies/blocked_by"))
+            .and(body_json(serde_json::json!({ "issue_id": 1007 })))
+            .respond_with(
+                ResponseTemplate::new(201).set_body_json(mock_github_issue(42, "Blocked")),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = GitHubClient::with_base_url(&mock_server.uri(), "owner", "repo", "test-token");
+        IssueTracker::link_issues(&client, "42", "7", "depends", "OUTWARD").unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_link_issues_rejects_unsupported_type() {
+        let client =
+            GitHubClient::with_base_url("http://127.0.0.1:1", "owner", "repo", "test-token");
+        let err = IssueTracker::link_issues(&client, "42", "7", "relates", "BOTH").unwrap_err();
+        assert!(err.to_string().contains("'relates'"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_unlink_issues_removes_sub_issue_and_dependency() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/repos/owner/repo/issues/10/sub_issue"))
+            .and(body_json(serde_json::json!({ "sub_issue_id": 1042 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(mock_github_issue(42, "Child")))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path(
+                "/repos/owner/repo/issues/10/dependencies/blocked_by/1007",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(mock_github_issue(10, "Epic")))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = GitHubClient::with_base_url(&mock_server.uri(), "owner", "repo", "test-token");
+        IssueTracker::unlink_issues(&client, "10", "subtask:1042").unwrap();
+        IssueTracker::unlink_issues(&client, "10", "blocked-by:1007").unwrap();
+        assert!(IssueTracker::unlink_issues(&client, "10", "relates").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_remove_issue_parent() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues/42/parent"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(mock_github_issue(10, "Epic")))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues/42"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(mock_github_issue(42, "Child")))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/repos/owner/repo/issues/10/sub_issue"))
+            .and(body_json(serde_json::json!({ "sub_issue_id": 1042 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(mock_github_issue(42, "Child")))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = GitHubClient::with_base_url(&mock_server.uri(), "owner", "repo", "test-token");
+        IssueTracker::remove_issue_parent(&client, "42").unwrap();
+    }
+
+    // ==================== Issue Types ====================
+
+    #[tokio::test]
+    async fn test_issue_type_is_listed_and_set() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/orgs/owner/issue-types"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": 1, "name": "Bug", "description": "An unexpected problem" },
+                { "id": 2, "name": "Feature", "description": null }
+            ])))
+            .mount(&mock_server)
+            .await;
+        let mut updated = mock_github_issue(42, "Typed");
+        updated["type"] = serde_json::json!({ "id": 2, "name": "Feature" });
+        Mock::given(method("PATCH"))
+            .and(path("/repos/owner/repo/issues/42"))
+            .and(body_partial_json(serde_json::json!({ "type": "Feature" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(updated))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = GitHubClient::with_base_url(&mock_server.uri(), "owner", "repo", "test-token");
+
+        let fields = IssueTracker::get_project_custom_fields(&client, "owner/repo").unwrap();
+        let type_field = fields.iter().find(|f| f.name == "Type").unwrap();
+        assert_eq!(type_field.values, vec!["Bug", "Feature"]);
+
+        let update = UpdateIssue {
+            custom_fields: vec![CustomFieldUpdate::SingleEnum {
+                name: "Type".to_string(),
+                value: "Feature".to_string(),
+            }],
+            ..Default::default()
+        };
+        let issue = IssueTracker::update_issue(&client, "42", &update).unwrap();
+        assert_eq!(
+            issue.custom_fields.iter().find_map(|cf| match cf {
+                CustomField::SingleEnum { name, value } if name == "Type" => value.as_deref(),
+                _ => None,
+            }),
+            Some("Feature")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_issue_types_absent_for_personal_accounts() {
+        let mock_server = MockServer::start().await;
+
+        let client = GitHubClient::with_base_url(&mock_server.uri(), "owner", "repo", "test-token");
+        let fields = IssueTracker::get_project_custom_fields(&client, "owner/repo").unwrap();
+
+        assert!(fields.iter().all(|f| f.name != "Type"));
+    }
+
+    /// GraphQL `projectV2` response for a board with Status and Estimate
+    fn mock_project_v2() -> serde_json::Value {
+        serde_json::json!({ "data": { "repositoryOwner": { "projectV2": {
+            "id": "PVT_1",
+            "number": 3,
+            "title": "Roadmap",
+            "fields": { "nodes": [
+                { "id": "F_status", "name": "Status", "dataType": "SINGLE_SELECT",
+                  "options": [
+                      { "id": "O_todo", "name": "Todo" },
+                      { "id": "O_prog", "name": "In Progress" },
+                      { "id": "O_done", "name": "Done" }
+                  ] },
+                { "id": "F_est", "name": "Estimate", "dataType": "NUMBER" }
+            ] }
+        } } } })
+    }
+
+    /// GraphQL `nodes` response placing issue `I_42` on the board
+    fn mock_project_items(status: &str) -> serde_json::Value {
+        serde_json::json!({ "data": { "nodes": [{
+            "id": "I_42",
+            "projectItems": { "nodes": [{
+                "id": "PVTI_1",
+                "project": { "id": "PVT_1" },
+                "fieldValues": { "nodes": [
+                    { "__typename": "ProjectV2ItemFieldSingleSelectValue", "name": status,
+                      "field": { "name": "Status" } },
+                    { "__typename": "ProjectV2ItemFieldNumberValue", "number": 5,
+                      "field": { "name": "Estimate" } }
+                ] }
+            }] }
+        }] } })
+    }
+
+    async fn mount_project_queries(server: &MockServer, status: &str) {
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("projectV2(number"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(mock_project_v2()))
+            .expect(1)
+            .mount(server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("projectItems"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(mock_project_items(status)))
+            .mount(server)
+            .await;
+    }
+
+    fn issue_with_node_id(number: u64) -> serde_json::Value {
+        let mut issue = mock_github_issue(number, "Board issue");
+        issue["node_id"] = serde_json::json!("I_42");
+        issue
+    }
+
+    #[tokio::test]
+    async fn test_project_v2_fields_are_read_onto_issues() {
+        let mock_server = MockServer::start().await;
+        mount_project_queries(&mock_server, "In Progress").await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues/42"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(issue_with_node_id(42)))
+            .mount(&mock_server)
+            .await;
+
+        let client = GitHubClient::with_base_url(&mock_server.uri(), "owner", "repo", "test-token")
+            .with_project_number(Some(3));
+        let issue = IssueTracker::get_issue(&client, "42").unwrap();
+
+        assert!(issue.custom_fields.iter().any(|cf| matches!(
+            cf,
+            CustomField::State { name, value: Some(v), is_resolved: false }
+                if name == "Status" && v == "In Progress"
+        )));
+        assert!(issue.custom_fields.iter().any(|cf| matches!(
+            cf,
+            CustomField::Text { name, value: Some(v) } if name == "Estimate" && v == "5"
+        )));
+
+        let fields = IssueTracker::get_project_custom_fields(&client, "owner/repo").unwrap();
+        let status: Vec<_> = fields.iter().filter(|f| f.name == "Status").collect();
+        assert_eq!(status.len(), 1, "board Status replaces open/closed");
+        assert_eq!(status[0].values, vec!["Todo", "In Progress", "Done"]);
+    }
+
+    #[tokio::test]
+    async fn test_project_v2_update_moves_card_and_closes_issue() {
+        let mock_server = MockServer::start().await;
+        mount_project_queries(&mock_server, "Done").await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/repos/owner/repo/issues/42"))
+            .and(body_json(serde_json::json!({ "state": "closed" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(issue_with_node_id(42)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("addProjectV2ItemById"))
+            .and(body_partial_json(serde_json::json!({
+                "variables": { "projectId": "PVT_1", "contentId": "I_42" }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "addProjectV2ItemById": { "item": { "id": "PVTI_1" } } }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("updateProjectV2ItemFieldValue"))
+            .and(body_partial_json(serde_json::json!({
+                "variables": { "fieldId": "F_status", "value": { "singleSelectOptionId": "O_done" } }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "updateProjectV2ItemFieldValue": { "projectV2Item": { "id": "PVTI_1" } } }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("clearProjectV2ItemFieldValue"))
+            .and(body_partial_json(serde_json::json!({
+                "variables": { "itemId": "PVTI_1", "fieldId": "F_est" }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "clearProjectV2ItemFieldValue": { "projectV2Item": { "id": "PVTI_1" } } }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = GitHubClient::with_base_url(&mock_server.uri(), "owner", "repo", "test-token")
+            .with_project_number(Some(3));
+        let update = UpdateIssue {
+            custom_fields: vec![
+                CustomFieldUpdate::State {
+                    name: "State".to_string(),
+                    value: "Done".to_string(),
+                },
+                CustomFieldUpdate::SingleEnum {
+                    name: "Estimate".to_string(),
+                    value: String::new(),
+                },
+            ],
+            ..Default::default()
+        };
+        let issue = IssueTracker::update_issue(&client, "42", &update).unwrap();
+        assert!(issue.custom_fields.iter().any(|cf| matches!(
+            cf,
+            CustomField::State { name, value: Some(v), .. } if name == "Status" && v == "Done"
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_project_v2_invalid_value_fails_before_any_write() {
+        let mock_server = MockServer::start().await;
+        mount_project_queries(&mock_server, "Todo").await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/repos/owner/repo/issues/42"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(issue_with_node_id(42)))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let client = GitHubClient::with_base_url(&mock_server.uri(), "owner", "repo", "test-token")
+            .with_project_number(Some(3));
+        let update = UpdateIssue {
+            custom_fields: vec![CustomFieldUpdate::State {
+                name: "Status".to_string(),
+                value: "Blocked".to_string(),
+            }],
+            ..Default::default()
+        };
+        let err = IssueTracker::update_issue(&client, "42", &update).unwrap_err();
+        assert!(err.to_string().contains("Todo, In Progress, Done"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_project_v2_graphql_errors_surface() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": null,
+                "errors": [{ "type": "INSUFFICIENT_SCOPES", "message": "Your token has not been granted the required scopes" }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = GitHubClient::with_base_url(&mock_server.uri(), "owner", "repo", "test-token")
+            .with_project_number(Some(3));
+        let err = IssueTracker::get_project_custom_fields(&client, "owner/repo").unwrap_err();
+        assert!(err.to_string().contains("required scopes"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_project_v2_create_adds_issue_to_board() {
+        let mock_server = MockServer::start().await;
+        mount_project_queries(&mock_server, "Todo").await;
+
+        Mock::given(method("POST"))
+            .and(path("/repos/owner/repo/issues"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(issue_with_node_id(42)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("addProjectV2ItemById"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "addProjectV2ItemById": { "item": { "id": "PVTI_1" } } }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("updateProjectV2ItemFieldValue"))
+            .and(body_partial_json(serde_json::json!({
+                "variables": { "fieldId": "F_est", "value": { "number": 8.0 } }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "updateProjectV2ItemFieldValue": { "projectV2Item": { "id": "PVTI_1" } } }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = GitHubClient::with_base_url(&mock_server.uri(), "owner", "repo", "test-token")
+            .with_project_number(Some(3));
+        let create = tracker_core::CreateIssue {
+            project_id: "owner/repo".to_string(),
+            summary: "Board issue".to_string(),
+            description: None,
+            custom_fields: vec![CustomFieldUpdate::SingleEnum {
+                name: "estimate".to_string(),
+                value: "8".to_string(),
+            }],
+            tags: vec![],
+            parent: None,
+        };
+        IssueTracker::create_issue(&client, &create).unwrap();
+    }
+
+    // ==================== Org Scope ====================
+
+    /// An issue as returned for a repository of the `acme` organization
+    fn org_issue(repo: &str, number: u64, title: &str) -> serde_json::Value {
+        let mut issue = mock_github_issue(number, title);
+        issue["repository_url"] =
+            serde_json::json!(format!("https://api.github.com/repos/acme/{}", repo));
+        issue
+    }
+
+    fn org_repo(id: u64, name: &str, topics: &[&str]) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "name": name,
+            "full_name": format!("acme/{}", name),
+            "description": null,
+            "owner": {"login": "acme", "id": 1},
+            "topics": topics
+        })
+    }
+
+    async fn mount_org_repos(server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/orgs/acme/repos"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                org_repo(1, "svc-billing", &["backend"]),
+                org_repo(2, "svc-web", &["frontend"]),
+                org_repo(3, "svc-auth", &["backend"]),
+                org_repo(4, "docs", &["backend"])
+            ])))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    fn org_client(server: &MockServer, topic: Option<&str>, pattern: Option<&str>) -> GitHubClient {
+        let filter = crate::RepoFilter::new(topic.map(str::to_string), pattern).unwrap();
+        GitHubClient::with_base_url(&server.uri(), "acme", "", "test-token").with_org_scope(filter)
+    }
+
+    #[tokio::test]
+    async fn test_org_scope_lists_and_searches_filtered_repos() {
+        let mock_server = MockServer::start().await;
+        mount_org_repos(&mock_server).await;
+
+        Mock::given(method("GET"))
+            .and(path("/search/issues"))
+            .and(query_param(
+                "q",
+                "repo:acme/svc-auth repo:acme/svc-billing is:open is:issue",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 2,
+                "incomplete_results": false,
+                "items": [
+                    org_issue("svc-auth", 3, "Token expiry"),
+                    org_issue("svc-billing", 12, "Invoice rounding")
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = org_client(&mock_server, Some("backend"), Some("^svc-"));
+
+        let projects = IssueTracker::list_projects(&client).unwrap();
+        let names: Vec<_> = projects.iter().map(|p| p.short_name.as_str()).collect();
+        assert_eq!(names, vec!["acme/svc-auth", "acme/svc-billing"]);
+
+        let result = IssueTracker::search_issues(&client, "#open", 10, 0).unwrap();
+        let ids: Vec<_> = result.items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["svc-auth#3", "svc-billing#12"]);
+        assert_eq!(result.items[1].id_readable, "acme/svc-billing#12");
+    }
+
+    #[tokio::test]
+    async fn test_org_scope_without_filter_searches_the_org() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/search/issues"))
+            .and(query_param("q", "org:acme is:issue"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 0,
+                "incomplete_results": false,
+                "items": []
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = org_client(&mock_server, None, None);
+        let result = IssueTracker::search_issues(&client, "", 10, 0).unwrap();
+        assert!(result.items.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_org_scope_routes_issue_refs_to_their_repo() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/svc-web/issues/7"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(org_issue(
+                "svc-web",
+                7,
+                "Broken nav",
+            )))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/acme/svc-web/issues/7/comments"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "id": 1,
+                "body": "On it",
+                "user": {"login": "testuser", "id": 1},
+                "created_at": "2024-01-15T10:30:00Z",
+                "updated_at": "2024-01-15T10:30:00Z"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = org_client(&mock_server, None, None);
+
+        let issue = IssueTracker::get_issue(&client, "svc-web#7").unwrap();
+        assert_eq!(issue.id, "svc-web#7");
+        assert_eq!(issue.project.short_name.as_deref(), Some("acme/svc-web"));
+        IssueTracker::add_comment(&client, "acme/svc-web#7", "On it").unwrap();
+
+        // Without a default repository a bare number is ambiguous
+        let err = IssueTracker::get_issue(&client, "7").unwrap_err();
+        assert!(err.to_string().contains("repo#number"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_org_scope_resolves_numeric_project_ids() {
+        let mock_server = MockServer::start().await;
+        mount_org_repos(&mock_server).await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/svc-auth/assignees"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!([{"login": "alice", "id": 7}])),
+            )
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let client = org_client(&mock_server, None, None);
+
+        // Cache refresh passes the numeric ID `list_projects` reported
+        let users = IssueTracker::list_project_users(&client, "3").unwrap();
+        assert_eq!(users[0].login.as_deref(), Some("alice"));
+        IssueTracker::list_project_users(&client, "acme/svc-auth").unwrap();
+    }
 }
@@ -3,8 +3,8 @@
 use chrono::{DateTime, Utc};
 use tracker_core::{
     Comment, CommentAuthor, CreateIssue, CustomField, CustomFieldUpdate, Issue, IssueHistoryEvent,
-    IssueTag, Project, ProjectCustomField, ProjectRef, StateValueInfo, Tag, TagColor, UpdateIssue,
-    canonical_field_name,
+    IssueLink, IssueLinkType, IssueTag, LinkedIssue, Project, ProjectCustomField, ProjectRef,
+    StateValueInfo, Tag, TagColor, TrackerError, UpdateIssue, User, canonical_field_name,
 };
 
 use crate::models::*;
@@ -33,11 +33,17 @@ pub fn github_issue_to_core(issue: GitHubIssue, owner: &str, repo: &str) -> Issu
         display_name: issue.assignee.as_ref().map(|u| u.login.clone()),
     });
 
-    // Map first label as Type (for compatibility with other backends)
-    if let Some(first_label) = issue.labels.first() {
+    // Map the native issue type as Type, falling back to the first label for
+    // repositories without issue types (for compatibility with other backends)
+    let issue_type = issue
+        .issue_type
+        .as_ref()
+        .map(|t| t.name.clone())
+        .or_else(|| issue.labels.first().map(|l| l.name.clone()));
+    if let Some(issue_type) = issue_type {
         custom_fields.push(CustomField::SingleEnum {
             name: "Type".to_string(),
-            value: Some(first_label.name.clone()),
+            value: Some(issue_type),
         });
     }
 
@@ -300,6 +306,25 @@ impl From<GitHubRepo> for Project {
     }
 }
 
+impl From<GitHubUser> for User {
+    fn from(u: GitHubUser) -> Self {
+        // The assignees endpoint only returns the login; `name` is present
+        // when the user object came from a full profile lookup.
+        let display_name = u
+            .extra
+            .get("name")
+            .and_then(|n| n.as_str())
+            .filter(|n| !n.is_empty())
+            .unwrap_or(&u.login)
+            .to_string();
+        Self {
+            id: u.id.to_string(),
+            login: Some(u.login),
+            display_name,
+        }
+    }
+}
+
 impl From<GitHubLabel> for IssueTag {
     fn from(l: GitHubLabel) -> Self {
         Self {
@@ -344,24 +369,27 @@ pub fn create_issue_from_core(issue: &CreateIssue) -> CreateGitHubIssue {
             Some(assignees)
         },
         milestone: None,
+        issue_type: issue_type_from_updates(&issue.custom_fields),
     }
 }
 
+/// The issue type named by a `Type` field update, if any.
+fn issue_type_from_updates(fields: &[CustomFieldUpdate]) -> Option<String> {
+    fields.iter().find_map(|cf| match cf {
+        CustomFieldUpdate::SingleEnum { name, value } if name.eq_ignore_ascii_case("type") => {
+            Some(value.clone())
+        }
+        _ => None,
+    })
+}
+
 /// Convert a tracker-core UpdateIssue to a GitHub UpdateGitHubIssue
 pub fn update_issue_from_core(update: &UpdateIssue) -> UpdateGitHubIssue {
     // Extract state from custom fields (CLI sends "State" or "Stage", backends may use "Status")
     let state = update.custom_fields.iter().find_map(|cf| match cf {
-        CustomFieldUpdate::State { name, value }
-            if name.eq_ignore_ascii_case("status")
-                || name.eq_ignore_ascii_case("state")
-                || name.eq_ignore_ascii_case("stage") =>
-        {
-            let mapped_value = match value.to_lowercase().as_str() {
-                "done" | "resolved" | "closed" | "completed" => "closed",
-                "open" | "in progress" | "develop" | "reopened" => "open",
-                _ => value.as_str(), // Fallback
-            };
-            Some(mapped_value.to_string())
+        CustomFieldUpdate::State { name, value } if is_state_field_name(name) => {
+            // Fallback: pass unknown values through as-is
+            Some(github_issue_state(value).unwrap_or(value).to_string())
         }
         _ => None,
     });
@@ -394,9 +422,214 @@ pub fn update_issue_from_core(update: &UpdateIssue) -> UpdateGitHubIssue {
             Some(assignees)
         },
         milestone: None,
+        issue_type: issue_type_from_updates(&update.custom_fields),
+    }
+}
+
+/// Whether a field name refers to the workflow state ("State"/"Stage" from the
+/// CLI, "Status" on GitHub).
+pub(crate) fn is_state_field_name(name: &str) -> bool {
+    name.eq_ignore_ascii_case("status")
+        || name.eq_ignore_ascii_case("state")
+        || name.eq_ignore_ascii_case("stage")
+}
+
+/// Map a workflow state name onto GitHub's issue state (`open`/`closed`).
+pub(crate) fn github_issue_state(value: &str) -> Option<&'static str> {
+    match value.to_lowercase().as_str() {
+        "done" | "resolved" | "closed" | "completed" => Some("closed"),
+        "open" | "in progress" | "develop" | "reopened" => Some("open"),
+        _ => None,
+    }
+}
+
+/// Merge a Projects (v2) board item's field values into a converted issue.
+///
+/// The board's Status column replaces the open/closed `Status` field (keeping
+/// it resolved when the issue is closed or the column reads as done); other
+/// values are added as custom fields, replacing any same-named field. Values
+/// of fields that mirror the issue (title, labels, ...) are skipped.
+pub fn apply_project_item(issue: &mut Issue, project: &ProjectV2, item: &ProjectV2Item) {
+    let closed = issue.custom_fields.iter().any(
+        |cf| matches!(cf, CustomField::State { name, is_resolved: true, .. } if name == "Status"),
+    );
+
+    for value in &item.field_values {
+        let Some(field) = value.field_name().and_then(|name| project.field(name)) else {
+            continue;
+        };
+        let Some(display) = value.display_value() else {
+            continue;
+        };
+
+        let cf = match field.kind() {
+            Some(ProjectV2FieldKind::SingleSelect)
+                if project.status_field().is_some_and(|s| s.id == field.id) =>
+            {
+                CustomField::State {
+                    name: "Status".to_string(),
+                    is_resolved: closed || github_issue_state(&display) == Some("closed"),
+                    value: Some(display),
+                }
+            }
+            Some(ProjectV2FieldKind::SingleSelect | ProjectV2FieldKind::Iteration) => {
+                CustomField::SingleEnum {
+                    name: field.name.clone(),
+                    value: Some(display),
+                }
+            }
+            _ => CustomField::Text {
+                name: field.name.clone(),
+                value: Some(display),
+            },
+        };
+
+        let name = custom_field_name(&cf).to_string();
+        match issue
+            .custom_fields
+            .iter_mut()
+            .find(|existing| custom_field_name(existing).eq_ignore_ascii_case(&name))
+        {
+            Some(existing) => *existing = cf,
+            None => issue.custom_fields.push(cf),
+        }
+    }
+}
+
+fn custom_field_name(cf: &CustomField) -> &str {
+    match cf {
+        CustomField::SingleEnum { name, .. }
+        | CustomField::State { name, .. }
+        | CustomField::SingleUser { name, .. }
+        | CustomField::Text { name, .. }
+        | CustomField::MultiEnum { name, .. }
+        | CustomField::Unknown { name, .. } => name,
     }
 }
 
+/// Encode a CLI value as a `ProjectV2FieldValue` input for a board field.
+///
+/// Single-select options and iterations are matched by name
+/// (case-insensitive). Returns `None` for an empty value, meaning the field
+/// should be cleared.
+pub fn project_field_value(
+    field: &ProjectV2Field,
+    value: &str,
+) -> tracker_core::Result<Option<serde_json::Value>> {
+    use serde_json::json;
+
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+
+    let invalid = |valid: Vec<&str>| {
+        TrackerError::InvalidInput(format!(
+            "Invalid value '{}' for project field '{}'. Valid values: {}",
+            value,
+            field.name,
+            valid.join(", ")
+        ))
+    };
+
+    let encoded = match field.kind() {
+        Some(ProjectV2FieldKind::SingleSelect) => {
+            let option = field
+                .options
+                .iter()
+                .find(|o| o.name.eq_ignore_ascii_case(value))
+                .ok_or_else(|| invalid(field.options.iter().map(|o| o.name.as_str()).collect()))?;
+            json!({ "singleSelectOptionId": option.id })
+        }
+        Some(ProjectV2FieldKind::Iteration) => {
+            let iteration = field
+                .iterations()
+                .find(|i| i.title.eq_ignore_ascii_case(value))
+                .ok_or_else(|| invalid(field.iterations().map(|i| i.title.as_str()).collect()))?;
+            json!({ "iterationId": iteration.id })
+        }
+        Some(ProjectV2FieldKind::Number) => {
+            let number: f64 = value.parse().map_err(|_| {
+                TrackerError::InvalidInput(format!(
+                    "Project field '{}' expects a number, got '{}'",
+                    field.name, value
+                ))
+            })?;
+            json!({ "number": number })
+        }
+        Some(ProjectV2FieldKind::Date) => {
+            chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+                TrackerError::InvalidInput(format!(
+                    "Project field '{}' expects a date (YYYY-MM-DD), got '{}'",
+                    field.name, value
+                ))
+            })?;
+            json!({ "date": value })
+        }
+        Some(ProjectV2FieldKind::Text) => json!({ "text": value }),
+        None => {
+            return Err(TrackerError::InvalidInput(format!(
+                "Project field '{}' ({}) cannot be set from the CLI",
+                field.name, field.data_type
+            )));
+        }
+    };
+    Ok(Some(encoded))
+}
+
+/// Describe a board's writable fields as project custom fields.
+///
+/// The Status column is a `state[1]` field whose done-like options count as
+/// resolved.
+pub fn project_v2_custom_fields(project: &ProjectV2) -> Vec<ProjectCustomField> {
+    let status_id = project.status_field().map(|f| f.id.as_str());
+
+    project
+        .fields
+        .iter()
+        .filter_map(|field| {
+            let (field_type, values) = match field.kind()? {
+                ProjectV2FieldKind::SingleSelect if Some(field.id.as_str()) == status_id => (
+                    "state[1]",
+                    field.options.iter().map(|o| o.name.clone()).collect(),
+                ),
+                ProjectV2FieldKind::SingleSelect => (
+                    "enum[1]",
+                    field.options.iter().map(|o| o.name.clone()).collect(),
+                ),
+                ProjectV2FieldKind::Iteration => (
+                    "enum[1]",
+                    field.iterations().map(|i| i.title.clone()).collect(),
+                ),
+                ProjectV2FieldKind::Number => ("number", Vec::new()),
+                ProjectV2FieldKind::Date => ("date", Vec::new()),
+                ProjectV2FieldKind::Text => ("string", Vec::new()),
+            };
+            let state_values = if field_type == "state[1]" {
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(ordinal, name)| StateValueInfo {
+                        name: name.clone(),
+                        is_resolved: github_issue_state(name) == Some("closed"),
+                        ordinal: ordinal as i32,
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            Some(ProjectCustomField {
+                id: field.id.clone(),
+                name: field.name.clone(),
+                field_type: field_type.to_string(),
+                required: false,
+                values,
+                state_values,
+            })
+        })
+        .collect()
+}
+
 /// Convert a simple tracker-core query to GitHub search syntax
 pub fn convert_query_to_github(query: &str) -> String {
     let mut parts = Vec::new();
@@ -486,6 +719,133 @@ pub fn get_standard_custom_fields() -> Vec<ProjectCustomField> {
     ]
 }
 
+/// The `Type` field, listing the organization's issue types.
+pub fn issue_type_custom_field(types: &[GitHubIssueType]) -> ProjectCustomField {
+    ProjectCustomField {
+        id: "type".to_string(),
+        name: "Type".to_string(),
+        field_type: "enum[1]".to_string(),
+        required: false,
+        values: types.iter().map(|t| t.name.clone()).collect(),
+        state_values: vec![],
+    }
+}
+
+// ==================== Issue Links ====================
+
+fn subtask_link_type() -> IssueLinkType {
+    IssueLinkType {
+        id: "subtask".to_string(),
+        name: "Subtask".to_string(),
+        source_to_target: Some("is parent of".to_string()),
+        target_to_source: Some("is subtask of".to_string()),
+        directed: true,
+    }
+}
+
+fn blocks_link_type() -> IssueLinkType {
+    IssueLinkType {
+        id: "blocks".to_string(),
+        name: "Blocks".to_string(),
+        source_to_target: Some("blocks".to_string()),
+        target_to_source: Some("is blocked by".to_string()),
+        directed: true,
+    }
+}
+
+/// Link types GitHub supports natively: sub-issues and dependencies.
+pub fn get_github_link_types() -> Vec<IssueLinkType> {
+    vec![subtask_link_type(), blocks_link_type()]
+}
+
+/// Reference a related issue, which may live in another repository.
+///
+/// `issue_id` formats the ID for an issue's owner, repo and number.
+fn github_linked_issue(
+    issue: GitHubIssue,
+    owner: &str,
+    repo: &str,
+    issue_id: &impl Fn(&str, &str, u64) -> String,
+) -> LinkedIssue {
+    let (owner, repo) = issue
+        .repository()
+        .and_then(|r| r.split_once('/'))
+        .unwrap_or((owner, repo));
+    LinkedIssue {
+        id: issue_id(owner, repo, issue.number),
+        id_readable: Some(format!("{}/{}#{}", owner, repo, issue.number)),
+        summary: Some(issue.title),
+    }
+}
+
+/// An issue's sub-issue and dependency relations, as fetched from GitHub.
+#[derive(Debug, Default)]
+pub struct GitHubIssueRelations {
+    pub parent: Option<GitHubIssue>,
+    pub sub_issues: Vec<GitHubIssue>,
+    pub blocked_by: Vec<GitHubIssue>,
+    pub blocking: Vec<GitHubIssue>,
+}
+
+/// Convert sub-issue and dependency relations of an issue in `owner/repo`
+/// to core links.
+///
+/// Link IDs record what `unlink_issues` needs to remove the relation:
+/// `parent:<issue>` and `blocking:<issue>` name the other issue by its ID,
+/// `subtask:<id>` and `blocked-by:<id>` by the global ID the API expects.
+pub fn github_relations_to_core(
+    relations: GitHubIssueRelations,
+    owner: &str,
+    repo: &str,
+    issue_id: impl Fn(&str, &str, u64) -> String,
+) -> Vec<IssueLink> {
+    let mut links = Vec::new();
+
+    if let Some(parent) = relations.parent {
+        let linked = github_linked_issue(parent, owner, repo, &issue_id);
+        links.push(IssueLink {
+            id: format!("parent:{}", linked.id),
+            direction: Some("INWARD".to_string()),
+            link_type: IssueLinkType {
+                id: "parent".to_string(),
+                name: "Parent".to_string(),
+                ..subtask_link_type()
+            },
+            issues: vec![linked],
+        });
+    }
+
+    for child in relations.sub_issues {
+        links.push(IssueLink {
+            id: format!("subtask:{}", child.id),
+            direction: Some("OUTWARD".to_string()),
+            link_type: subtask_link_type(),
+            issues: vec![github_linked_issue(child, owner, repo, &issue_id)],
+        });
+    }
+
+    for blocker in relations.blocked_by {
+        links.push(IssueLink {
+            id: format!("blocked-by:{}", blocker.id),
+            direction: Some("INWARD".to_string()),
+            link_type: blocks_link_type(),
+            issues: vec![github_linked_issue(blocker, owner, repo, &issue_id)],
+        });
+    }
+
+    for blocked in relations.blocking {
+        let linked = github_linked_issue(blocked, owner, repo, &issue_id);
+        links.push(IssueLink {
+            id: format!("blocking:{}", linked.id),
+            direction: Some("OUTWARD".to_string()),
+            link_type: blocks_link_type(),
+            issues: vec![linked],
+        });
+    }
+
+    links
+}
+
 /// Parse a GitHub ISO 8601 datetime string
 fn parse_github_datetime(dt: &str) -> Option<DateTime<Utc>> {
     chrono::DateTime::parse_from_rfc3339(dt)
@@ -311,6 +311,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn issue_type_maps_to_type_before_first_label() {
+        let labels = serde_json::json!([{ "id": 1, "name": "bug", "color": "fc2929" }]);
+        let typed = issue_from_json(serde_json::json!({
+            "labels": labels,
+            "type": { "id": 5, "name": "Feature", "description": null }
+        }));
+        let core = github_issue_to_core(typed, "owner", "repo");
+        match fields_named(&core.custom_fields, "Type")[..] {
+            [CustomField::SingleEnum { value, .. }] => {
+                assert_eq!(value.as_deref(), Some("Feature"))
+            }
+            ref other => panic!("expected one Type field, got {:?}", other),
+        }
+        assert!(fields_named(&core.custom_fields, "type").is_empty());
+
+        let untyped = issue_from_json(serde_json::json!({ "labels": labels, "type": null }));
+        let core = github_issue_to_core(untyped, "owner", "repo");
+        match fields_named(&core.custom_fields, "Type")[..] {
+            [CustomField::SingleEnum { value, .. }] => assert_eq!(value.as_deref(), Some("bug")),
+            ref other => panic!("expected one Type field, got {:?}", other),
+        }
+    }
+
     #[test]
     fn projection_drops_noise_keys() {
         let issue = issue_from_json(serde_json::json!({
@@ -521,4 +545,152 @@ mod tests {
             "null extra should not be surfaced"
         );
     }
+
+    // ------------------------------------------------------------------
+    // Projects (v2) board fields
+    // ------------------------------------------------------------------
+
+    use crate::convert::{apply_project_item, project_field_value, project_v2_custom_fields};
+    use crate::models::{ProjectV2, ProjectV2Item};
+
+    /// A board as the GraphQL API returns it: Status, an iteration, and one
+    /// field of each scalar kind, plus a Title field that mirrors the issue.
+    fn sample_project() -> ProjectV2 {
+        serde_json::from_value(serde_json::json!({
+            "id": "PVT_1",
+            "number": 3,
+            "title": "Roadmap",
+            "fields": { "nodes": [
+                { "id": "F_title", "name": "Title", "dataType": "TITLE" },
+                { "id": "F_status", "name": "Status", "dataType": "SINGLE_SELECT",
+                  "options": [
+                      { "id": "O_todo", "name": "Todo" },
+                      { "id": "O_prog", "name": "In Progress" },
+                      { "id": "O_done", "name": "Done" }
+                  ] },
+                { "id": "F_sprint", "name": "Sprint", "dataType": "ITERATION",
+                  "configuration": {
+                      "iterations": [{ "id": "I_2", "title": "Sprint 2", "startDate": "2026-05-11" }],
+                      "completedIterations": [{ "id": "I_1", "title": "Sprint 1", "startDate": "2026-04-27" }]
+                  } },
+                { "id": "F_est", "name": "Estimate", "dataType": "NUMBER" },
+                { "id": "F_due", "name": "Due", "dataType": "DATE" },
+                { "id": "F_note", "name": "Note", "dataType": "TEXT" },
+                null
+            ] }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn project_field_value_encodes_each_kind() {
+        let project = sample_project();
+        let encode = |field: &str, value: &str| {
+            project_field_value(project.field(field).unwrap(), value).unwrap()
+        };
+
+        assert_eq!(
+            encode("status", "in progress"),
+            Some(serde_json::json!({ "singleSelectOptionId": "O_prog" }))
+        );
+        assert_eq!(
+            encode("Sprint", "Sprint 1"),
+            Some(serde_json::json!({ "iterationId": "I_1" }))
+        );
+        assert_eq!(
+            encode("Estimate", "2.5"),
+            Some(serde_json::json!({ "number": 2.5 }))
+        );
+        assert_eq!(
+            encode("Due", "2026-06-01"),
+            Some(serde_json::json!({ "date": "2026-06-01" }))
+        );
+        assert_eq!(
+            encode("Note", "ship it"),
+            Some(serde_json::json!({ "text": "ship it" }))
+        );
+        assert_eq!(encode("Estimate", ""), None, "empty clears the field");
+        assert!(
+            project.field("Title").is_none(),
+            "mirrored fields are not writable"
+        );
+    }
+
+    #[test]
+    fn project_field_value_rejects_bad_values() {
+        let project = sample_project();
+        let status = project.field("Status").unwrap();
+        let err = project_field_value(status, "Develop").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Valid values: Todo, In Progress, Done"),
+            "{err}"
+        );
+        assert!(project_field_value(project.field("Estimate").unwrap(), "lots").is_err());
+        assert!(project_field_value(project.field("Due").unwrap(), "next week").is_err());
+    }
+
+    #[test]
+    fn apply_project_item_merges_board_values() {
+        let project = sample_project();
+        let item: ProjectV2Item = serde_json::from_value(serde_json::json!({
+            "id": "PVTI_1",
+            "project": { "id": "PVT_1" },
+            "fieldValues": { "nodes": [
+                { "__typename": "ProjectV2ItemFieldTextValue", "text": "Example issue",
+                  "field": { "name": "Title" } },
+                { "__typename": "ProjectV2ItemFieldSingleSelectValue", "name": "Done",
+                  "field": { "name": "Status" } },
+                { "__typename": "ProjectV2ItemFieldIterationValue", "title": "Sprint 2",
+                  "field": { "name": "Sprint" } },
+                { "__typename": "ProjectV2ItemFieldNumberValue", "number": 3.0,
+                  "field": { "name": "Estimate" } },
+                { "__typename": "ProjectV2ItemFieldLabelValue" }
+            ] }
+        }))
+        .unwrap();
+
+        let mut core = github_issue_to_core(issue_from_json(serde_json::json!({})), "o", "r");
+        apply_project_item(&mut core, &project, &item);
+
+        assert!(matches!(
+            fields_named(&core.custom_fields, "Status")[..],
+            [CustomField::State { value: Some(v), is_resolved: true, .. }] if v == "Done"
+        ));
+        assert!(matches!(
+            fields_named(&core.custom_fields, "Sprint")[..],
+            [CustomField::SingleEnum { value: Some(v), .. }] if v == "Sprint 2"
+        ));
+        assert!(matches!(
+            fields_named(&core.custom_fields, "Estimate")[..],
+            [CustomField::Text { value: Some(v), .. }] if v == "3"
+        ));
+        assert!(fields_named(&core.custom_fields, "Title").is_empty());
+    }
+
+    #[test]
+    fn project_v2_custom_fields_describe_board() {
+        let fields = project_v2_custom_fields(&sample_project());
+        let types: Vec<(&str, &str)> = fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.field_type.as_str()))
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                ("Status", "state[1]"),
+                ("Sprint", "enum[1]"),
+                ("Estimate", "number"),
+                ("Due", "date"),
+                ("Note", "string"),
+            ]
+        );
+        let resolved: Vec<bool> = fields[0]
+            .state_values
+            .iter()
+            .map(|v| v.is_resolved)
+            .collect();
+        assert_eq!(resolved, vec![false, false, true]);
+        assert_eq!(fields[1].values, vec!["Sprint 2", "Sprint 1"]);
+    }
 }
@@ -2,7 +2,10 @@ pub mod client;
 mod convert;
 pub mod error;
 pub mod models;
+pub mod org;
+mod projects;
 mod trait_impl;
+pub mod webhook;
 pub(crate) mod wiki;
 
 #[cfg(test)]
@@ -15,6 +18,7 @@ mod wiki_tests;
 pub use client::GitHubClient;
 pub use error::{GitHubError, Result};
 pub use models::*;
+pub use org::RepoFilter;
 pub use wiki::WikiManager;
 
 // Re-export tracker-core types for convenience
@@ -22,6 +22,15 @@ pub struct GitHubMilestone {
     pub title: String,
 }
 
+/// GitHub issue type (organization-level, e.g. "Bug", "Feature", "Task")
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GitHubIssueType {
+    pub id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
 /// GitHub pull request indicator (presence means the issue is actually a PR)
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct GitHubPullRequest {
@@ -42,6 +51,9 @@ pub struct GitHubIssue {
     #[serde(default)]
     pub assignees: Vec<GitHubUser>,
     pub milestone: Option<GitHubMilestone>,
+    /// Issue type, when the owning organization has issue types enabled
+    #[serde(rename = "type", default)]
+    pub issue_type: Option<GitHubIssueType>,
     pub created_at: String,
     pub updated_at: String,
     pub closed_at: Option<String>,
@@ -61,6 +73,21 @@ impl GitHubIssue {
     pub fn is_pull_request(&self) -> bool {
         self.pull_request.is_some()
     }
+
+    /// GraphQL node ID, used to address the issue in Projects (v2) mutations
+    pub fn node_id(&self) -> Option<&str> {
+        self.extra.get("node_id").and_then(Value::as_str)
+    }
+
+    /// `owner/repo` of the repository the issue lives in, read from
+    /// `repository_url`. Sub-issues and dependencies may cross repositories.
+    pub fn repository(&self) -> Option<&str> {
+        self.extra
+            .get("repository_url")
+            .and_then(Value::as_str)
+            .and_then(|url| url.rsplit_once("/repos/"))
+            .map(|(_, path)| path)
+    }
 }
 
 /// GitHub search result
@@ -83,6 +110,9 @@ pub struct CreateGitHubIssue {
     pub assignees: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub milestone: Option<u64>,
+    /// Issue type name
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<String>,
 }
 
 /// Request body for updating a GitHub issue
@@ -100,4 +130,7 @@ pub struct UpdateGitHubIssue {
     pub assignees: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub milestone: Option<u64>,
+    /// Issue type name
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<String>,
 }
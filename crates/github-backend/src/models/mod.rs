@@ -2,10 +2,12 @@ pub mod comment;
 pub mod issue;
 pub mod label;
 pub mod project;
+pub mod project_v2;
 pub mod timeline;
 
 pub use comment::*;
 pub use issue::*;
 pub use label::*;
 pub use project::*;
+pub use project_v2::*;
 pub use timeline::*;
@@ -10,4 +10,6 @@ pub struct GitHubRepo {
     pub full_name: String,
     pub description: Option<String>,
     pub owner: GitHubUser,
+    #[serde(default)]
+    pub topics: Vec<String>,
 }
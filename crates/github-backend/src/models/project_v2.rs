@@ -0,0 +1,199 @@
+//! GitHub Projects (v2) models, as returned by the GraphQL API.
+//!
+//! Projects v2 has no REST API: boards, their fields and per-issue field
+//! values are only reachable through GraphQL.
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Unwrap a GraphQL connection (`{ "nodes": [...] }`) into its nodes,
+/// skipping the `null` entries GitHub returns for inaccessible items.
+fn from_nodes<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    struct Connection<T> {
+        #[serde(default = "Vec::new")]
+        nodes: Vec<Option<T>>,
+    }
+
+    Ok(Option::<Connection<T>>::deserialize(deserializer)?
+        .map(|c| c.nodes.into_iter().flatten().collect())
+        .unwrap_or_default())
+}
+
+/// A Projects v2 board with the field definitions it carries.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProjectV2 {
+    /// GraphQL node ID (used by mutations)
+    pub id: String,
+    pub number: u64,
+    pub title: String,
+    #[serde(default, deserialize_with = "from_nodes")]
+    pub fields: Vec<ProjectV2Field>,
+}
+
+impl ProjectV2 {
+    /// Find a writable field by name (case-insensitive).
+    pub fn field(&self, name: &str) -> Option<&ProjectV2Field> {
+        self.fields
+            .iter()
+            .filter(|f| f.kind().is_some())
+            .find(|f| f.name.eq_ignore_ascii_case(name))
+    }
+
+    /// The board's workflow column field: the single-select named "Status".
+    pub fn status_field(&self) -> Option<&ProjectV2Field> {
+        self.field("Status")
+            .filter(|f| f.kind() == Some(ProjectV2FieldKind::SingleSelect))
+    }
+}
+
+/// A field definition on a board.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectV2Field {
+    pub id: String,
+    pub name: String,
+    /// GraphQL `ProjectV2FieldType`, e.g. `SINGLE_SELECT` or `ITERATION`
+    pub data_type: String,
+    /// Options of a single-select field
+    #[serde(default)]
+    pub options: Vec<ProjectV2SingleSelectOption>,
+    /// Iterations of an iteration field
+    #[serde(default)]
+    pub configuration: Option<ProjectV2IterationConfiguration>,
+}
+
+/// The field kinds that live on the board itself. Other data types (title,
+/// assignees, labels, milestone, ...) mirror the issue and are managed through
+/// the issue's own fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectV2FieldKind {
+    SingleSelect,
+    Iteration,
+    Number,
+    Date,
+    Text,
+}
+
+impl ProjectV2Field {
+    pub fn kind(&self) -> Option<ProjectV2FieldKind> {
+        match self.data_type.as_str() {
+            "SINGLE_SELECT" => Some(ProjectV2FieldKind::SingleSelect),
+            "ITERATION" => Some(ProjectV2FieldKind::Iteration),
+            "NUMBER" => Some(ProjectV2FieldKind::Number),
+            "DATE" => Some(ProjectV2FieldKind::Date),
+            "TEXT" => Some(ProjectV2FieldKind::Text),
+            _ => None,
+        }
+    }
+
+    /// Active iterations followed by completed ones.
+    pub fn iterations(&self) -> impl Iterator<Item = &ProjectV2Iteration> {
+        self.configuration
+            .iter()
+            .flat_map(|c| c.iterations.iter().chain(&c.completed_iterations))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProjectV2SingleSelectOption {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectV2IterationConfiguration {
+    #[serde(default)]
+    pub iterations: Vec<ProjectV2Iteration>,
+    #[serde(default)]
+    pub completed_iterations: Vec<ProjectV2Iteration>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectV2Iteration {
+    pub id: String,
+    pub title: String,
+    pub start_date: Option<String>,
+}
+
+/// An issue's membership in a board, with the values set on it.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectV2Item {
+    pub id: String,
+    pub project: ProjectV2ItemProject,
+    #[serde(default, deserialize_with = "from_nodes")]
+    pub field_values: Vec<ProjectV2ItemFieldValue>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProjectV2ItemProject {
+    pub id: String,
+}
+
+/// An issue together with the boards it is on.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectV2IssueItems {
+    /// GraphQL node ID of the issue
+    pub id: String,
+    #[serde(default, deserialize_with = "from_nodes")]
+    pub project_items: Vec<ProjectV2Item>,
+}
+
+/// One field value on a board item. GraphQL returns a union; only the member
+/// matching `typename` has its value key set.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProjectV2ItemFieldValue {
+    #[serde(rename = "__typename")]
+    pub typename: String,
+    #[serde(default)]
+    pub field: Option<ProjectV2FieldRef>,
+    /// Single-select option name
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Iteration title
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub number: Option<f64>,
+    /// `YYYY-MM-DD`
+    #[serde(default)]
+    pub date: Option<String>,
+    #[serde(default)]
+    pub text: Option<String>,
+}
+
+impl ProjectV2ItemFieldValue {
+    pub fn field_name(&self) -> Option<&str> {
+        self.field.as_ref().map(|f| f.name.as_str())
+    }
+
+    /// The value rendered as the CLI shows it.
+    pub fn display_value(&self) -> Option<String> {
+        match self.typename.as_str() {
+            "ProjectV2ItemFieldSingleSelectValue" => self.name.clone(),
+            "ProjectV2ItemFieldIterationValue" => self.title.clone(),
+            "ProjectV2ItemFieldNumberValue" => self.number.map(|n| {
+                if n.fract() == 0.0 {
+                    (n as i64).to_string()
+                } else {
+                    n.to_string()
+                }
+            }),
+            "ProjectV2ItemFieldDateValue" => self.date.clone(),
+            "ProjectV2ItemFieldTextValue" => self.text.clone(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProjectV2FieldRef {
+    pub name: String,
+}
@@ -0,0 +1,167 @@
+//! Organization-wide scope: one client spanning many repositories of an owner.
+//!
+//! In org scope issues are addressed as `repo#123` (or `owner/repo#123`), and
+//! searches cover every repository that passes the configured topic and name
+//! filters.
+
+use regex::Regex;
+
+use crate::client::GitHubClient;
+use crate::error::{GitHubError, Result};
+use crate::models::*;
+
+/// Which of the owner's repositories an org-scoped client spans.
+#[derive(Debug, Clone, Default)]
+pub struct RepoFilter {
+    /// Only repositories tagged with this topic
+    pub topic: Option<String>,
+    /// Only repositories whose name matches this pattern
+    pub pattern: Option<Regex>,
+}
+
+impl RepoFilter {
+    /// Build a filter from a topic and a name regex.
+    pub fn new(
+        topic: Option<String>,
+        pattern: Option<&str>,
+    ) -> std::result::Result<Self, regex::Error> {
+        Ok(Self {
+            topic,
+            pattern: pattern.map(Regex::new).transpose()?,
+        })
+    }
+
+    /// Whether every repository passes.
+    pub fn is_empty(&self) -> bool {
+        self.topic.is_none() && self.pattern.is_none()
+    }
+
+    pub fn matches(&self, repo: &GitHubRepo) -> bool {
+        self.topic
+            .as_ref()
+            .is_none_or(|topic| repo.topics.iter().any(|t| t.eq_ignore_ascii_case(topic)))
+            && self
+                .pattern
+                .as_ref()
+                .is_none_or(|pattern| pattern.is_match(&repo.name))
+    }
+}
+
+impl GitHubClient {
+    /// The configured repository; org scope may leave it unset.
+    pub(crate) fn home_repo(&self) -> Option<&str> {
+        Some(self.repo()).filter(|r| !r.is_empty())
+    }
+
+    /// Repositories of the owner that pass the org filter, fetched once and
+    /// cached. Sorted by name.
+    pub fn org_repos(&self) -> Result<Vec<GitHubRepo>> {
+        let filter = self.org_filter().cloned().unwrap_or_default();
+
+        let mut cache = self.org_repos_cache().lock().unwrap();
+        if let Some(ref repos) = *cache {
+            return Ok(repos.clone());
+        }
+
+        let mut repos: Vec<GitHubRepo> = self
+            .list_owner_repos()?
+            .into_iter()
+            .filter(|r| filter.matches(r))
+            .collect();
+        repos.sort_by(|a, b| a.name.cmp(&b.name));
+
+        *cache = Some(repos.clone());
+        Ok(repos)
+    }
+
+    /// Every repository of the owner, whether an organization or a user.
+    fn list_owner_repos(&self) -> Result<Vec<GitHubRepo>> {
+        let owner = urlencoding::encode(self.owner()).into_owned();
+        match self.list_repos_at(&format!("/orgs/{}/repos", owner)) {
+            Err(GitHubError::Api { status: 404, .. }) => {
+                self.list_repos_at(&format!("/users/{}/repos", owner))
+            }
+            other => other,
+        }
+    }
+
+    fn list_repos_at(&self, path: &str) -> Result<Vec<GitHubRepo>> {
+        const PER_PAGE: usize = 100;
+        const MAX_PAGES: usize = 1_000;
+
+        let mut repos = Vec::new();
+        for page in 1..=MAX_PAGES {
+            let url = format!(
+                "{}{}?per_page={}&page={}",
+                self.base_url(),
+                path,
+                PER_PAGE,
+                page
+            );
+
+            let response = self
+                .agent()
+                .get(&url)
+                .header("Authorization", &self.auth_header())
+                .header("Accept", "application/vnd.github+json")
+                .header("X-GitHub-Api-Version", "2022-11-28")
+                .call()
+                .map_err(GitHubError::Http)?;
+
+            let mut response = self.check_response(response)?;
+            let batch: Vec<GitHubRepo> = response.body_mut().read_json()?;
+            let fetched = batch.len();
+            repos.extend(batch);
+            if fetched < PER_PAGE {
+                return Ok(repos);
+            }
+        }
+        Err(GitHubError::PaginationStalled(format!(
+            "repositories of '{}' did not terminate after {} pages",
+            self.owner(),
+            MAX_PAGES
+        )))
+    }
+
+    /// Search qualifiers limiting a query to this client's repositories.
+    ///
+    /// Returns `None` when the org filter admits no repository. In org scope
+    /// a query that names its own `repo:` is left unscoped, since repeated
+    /// `repo:` qualifiers are OR-ed.
+    pub(crate) fn search_scope(&self, query: &str) -> Result<Option<String>> {
+        let Some(filter) = self.org_filter() else {
+            return Ok(Some(format!("repo:{}/{}", self.owner(), self.repo())));
+        };
+        if query.split_whitespace().any(|t| t.starts_with("repo:")) {
+            return Ok(Some(String::new()));
+        }
+        if filter.is_empty() {
+            return Ok(Some(format!("org:{}", self.owner())));
+        }
+
+        let repos = self.org_repos()?;
+        if repos.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(
+            repos
+                .iter()
+                .map(|r| format!("repo:{}", r.full_name))
+                .collect::<Vec<_>>()
+                .join(" "),
+        ))
+    }
+
+    /// The ID an issue is addressed by: its number in the configured
+    /// repository, `repo#123` elsewhere under the owner (always, in org
+    /// scope) and `owner/repo#123` under another owner.
+    pub fn issue_ref(&self, owner: &str, repo: &str, number: u64) -> String {
+        if !owner.eq_ignore_ascii_case(self.owner()) {
+            format!("{}/{}#{}", owner, repo, number)
+        } else if self.org_filter().is_none() && repo.eq_ignore_ascii_case(self.repo()) {
+            number.to_string()
+        } else {
+            format!("{}#{}", repo, number)
+        }
+    }
+}
@@ -0,0 +1,281 @@
+//! GitHub Projects (v2) support over the GraphQL API.
+//!
+//! When a project number is configured, the board's single-select, iteration,
+//! number, date and text fields are read onto issues as custom fields and
+//! written back through `updateProjectV2ItemFieldValue`.
+
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use crate::client::GitHubClient;
+use crate::error::{GitHubError, Result};
+use crate::models::*;
+
+const PROJECT_QUERY: &str = r#"
+query($owner: String!, $number: Int!) {
+  repositoryOwner(login: $owner) {
+    ... on ProjectV2Owner {
+      projectV2(number: $number) {
+        id
+        number
+        title
+        fields(first: 100) {
+          nodes {
+            ... on ProjectV2FieldCommon { id name dataType }
+            ... on ProjectV2SingleSelectField { options { id name } }
+            ... on ProjectV2IterationField {
+              configuration {
+                iterations { id title startDate }
+                completedIterations { id title startDate }
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+}
+"#;
+
+const ISSUE_ITEMS_QUERY: &str = r#"
+query($ids: [ID!]!) {
+  nodes(ids: $ids) {
+    ... on Issue {
+      id
+      projectItems(first: 20) {
+        nodes {
+          id
+          project { id }
+          fieldValues(first: 50) {
+            nodes {
+              __typename
+              ... on ProjectV2ItemFieldSingleSelectValue {
+                name
+                field { ... on ProjectV2FieldCommon { name } }
+              }
+              ... on ProjectV2ItemFieldIterationValue {
+                title
+                field { ... on ProjectV2FieldCommon { name } }
+              }
+              ... on ProjectV2ItemFieldNumberValue {
+                number
+                field { ... on ProjectV2FieldCommon { name } }
+              }
+              ... on ProjectV2ItemFieldDateValue {
+                date
+                field { ... on ProjectV2FieldCommon { name } }
+              }
+              ... on ProjectV2ItemFieldTextValue {
+                text
+                field { ... on ProjectV2FieldCommon { name } }
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+}
+"#;
+
+const ADD_ITEM_MUTATION: &str = r#"
+mutation($projectId: ID!, $contentId: ID!) {
+  addProjectV2ItemById(input: { projectId: $projectId, contentId: $contentId }) {
+    item { id }
+  }
+}
+"#;
+
+const UPDATE_FIELD_MUTATION: &str = r#"
+mutation($projectId: ID!, $itemId: ID!, $fieldId: ID!, $value: ProjectV2FieldValue!) {
+  updateProjectV2ItemFieldValue(
+    input: { projectId: $projectId, itemId: $itemId, fieldId: $fieldId, value: $value }
+  ) {
+    projectV2Item { id }
+  }
+}
+"#;
+
+const CLEAR_FIELD_MUTATION: &str = r#"
+mutation($projectId: ID!, $itemId: ID!, $fieldId: ID!) {
+  clearProjectV2ItemFieldValue(
+    input: { projectId: $projectId, itemId: $itemId, fieldId: $fieldId }
+  ) {
+    projectV2Item { id }
+  }
+}
+"#;
+
+/// `nodes(ids:)` accepts at most 100 IDs per call.
+const MAX_NODE_IDS: usize = 100;
+
+impl GitHubClient {
+    /// GraphQL endpoint for this client's API host.
+    ///
+    /// GitHub Enterprise serves REST under `/api/v3` and GraphQL under
+    /// `/api/graphql`; api.github.com serves GraphQL at `/graphql`.
+    fn graphql_url(&self) -> String {
+        match self.base_url().strip_suffix("/api/v3") {
+            Some(host) => format!("{}/api/graphql", host),
+            None => format!("{}/graphql", self.base_url()),
+        }
+    }
+
+    /// Run a GraphQL query and deserialize its `data`.
+    pub(crate) fn graphql<T: DeserializeOwned>(&self, query: &str, variables: Value) -> Result<T> {
+        let response = self
+            .agent()
+            .post(&self.graphql_url())
+            .header("Authorization", &self.auth_header())
+            .header("Content-Type", "application/json")
+            .send_json(json!({ "query": query, "variables": variables }))
+            .map_err(GitHubError::Http)?;
+
+        let mut response = self.check_response(response)?;
+        let envelope: Value = response.body_mut().read_json()?;
+
+        if let Some(errors) = envelope.get("errors").and_then(Value::as_array)
+            && !errors.is_empty()
+        {
+            if errors
+                .iter()
+                .any(|e| e.get("type").and_then(Value::as_str) == Some("RATE_LIMITED"))
+            {
+                return Err(GitHubError::RateLimited);
+            }
+            let message = errors
+                .iter()
+                .filter_map(|e| e.get("message").and_then(Value::as_str))
+                .collect::<Vec<_>>()
+                .join("; ");
+            return Err(GitHubError::Api {
+                status: 200,
+                message,
+            });
+        }
+
+        match envelope.get("data") {
+            Some(data) if !data.is_null() => Ok(T::deserialize(data)?),
+            _ => Err(GitHubError::Api {
+                status: 200,
+                message: "GitHub GraphQL response did not contain data".to_string(),
+            }),
+        }
+    }
+
+    /// The configured Projects v2 board, fetched once and cached.
+    ///
+    /// Returns `None` when no project number is configured.
+    pub fn project_v2(&self) -> Result<Option<ProjectV2>> {
+        let Some(number) = self.project_number() else {
+            return Ok(None);
+        };
+
+        let mut cache = self.project_cache().lock().unwrap();
+        if let Some(ref project) = *cache {
+            return Ok(Some(project.clone()));
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Owner {
+            project_v2: Option<ProjectV2>,
+        }
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Data {
+            repository_owner: Option<Owner>,
+        }
+
+        let data: Data = self.graphql(
+            PROJECT_QUERY,
+            json!({ "owner": self.owner(), "number": number }),
+        )?;
+        let project = data
+            .repository_owner
+            .and_then(|o| o.project_v2)
+            .ok_or_else(|| {
+                GitHubError::ProjectNotFound(format!("{} project #{}", self.owner(), number))
+            })?;
+
+        *cache = Some(project.clone());
+        Ok(Some(project))
+    }
+
+    /// Board items for a set of issues, keyed by issue node ID.
+    ///
+    /// Issues that are on no board map to an empty list.
+    pub fn project_items_for(
+        &self,
+        issue_node_ids: &[String],
+    ) -> Result<HashMap<String, Vec<ProjectV2Item>>> {
+        #[derive(serde::Deserialize)]
+        struct Data {
+            nodes: Vec<Option<ProjectV2IssueItems>>,
+        }
+
+        let mut items = HashMap::new();
+        for chunk in issue_node_ids.chunks(MAX_NODE_IDS) {
+            let data: Data = self.graphql(ISSUE_ITEMS_QUERY, json!({ "ids": chunk }))?;
+            for issue in data.nodes.into_iter().flatten() {
+                items.insert(issue.id, issue.project_items);
+            }
+        }
+        Ok(items)
+    }
+
+    /// Add an issue (by node ID) to a board, returning the item ID.
+    ///
+    /// GitHub returns the existing item when the issue is already on the board.
+    pub fn add_project_item(&self, project_id: &str, issue_node_id: &str) -> Result<String> {
+        let data: Value = self.graphql(
+            ADD_ITEM_MUTATION,
+            json!({ "projectId": project_id, "contentId": issue_node_id }),
+        )?;
+        data.pointer("/addProjectV2ItemById/item/id")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| GitHubError::Api {
+                status: 200,
+                message: "addProjectV2ItemById returned no item".to_string(),
+            })
+    }
+
+    /// Set a board field on an item. `value` is a `ProjectV2FieldValue` input
+    /// object, e.g. `{"singleSelectOptionId": "..."}` (see
+    /// [`project_field_value`](crate::convert::project_field_value)).
+    pub fn set_project_field(
+        &self,
+        project_id: &str,
+        item_id: &str,
+        field_id: &str,
+        value: Value,
+    ) -> Result<()> {
+        let _: Value = self.graphql(
+            UPDATE_FIELD_MUTATION,
+            json!({
+                "projectId": project_id,
+                "itemId": item_id,
+                "fieldId": field_id,
+                "value": value,
+            }),
+        )?;
+        Ok(())
+    }
+
+    /// Clear a board field on an item.
+    pub fn clear_project_field(
+        &self,
+        project_id: &str,
+        item_id: &str,
+        field_id: &str,
+    ) -> Result<()> {
+        let _: Value = self.graphql(
+            CLEAR_FIELD_MUTATION,
+            json!({ "projectId": project_id, "itemId": item_id, "fieldId": field_id }),
+        )?;
+        Ok(())
+    }
+}
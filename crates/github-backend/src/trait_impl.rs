@@ -2,61 +2,99 @@
 
 use tracker_core::{
     Article, ArticleAttachment, ArticleRef, AttachmentUpload, Comment, CommentAuthor,
-    CreateArticle, CreateIssue, CreateProject, CreateTag, Issue, IssueAttachment,
-    IssueHistoryEvent, IssueLink, IssueTag, IssueTracker, KnowledgeBase, Project,
-    ProjectCustomField, ProjectRef, Result, SearchResult, Tag, TrackerError, UpdateArticle,
-    UpdateIssue,
+    CreateArticle, CreateIssue, CreateProject, CreateTag, CustomFieldUpdate, Issue,
+    IssueAttachment, IssueHistoryEvent, IssueLink, IssueLinkType, IssueTag, IssueTracker,
+    KnowledgeBase, Project, ProjectCustomField, ProjectRef, Result, SearchResult, Tag,
+    TrackerError, UpdateArticle, UpdateIssue, User,
 };
 
 use crate::client::GitHubClient;
 use crate::convert::{
-    convert_query_to_github, create_issue_from_core, get_standard_custom_fields,
-    github_issue_to_core, github_timeline_to_events, update_issue_from_core,
+    GitHubIssueRelations, apply_project_item, convert_query_to_github, create_issue_from_core,
+    get_github_link_types, get_standard_custom_fields, github_issue_state, github_issue_to_core,
+    github_relations_to_core, github_timeline_to_events, is_state_field_name,
+    issue_type_custom_field, project_field_value, project_v2_custom_fields, update_issue_from_core,
 };
+use crate::error::GitHubError;
+use crate::models::{GitHubIssue, ProjectV2};
 use crate::wiki::WikiPage;
 
-/// Parse an issue number from a string identifier.
+/// An issue identifier split into its parts.
+#[derive(Debug, PartialEq, Eq)]
+struct IssueRef<'a> {
+    owner: Option<&'a str>,
+    repo: Option<&'a str>,
+    number: u64,
+}
+
+/// Parse an issue identifier.
 ///
 /// Accepts:
 /// - A raw number: "42"
+/// - A repo#number format: "repo#42" (a repository of the configured owner)
 /// - An owner/repo#number format: "owner/repo#42"
-fn parse_issue_number(id: &str) -> std::result::Result<u64, TrackerError> {
+fn parse_issue_ref(id: &str) -> std::result::Result<IssueRef<'_>, TrackerError> {
     // Try parsing as a raw number first
-    if let Ok(n) = id.parse::<u64>() {
-        return Ok(n);
+    if let Ok(number) = id.parse::<u64>() {
+        return Ok(IssueRef {
+            owner: None,
+            repo: None,
+            number,
+        });
     }
 
-    // Try owner/repo#number format
-    if let Some(hash_pos) = id.rfind('#') {
-        let number_str = &id[hash_pos + 1..];
-        if let Ok(n) = number_str.parse::<u64>() {
-            return Ok(n);
-        }
+    if let Some((path, number_str)) = id.rsplit_once('#')
+        && let Ok(number) = number_str.parse::<u64>()
+    {
+        let (owner, repo) = match path.split_once('/') {
+            Some((owner, repo)) => (Some(owner), Some(repo)),
+            None => (None, Some(path)),
+        };
+        return Ok(IssueRef {
+            owner: owner.filter(|o| !o.is_empty()),
+            repo: repo.filter(|r| !r.is_empty()),
+            number,
+        });
     }
 
     Err(TrackerError::InvalidInput(format!(
-        "Invalid GitHub issue identifier: '{}'. Expected a number or 'owner/repo#number' format.",
+        "Invalid GitHub issue identifier: '{}'. Expected a number, 'repo#number' or 'owner/repo#number' format.",
         id
     )))
 }
 
+/// A client addressing one repository: the configured one or a sibling.
+enum RepoClient<'a> {
+    Home(&'a GitHubClient),
+    Other(Box<GitHubClient>),
+}
+
+impl std::ops::Deref for RepoClient<'_> {
+    type Target = GitHubClient;
+
+    fn deref(&self) -> &GitHubClient {
+        match self {
+            RepoClient::Home(client) => client,
+            RepoClient::Other(client) => client,
+        }
+    }
+}
+
 impl IssueTracker for GitHubClient {
     fn get_issue(&self, id: &str) -> Result<Issue> {
-        let number = parse_issue_number(id)?;
-        let issue = self.get_issue(number)?;
+        let (client, number) = self.locate(id)?;
+        let issue = client.get_issue(number)?;
 
         // If this is a PR, report as not found
         if issue.is_pull_request() {
             return Err(TrackerError::IssueNotFound(id.to_string()));
         }
 
-        Ok(github_issue_to_core(issue, self.owner(), self.repo()))
+        self.issue_to_core(issue)
     }
 
     fn search_issues(&self, query: &str, limit: usize, skip: usize) -> Result<SearchResult<Issue>> {
         let github_query = convert_query_to_github(query);
-        let owner = self.owner().to_string();
-        let repo = self.repo().to_string();
 
         if limit == 0 {
             return Ok(SearchResult::from_items(Vec::new()));
@@ -82,8 +120,7 @@ impl IssueTracker for GitHubClient {
                     .into_iter()
                     .filter(|i| !i.is_pull_request())
                     .skip(page_offset)
-                    .take(remaining)
-                    .map(|i| github_issue_to_core(i, &owner, &repo)),
+                    .take(remaining),
             );
 
             if page_len < per_page {
@@ -94,6 +131,7 @@ impl IssueTracker for GitHubClient {
             page_offset = 0;
         }
 
+        let items = self.issues_to_core(items)?;
         if let Some(total) = total {
             Ok(SearchResult::with_total(items, total))
         } else {
@@ -106,30 +144,47 @@ impl IssueTracker for GitHubClient {
     }
 
     fn create_issue(&self, issue: &CreateIssue) -> Result<Issue> {
+        // Resolve board values first so a bad one fails before anything is created
+        let (board, _) = self.split_board_updates(&issue.custom_fields)?;
         let github_issue = create_issue_from_core(issue);
-        let created = self.create_issue(&github_issue)?;
+        let created = self
+            .project_client(&issue.project_id)?
+            .create_issue(&github_issue)?;
 
         // If a parent was requested, add as sub-issue via the sub-issues API
         if let Some(ref parent_id) = issue.parent {
-            let parent_number = parse_issue_number(parent_id)?;
-            self.add_sub_issue(parent_number, created.id)?;
+            let (parent, parent_number) = self.locate(parent_id)?;
+            parent.add_sub_issue(parent_number, created.id)?;
+        }
+
+        // New issues join the configured board even without field values
+        if let Some(board) = board {
+            self.apply_board_update(&created, &board)?;
         }
 
-        Ok(github_issue_to_core(created, self.owner(), self.repo()))
+        self.issue_to_core(created)
     }
 
     fn update_issue(&self, id: &str, update: &UpdateIssue) -> Result<Issue> {
-        let number = parse_issue_number(id)?;
-        let github_update = update_issue_from_core(update);
-        let updated = self.update_issue(number, &github_update)?;
+        let (client, number) = self.locate(id)?;
+        let (board, custom_fields) = self.split_board_updates(&update.custom_fields)?;
+        let github_update = update_issue_from_core(&UpdateIssue {
+            custom_fields,
+            ..update.clone()
+        });
+        let updated = client.update_issue(number, &github_update)?;
 
         // If a parent was requested, add as sub-issue via the sub-issues API
         if let Some(ref parent_id) = update.parent {
-            let parent_number = parse_issue_number(parent_id)?;
-            self.add_sub_issue(parent_number, updated.id)?;
+            let (parent, parent_number) = self.locate(parent_id)?;
+            parent.add_sub_issue(parent_number, updated.id)?;
         }
 
-        Ok(github_issue_to_core(updated, self.owner(), self.repo()))
+        if let Some(board) = board.filter(|b| !b.values.is_empty()) {
+            self.apply_board_update(&updated, &board)?;
+        }
+
+        self.issue_to_core(updated)
     }
 
     fn delete_issue(&self, _id: &str) -> Result<()> {
@@ -158,7 +213,12 @@ impl IssueTracker for GitHubClient {
     }
 
     fn list_projects(&self) -> Result<Vec<Project>> {
-        Ok(self.list_repos()?.into_iter().map(Into::into).collect())
+        let repos = if self.org_filter().is_some() {
+            self.org_repos()?
+        } else {
+            self.list_repos()?
+        };
+        Ok(repos.into_iter().map(Into::into).collect())
     }
 
     fn get_project(&self, id: &str) -> Result<Project> {
@@ -202,11 +262,47 @@ impl IssueTracker for GitHubClient {
     }
 
     fn get_project_custom_fields(&self, _project_id: &str) -> Result<Vec<ProjectCustomField>> {
-        Ok(get_standard_custom_fields())
+        let mut fields = get_standard_custom_fields();
+        if let Some(project) = self.project_v2()? {
+            // The board's Status column supersedes the open/closed state
+            if project.status_field().is_some() {
+                fields.retain(|f| f.id != "status");
+            }
+            fields.extend(project_v2_custom_fields(&project));
+        }
+        // A board field named Type takes precedence over the issue type
+        let types = self.list_issue_types()?;
+        if !types.is_empty() && !fields.iter().any(|f| f.name.eq_ignore_ascii_case("type")) {
+            fields.push(issue_type_custom_field(&types));
+        }
+        Ok(fields)
+    }
+
+    fn list_project_users(&self, project_id: &str) -> Result<Vec<User>> {
+        Ok(self
+            .project_client(project_id)?
+            .list_assignees()?
+            .into_iter()
+            .map(Into::into)
+            .collect())
     }
 
     fn list_tags(&self) -> Result<Vec<IssueTag>> {
-        Ok(self.list_labels()?.into_iter().map(Into::into).collect())
+        if self.org_filter().is_none() {
+            return Ok(self.list_labels()?.into_iter().map(Into::into).collect());
+        }
+
+        // Labels are per repository; list each name once across the org
+        let mut seen = HashSet::new();
+        let mut tags = Vec::new();
+        for repo in self.org_repos()? {
+            for label in self.in_repo(&repo.owner.login, &repo.name).list_labels()? {
+                if seen.insert(label.name.to_lowercase()) {
+                    tags.push(label.into());
+                }
+            }
+        }
+        Ok(tags)
     }
 
     fn create_tag(&self, tag: &CreateTag) -> Result<IssueTag> {
@@ -223,11 +319,11 @@ impl IssueTracker for GitHubClient {
             description: tag.description.clone(),
         };
 
-        Ok(self.create_label(&create)?.into())
+        Ok(self.repo_client(None, None)?.create_label(&create)?.into())
     }
 
     fn delete_tag(&self, name: &str) -> Result<()> {
-        Ok(self.delete_label(name)?)
+        Ok(self.repo_client(None, None)?.delete_label(name)?)
     }
 
     fn update_tag(&self, current_name: &str, tag: &CreateTag) -> Result<IssueTag> {
@@ -248,40 +344,112 @@ impl IssueTracker for GitHubClient {
             description: tag.description.clone(),
         };
 
-        Ok(self.update_label(current_name, &update)?.into())
+        Ok(self
+            .repo_client(None, None)?
+            .update_label(current_name, &update)?
+            .into())
     }
 
-    fn get_issue_links(&self, _issue_id: &str) -> Result<Vec<IssueLink>> {
-        // GitHub has no formal issue link system
-        Ok(Vec::new())
+    fn list_link_types(&self) -> Result<Vec<IssueLinkType>> {
+        Ok(get_github_link_types())
+    }
+
+    fn get_issue_links(&self, issue_id: &str) -> Result<Vec<IssueLink>> {
+        let (client, number) = self.locate(issue_id)?;
+        let relations = GitHubIssueRelations {
+            parent: client.get_parent_issue(number)?,
+            sub_issues: unless_unavailable(client.list_sub_issues(number))?,
+            blocked_by: unless_unavailable(client.list_blocked_by(number))?,
+            blocking: unless_unavailable(client.list_blocking(number))?,
+        };
+        Ok(github_relations_to_core(
+            relations,
+            client.owner(),
+            client.repo(),
+            |owner, repo, number| self.issue_ref(owner, repo, number),
+        ))
     }
 
     fn link_issues(
         &self,
-        _source: &str,
-        _target: &str,
-        _link_type: &str,
+        source: &str,
+        target: &str,
+        link_type: &str,
         _direction: &str,
     ) -> Result<()> {
-        Err(TrackerError::InvalidInput(
-            "GitHub does not support formal issue links. Reference issues via #number in comments instead."
-                .to_string(),
-        ))
+        match link_type.to_lowercase().as_str() {
+            "subtask" | "subtask-of" => self.link_subtask(source, target),
+            "parent" | "parent-of" => self.link_subtask(target, source),
+            // source is blocked by target
+            "depends" | "blocked-by" | "is-blocked-by" => self.add_dependency(source, target),
+            // source blocks target
+            "required" | "blocks" => self.add_dependency(target, source),
+            other => Err(TrackerError::InvalidInput(format!(
+                "GitHub supports only sub-issue and dependency links (subtask, parent, depends, blocks), not '{}'. Reference issues via #number in comments instead.",
+                other
+            ))),
+        }
     }
 
     fn link_subtask(&self, child: &str, parent: &str) -> Result<()> {
-        let child_number = parse_issue_number(child)?;
-        let parent_number = parse_issue_number(parent)?;
+        let (child_client, child_number) = self.locate(child)?;
+        let (parent_client, parent_number) = self.locate(parent)?;
 
         // Fetch the child issue to get its global ID (the sub-issues API needs id, not number)
-        let child_issue = self.get_issue(child_number)?;
+        let child_issue = child_client.get_issue(child_number)?;
 
-        Ok(self.add_sub_issue(parent_number, child_issue.id)?)
+        Ok(parent_client.add_sub_issue(parent_number, child_issue.id)?)
+    }
+
+    fn remove_issue_parent(&self, issue_id: &str) -> Result<()> {
+        let (client, number) = self.locate(issue_id)?;
+        let Some(parent) = client.get_parent_issue(number)? else {
+            return Ok(());
+        };
+        let issue = client.get_issue(number)?;
+        let parent_client = self.client_for(&parent);
+        Ok(parent_client.remove_sub_issue(parent.number, issue.id)?)
+    }
+
+    fn unlink_issues(&self, source: &str, link_id: &str) -> Result<()> {
+        let (client, number) = self.locate(source)?;
+        let invalid = || {
+            TrackerError::InvalidInput(format!(
+                "Invalid GitHub link ID: '{}'. Use an ID from `issue get --full`.",
+                link_id
+            ))
+        };
+        let (kind, other) = link_id.split_once(':').ok_or_else(invalid)?;
+        let global_id = || other.parse::<u64>().map_err(|_| invalid());
+
+        match kind {
+            "parent" => {
+                let issue = client.get_issue(number)?;
+                let (parent, parent_number) = self.locate(other)?;
+                Ok(parent.remove_sub_issue(parent_number, issue.id)?)
+            }
+            "subtask" => Ok(client.remove_sub_issue(number, global_id()?)?),
+            "blocked-by" => Ok(client.remove_blocked_by(number, global_id()?)?),
+            "blocking" => {
+                let issue = client.get_issue(number)?;
+                let (blocked, blocked_number) = self.locate(other)?;
+                Ok(blocked.remove_blocked_by(blocked_number, issue.id)?)
+            }
+            _ => Err(TrackerError::InvalidInput(format!(
+                "Unknown GitHub link kind '{}' in link ID '{}'",
+                kind, link_id
+            ))),
+        }
+    }
+
+    fn remove_issue_tag(&self, issue_id: &str, tag: &str) -> Result<()> {
+        let (client, number) = self.locate(issue_id)?;
+        Ok(client.remove_issue_label(number, tag)?)
     }
 
     fn add_comment(&self, issue_id: &str, text: &str) -> Result<Comment> {
-        let number = parse_issue_number(issue_id)?;
-        Ok(self.add_comment(number, text)?.into())
+        let (client, number) = self.locate(issue_id)?;
+        Ok(client.add_comment(number, text)?.into())
     }
 
     fn add_issue_comment_attachment(
@@ -297,8 +465,8 @@ impl IssueTracker for GitHubClient {
     }
 
     fn get_comments(&self, issue_id: &str) -> Result<Vec<Comment>> {
-        let number = parse_issue_number(issue_id)?;
-        Ok(self
+        let (client, number) = self.locate(issue_id)?;
+        Ok(client
             .get_comments(number)?
             .into_iter()
             .map(Into::into)
@@ -310,14 +478,14 @@ impl IssueTracker for GitHubClient {
             return Ok(Vec::new());
         }
 
-        let number = parse_issue_number(issue_id)?;
+        let (client, number) = self.locate(issue_id)?;
         let per_page = 100;
         let mut page = (skip / per_page) + 1;
         let mut page_offset = skip % per_page;
         let mut comments = Vec::new();
 
         while comments.len() < limit {
-            let page_comments = self.get_comments_page(number, per_page, page)?;
+            let page_comments = client.get_comments_page(number, per_page, page)?;
             let page_len = page_comments.len();
             let remaining = limit - comments.len();
 
@@ -341,12 +509,226 @@ impl IssueTracker for GitHubClient {
     }
 
     fn get_issue_history(&self, issue_id: &str) -> Result<Vec<IssueHistoryEvent>> {
-        let number = parse_issue_number(issue_id)?;
-        let timeline = self.get_issue_timeline(number)?;
+        let (client, number) = self.locate(issue_id)?;
+        let timeline = client.get_issue_timeline(number)?;
         Ok(github_timeline_to_events(timeline))
     }
 }
 
+/// Projects (v2) field writes split out of an issue update. Values are encoded
+/// up front so an invalid one fails before anything is written.
+struct BoardUpdate {
+    project: ProjectV2,
+    /// (field ID, encoded value); `None` clears the field
+    values: Vec<(String, Option<serde_json::Value>)>,
+}
+
+/// Treat a 404 from the sub-issue or dependency APIs as "no relations":
+/// GitHub Enterprise Server releases without those features answer 404.
+fn unless_unavailable(
+    result: crate::error::Result<Vec<GitHubIssue>>,
+) -> crate::error::Result<Vec<GitHubIssue>> {
+    match result {
+        Err(GitHubError::Api { status: 404, .. }) => Ok(Vec::new()),
+        other => other,
+    }
+}
+
+impl GitHubClient {
+    /// The client for an issue's repository and the issue number.
+    fn locate(&self, id: &str) -> Result<(RepoClient<'_>, u64)> {
+        let issue = parse_issue_ref(id)?;
+        Ok((self.repo_client(issue.owner, issue.repo)?, issue.number))
+    }
+
+    /// The client for a repository, defaulting to the configured owner and
+    /// repository.
+    fn repo_client(&self, owner: Option<&str>, repo: Option<&str>) -> Result<RepoClient<'_>> {
+        let owner = owner.unwrap_or(self.owner());
+        match repo.or(self.home_repo()) {
+            Some(repo)
+                if owner.eq_ignore_ascii_case(self.owner())
+                    && repo.eq_ignore_ascii_case(self.repo()) =>
+            {
+                Ok(RepoClient::Home(self))
+            }
+            Some(repo) => Ok(RepoClient::Other(Box::new(self.in_repo(owner, repo)))),
+            None => Err(TrackerError::InvalidInput(
+                "No default GitHub repository in org scope. Address issues as 'repo#number', or set github.repo."
+                    .to_string(),
+            )),
+        }
+    }
+
+    /// The client for a project: `owner/repo`, a repository name, or the
+    /// numeric repository ID `list_projects` reports.
+    fn project_client(&self, project_id: &str) -> Result<RepoClient<'_>> {
+        if let Some((owner, repo)) = project_id.split_once('/') {
+            return self.repo_client(Some(owner), Some(repo));
+        }
+        if project_id.is_empty() {
+            return self.repo_client(None, None);
+        }
+        if project_id.parse::<u64>().is_err() {
+            return self.repo_client(None, Some(project_id));
+        }
+        if self.org_filter().is_none() {
+            return Ok(RepoClient::Home(self));
+        }
+        let repo = self
+            .org_repos()?
+            .into_iter()
+            .find(|r| r.id.to_string() == project_id)
+            .ok_or_else(|| TrackerError::ProjectNotFound(project_id.to_string()))?;
+        self.repo_client(Some(&repo.owner.login), Some(&repo.name))
+    }
+
+    /// The client for the repository an issue lives in.
+    fn client_for(&self, issue: &GitHubIssue) -> RepoClient<'_> {
+        match issue.repository().and_then(|r| r.split_once('/')) {
+            Some((owner, repo)) => self
+                .repo_client(Some(owner), Some(repo))
+                .unwrap_or(RepoClient::Home(self)),
+            None => RepoClient::Home(self),
+        }
+    }
+
+    /// Record that `blocked` is blocked by `blocker`.
+    fn add_dependency(&self, blocked: &str, blocker: &str) -> Result<()> {
+        let (blocked_client, blocked_number) = self.locate(blocked)?;
+        let (blocker_client, blocker_number) = self.locate(blocker)?;
+
+        // The dependencies API takes the blocker's global ID, not its number
+        let blocker_issue = blocker_client.get_issue(blocker_number)?;
+
+        Ok(blocked_client.add_blocked_by(blocked_number, blocker_issue.id)?)
+    }
+
+    fn issue_to_core(&self, issue: GitHubIssue) -> Result<Issue> {
+        Ok(self.issues_to_core(vec![issue])?.remove(0))
+    }
+
+    /// Convert an issue from whichever repository it lives in, addressing it
+    /// by [`issue_ref`](GitHubClient::issue_ref).
+    fn github_issue_to_core(&self, issue: GitHubIssue) -> Issue {
+        let (owner, repo) = match issue.repository().and_then(|r| r.split_once('/')) {
+            Some((owner, repo)) => (owner.to_string(), repo.to_string()),
+            None => (self.owner().to_string(), self.repo().to_string()),
+        };
+        let id = self.issue_ref(&owner, &repo, issue.number);
+        Issue {
+            id,
+            ..github_issue_to_core(issue, &owner, &repo)
+        }
+    }
+
+    /// Convert issues, merging in their values from the configured board.
+    fn issues_to_core(&self, issues: Vec<GitHubIssue>) -> Result<Vec<Issue>> {
+        let Some(project) = self.project_v2()? else {
+            return Ok(issues
+                .into_iter()
+                .map(|i| self.github_issue_to_core(i))
+                .collect());
+        };
+
+        let node_ids: Vec<String> = issues
+            .iter()
+            .filter_map(|i| i.node_id().map(str::to_string))
+            .collect();
+        let items = self.project_items_for(&node_ids)?;
+
+        Ok(issues
+            .into_iter()
+            .map(|issue| {
+                let item = issue
+                    .node_id()
+                    .and_then(|id| items.get(id))
+                    .and_then(|items| items.iter().find(|i| i.project.id == project.id));
+                let mut core = self.github_issue_to_core(issue);
+                if let Some(item) = item {
+                    apply_project_item(&mut core, &project, item);
+                }
+                core
+            })
+            .collect())
+    }
+
+    /// Split custom field updates into board field writes and the rest.
+    ///
+    /// A state update goes to the board's Status column when it names one of
+    /// its options; open/closed-like values are also kept for the issue
+    /// itself, so completing an issue moves the card and closes the issue.
+    fn split_board_updates(
+        &self,
+        fields: &[CustomFieldUpdate],
+    ) -> Result<(Option<BoardUpdate>, Vec<CustomFieldUpdate>)> {
+        let Some(project) = self.project_v2()? else {
+            return Ok((None, fields.to_vec()));
+        };
+
+        let mut values = Vec::new();
+        let mut rest = Vec::new();
+        for cf in fields {
+            let (field, value) = match cf {
+                CustomFieldUpdate::State { name, value } if is_state_field_name(name) => {
+                    let Some(status) = project.status_field() else {
+                        rest.push(cf.clone());
+                        continue;
+                    };
+                    let on_board = status
+                        .options
+                        .iter()
+                        .any(|o| o.name.eq_ignore_ascii_case(value));
+                    let is_issue_state = github_issue_state(value).is_some();
+                    if is_issue_state {
+                        rest.push(cf.clone());
+                        if !on_board {
+                            continue;
+                        }
+                    }
+                    // A value that is neither fails below with the board's options
+                    (status, value)
+                }
+                CustomFieldUpdate::SingleEnum { name, value }
+                | CustomFieldUpdate::State { name, value } => match project.field(name) {
+                    Some(f) => (f, value),
+                    None => {
+                        rest.push(cf.clone());
+                        continue;
+                    }
+                },
+                _ => {
+                    rest.push(cf.clone());
+                    continue;
+                }
+            };
+            values.push((field.id.clone(), project_field_value(field, value)?));
+        }
+
+        Ok((Some(BoardUpdate { project, values }), rest))
+    }
+
+    /// Put the issue on the board (a no-op if it already is) and write the
+    /// board values.
+    fn apply_board_update(&self, issue: &GitHubIssue, board: &BoardUpdate) -> Result<()> {
+        let node_id = issue.node_id().ok_or_else(|| TrackerError::Api {
+            status: 200,
+            message: format!("GitHub issue #{} has no node_id", issue.number),
+        })?;
+        let project_id = &board.project.id;
+        let item_id = self.add_project_item(project_id, node_id)?;
+        for (field_id, value) in &board.values {
+            match value {
+                Some(value) => {
+                    self.set_project_field(project_id, &item_id, field_id, value.clone())?
+                }
+                None => self.clear_project_field(project_id, &item_id, field_id)?,
+            }
+        }
+        Ok(())
+    }
+}
+
 // ============================================================================
 // KnowledgeBase Implementation
 // ============================================================================
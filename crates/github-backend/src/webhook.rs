@@ -0,0 +1,126 @@
+//! Normalize GitHub `issues` and `issue_comment` webhook deliveries.
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use tracker_core::{TrackerEvent, TrackerEventKind};
+
+const SOURCE: &str = "github";
+
+/// Convert a delivery into a [`TrackerEvent`]. `event` is the
+/// `X-GitHub-Event` header. Pull requests, deletions, and other event types
+/// yield `None`.
+pub fn parse_webhook(event: Option<&str>, payload: &Value) -> Option<TrackerEvent> {
+    let issue = payload.get("issue")?;
+    // issue_comment also fires for pull request conversations
+    if issue.get("pull_request").is_some() {
+        return None;
+    }
+
+    let action = payload["action"].as_str()?;
+    let kind = match (event?, action) {
+        ("issues", "opened") => TrackerEventKind::IssueCreated,
+        ("issues", "closed" | "reopened") => TrackerEventKind::IssueTransitioned,
+        ("issues", "deleted" | "transferred") => return None,
+        ("issues", _) => TrackerEventKind::IssueUpdated,
+        ("issue_comment", "created") => TrackerEventKind::IssueCommented,
+        _ => return None,
+    };
+
+    let number = issue["number"].as_u64()?;
+    let repo = payload["repository"]["full_name"].as_str();
+    let id = match repo {
+        Some(repo) => format!("{}#{}", repo, number),
+        None => format!("#{}", number),
+    };
+
+    let mut tracker_event = TrackerEvent::new(SOURCE, kind, id);
+    tracker_event.summary = issue["title"].as_str().map(String::from);
+    tracker_event.project = repo.map(String::from);
+    tracker_event.actor = payload["sender"]["login"].as_str().map(String::from);
+    tracker_event.url = issue["html_url"].as_str().map(String::from);
+    tracker_event.at = timestamp(&issue["updated_at"]);
+
+    match kind {
+        TrackerEventKind::IssueTransitioned => {
+            let (from, to) = if action == "closed" {
+                ("open", "closed")
+            } else {
+                ("closed", "open")
+            };
+            tracker_event.from = Some(from.to_string());
+            tracker_event.to = Some(to.to_string());
+        }
+        TrackerEventKind::IssueCommented => {
+            let comment = &payload["comment"];
+            tracker_event.comment = comment["body"].as_str().map(String::from);
+            tracker_event.url = comment["html_url"]
+                .as_str()
+                .map(String::from)
+                .or(tracker_event.url);
+            tracker_event.at = timestamp(&comment["created_at"]).or(tracker_event.at);
+        }
+        _ => {}
+    }
+
+    Some(tracker_event)
+}
+
+fn timestamp(value: &Value) -> Option<DateTime<Utc>> {
+    value.as_str()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn issue_payload(action: &str) -> Value {
+        json!({
+            "action": action,
+            "issue": {
+                "number": 42,
+                "title": "Crash on start",
+                "state": "closed",
+                "html_url": "https://github.com/acme/app/issues/42",
+                "updated_at": "2026-05-01T12:00:00Z"
+            },
+            "repository": { "full_name": "acme/app" },
+            "sender": { "login": "octocat" }
+        })
+    }
+
+    #[test]
+    fn maps_issue_actions() {
+        let event = parse_webhook(Some("issues"), &issue_payload("opened")).unwrap();
+        assert_eq!(event.kind, TrackerEventKind::IssueCreated);
+        assert_eq!(event.issue, "acme/app#42");
+        assert_eq!(event.actor.as_deref(), Some("octocat"));
+
+        let event = parse_webhook(Some("issues"), &issue_payload("closed")).unwrap();
+        assert_eq!(event.kind, TrackerEventKind::IssueTransitioned);
+        assert_eq!(event.to.as_deref(), Some("closed"));
+
+        let event = parse_webhook(Some("issues"), &issue_payload("labeled")).unwrap();
+        assert_eq!(event.kind, TrackerEventKind::IssueUpdated);
+
+        assert!(parse_webhook(Some("issues"), &issue_payload("deleted")).is_none());
+        assert!(parse_webhook(Some("ping"), &json!({ "zen": "Keep it simple." })).is_none());
+    }
+
+    #[test]
+    fn maps_issue_comments_but_not_pull_request_comments() {
+        let mut payload = issue_payload("created");
+        payload["comment"] = json!({
+            "body": "Reproduced on 1.2",
+            "html_url": "https://github.com/acme/app/issues/42#issuecomment-1",
+            "created_at": "2026-05-01T12:05:00Z"
+        });
+        let event = parse_webhook(Some("issue_comment"), &payload).unwrap();
+        assert_eq!(event.kind, TrackerEventKind::IssueCommented);
+        assert_eq!(event.comment.as_deref(), Some("Reproduced on 1.2"));
+        assert_eq!(event.at, "2026-05-01T12:05:00Z".parse().ok());
+
+        payload["issue"]["pull_request"] = json!({ "url": "..." });
+        assert!(parse_webhook(Some("issue_comment"), &payload).is_none());
+    }
+}
@@ -399,7 +399,7 @@ impl WikiManager {
                 let yaml_lines = &lines[1..end_idx + 1];
                 let yaml_str = yaml_lines.join("\n");
 
-                if let Ok(front_matter) = serde_yaml::from_str::<FrontMatter>(&yaml_str) {
+                if let Ok(front_matter) = serde_norway::from_str::<FrontMatter>(&yaml_str) {
                     let content_start = (end_idx + 2).min(lines.len());
                     let content_lines = &lines[content_start..];
                     let content = content_lines.join("\n");
@@ -732,7 +732,7 @@ impl WikiManager {
             tags: tags.to_vec(),
         };
 
-        let yaml = match serde_yaml::to_string(&front_matter) {
+        let yaml = match serde_norway::to_string(&front_matter) {
             Ok(y) => y.trim_start_matches("---\n").trim().to_string(),
             Err(_) => return content.to_string(),
         };
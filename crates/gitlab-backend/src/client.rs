@@ -287,6 +287,34 @@ impl GitLabClient {
         Ok(updated)
     }
 
+    /// Add spent time to an issue; `duration` uses GitLab's human format (e.g. "90m")
+    pub fn add_spent_time(
+        &self,
+        iid: u64,
+        duration: &str,
+        summary: Option<&str>,
+    ) -> Result<GitLabTimeStats> {
+        let url = self.project_url(&format!("/issues/{}/add_spent_time", iid))?;
+
+        let mut body = serde_json::json!({ "duration": duration });
+        if let Some(summary) = summary {
+            body["summary"] = serde_json::Value::String(summary.to_string());
+        }
+
+        let response = self
+            .agent
+            .post(&url)
+            .header("PRIVATE-TOKEN", &self.token)
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .send_json(&body)
+            .map_err(|e| self.handle_error(e))?;
+
+        let mut response = self.check_response(response)?;
+        let stats: GitLabTimeStats = response.body_mut().read_json()?;
+        Ok(stats)
+    }
+
     /// Delete an issue
     pub fn delete_issue(&self, iid: u64) -> Result<()> {
         let url = self.project_url(&format!("/issues/{}", iid))?;
@@ -709,11 +737,25 @@ impl GitLabClient {
     /// Both IDs must be **global** numeric IDs (not project-scoped IIDs).
     /// The mutation uses the `hierarchyWidget` on `workItemUpdate`.
     pub fn set_work_item_parent(&self, child_global_id: u64, parent_global_id: u64) -> Result<()> {
+        let parent = format!(r#""gid://gitlab/Issue/{}""#, parent_global_id);
+        self.update_work_item_parent(child_global_id, &parent)
+    }
+
+    /// Clear the parent of a work item (issue) using the GitLab GraphQL API.
+    ///
+    /// The ID must be a **global** numeric ID (not a project-scoped IID).
+    pub fn remove_work_item_parent(&self, child_global_id: u64) -> Result<()> {
+        self.update_work_item_parent(child_global_id, "null")
+    }
+
+    /// Run `workItemUpdate` with `hierarchyWidget.parentId` set to the given
+    /// GraphQL literal (a quoted global ID, or `null`).
+    fn update_work_item_parent(&self, child_global_id: u64, parent_literal: &str) -> Result<()> {
         let graphql_url = self.graphql_url();
 
         let query = format!(
-            r#"mutation {{ workItemUpdate(input: {{ id: "gid://gitlab/Issue/{}", hierarchyWidget: {{ parentId: "gid://gitlab/Issue/{}" }} }}) {{ workItem {{ id }} errors }} }}"#,
-            child_global_id, parent_global_id
+            r#"mutation {{ workItemUpdate(input: {{ id: "gid://gitlab/Issue/{}", hierarchyWidget: {{ parentId: {} }} }}) {{ workItem {{ id }} errors }} }}"#,
+            child_global_id, parent_literal
         );
 
         let body = serde_json::json!({ "query": query });
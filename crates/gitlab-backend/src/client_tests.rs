@@ -1122,4 +1122,46 @@ mod tests {
         assert_eq!(events[3].to.as_deref(), Some("opened"));
         assert_eq!(events[3].author.as_ref().unwrap().login, "alice");
     }
+
+    #[tokio::test]
+    async fn test_remove_label_and_log_spent_time() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("PUT"))
+            .and(path("/projects/123/issues/42"))
+            .and(wiremock::matchers::body_json(
+                serde_json::json!({ "remove_labels": "stale" }),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(mock_gitlab_issue(42, "Labelled")),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/projects/123/issues/42/add_spent_time"))
+            .and(wiremock::matchers::body_json(
+                serde_json::json!({ "duration": "90m", "summary": "Pairing" }),
+            ))
+            .respond_with(
+                ResponseTemplate::new(201)
+                    .set_body_json(serde_json::json!({ "total_time_spent": 5400 })),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = GitLabClient::new(&mock_server.uri(), "test-token", Some("123"));
+        IssueTracker::remove_issue_tag(&client, "#42", "stale").unwrap();
+
+        let work = tracker_core::LogWork {
+            minutes: 90,
+            text: Some("Pairing".to_string()),
+            date: None,
+        };
+        let item = IssueTracker::log_work(&client, "42", &work).unwrap();
+        assert_eq!(item.minutes, 90);
+        assert!(item.id.is_none());
+    }
 }
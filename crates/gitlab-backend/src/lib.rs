@@ -3,6 +3,7 @@ mod convert;
 pub mod error;
 pub mod models;
 mod trait_impl;
+pub mod webhook;
 
 #[cfg(test)]
 mod client_tests;
@@ -90,6 +90,9 @@ pub struct UpdateGitLabIssue {
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub labels: Option<String>,
+    /// Comma-separated labels to remove, leaving the others in place
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove_labels: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state_event: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -98,6 +101,13 @@ pub struct UpdateGitLabIssue {
     pub milestone_id: Option<u64>,
 }
 
+/// Issue time tracking totals, returned by the spent-time endpoints
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitLabTimeStats {
+    #[serde(default)]
+    pub total_time_spent: u64,
+}
+
 /// Request to create an issue link
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateGitLabIssueLink {
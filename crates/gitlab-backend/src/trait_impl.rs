@@ -3,8 +3,8 @@
 use tracker_core::{
     Article, ArticleAttachment, ArticleRef, AttachmentUpload, Comment, CreateArticle, CreateIssue,
     CreateProject, CreateTag, Issue, IssueAttachment, IssueHistoryEvent, IssueLink, IssueLinkType,
-    IssueTag, IssueTracker, KnowledgeBase, Project, ProjectCustomField, ProjectRef, Result,
-    SearchResult, TrackerError, UpdateArticle, UpdateIssue, User,
+    IssueTag, IssueTracker, KnowledgeBase, LogWork, Project, ProjectCustomField, ProjectRef,
+    Result, SearchResult, TrackerError, UpdateArticle, UpdateIssue, User, WorkItem,
 };
 
 use crate::client::GitLabClient;
@@ -164,6 +164,7 @@ impl IssueTracker for GitLabClient {
             title: update.summary.clone(),
             description: update.description.clone(),
             labels,
+            remove_labels: None,
             state_event,
             assignee_ids: None,
             milestone_id: None,
@@ -174,6 +175,7 @@ impl IssueTracker for GitLabClient {
         let has_rest_fields = gitlab_update.title.is_some()
             || gitlab_update.description.is_some()
             || gitlab_update.labels.is_some()
+            || gitlab_update.remove_labels.is_some()
             || gitlab_update.state_event.is_some()
             || gitlab_update.assignee_ids.is_some()
             || gitlab_update.milestone_id.is_some();
@@ -386,6 +388,38 @@ impl IssueTracker for GitLabClient {
         Ok(self.set_work_item_parent(child_issue.id, parent_issue.id)?)
     }
 
+    fn remove_issue_parent(&self, issue_id: &str) -> Result<()> {
+        let iid = parse_issue_iid(issue_id)?;
+        let issue = self.get_issue(iid)?;
+        Ok(self.remove_work_item_parent(issue.id)?)
+    }
+
+    fn remove_issue_tag(&self, issue_id: &str, tag: &str) -> Result<()> {
+        let iid = parse_issue_iid(issue_id)?;
+        let update = UpdateGitLabIssue {
+            remove_labels: Some(tag.to_string()),
+            ..Default::default()
+        };
+        self.update_issue(iid, &update)?;
+        Ok(())
+    }
+
+    fn log_work(&self, issue_id: &str, work: &LogWork) -> Result<WorkItem> {
+        if work.date.is_some() {
+            return Err(TrackerError::InvalidInput(
+                "GitLab records spent time on the current day; omit the work date".to_string(),
+            ));
+        }
+        let iid = parse_issue_iid(issue_id)?;
+        let duration = format!("{}m", work.minutes);
+        self.add_spent_time(iid, &duration, work.text.as_deref())?;
+        Ok(WorkItem {
+            id: None,
+            minutes: work.minutes,
+            text: work.text.clone(),
+        })
+    }
+
     fn add_comment(&self, issue_id: &str, text: &str) -> Result<Comment> {
         let iid = parse_issue_iid(issue_id)?;
         Ok(self.add_note(iid, text)?.into())
@@ -0,0 +1,116 @@
+//! Normalize GitLab issue and note (comment) webhook deliveries.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde_json::Value;
+use tracker_core::{TrackerEvent, TrackerEventKind};
+
+const SOURCE: &str = "gitlab";
+
+/// Convert a delivery into a [`TrackerEvent`]. `event` is the
+/// `X-Gitlab-Event` header (e.g. "Issue Hook", "Note Hook"). Merge requests,
+/// notes on other objects, and other hooks yield `None`.
+pub fn parse_webhook(event: Option<&str>, payload: &Value) -> Option<TrackerEvent> {
+    let attributes = &payload["object_attributes"];
+    let (kind, issue) = match event?.trim_start_matches("Confidential ") {
+        "Issue Hook" => {
+            let kind = match attributes["action"].as_str()? {
+                "open" => TrackerEventKind::IssueCreated,
+                "close" | "reopen" => TrackerEventKind::IssueTransitioned,
+                "update" => TrackerEventKind::IssueUpdated,
+                _ => return None,
+            };
+            (kind, attributes)
+        }
+        "Note Hook" if attributes["noteable_type"] == "Issue" => {
+            (TrackerEventKind::IssueCommented, &payload["issue"])
+        }
+        _ => return None,
+    };
+
+    let iid = issue["iid"].as_u64()?;
+    let mut tracker_event = TrackerEvent::new(SOURCE, kind, format!("#{}", iid));
+    tracker_event.summary = issue["title"].as_str().map(String::from);
+    tracker_event.project = payload["project"]["path_with_namespace"]
+        .as_str()
+        .map(String::from);
+    tracker_event.actor = payload["user"]["username"].as_str().map(String::from);
+    tracker_event.url = attributes["url"].as_str().map(String::from);
+    tracker_event.at = timestamp(&attributes["updated_at"]);
+
+    match kind {
+        TrackerEventKind::IssueTransitioned => {
+            let (from, to) = if attributes["action"] == "close" {
+                ("opened", "closed")
+            } else {
+                ("closed", "opened")
+            };
+            tracker_event.from = Some(from.to_string());
+            tracker_event.to = Some(to.to_string());
+        }
+        TrackerEventKind::IssueCommented => {
+            tracker_event.comment = attributes["note"].as_str().map(String::from);
+            tracker_event.at = timestamp(&attributes["created_at"]).or(tracker_event.at);
+        }
+        _ => {}
+    }
+
+    Some(tracker_event)
+}
+
+/// GitLab webhooks use "2026-05-01 12:00:00 UTC" rather than RFC 3339.
+fn timestamp(value: &Value) -> Option<DateTime<Utc>> {
+    let value = value.as_str()?;
+    value.parse().ok().or_else(|| {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S UTC")
+            .ok()
+            .map(|naive| naive.and_utc())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn maps_issue_hook_actions() {
+        let payload = json!({
+            "object_kind": "issue",
+            "user": { "username": "jdoe" },
+            "project": { "path_with_namespace": "acme/app" },
+            "object_attributes": {
+                "iid": 7,
+                "title": "Crash on start",
+                "action": "close",
+                "url": "https://gitlab.com/acme/app/-/issues/7",
+                "updated_at": "2026-05-01 12:00:00 UTC"
+            }
+        });
+        let event = parse_webhook(Some("Issue Hook"), &payload).unwrap();
+        assert_eq!(event.kind, TrackerEventKind::IssueTransitioned);
+        assert_eq!(event.issue, "#7");
+        assert_eq!(event.project.as_deref(), Some("acme/app"));
+        assert_eq!(event.to.as_deref(), Some("closed"));
+        assert_eq!(event.at, "2026-05-01T12:00:00Z".parse().ok());
+    }
+
+    #[test]
+    fn maps_issue_notes_only() {
+        let mut payload = json!({
+            "object_kind": "note",
+            "user": { "username": "jdoe" },
+            "object_attributes": {
+                "note": "Looks good",
+                "noteable_type": "Issue",
+                "created_at": "2026-05-01 12:05:00 UTC"
+            },
+            "issue": { "iid": 7, "title": "Crash on start" }
+        });
+        let event = parse_webhook(Some("Note Hook"), &payload).unwrap();
+        assert_eq!(event.kind, TrackerEventKind::IssueCommented);
+        assert_eq!(event.comment.as_deref(), Some("Looks good"));
+
+        payload["object_attributes"]["noteable_type"] = json!("MergeRequest");
+        assert!(parse_webhook(Some("Note Hook"), &payload).is_none());
+    }
+}
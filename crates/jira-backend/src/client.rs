@@ -8,11 +8,35 @@ use ureq::unversioned::multipart::{Form, Part};
 use crate::error::{JiraError, Result};
 use crate::models::*;
 
+/// Which Jira product a [`JiraClient`] talks to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JiraDeployment {
+    /// Jira Cloud: REST API v3, ADF rich text, account IDs
+    #[default]
+    Cloud,
+    /// Jira Server / Data Center: REST API v2, wiki markup, usernames
+    Server,
+}
+
+impl JiraDeployment {
+    /// Convert Markdown to this deployment's rich-text format: an ADF
+    /// document on Cloud, a wiki markup string on Server / Data Center.
+    pub(crate) fn rich_text(self, markdown: &str) -> serde_json::Value {
+        match self {
+            Self::Cloud => crate::markdown::adf::markdown_to_adf(markdown),
+            Self::Server => {
+                serde_json::Value::String(crate::markdown::wiki::markdown_to_wiki(markdown))
+            }
+        }
+    }
+}
+
 /// Jira REST API client
 pub struct JiraClient {
     agent: Agent,
     base_url: String,
     auth_header: String,
+    deployment: JiraDeployment,
     link_mappings: HashMap<String, String>,
     /// Cached instance-level field metadata (lazy-loaded)
     field_cache: Mutex<Option<Vec<JiraField>>>,
@@ -22,8 +46,23 @@ impl JiraClient {
     /// Create a new Jira client with Basic Auth
     ///
     /// For Jira Cloud, use your email and an API token.
-    /// For Jira Server, use your username and password.
+    /// For Jira Server, use your username and password together with
+    /// [`JiraClient::with_deployment`].
     pub fn new(base_url: &str, email: &str, api_token: &str) -> Self {
+        // Base64 encode credentials for Basic Auth
+        let credentials = format!("{}:{}", email.trim(), api_token.trim());
+        let encoded = base64_encode(&credentials);
+        Self::with_auth_header(base_url, format!("Basic {}", encoded))
+    }
+
+    /// Create a Jira Server / Data Center client authenticating with a
+    /// personal access token (Bearer auth)
+    pub fn new_server(base_url: &str, personal_access_token: &str) -> Self {
+        Self::with_auth_header(base_url, format!("Bearer {}", personal_access_token.trim()))
+            .with_deployment(JiraDeployment::Server)
+    }
+
+    fn with_auth_header(base_url: &str, auth_header: String) -> Self {
         let agent = Agent::config_builder()
             .timeout_global(Some(Duration::from_secs(30)))
             // Don't treat HTTP errors as errors - we'll handle them ourselves
@@ -31,20 +70,27 @@ impl JiraClient {
             .build()
             .into();
 
-        // Base64 encode credentials for Basic Auth
-        let credentials = format!("{}:{}", email.trim(), api_token.trim());
-        let encoded = base64_encode(&credentials);
-        let auth_header = format!("Basic {}", encoded);
-
         Self {
             agent,
             base_url: base_url.trim_end_matches('/').to_string(),
             auth_header,
+            deployment: JiraDeployment::Cloud,
             link_mappings: HashMap::new(),
             field_cache: Mutex::new(None),
         }
     }
 
+    /// Set the Jira product the client talks to (Cloud by default)
+    pub fn with_deployment(mut self, deployment: JiraDeployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+
+    /// The Jira product the client talks to
+    pub fn deployment(&self) -> JiraDeployment {
+        self.deployment
+    }
+
     /// Set custom link type mappings (canonical name -> Jira link type name)
     pub fn with_link_mappings(mut self, mappings: HashMap<String, String>) -> Self {
         self.link_mappings = mappings;
@@ -68,9 +114,13 @@ impl JiraClient {
         .to_string()
     }
 
-    /// Get the API base URL (v3 for Cloud, v2 for Server)
+    /// Get the API base URL (v3 for Cloud, v2 for Server / Data Center)
     fn api_url(&self, path: &str) -> String {
-        format!("{}/rest/api/3{}", self.base_url, path)
+        let version = match self.deployment {
+            JiraDeployment::Cloud => 3,
+            JiraDeployment::Server => 2,
+        };
+        format!("{}/rest/api/{}{}", self.base_url, version, path)
     }
 
     /// Handle HTTP errors
@@ -217,6 +267,38 @@ impl JiraClient {
         Ok(result)
     }
 
+    /// Search issues using JQL on Jira Server / Data Center.
+    ///
+    /// `/search` is offset-paged there (`startAt`/`maxResults`) and reports
+    /// the `total` number of matches; the server caps `maxResults` (50 by
+    /// default, configurable by admins).
+    pub fn search_issues_page(
+        &self,
+        jql: &str,
+        start_at: usize,
+        max_results: usize,
+    ) -> Result<JiraSearchPage> {
+        let url = format!(
+            "{}?jql={}&startAt={}&maxResults={}&fields=*all",
+            self.api_url("/search"),
+            urlencoding::encode(jql),
+            start_at,
+            max_results,
+        );
+
+        let response = self
+            .agent
+            .get(&url)
+            .header("Authorization", &self.auth_header)
+            .header("Accept", "application/json")
+            .call()
+            .map_err(|e| self.handle_error(e))?;
+
+        let mut response = self.check_response(response)?;
+        let page: JiraSearchPage = response.body_mut().read_json()?;
+        Ok(page)
+    }
+
     /// Create a new issue
     pub fn create_issue(&self, issue: &CreateJiraIssue) -> Result<JiraIssue> {
         let url = self.api_url("/issue");
@@ -264,6 +346,24 @@ impl JiraClient {
         self.get_issue(key)
     }
 
+    /// Apply an `update` operation set (e.g. `{"labels": [{"remove": "x"}]}`)
+    /// to an issue
+    pub fn edit_issue_operations(&self, key: &str, update: &serde_json::Value) -> Result<()> {
+        let url = self.api_url(&format!("/issue/{}", key));
+
+        let response = self
+            .agent
+            .put(&url)
+            .header("Authorization", &self.auth_header)
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .send_json(serde_json::json!({ "update": update }))
+            .map_err(|e| self.handle_error(e))?;
+
+        self.check_response(response)?;
+        Ok(())
+    }
+
     /// Delete an issue
     pub fn delete_issue(&self, key: &str) -> Result<()> {
         let url = self.api_url(&format!("/issue/{}", key));
@@ -362,7 +462,9 @@ impl JiraClient {
     pub fn add_comment(&self, key: &str, body: &str) -> Result<JiraComment> {
         let url = self.api_url(&format!("/issue/{}/comment", key));
 
-        let comment = CreateJiraComment::from_text(body);
+        let comment = CreateJiraComment {
+            body: self.deployment.rich_text(body),
+        };
 
         let response = self
             .agent
@@ -378,6 +480,24 @@ impl JiraClient {
         Ok(created)
     }
 
+    /// Add a worklog entry to an issue
+    pub fn add_worklog(&self, key: &str, worklog: &CreateJiraWorklog) -> Result<JiraWorklog> {
+        let url = self.api_url(&format!("/issue/{}/worklog", key));
+
+        let response = self
+            .agent
+            .post(&url)
+            .header("Authorization", &self.auth_header)
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .send_json(worklog)
+            .map_err(|e| self.handle_error(e))?;
+
+        let mut response = self.check_response(response)?;
+        let created: JiraWorklog = response.body_mut().read_json()?;
+        Ok(created)
+    }
+
     /// Get comments on an issue
     pub fn get_comments(&self, key: &str) -> Result<Vec<JiraComment>> {
         self.get_comments_page(key, 100, 0)
@@ -447,6 +567,10 @@ impl JiraClient {
     /// Jira returns changelog entries oldest-first; that order is preserved
     /// here (the caller re-sorts as needed).
     pub fn get_issue_changelog(&self, key: &str) -> Result<Vec<JiraChangelogEntry>> {
+        if self.deployment == JiraDeployment::Server {
+            return self.get_expanded_changelog(key);
+        }
+
         const PAGE: usize = 100;
         // Generous backstop: real changelogs are orders of magnitude smaller.
         // It guards against a server that ignores `startAt` or never sets
@@ -472,6 +596,30 @@ impl JiraClient {
         )))
     }
 
+    /// Jira Server / Data Center has no changelog endpoint; the full history
+    /// comes with the issue via `expand=changelog`.
+    fn get_expanded_changelog(&self, key: &str) -> Result<Vec<JiraChangelogEntry>> {
+        let url = self.api_url(&format!("/issue/{}?fields=summary&expand=changelog", key));
+
+        let response = self
+            .agent
+            .get(&url)
+            .header("Authorization", &self.auth_header)
+            .header("Accept", "application/json")
+            .call()
+            .map_err(|e| self.handle_error(e))?;
+
+        let mut response = self.check_response(response)?;
+
+        #[derive(serde::Deserialize)]
+        struct ExpandedIssue {
+            #[serde(default)]
+            changelog: Option<JiraExpandedChangelog>,
+        }
+        let issue: ExpandedIssue = response.body_mut().read_json()?;
+        Ok(issue.changelog.map(|c| c.histories).unwrap_or_default())
+    }
+
     // ==================== Link Operations ====================
 
     /// Create a link between two issues
@@ -545,6 +693,10 @@ impl JiraClient {
 
     /// List all labels in the Jira instance
     pub fn list_labels(&self) -> Result<Vec<String>> {
+        if self.deployment == JiraDeployment::Server {
+            return self.suggest_labels();
+        }
+
         let url = self.api_url("/label");
         let response = self
             .agent
@@ -564,6 +716,33 @@ impl JiraClient {
         Ok(result.values)
     }
 
+    /// Jira Server / Data Center has no label listing in REST API v2; the
+    /// label picker's suggestion endpoint returns every label for an empty query.
+    fn suggest_labels(&self) -> Result<Vec<String>> {
+        let url = format!("{}/rest/api/1.0/labels/suggest?query=", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .header("Authorization", &self.auth_header)
+            .header("Accept", "application/json")
+            .call()
+            .map_err(|e| self.handle_error(e))?;
+        let mut response = self.check_response(response)?;
+
+        // Jira returns: {"token": "", "suggestions": [{"label": "label1", "html": "..."}]}
+        #[derive(serde::Deserialize)]
+        struct Suggestion {
+            label: String,
+        }
+        #[derive(serde::Deserialize)]
+        struct SuggestResponse {
+            #[serde(default)]
+            suggestions: Vec<Suggestion>,
+        }
+        let result: SuggestResponse = response.body_mut().read_json()?;
+        Ok(result.suggestions.into_iter().map(|s| s.label).collect())
+    }
+
     /// List all issue link types
     pub fn list_link_types(&self) -> Result<Vec<JiraIssueLinkType>> {
         let url = self.api_url("/issueLinkType");
@@ -590,11 +769,15 @@ impl JiraClient {
 
     /// List users assignable to issues in a project
     pub fn list_assignable_users(&self, project_key: &str) -> Result<Vec<JiraUser>> {
-        let url = format!(
+        let mut url = format!(
             "{}?project={}",
             self.api_url("/user/assignable/search"),
             urlencoding::encode(project_key)
         );
+        // Server / Data Center requires a username filter; empty matches everyone.
+        if self.deployment == JiraDeployment::Server {
+            url.push_str("&username=");
+        }
 
         let response = self
             .agent
@@ -611,7 +794,7 @@ impl JiraClient {
 
     // ==================== Transition Operations ====================
 
-    /// GET /rest/api/3/issue/{key}/transitions
+    /// GET /rest/api/{3|2}/issue/{key}/transitions
     pub fn list_transitions(&self, issue_key: &str) -> Result<Vec<Transition>> {
         let url = self.api_url(&format!("/issue/{}/transitions", issue_key));
 
@@ -628,13 +811,22 @@ impl JiraClient {
         Ok(resp.transitions)
     }
 
-    /// POST /rest/api/3/issue/{key}/transitions
-    pub fn transition_issue(&self, issue_key: &str, transition_id: &str) -> Result<()> {
+    /// POST /rest/api/{3|2}/issue/{key}/transitions
+    ///
+    /// `resolution` is set on the transition screen; Jira rejects it when the
+    /// transition has no resolution field.
+    pub fn transition_issue(
+        &self,
+        issue_key: &str,
+        transition_id: &str,
+        resolution: Option<&str>,
+    ) -> Result<()> {
         let url = self.api_url(&format!("/issue/{}/transitions", issue_key));
         let body = TransitionRequest {
             transition: TransitionId {
                 id: transition_id.to_string(),
             },
+            fields: resolution.map(|name| serde_json::json!({ "resolution": { "name": name } })),
         };
 
         let response = self
@@ -675,7 +867,7 @@ impl JiraClient {
 
     // ==================== Project Status Operations ====================
 
-    /// GET /rest/api/3/project/{projectIdOrKey}/statuses
+    /// GET /rest/api/{3|2}/project/{projectIdOrKey}/statuses
     pub fn list_project_statuses(
         &self,
         project_key: &str,
@@ -2018,4 +2018,248 @@ mod tests {
         let issue = IssueTracker::update_issue(&client, "TEST-1", &update).unwrap();
         assert_eq!(issue.id_readable, "TEST-1");
     }
+
+    #[tokio::test]
+    async fn test_transition_issue_sends_resolution() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/TEST-7/transitions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "transitions": [{ "id": "41", "name": "Close", "to": { "id": "6", "name": "Done" } }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue/TEST-7/transitions"))
+            .and(wiremock::matchers::body_json(serde_json::json!({
+                "transition": { "id": "41" },
+                "fields": { "resolution": { "name": "Won't Do" } }
+            })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/3/issue/TEST-7"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(mock_jira_issue("TEST-7", "Test")),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = JiraClient::new(&mock_server.uri(), "test@test.com", "test-token");
+
+        use tracker_core::{IssueTracker, IssueTransition};
+        let transition = IssueTransition {
+            state: "done".to_string(),
+            resolution: Some("Won't Do".to_string()),
+        };
+        let issue = IssueTracker::transition_issue(&client, "TEST-7", &transition).unwrap();
+        assert_eq!(issue.id_readable, "TEST-7");
+    }
+
+    #[tokio::test]
+    async fn test_label_removal_and_worklog_requests() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("PUT"))
+            .and(path("/rest/api/3/issue/TEST-8"))
+            .and(wiremock::matchers::body_json(serde_json::json!({
+                "update": { "labels": [{ "remove": "stale" }] }
+            })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue/TEST-8/worklog"))
+            .and(wiremock::matchers::body_partial_json(serde_json::json!({
+                "timeSpentSeconds": 5400,
+                "started": "2026-05-01T09:00:00.000+0000"
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "id": "100",
+                "timeSpentSeconds": 5400
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = JiraClient::new(&mock_server.uri(), "test@test.com", "test-token");
+
+        use tracker_core::{IssueTracker, LogWork};
+        IssueTracker::remove_issue_tag(&client, "TEST-8", "stale").unwrap();
+        let work = LogWork {
+            minutes: 90,
+            text: Some("Pairing".to_string()),
+            date: "2026-05-01".parse().ok(),
+        };
+        let item = IssueTracker::log_work(&client, "TEST-8", &work).unwrap();
+        assert_eq!(item.id.as_deref(), Some("100"));
+        assert_eq!(item.minutes, 90);
+    }
+
+    /// A Server / Data Center issue: v2 `self` URL, wiki-markup description
+    /// and a `name`-identified assignee instead of an `accountId`.
+    fn mock_server_issue(key: &str) -> serde_json::Value {
+        let mut issue = mock_jira_issue(key, "Server issue");
+        issue["self"] =
+            serde_json::json!(format!("https://jira.example.com/rest/api/2/issue/{}", key));
+        issue["fields"]["description"] =
+            serde_json::json!("h2. Steps\n* open *the* app\n* click {{Save}}");
+        issue["fields"]["assignee"] = serde_json::json!({
+            "name": "jdoe",
+            "key": "JIRAUSER10100",
+            "displayName": "Jane Doe",
+            "active": true
+        });
+        issue
+    }
+
+    #[tokio::test]
+    async fn test_server_search_uses_v2_offsets_and_bearer_token() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/2/search"))
+            .and(header("Authorization", "Bearer test-pat"))
+            .and(query_param("jql", "project = PROJ"))
+            .and(query_param("startAt", "1"))
+            .and(query_param("maxResults", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "startAt": 1,
+                "maxResults": 2,
+                "total": 7,
+                "issues": [mock_server_issue("PROJ-2"), mock_server_issue("PROJ-3")]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/2/search"))
+            .and(query_param("maxResults", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "startAt": 0,
+                "maxResults": 0,
+                "total": 7,
+                "issues": []
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = JiraClient::new_server(&mock_server.uri(), "test-pat");
+
+        use tracker_core::IssueTracker;
+        let result = IssueTracker::search_issues(&client, "project = PROJ", 2, 1).unwrap();
+        let keys: Vec<&str> = result
+            .items
+            .iter()
+            .map(|i| i.id_readable.as_str())
+            .collect();
+        assert_eq!(keys, vec!["PROJ-2", "PROJ-3"]);
+        assert_eq!(result.total, Some(7));
+        assert_eq!(
+            result.items[0].description.as_deref(),
+            Some("## Steps\n\n- open **the** app\n- click `Save`")
+        );
+        assert_eq!(
+            IssueTracker::get_issue_count(&client, "project = PROJ").unwrap(),
+            Some(7)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_server_comment_is_posted_as_wiki_markup() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/api/2/issue/PROJ-1/comment"))
+            .and(wiremock::matchers::body_json(serde_json::json!({
+                "body": "Ping [~jdoe], see *this*"
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "id": "10500",
+                "body": "Ping [~jdoe], see *this*",
+                "author": { "name": "jdoe", "displayName": "Jane Doe" },
+                "created": "2026-05-01T09:00:00.000+0000"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = JiraClient::new_server(&mock_server.uri(), "test-pat");
+
+        use tracker_core::{IssueTracker, User};
+        let mention = IssueTracker::format_mention(
+            &client,
+            &User {
+                id: "jdoe".to_string(),
+                login: None,
+                display_name: "Jane Doe".to_string(),
+            },
+        );
+        assert_eq!(mention, "[~jdoe]");
+        let comment =
+            IssueTracker::add_comment(&client, "PROJ-1", &format!("Ping {mention}, see **this**"))
+                .unwrap();
+        assert_eq!(comment.text, "Ping [~jdoe], see **this**");
+        assert_eq!(comment.author.unwrap().login, "jdoe");
+    }
+
+    #[tokio::test]
+    async fn test_server_history_and_labels() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/2/issue/PROJ-1"))
+            .and(query_param("expand", "changelog"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "key": "PROJ-1",
+                "changelog": {
+                    "histories": [{
+                        "id": "1",
+                        "author": { "name": "jdoe", "displayName": "Jane Doe" },
+                        "created": "2026-05-01T09:00:00.000+0000",
+                        "items": [{
+                            "field": "status",
+                            "fromString": "Open",
+                            "toString": "Done"
+                        }]
+                    }]
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/rest/api/1.0/labels/suggest"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "suggestions": [{ "label": "backend" }, { "label": "urgent" }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = JiraClient::new_server(&mock_server.uri(), "test-pat");
+
+        use tracker_core::IssueTracker;
+        let history = IssueTracker::get_issue_history(&client, "PROJ-1").unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].author.as_ref().unwrap().login, "jdoe");
+        assert_eq!(history[0].from.as_deref(), Some("Open"));
+        assert_eq!(history[0].to.as_deref(), Some("Done"));
+
+        let tags: Vec<String> = IssueTracker::list_tags(&client)
+            .unwrap()
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(tags, vec!["backend", "urgent"]);
+    }
 }
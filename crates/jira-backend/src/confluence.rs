@@ -5,6 +5,7 @@ use tracker_core::AttachmentUpload;
 use ureq::Agent;
 use ureq::unversioned::multipart::{Form, Part};
 
+use crate::client::JiraDeployment;
 use crate::error::{JiraError, Result};
 use crate::models::confluence::*;
 
@@ -16,6 +17,7 @@ pub struct ConfluenceClient {
     agent: Agent,
     base_url: String,
     auth_header: String,
+    deployment: JiraDeployment,
 }
 
 impl ConfluenceClient {
@@ -47,9 +49,22 @@ impl ConfluenceClient {
             agent,
             base_url,
             auth_header,
+            deployment: JiraDeployment::Cloud,
         }
     }
 
+    /// Set the Jira product this Confluence client sits next to (Cloud by
+    /// default). Only Confluence Cloud's v2 API is implemented, so article
+    /// operations fail with a clear error on Server / Data Center.
+    pub fn with_deployment(mut self, deployment: JiraDeployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+
+    pub(crate) fn deployment(&self) -> JiraDeployment {
+        self.deployment
+    }
+
     /// Get the v2 API URL
     fn api_v2_url(&self, path: &str) -> String {
         format!("{}/api/v2{}", self.base_url, path)
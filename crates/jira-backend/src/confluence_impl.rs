@@ -6,13 +6,27 @@ use tracker_core::{
     CreateArticle, KnowledgeBase, ProjectRef, Result, TrackerError, UpdateArticle,
 };
 
+use crate::client::JiraDeployment;
 use crate::confluence::ConfluenceClient;
 use crate::convert::parse_jira_datetime;
-use crate::markdown::storage::{markdown_to_storage, storage_to_text};
+use crate::markdown::storage::{markdown_to_storage, storage_to_markdown};
 use crate::models::confluence::*;
 
+impl ConfluenceClient {
+    fn ensure_cloud(&self) -> Result<()> {
+        match self.deployment() {
+            JiraDeployment::Cloud => Ok(()),
+            JiraDeployment::Server => Err(TrackerError::InvalidInput(
+                "Confluence articles are not supported in Jira Server / Data Center mode"
+                    .to_string(),
+            )),
+        }
+    }
+}
+
 impl KnowledgeBase for ConfluenceClient {
     fn get_article(&self, id: &str) -> Result<Article> {
+        self.ensure_cloud()?;
         self.get_page(id)
             .map(confluence_page_to_article)
             .map_err(TrackerError::from)
@@ -24,6 +38,7 @@ impl KnowledgeBase for ConfluenceClient {
         limit: usize,
         skip: usize,
     ) -> Result<Vec<Article>> {
+        self.ensure_cloud()?;
         if limit == 0 {
             return Ok(Vec::new());
         }
@@ -70,6 +85,7 @@ impl KnowledgeBase for ConfluenceClient {
         project_id: Option<&str>,
         max_results: usize,
     ) -> Result<Vec<Article>> {
+        self.ensure_cloud()?;
         if max_results == 0 {
             return Ok(Vec::new());
         }
@@ -106,6 +122,7 @@ impl KnowledgeBase for ConfluenceClient {
     }
 
     fn search_articles(&self, query: &str, limit: usize, skip: usize) -> Result<Vec<Article>> {
+        self.ensure_cloud()?;
         self.search_pages(query, limit, skip)
             .map(|r| {
                 r.results
@@ -117,6 +134,7 @@ impl KnowledgeBase for ConfluenceClient {
     }
 
     fn create_article(&self, article: &CreateArticle) -> Result<Article> {
+        self.ensure_cloud()?;
         let page = CreateConfluencePage {
             space_id: article.project_id.clone(),
             title: article.summary.clone(),
@@ -138,6 +156,7 @@ impl KnowledgeBase for ConfluenceClient {
     }
 
     fn update_article(&self, id: &str, update: &UpdateArticle) -> Result<Article> {
+        self.ensure_cloud()?;
         // First get the current page to get the version number and current status
         let current = self.get_page(id).map_err(TrackerError::from)?;
         let current_version = current.version.as_ref().map(|v| v.number).unwrap_or(1);
@@ -166,10 +185,12 @@ impl KnowledgeBase for ConfluenceClient {
     }
 
     fn delete_article(&self, id: &str) -> Result<()> {
+        self.ensure_cloud()?;
         self.delete_page(id).map_err(TrackerError::from)
     }
 
     fn get_child_articles(&self, parent_id: &str) -> Result<Vec<Article>> {
+        self.ensure_cloud()?;
         self.get_child_pages(parent_id, 100)
             .map(|r| {
                 r.results
@@ -181,6 +202,7 @@ impl KnowledgeBase for ConfluenceClient {
     }
 
     fn move_article(&self, article_id: &str, new_parent_id: Option<&str>) -> Result<Article> {
+        self.ensure_cloud()?;
         // Get current page
         let current = self.get_page(article_id).map_err(TrackerError::from)?;
         let current_version = current.version.as_ref().map(|v| v.number).unwrap_or(1);
@@ -213,6 +235,7 @@ impl KnowledgeBase for ConfluenceClient {
     }
 
     fn list_article_attachments(&self, article_id: &str) -> Result<Vec<ArticleAttachment>> {
+        self.ensure_cloud()?;
         self.get_page_attachments(article_id, 100)
             .map(|r| {
                 r.results
@@ -228,6 +251,7 @@ impl KnowledgeBase for ConfluenceClient {
         article_id: &str,
         upload: &AttachmentUpload,
     ) -> Result<Vec<ArticleAttachment>> {
+        self.ensure_cloud()?;
         self.add_content_attachments(article_id, upload)
             .map(|attachments| {
                 attachments
@@ -239,6 +263,7 @@ impl KnowledgeBase for ConfluenceClient {
     }
 
     fn get_article_comments(&self, article_id: &str) -> Result<Vec<Comment>> {
+        self.ensure_cloud()?;
         self.get_page_comments(article_id, 100)
             .map(|r| {
                 r.results
@@ -250,6 +275,7 @@ impl KnowledgeBase for ConfluenceClient {
     }
 
     fn add_article_comment(&self, article_id: &str, text: &str) -> Result<Comment> {
+        self.ensure_cloud()?;
         self.add_page_comment(article_id, text)
             .map(confluence_comment_to_comment)
             .map_err(TrackerError::from)
@@ -261,6 +287,7 @@ impl KnowledgeBase for ConfluenceClient {
         text: &str,
         upload: &AttachmentUpload,
     ) -> Result<Comment> {
+        self.ensure_cloud()?;
         let comment = self
             .add_page_comment(article_id, text)
             .map_err(TrackerError::from)?;
@@ -299,7 +326,7 @@ fn confluence_page_to_article(page: ConfluencePage) -> Article {
         b.storage
             .as_ref()
             .and_then(|s| s.value.clone())
-            .map(|v| storage_to_text(&v))
+            .map(|v| storage_to_markdown(&v))
     });
 
     let created = parse_jira_datetime(&page.created_at).unwrap_or_else(Utc::now);
@@ -412,7 +439,7 @@ fn confluence_comment_to_comment(comment: ConfluenceComment) -> Comment {
         .as_ref()
         .and_then(|b| b.storage.as_ref())
         .and_then(|s| s.value.clone())
-        .map(|v| storage_to_text(&v))
+        .map(|v| storage_to_markdown(&v))
         .unwrap_or_default();
 
     let created = parse_jira_datetime(&comment.created_at);
@@ -10,9 +10,9 @@ use tracker_core::{
     Tag, UpdateIssue, User, canonical_field_name,
 };
 
-use crate::markdown::adf::{
-    adf_to_text as adf_document_to_text, markdown_to_adf as markdown_to_adf_document,
-};
+use crate::client::JiraDeployment;
+use crate::markdown::adf::adf_to_markdown as adf_document_to_markdown;
+use crate::markdown::rich_text_to_markdown;
 use crate::models::*;
 
 /// Convert a Jira issue to a tracker-core Issue.
@@ -25,7 +25,7 @@ pub fn jira_issue_to_core(j: JiraIssue, jira_fields: &[JiraField]) -> Issue {
         .fields
         .description
         .as_ref()
-        .map(adf_document_to_text)
+        .map(rich_text_to_markdown)
         .filter(|s| !s.is_empty());
 
     let is_resolved = j
@@ -60,7 +60,7 @@ pub fn jira_issue_to_core(j: JiraIssue, jira_fields: &[JiraField]) -> Issue {
             .fields
             .assignee
             .as_ref()
-            .and_then(|u| u.account_id.clone()),
+            .and_then(|u| u.user_id().map(String::from)),
         display_name: j
             .fields
             .assignee
@@ -74,7 +74,7 @@ pub fn jira_issue_to_core(j: JiraIssue, jira_fields: &[JiraField]) -> Issue {
     if let Some(ref reporter) = j.fields.reporter {
         custom_fields.push(CustomField::SingleUser {
             name: "Reporter".to_string(),
-            login: reporter.account_id.clone(),
+            login: reporter.user_id().map(String::from),
             display_name: reporter.display_name.clone(),
         });
     }
@@ -207,13 +207,13 @@ fn json_value_to_custom_field(
     // ADF rich-text documents ({"type":"doc","version":1,"content":[…]}) are
     // gated before the schema match: rich-text custom fields report schema type
     // "string" yet return an object value, so neither the (Some("string"), …)
-    // arm nor the schemaless heuristics would render them. Flatten to plain text
+    // arm nor the schemaless heuristics would render them. Render to Markdown
     // the same way descriptions and comments already do; when the document
-    // flattens to nothing, preserve it raw rather than dropping it.
+    // renders to nothing, preserve it raw rather than dropping it.
     if let Value::Object(obj) = value
         && obj.get("type").and_then(|t| t.as_str()) == Some("doc")
     {
-        let txt = adf_document_to_text(value);
+        let txt = adf_document_to_markdown(value);
         return if txt.is_empty() {
             Some(CustomField::Unknown {
                 name,
@@ -245,6 +245,7 @@ fn json_value_to_custom_field(
         (Some("user"), Value::Object(obj)) => {
             let login = obj
                 .get("accountId")
+                .or_else(|| obj.get("name"))
                 .and_then(|v| v.as_str())
                 .map(String::from);
             let display_name = obj
@@ -281,6 +282,7 @@ fn json_value_to_custom_field(
             } else if let Some(dn) = obj.get("displayName").and_then(|v| v.as_str()) {
                 let login = obj
                     .get("accountId")
+                    .or_else(|| obj.get("name"))
                     .and_then(|v| v.as_str())
                     .map(String::from);
                 Some(CustomField::SingleUser {
@@ -402,9 +404,9 @@ impl From<JiraComment> for Comment {
     fn from(c: JiraComment) -> Self {
         Self {
             id: c.id,
-            text: adf_document_to_text(&c.body),
+            text: rich_text_to_markdown(&c.body),
             author: c.author.map(|u| CommentAuthor {
-                login: u.account_id.unwrap_or_default(),
+                login: u.user_id().unwrap_or_default().to_string(),
                 name: u.display_name,
             }),
             created: parse_jira_datetime(&c.created),
@@ -428,7 +430,7 @@ pub fn jira_changelog_to_history_events(
             continue;
         };
         let author = entry.author.map(|u| CommentAuthor {
-            login: u.account_id.unwrap_or_default(),
+            login: u.user_id().unwrap_or_default().to_string(),
             name: u.display_name,
         });
         for item in entry.items {
@@ -465,8 +467,8 @@ impl From<JiraIssueLinkType> for IssueLinkType {
 impl From<JiraUser> for User {
     fn from(u: JiraUser) -> Self {
         Self {
-            id: u.account_id.clone().unwrap_or_default(),
-            login: u.account_id,
+            id: u.user_id().unwrap_or_default().to_string(),
+            login: u.user_id().map(String::from),
             display_name: u.display_name.unwrap_or_else(|| "Unknown".to_string()),
         }
     }
@@ -518,14 +520,13 @@ impl From<JiraIssueLink> for IssueLink {
 /// Convert CreateIssue to Jira format.
 /// When `jira_fields` is provided, custom field updates are resolved to Jira field IDs
 /// and included in the request. Without it, only standard fields (priority, type, labels) are sent.
+/// The description is written in `deployment`'s rich-text format.
 pub fn create_issue_to_jira(
     issue: &CreateIssue,
     jira_fields: &[JiraField],
+    deployment: JiraDeployment,
 ) -> tracker_core::Result<CreateJiraIssue> {
-    let description = issue
-        .description
-        .as_ref()
-        .map(|d| markdown_to_adf_document(d));
+    let description = issue.description.as_ref().map(|d| deployment.rich_text(d));
 
     // Extract priority and issue type from custom fields in a single pass
     let mut priority = None;
@@ -580,14 +581,13 @@ pub fn create_issue_to_jira(
 /// Convert UpdateIssue to Jira format.
 /// When `jira_fields` is provided, custom field updates are resolved to Jira field IDs
 /// and included in the request. Without it, only standard fields (priority, labels) are sent.
+/// The description is written in `deployment`'s rich-text format.
 pub fn update_issue_to_jira(
     update: &UpdateIssue,
     jira_fields: &[JiraField],
+    deployment: JiraDeployment,
 ) -> tracker_core::Result<UpdateJiraIssue> {
-    let description = update
-        .description
-        .as_ref()
-        .map(|d| markdown_to_adf_document(d));
+    let description = update.description.as_ref().map(|d| deployment.rich_text(d));
 
     let priority = update.custom_fields.iter().find_map(|cf| match cf {
         CustomFieldUpdate::SingleEnum { name, value } if name.eq_ignore_ascii_case("priority") => {
@@ -1059,7 +1059,7 @@ mod tests {
             parent: Some("PROJ-100".to_string()),
         };
 
-        let jira = create_issue_to_jira(&issue, &[]).unwrap();
+        let jira = create_issue_to_jira(&issue, &[], JiraDeployment::Cloud).unwrap();
         let parent = jira.fields.parent.expect("parent should be set");
         assert_eq!(parent.key.as_deref(), Some("PROJ-100"));
         assert!(parent.id.is_none());
@@ -1076,7 +1076,7 @@ mod tests {
             parent: None,
         };
 
-        let jira = create_issue_to_jira(&issue, &[]).unwrap();
+        let jira = create_issue_to_jira(&issue, &[], JiraDeployment::Cloud).unwrap();
         assert!(jira.fields.parent.is_none());
     }
 
@@ -1095,7 +1095,7 @@ mod tests {
             custom: false,
             schema: None,
         }];
-        let jira = update_issue_to_jira(&update, &fields).unwrap();
+        let jira = update_issue_to_jira(&update, &fields, JiraDeployment::Cloud).unwrap();
         let json = serde_json::to_value(&jira).unwrap();
         assert_eq!(json["fields"]["timetracking"]["originalEstimate"], "4h");
         assert!(json["fields"].get("timeoriginalestimate").is_none());
@@ -1111,7 +1111,7 @@ mod tests {
             parent: Some("PROJ-200".to_string()),
         };
 
-        let jira = update_issue_to_jira(&update, &[]).unwrap();
+        let jira = update_issue_to_jira(&update, &[], JiraDeployment::Cloud).unwrap();
         let parent = jira.fields.parent.expect("parent should be set");
         assert_eq!(parent.key.as_deref(), Some("PROJ-200"));
     }
@@ -1126,7 +1126,7 @@ mod tests {
             parent: Some("DS-100".to_string()),
         };
 
-        let jira = update_issue_to_jira(&update, &[]).unwrap();
+        let jira = update_issue_to_jira(&update, &[], JiraDeployment::Cloud).unwrap();
         let json = serde_json::to_value(&jira).unwrap();
 
         // parent.key should be present, parent.id should be omitted
@@ -1160,7 +1160,7 @@ mod tests {
             }),
         }];
 
-        let jira = create_issue_to_jira(&issue, &fields).unwrap();
+        let jira = create_issue_to_jira(&issue, &fields, JiraDeployment::Cloud).unwrap();
         let json = serde_json::to_value(&jira).unwrap();
         assert_eq!(json["fields"]["customfield_10016"], 5.0);
     }
@@ -1189,7 +1189,7 @@ mod tests {
             }),
         }];
 
-        let jira = update_issue_to_jira(&update, &fields).unwrap();
+        let jira = update_issue_to_jira(&update, &fields, JiraDeployment::Cloud).unwrap();
         let json = serde_json::to_value(&jira).unwrap();
         assert_eq!(json["fields"]["customfield_10016"], 8.0);
     }
@@ -2131,6 +2131,8 @@ mod tests {
         let mut issue = mock_jira_issue_for_conversion(Default::default());
         issue.fields.reporter = Some(JiraUser {
             account_id: Some("rep-1".to_string()),
+            name: None,
+            key: None,
             display_name: Some("Rita Reporter".to_string()),
             email_address: None,
             active: true,
@@ -6,11 +6,12 @@ pub mod error;
 mod markdown;
 pub mod models;
 mod trait_impl;
+pub mod webhook;
 
 #[cfg(test)]
 mod client_tests;
 
-pub use client::JiraClient;
+pub use client::{JiraClient, JiraDeployment};
 pub use confluence::ConfluenceClient;
 pub use error::{JiraError, Result};
 pub use models::*;
@@ -459,7 +459,74 @@ pub(crate) fn markdown_to_adf(text: &str) -> serde_json::Value {
     // Flush any trailing inline content
     flush_inline(&mut inline_buf, &mut block_stack, &mut doc_content);
 
-    json!({ "type": "doc", "version": 1, "content": doc_content })
+    let mut doc = json!({ "type": "doc", "version": 1, "content": doc_content });
+    expand_mentions(&mut doc);
+    doc
+}
+
+const MENTION_PREFIX: &str = "[~accountid:";
+
+/// Replace Jira `[~accountid:ID]` mention syntax in unmarked text with ADF
+/// mention nodes. pulldown-cmark may split the brackets into separate text
+/// events, so adjacent plain text nodes are merged first.
+fn expand_mentions(node: &mut serde_json::Value) {
+    use serde_json::{Value, json};
+
+    if node["type"] == "codeBlock" {
+        return;
+    }
+    let Some(content) = node.get_mut("content").and_then(|c| c.as_array_mut()) else {
+        return;
+    };
+    let plain = |n: &Value| n["type"] == "text" && n.get("marks").is_none();
+    let plain_text: String = content
+        .iter()
+        .filter(|n| plain(n))
+        .filter_map(|n| n["text"].as_str())
+        .collect();
+    if !plain_text.contains(MENTION_PREFIX) {
+        content.iter_mut().for_each(expand_mentions);
+        return;
+    }
+
+    let mut merged: Vec<Value> = Vec::with_capacity(content.len());
+    for child in content.drain(..) {
+        match merged.last_mut() {
+            Some(last) if plain(last) && plain(&child) => {
+                let text = format!(
+                    "{}{}",
+                    last["text"].as_str().unwrap_or(""),
+                    child["text"].as_str().unwrap_or("")
+                );
+                last["text"] = Value::String(text);
+            }
+            _ => merged.push(child),
+        }
+    }
+
+    for mut child in merged {
+        if !plain(&child) {
+            expand_mentions(&mut child);
+            content.push(child);
+            continue;
+        }
+        let text = child["text"].as_str().unwrap_or("");
+        let mut rest = text;
+        while let Some(start) = rest.find(MENTION_PREFIX) {
+            let after = &rest[start + MENTION_PREFIX.len()..];
+            let Some(end) = after.find(']').filter(|&end| end > 0) else {
+                break;
+            };
+            if start > 0 {
+                content.push(json!({ "type": "text", "text": &rest[..start] }));
+            }
+            content.push(json!({ "type": "mention", "attrs": { "id": &after[..end] } }));
+            rest = &after[end + 1..];
+        }
+        if !rest.is_empty() {
+            content.push(json!({ "type": "text", "text": rest }));
+        }
+    }
 }
 
 /// Extract plain text from ADF document
@@ -514,6 +581,429 @@ pub(crate) fn adf_to_text(adf: &serde_json::Value) -> String {
     extract_text(adf).trim().to_string()
 }
 
+/// Convert an ADF document to Markdown.
+///
+/// The inverse of [`markdown_to_adf`]: every construct it produces (headings,
+/// lists, task lists, blockquotes, code blocks, tables, rules and inline
+/// marks) renders back to equivalent Markdown. ADF-only nodes map to the
+/// closest Markdown: panels become labelled blockquotes, status lozenges
+/// inline code, external media images and uploaded media an
+/// `[attachment: name]` placeholder. Mentions render as Jira's
+/// `[~accountid:ID]` syntax so they survive an edit round trip.
+pub(crate) fn adf_to_markdown(adf: &serde_json::Value) -> String {
+    render_blocks(children(adf), 0).trim().to_string()
+}
+
+pub(super) fn children(node: &serde_json::Value) -> &[serde_json::Value] {
+    node.get("content")
+        .and_then(|c| c.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+}
+
+pub(super) fn node_type(node: &serde_json::Value) -> &str {
+    node.get("type").and_then(|t| t.as_str()).unwrap_or("")
+}
+
+pub(super) fn attr<'a>(node: &'a serde_json::Value, name: &str) -> Option<&'a str> {
+    node.get("attrs")
+        .and_then(|a| a.get(name))
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+}
+
+pub(super) fn is_list(node: &serde_json::Value) -> bool {
+    matches!(
+        node_type(node),
+        "bulletList" | "orderedList" | "taskList" | "decisionList"
+    )
+}
+
+pub(super) fn is_inline(node: &serde_json::Value) -> bool {
+    matches!(
+        node_type(node),
+        "text"
+            | "hardBreak"
+            | "mention"
+            | "emoji"
+            | "status"
+            | "inlineCard"
+            | "date"
+            | "mediaInline"
+            | "placeholder"
+    )
+}
+
+/// Render a sequence of block nodes. A list directly following another block
+/// inside a list item is attached with a single newline so the item stays tight.
+fn render_blocks(nodes: &[serde_json::Value], depth: usize) -> String {
+    let mut out = String::new();
+    let mut inline_run: Vec<serde_json::Value> = Vec::new();
+    let mut blocks: Vec<(bool, String)> = Vec::new();
+
+    for node in nodes {
+        if is_inline(node) {
+            inline_run.push(node.clone());
+            continue;
+        }
+        if !inline_run.is_empty() {
+            blocks.push((false, render_inline(&std::mem::take(&mut inline_run))));
+        }
+        let rendered = render_block(node, depth);
+        if !rendered.is_empty() {
+            blocks.push((is_list(node), rendered));
+        }
+    }
+    if !inline_run.is_empty() {
+        blocks.push((false, render_inline(&inline_run)));
+    }
+
+    for (i, (list, text)) in blocks.iter().enumerate() {
+        if i > 0 {
+            out.push_str(if *list && depth > 0 { "\n" } else { "\n\n" });
+        }
+        out.push_str(text);
+    }
+    out
+}
+
+fn render_block(node: &serde_json::Value, depth: usize) -> String {
+    match node_type(node) {
+        "paragraph" => escape_line_start(&render_inline(children(node))),
+        "heading" => {
+            let level = node
+                .get("attrs")
+                .and_then(|a| a.get("level"))
+                .and_then(|l| l.as_u64())
+                .unwrap_or(1)
+                .clamp(1, 6) as usize;
+            format!("{} {}", "#".repeat(level), render_inline(children(node)))
+        }
+        "codeBlock" => {
+            let code: String = children(node)
+                .iter()
+                .filter_map(|n| n.get("text").and_then(|t| t.as_str()))
+                .collect();
+            let fence = "`".repeat(longest_run(&code, '`').max(2) + 1);
+            let lang = attr(node, "language").unwrap_or("");
+            format!("{fence}{lang}\n{}\n{fence}", code.trim_end_matches('\n'))
+        }
+        "blockquote" => quote(&render_blocks(children(node), depth)),
+        "panel" => {
+            let label = match attr(node, "panelType").unwrap_or("info") {
+                "note" => "Note",
+                "warning" => "Warning",
+                "error" => "Error",
+                "success" => "Success",
+                "tip" => "Tip",
+                _ => "Info",
+            };
+            let body = render_blocks(children(node), depth);
+            quote(&format!("**{label}:** {body}"))
+        }
+        "expand" | "nestedExpand" => {
+            let body = render_blocks(children(node), depth);
+            match attr(node, "title") {
+                Some(title) => format!("**{}**\n\n{body}", escape_text(title)),
+                None => body,
+            }
+        }
+        "bulletList" | "decisionList" => render_list(node, depth, |_| "- ".to_string()),
+        "orderedList" => {
+            let start = node
+                .get("attrs")
+                .and_then(|a| a.get("order"))
+                .and_then(|o| o.as_u64())
+                .unwrap_or(1);
+            render_list(node, depth, |i| format!("{}. ", start + i as u64))
+        }
+        "taskList" => {
+            let items: Vec<String> = children(node)
+                .iter()
+                .map(|item| {
+                    if node_type(item) == "taskList" {
+                        return indent(&render_block(item, depth + 1), 2);
+                    }
+                    let check = if attr(item, "state") == Some("DONE") {
+                        "x"
+                    } else {
+                        " "
+                    };
+                    let body = render_blocks(children(item), depth + 1);
+                    format!("- [{check}] {}", indent(&body, 2).trim_start())
+                })
+                .collect();
+            items.join("\n")
+        }
+        "rule" => "---".to_string(),
+        "table" => render_table(node),
+        "mediaSingle" | "mediaGroup" => children(node)
+            .iter()
+            .map(render_media)
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "media" => render_media(node),
+        "blockCard" | "embedCard" => attr(node, "url")
+            .map(|url| format!("<{url}>"))
+            .unwrap_or_default(),
+        _ => render_blocks(children(node), depth),
+    }
+}
+
+/// Render list items with `marker(index)`, indenting continuation lines to
+/// line up under the item text.
+fn render_list(node: &serde_json::Value, depth: usize, marker: impl Fn(usize) -> String) -> String {
+    children(node)
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let marker = marker(i);
+            let body = render_blocks(children(item), depth + 1);
+            format!("{marker}{}", indent(&body, marker.len()).trim_start())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_table(node: &serde_json::Value) -> String {
+    let rows: Vec<Vec<String>> = children(node)
+        .iter()
+        .map(|row| {
+            children(row)
+                .iter()
+                .map(|cell| {
+                    render_blocks(children(cell), 0)
+                        .replace('\n', " ")
+                        .replace('|', "\\|")
+                })
+                .collect()
+        })
+        .collect();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    if width == 0 {
+        return String::new();
+    }
+
+    let line = |cells: &[String]| {
+        let mut padded: Vec<&str> = cells.iter().map(String::as_str).collect();
+        padded.resize(width, "");
+        format!("| {} |", padded.join(" | "))
+    };
+    // GFM tables need a header row; Jira tables without one get an empty header.
+    let first_is_header = children(node)
+        .first()
+        .map(|row| {
+            children(row)
+                .iter()
+                .all(|cell| node_type(cell) == "tableHeader")
+        })
+        .unwrap_or(false);
+
+    let mut lines = Vec::new();
+    let body = if first_is_header {
+        lines.push(line(&rows[0]));
+        &rows[1..]
+    } else {
+        lines.push(line(&[]));
+        &rows[..]
+    };
+    lines.push(format!("|{}", " --- |".repeat(width)));
+    lines.extend(body.iter().map(|r| line(r)));
+    lines.join("\n")
+}
+
+fn render_media(node: &serde_json::Value) -> String {
+    let alt = attr(node, "alt");
+    match (attr(node, "type"), attr(node, "url")) {
+        (Some("external"), Some(url)) => format!("![{}]({url})", escape_text(alt.unwrap_or(""))),
+        _ => {
+            let name = alt.or_else(|| attr(node, "id")).unwrap_or("file");
+            format!("[attachment: {}]", escape_text(name))
+        }
+    }
+}
+
+/// Render inline nodes, grouping marks so that `**a** b` style runs stay
+/// valid Markdown (delimiters never enclose leading or trailing spaces).
+fn render_inline(nodes: &[serde_json::Value]) -> String {
+    let mut out = String::new();
+    for node in nodes {
+        match node_type(node) {
+            "text" => out.push_str(&render_text(node)),
+            "hardBreak" => out.push_str("\\\n"),
+            "mention" => match attr(node, "id") {
+                // Mentions read from Server wiki markup carry a username,
+                // which `[~username]` passes back through unchanged.
+                Some(id) if node["attrs"]["username"] == true => {
+                    out.push_str(&format!("[~{id}]"));
+                }
+                Some(id) => {
+                    out.push_str(MENTION_PREFIX);
+                    out.push_str(id);
+                    out.push(']');
+                }
+                None => {
+                    let name = attr(node, "text").unwrap_or("unknown");
+                    out.push('@');
+                    out.push_str(name.trim_start_matches('@'));
+                }
+            },
+            "emoji" => out.push_str(
+                attr(node, "text")
+                    .or_else(|| attr(node, "shortName"))
+                    .unwrap_or(""),
+            ),
+            "status" => {
+                if let Some(text) = attr(node, "text") {
+                    out.push_str(&code_span(text));
+                }
+            }
+            "inlineCard" => {
+                if let Some(url) = attr(node, "url") {
+                    out.push_str(&format!("<{url}>"));
+                }
+            }
+            "date" => {
+                let date = attr(node, "timestamp")
+                    .and_then(|ts| ts.parse::<i64>().ok())
+                    .and_then(chrono::DateTime::from_timestamp_millis)
+                    .map(|d| d.format("%Y-%m-%d").to_string());
+                if let Some(date) = date {
+                    out.push_str(&date);
+                }
+            }
+            "mediaInline" => out.push_str(&render_media(node)),
+            _ => out.push_str(&render_inline(children(node))),
+        }
+    }
+    out
+}
+
+fn render_text(node: &serde_json::Value) -> String {
+    let text = node.get("text").and_then(|t| t.as_str()).unwrap_or("");
+    let marks: Vec<&serde_json::Value> = node
+        .get("marks")
+        .and_then(|m| m.as_array())
+        .map(|m| m.iter().collect())
+        .unwrap_or_default();
+    let has = |name: &str| marks.iter().any(|m| node_type(m) == name);
+
+    // Keep surrounding whitespace outside emphasis delimiters.
+    let core = text.trim();
+    if core.is_empty() {
+        return text.to_string();
+    }
+    let lead = &text[..text.len() - text.trim_start().len()];
+    let trail = &text[text.trim_end().len()..];
+
+    let mut inner = if has("code") {
+        code_span(core)
+    } else {
+        escape_text(core)
+    };
+    if has("em") {
+        inner = format!("*{inner}*");
+    }
+    if has("strong") {
+        inner = format!("**{inner}**");
+    }
+    if has("strike") {
+        inner = format!("~~{inner}~~");
+    }
+    if let Some(link) = marks.iter().find(|m| node_type(m) == "link")
+        && let Some(href) = attr(link, "href")
+    {
+        inner = format!("[{inner}]({})", href.replace(' ', "%20"));
+    }
+    format!("{lead}{inner}{trail}")
+}
+
+fn code_span(text: &str) -> String {
+    let fence = "`".repeat(longest_run(text, '`') + 1);
+    if text.starts_with('`') || text.ends_with('`') {
+        format!("{fence} {text} {fence}")
+    } else {
+        format!("{fence}{text}{fence}")
+    }
+}
+
+fn longest_run(text: &str, ch: char) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+    for c in text.chars() {
+        if c == ch {
+            current += 1;
+            longest = longest.max(current);
+        } else {
+            current = 0;
+        }
+    }
+    longest
+}
+
+/// Backslash-escape characters that would otherwise start Markdown syntax.
+/// Underscores inside words are left alone since they never open emphasis.
+fn escape_text(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    for (i, &c) in chars.iter().enumerate() {
+        let escape = match c {
+            '\\' | '*' | '`' | '[' | ']' | '~' | '<' => true,
+            '_' => {
+                let word = |j: Option<&char>| j.is_some_and(|c| c.is_alphanumeric());
+                !(word(i.checked_sub(1).and_then(|j| chars.get(j))) && word(chars.get(i + 1)))
+            }
+            _ => false,
+        };
+        if escape {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Escape a leading character that would turn a paragraph into a heading,
+/// list, blockquote or rule.
+fn escape_line_start(text: &str) -> String {
+    let digits = text.chars().take_while(char::is_ascii_digit).count();
+    let after_digits = text[digits..].chars().next();
+    if digits > 0 && matches!(after_digits, Some('.') | Some(')')) {
+        return format!("{}\\{}", &text[..digits], &text[digits..]);
+    }
+    match text.chars().next() {
+        Some('#' | '>' | '-' | '+' | '=' | '|') => format!("\\{text}"),
+        _ => text.to_string(),
+    }
+}
+
+fn indent(text: &str, width: usize) -> String {
+    let pad = " ".repeat(width);
+    text.lines()
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("{pad}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn quote(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            if line.is_empty() {
+                ">".to_string()
+            } else {
+                format!("> {line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 #[cfg(test)]
 mod markdown_to_adf_tests {
     use super::*;
@@ -876,6 +1366,27 @@ mod markdown_to_adf_tests {
         assert!(text.contains("world"), "expected 'world' in output");
     }
 
+    #[test]
+    fn jira_mention_syntax_becomes_mention_node() {
+        let adf = markdown_to_adf("Over to [~accountid:5b10ac8d82e05b22cc7d4ef5] for review");
+        let content = first_block(&adf)["content"].as_array().unwrap();
+        assert_eq!(content.len(), 3);
+        assert_eq!(content[0]["text"], "Over to ");
+        assert_eq!(content[1]["type"], "mention");
+        assert_eq!(content[1]["attrs"]["id"], "5b10ac8d82e05b22cc7d4ef5");
+        assert_eq!(content[2]["text"], " for review");
+    }
+
+    #[test]
+    fn jira_mention_syntax_in_code_is_literal() {
+        let adf = markdown_to_adf("`[~accountid:abc]`\n\n```\n[~accountid:abc]\n```");
+        assert_eq!(first_block(&adf)["content"][0]["text"], "[~accountid:abc]");
+        assert_eq!(
+            adf["content"][1]["content"][0]["text"],
+            "[~accountid:abc]\n"
+        );
+    }
+
     #[test]
     fn block_html_text_preserved() {
         // Block-level HTML: tags stripped, inner text preserved
@@ -894,3 +1405,169 @@ mod markdown_to_adf_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod adf_to_markdown_tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Strip generated `localId` attributes so documents can be compared.
+    fn without_local_ids(mut value: serde_json::Value) -> serde_json::Value {
+        match &mut value {
+            serde_json::Value::Object(obj) => {
+                if let Some(serde_json::Value::Object(attrs)) = obj.get_mut("attrs") {
+                    attrs.remove("localId");
+                }
+                for v in obj.values_mut() {
+                    *v = without_local_ids(v.take());
+                }
+            }
+            serde_json::Value::Array(arr) => {
+                for v in arr.iter_mut() {
+                    *v = without_local_ids(v.take());
+                }
+            }
+            _ => {}
+        }
+        value
+    }
+
+    fn doc(content: serde_json::Value) -> serde_json::Value {
+        json!({ "type": "doc", "version": 1, "content": content })
+    }
+
+    #[test]
+    fn markdown_round_trips_through_adf() {
+        let md = "# Title\n\n\
+                  Some **bold**, *italic*, ~~gone~~, `code` and a [link](https://example.com).\n\n\
+                  Over to [~accountid:5b10ac8d82e05b22cc7d4ef5] for review.\n\n\
+                  - outer\n  - inner\n- second\n\n\
+                  3. three\n4. four\n\n\
+                  - [ ] todo\n- [x] done\n\n\
+                  > quoted\n\n\
+                  ```rust\nfn main() {}\n```\n\n\
+                  | A | B |\n| --- | --- |\n| 1 | 2 |\n\n\
+                  ---\n\n\
+                  Line one\\\nline two";
+
+        let adf = markdown_to_adf(md);
+        let rendered = adf_to_markdown(&adf);
+
+        assert_eq!(rendered, md);
+        assert_eq!(
+            without_local_ids(markdown_to_adf(&rendered)),
+            without_local_ids(adf)
+        );
+    }
+
+    #[test]
+    fn special_characters_are_escaped() {
+        let adf = doc(json!([
+            { "type": "paragraph", "content": [
+                { "type": "text", "text": "1. not a list, a*b, [x] and snake_case" }
+            ]}
+        ]));
+
+        let md = adf_to_markdown(&adf);
+
+        assert_eq!(md, "1\\. not a list, a\\*b, \\[x\\] and snake_case");
+        assert_eq!(adf_to_text(&markdown_to_adf(&md)), adf_to_text(&adf));
+    }
+
+    #[test]
+    fn multi_paragraph_list_item_stays_in_item() {
+        let md = "- First paragraph.\n\n  Second paragraph.";
+        let rendered = adf_to_markdown(&markdown_to_adf(md));
+        assert_eq!(rendered, md);
+    }
+
+    #[test]
+    fn marks_keep_whitespace_outside_delimiters() {
+        let adf = doc(json!([
+            { "type": "paragraph", "content": [
+                { "type": "text", "text": "bold ", "marks": [{ "type": "strong" }] },
+                { "type": "text", "text": "plain" }
+            ]}
+        ]));
+        assert_eq!(adf_to_markdown(&adf), "**bold** plain");
+    }
+
+    #[test]
+    fn code_with_backticks_uses_longer_fences() {
+        let adf = doc(json!([
+            { "type": "paragraph", "content": [
+                { "type": "text", "text": "a`b", "marks": [{ "type": "code" }] }
+            ]},
+            { "type": "codeBlock", "content": [{ "type": "text", "text": "```\nnested\n```" }] }
+        ]));
+        assert_eq!(
+            adf_to_markdown(&adf),
+            "``a`b``\n\n````\n```\nnested\n```\n````"
+        );
+    }
+
+    #[test]
+    fn panel_renders_as_labelled_blockquote() {
+        let adf = doc(json!([
+            { "type": "panel", "attrs": { "panelType": "warning" }, "content": [
+                { "type": "paragraph", "content": [{ "type": "text", "text": "Careful" }] }
+            ]}
+        ]));
+        assert_eq!(adf_to_markdown(&adf), "> **Warning:** Careful");
+    }
+
+    #[test]
+    fn inline_nodes_map_to_markdown() {
+        let adf = doc(json!([
+            { "type": "paragraph", "content": [
+                { "type": "mention", "attrs": { "id": "abc", "text": "@Jane Doe" } },
+                { "type": "text", "text": " set " },
+                { "type": "status", "attrs": { "text": "IN REVIEW", "color": "blue" } },
+                { "type": "text", "text": " " },
+                { "type": "emoji", "attrs": { "shortName": ":tada:", "text": "🎉" } },
+                { "type": "text", "text": " see " },
+                { "type": "inlineCard", "attrs": { "url": "https://example.com/x" } }
+            ]}
+        ]));
+        assert_eq!(
+            adf_to_markdown(&adf),
+            "[~accountid:abc] set `IN REVIEW` 🎉 see <https://example.com/x>"
+        );
+    }
+
+    #[test]
+    fn media_renders_as_image_or_attachment() {
+        let adf = doc(json!([
+            { "type": "mediaSingle", "content": [
+                { "type": "media", "attrs": { "type": "external", "url": "https://example.com/a.png", "alt": "diagram" } }
+            ]},
+            { "type": "mediaSingle", "content": [
+                { "type": "media", "attrs": { "type": "file", "id": "uuid-1", "collection": "", "alt": "screenshot.png" } }
+            ]}
+        ]));
+        assert_eq!(
+            adf_to_markdown(&adf),
+            "![diagram](https://example.com/a.png)\n\n[attachment: screenshot.png]"
+        );
+    }
+
+    #[test]
+    fn table_without_header_row_gets_empty_header() {
+        let adf = doc(json!([
+            { "type": "table", "content": [
+                { "type": "tableRow", "content": [
+                    { "type": "tableCell", "content": [
+                        { "type": "paragraph", "content": [{ "type": "text", "text": "a|b" }] }
+                    ]},
+                    { "type": "tableCell", "content": [
+                        { "type": "paragraph", "content": [{ "type": "text", "text": "c" }] }
+                    ]}
+                ]}
+            ]}
+        ]));
+        assert_eq!(
+            adf_to_markdown(&adf),
+            "|  |  |\n| --- | --- |\n| a\\|b | c |"
+        );
+    }
+}
@@ -1,2 +1,12 @@
 pub(crate) mod adf;
 pub(crate) mod storage;
+pub(crate) mod wiki;
+
+/// Render a Jira rich-text value as Markdown: an ADF document on Cloud, a
+/// wiki markup string on Server / Data Center.
+pub(crate) fn rich_text_to_markdown(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(wiki) => wiki::wiki_to_markdown(wiki),
+        adf => adf::adf_to_markdown(adf),
+    }
+}
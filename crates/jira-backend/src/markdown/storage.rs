@@ -2,85 +2,643 @@
 // Content Format Conversion
 // ============================================================================
 
-/// Convert Confluence storage format (XHTML) to plain text
-pub(crate) fn storage_to_text(storage: &str) -> String {
-    let mut result = storage.to_string();
-
-    // Replace common block elements with newlines
-    result = result.replace("<br/>", "\n");
-    result = result.replace("<br />", "\n");
-    result = result.replace("<br>", "\n");
-    result = result.replace("</p>", "\n");
-    result = result.replace("</div>", "\n");
-    result = result.replace("</li>", "\n");
-    result = result.replace("</h1>", "\n\n");
-    result = result.replace("</h2>", "\n\n");
-    result = result.replace("</h3>", "\n\n");
-    result = result.replace("</h4>", "\n");
-    result = result.replace("</h5>", "\n");
-    result = result.replace("</h6>", "\n");
-
-    // Strip HTML tags while fully preserving CDATA contents
-    let chars: Vec<char> = result.chars().collect();
-    let mut output = String::new();
-    let mut i = 0;
-    let mut in_tag = false;
-    let mut in_cdata = false;
-
-    while i < chars.len() {
-        if in_cdata {
-            // Check if we are at the end of CDATA: "]]>"
-            if i + 2 < chars.len() && chars[i] == ']' && chars[i + 1] == ']' && chars[i + 2] == '>'
+/// Convert Confluence storage format (XHTML) to Markdown.
+///
+/// The document is mapped onto ADF nodes and rendered with the ADF Markdown
+/// writer, so both Atlassian formats read back identically. Code macros
+/// become fenced blocks, info/note/warning/tip macros labelled blockquotes,
+/// `ac:task-list` a task list, and `ri:page`/`ri:attachment` references
+/// `page:`/`attachment:` links that [`markdown_to_storage`] turns back into
+/// Confluence links.
+pub(crate) fn storage_to_markdown(storage: &str) -> String {
+    let nodes = parse_storage(storage);
+    let doc = serde_json::json!({
+        "type": "doc",
+        "version": 1,
+        "content": storage_blocks(&nodes),
+    });
+    super::adf::adf_to_markdown(&doc)
+}
+
+/// A node of the (lenient) storage-format XML tree.
+#[derive(Debug)]
+enum XmlNode {
+    Element {
+        name: String,
+        attrs: Vec<(String, String)>,
+        children: Vec<XmlNode>,
+    },
+    Text(String),
+}
+
+impl XmlNode {
+    fn attr(&self, key: &str) -> Option<&str> {
+        match self {
+            XmlNode::Element { attrs, .. } => attrs
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str()),
+            XmlNode::Text(_) => None,
+        }
+    }
+
+    fn children(&self) -> &[XmlNode] {
+        match self {
+            XmlNode::Element { children, .. } => children,
+            XmlNode::Text(_) => &[],
+        }
+    }
+
+    fn child(&self, name: &str) -> Option<&XmlNode> {
+        self.children().iter().find(|c| c.name() == name)
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            XmlNode::Element { name, .. } => name,
+            XmlNode::Text(_) => "",
+        }
+    }
+
+    /// Concatenated text of all descendants, untouched.
+    fn text(&self) -> String {
+        match self {
+            XmlNode::Text(t) => t.clone(),
+            XmlNode::Element { children, .. } => children.iter().map(XmlNode::text).collect(),
+        }
+    }
+
+    /// Value of the `ac:parameter` child with the given name.
+    fn macro_param(&self, name: &str) -> Option<String> {
+        self.children()
+            .iter()
+            .find(|c| c.name() == "ac:parameter" && c.attr("ac:name") == Some(name))
+            .map(|c| c.text().trim().to_string())
+            .filter(|s| !s.is_empty())
+    }
+}
+
+const VOID_ELEMENTS: &[&str] = &["br", "hr", "img", "col", "meta", "input", "wbr"];
+
+/// Parse storage XHTML into a tree. Unbalanced markup is tolerated: closing
+/// tags pop back to the matching open element and stray ones are ignored.
+fn parse_storage(input: &str) -> Vec<XmlNode> {
+    fn element(name: String, attrs: Vec<(String, String)>) -> XmlNode {
+        XmlNode::Element {
+            name,
+            attrs,
+            children: Vec::new(),
+        }
+    }
+    fn push(stack: &mut [XmlNode], node: XmlNode) {
+        if let Some(XmlNode::Element { children, .. }) = stack.last_mut() {
+            children.push(node);
+        }
+    }
+    fn close(stack: &mut Vec<XmlNode>) {
+        if let Some(node) = stack.pop() {
+            push(stack, node);
+        }
+    }
+
+    let mut stack = vec![element(String::new(), Vec::new())];
+    let mut rest = input;
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("<![CDATA[") {
+            let end = after.find("]]>").unwrap_or(after.len());
+            push(&mut stack, XmlNode::Text(after[..end].to_string()));
+            rest = after.get(end + 3..).unwrap_or("");
+        } else if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.find("-->").map(|i| &after[i + 3..]).unwrap_or("");
+        } else if let Some(after) = rest.strip_prefix("</") {
+            let end = after.find('>').unwrap_or(after.len());
+            let name = after[..end].trim().to_ascii_lowercase();
+            rest = after.get(end + 1..).unwrap_or("");
+            if let Some(pos) = stack.iter().rposition(|n| n.name() == name)
+                && pos > 0
             {
-                in_cdata = false;
-                i += 3;
-            } else {
-                output.push(chars[i]);
-                i += 1;
+                while stack.len() > pos {
+                    close(&mut stack);
+                }
             }
-        } else if in_tag {
-            if chars[i] == '>' {
-                in_tag = false;
+        } else if rest.starts_with('<') && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+        {
+            let end = tag_end(rest);
+            let inner = &rest[1..end];
+            rest = rest.get(end + 1..).unwrap_or("");
+            let self_closing = inner.ends_with('/');
+            let inner = inner.trim_end_matches('/');
+            let name_end = inner
+                .find(|c: char| c.is_whitespace())
+                .unwrap_or(inner.len());
+            let name = inner[..name_end].to_ascii_lowercase();
+            let attrs = parse_attrs(&inner[name_end..]);
+            let void = self_closing || VOID_ELEMENTS.contains(&name.as_str());
+            stack.push(element(name, attrs));
+            if void {
+                close(&mut stack);
             }
-            i += 1;
         } else {
-            // Check for CDATA start: "<![CDATA["
-            if chars[i] == '<'
-                && i + 8 < chars.len()
-                && chars[i + 1] == '!'
-                && chars[i + 2] == '['
-                && chars[i + 3] == 'C'
-                && chars[i + 4] == 'D'
-                && chars[i + 5] == 'A'
-                && chars[i + 6] == 'T'
-                && chars[i + 7] == 'A'
-                && chars[i + 8] == '['
-            {
-                in_cdata = true;
-                i += 9;
-            } else if chars[i] == '<' {
-                in_tag = true;
-                i += 1;
+            let end = rest[1..].find('<').map(|i| i + 1).unwrap_or(rest.len());
+            push(&mut stack, XmlNode::Text(decode_entities(&rest[..end])));
+            rest = &rest[end..];
+        }
+    }
+
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+    match stack.pop() {
+        Some(XmlNode::Element { children, .. }) => children,
+        _ => Vec::new(),
+    }
+}
+
+/// Index of the `>` closing the tag that starts `input`, skipping quoted
+/// attribute values.
+fn tag_end(input: &str) -> usize {
+    let mut quote = None;
+    for (i, c) in input.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            (None, '>') => return i,
+            _ => {}
+        }
+    }
+    input.len()
+}
+
+fn parse_attrs(input: &str) -> Vec<(String, String)> {
+    let mut attrs = Vec::new();
+    let mut rest = input.trim_start();
+    while !rest.is_empty() {
+        let key_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let key = rest[..key_end].to_ascii_lowercase();
+        rest = rest[key_end..].trim_start();
+        let value = if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            match after.chars().next() {
+                Some(q @ ('"' | '\'')) => {
+                    let end = after[1..].find(q).map(|i| i + 1).unwrap_or(after.len());
+                    let value = &after[1..end];
+                    rest = after.get(end + 1..).unwrap_or("");
+                    value
+                }
+                _ => {
+                    let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                    rest = &after[end..];
+                    &after[..end]
+                }
+            }
+        } else {
+            ""
+        };
+        if !key.is_empty() {
+            attrs.push((key, decode_entities(value)));
+        }
+        rest = rest.trim_start();
+    }
+    attrs
+}
+
+fn decode_entities(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest[1..].find(';').map(|i| i + 1).filter(|&i| i <= 10) else {
+            out.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            "ndash" => Some('–'),
+            "mdash" => Some('—'),
+            "hellip" => Some('…'),
+            "lsquo" => Some('‘'),
+            "rsquo" => Some('’'),
+            "ldquo" => Some('“'),
+            "rdquo" => Some('”'),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|d| d.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn is_block_element(node: &XmlNode) -> bool {
+    match node.name() {
+        "p" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "ul" | "ol" | "table" | "blockquote"
+        | "pre" | "hr" | "div" | "section" | "ac:task-list" | "ac:layout" | "ac:layout-section"
+        | "ac:layout-cell" | "ac:rich-text-body" => true,
+        "ac:structured-macro" => node.attr("ac:name") != Some("status"),
+        _ => false,
+    }
+}
+
+/// Convert mixed block/inline children to ADF block nodes. Runs of inline
+/// content become paragraphs; whitespace between blocks is dropped.
+fn storage_blocks(nodes: &[XmlNode]) -> Vec<serde_json::Value> {
+    let mut blocks = Vec::new();
+    let mut run = Vec::new();
+    for node in nodes {
+        if is_block_element(node) {
+            flush_paragraph(&mut run, &mut blocks);
+            blocks.extend(storage_block(node));
+        } else {
+            storage_inline(node, &[], &mut run);
+        }
+    }
+    flush_paragraph(&mut run, &mut blocks);
+    blocks
+}
+
+fn flush_paragraph(run: &mut Vec<serde_json::Value>, blocks: &mut Vec<serde_json::Value>) {
+    let content = trim_inline(std::mem::take(run));
+    if !content.is_empty() {
+        blocks.push(serde_json::json!({ "type": "paragraph", "content": content }));
+    }
+}
+
+/// Trim whitespace at the edges of an inline run, dropping emptied nodes.
+fn trim_inline(mut nodes: Vec<serde_json::Value>) -> Vec<serde_json::Value> {
+    fn trim(node: &mut serde_json::Value, start: bool) -> bool {
+        let Some(text) = node.get("text").and_then(|t| t.as_str()) else {
+            return false;
+        };
+        let trimmed = if start {
+            text.trim_start()
+        } else {
+            text.trim_end()
+        }
+        .to_string();
+        let empty = trimmed.is_empty();
+        node["text"] = serde_json::Value::String(trimmed);
+        empty
+    }
+    while nodes.first_mut().is_some_and(|n| trim(n, true)) {
+        nodes.remove(0);
+    }
+    while nodes.last_mut().is_some_and(|n| trim(n, false)) {
+        nodes.pop();
+    }
+    nodes
+}
+
+fn storage_block(node: &XmlNode) -> Vec<serde_json::Value> {
+    use serde_json::json;
+
+    let inline = |node: &XmlNode| {
+        let mut run = Vec::new();
+        for child in node.children() {
+            storage_inline(child, &[], &mut run);
+        }
+        trim_inline(run)
+    };
+
+    match node.name() {
+        "p" => {
+            let content = inline(node);
+            if content.is_empty() {
+                Vec::new()
+            } else {
+                vec![json!({ "type": "paragraph", "content": content })]
+            }
+        }
+        name @ ("h1" | "h2" | "h3" | "h4" | "h5" | "h6") => {
+            let level: u64 = name[1..].parse().unwrap_or(1);
+            vec![json!({ "type": "heading", "attrs": { "level": level }, "content": inline(node) })]
+        }
+        "ul" | "ol" => {
+            let items: Vec<_> = node
+                .children()
+                .iter()
+                .filter(|c| c.name() == "li")
+                .map(|li| json!({ "type": "listItem", "content": storage_blocks(li.children()) }))
+                .collect();
+            if node.name() == "ul" {
+                vec![json!({ "type": "bulletList", "content": items })]
             } else {
-                output.push(chars[i]);
-                i += 1;
+                let order: u64 = node.attr("start").and_then(|s| s.parse().ok()).unwrap_or(1);
+                vec![
+                    json!({ "type": "orderedList", "attrs": { "order": order }, "content": items }),
+                ]
             }
         }
+        "ac:task-list" => {
+            let items: Vec<_> = node
+                .children()
+                .iter()
+                .filter(|c| c.name() == "ac:task")
+                .map(|task| {
+                    let done = task
+                        .child("ac:task-status")
+                        .is_some_and(|s| s.text().trim() == "complete");
+                    let content = task.child("ac:task-body").map(inline).unwrap_or_default();
+                    json!({
+                        "type": "taskItem",
+                        "attrs": { "state": if done { "DONE" } else { "TODO" } },
+                        "content": content
+                    })
+                })
+                .collect();
+            vec![json!({ "type": "taskList", "content": items })]
+        }
+        "table" => {
+            let mut rows = Vec::new();
+            collect_rows(node, &mut rows);
+            let rows: Vec<_> = rows
+                .into_iter()
+                .map(|tr| {
+                    let cells: Vec<_> = tr
+                        .children()
+                        .iter()
+                        .filter(|c| matches!(c.name(), "th" | "td"))
+                        .map(|cell| {
+                            let kind = if cell.name() == "th" {
+                                "tableHeader"
+                            } else {
+                                "tableCell"
+                            };
+                            json!({ "type": kind, "content": storage_blocks(cell.children()) })
+                        })
+                        .collect();
+                    json!({ "type": "tableRow", "content": cells })
+                })
+                .collect();
+            vec![json!({ "type": "table", "content": rows })]
+        }
+        "blockquote" => {
+            vec![json!({ "type": "blockquote", "content": storage_blocks(node.children()) })]
+        }
+        "pre" => vec![code_block(&node.text(), None)],
+        "hr" => vec![json!({ "type": "rule" })],
+        "ac:structured-macro" => storage_macro(node),
+        _ => storage_blocks(node.children()),
     }
+}
 
-    // Decode common HTML entities
-    output = output.replace("&nbsp;", " ");
-    output = output.replace("&amp;", "&");
-    output = output.replace("&lt;", "<");
-    output = output.replace("&gt;", ">");
-    output = output.replace("&quot;", "\"");
+fn collect_rows<'a>(node: &'a XmlNode, rows: &mut Vec<&'a XmlNode>) {
+    for child in node.children() {
+        match child.name() {
+            "tr" => rows.push(child),
+            "thead" | "tbody" | "tfoot" => collect_rows(child, rows),
+            _ => {}
+        }
+    }
+}
+
+fn code_block(code: &str, language: Option<&str>) -> serde_json::Value {
+    let content = if code.is_empty() {
+        serde_json::json!([])
+    } else {
+        serde_json::json!([{ "type": "text", "text": code }])
+    };
+    match language {
+        Some(lang) => serde_json::json!({
+            "type": "codeBlock",
+            "attrs": { "language": lang },
+            "content": content
+        }),
+        None => serde_json::json!({ "type": "codeBlock", "content": content }),
+    }
+}
+
+fn storage_macro(node: &XmlNode) -> Vec<serde_json::Value> {
+    use serde_json::json;
+
+    let body = || {
+        node.child("ac:rich-text-body")
+            .map(|b| storage_blocks(b.children()))
+            .unwrap_or_default()
+    };
+    let titled = |mut content: Vec<serde_json::Value>| {
+        if let Some(title) = node.macro_param("title") {
+            content.insert(
+                0,
+                json!({ "type": "paragraph", "content": [
+                    { "type": "text", "text": title, "marks": [{ "type": "strong" }] }
+                ]}),
+            );
+        }
+        content
+    };
+
+    match node.attr("ac:name").unwrap_or("") {
+        "code" | "noformat" => {
+            let code = node
+                .child("ac:plain-text-body")
+                .map(XmlNode::text)
+                .unwrap_or_default();
+            let language = node.macro_param("language");
+            vec![code_block(&code, language.as_deref())]
+        }
+        kind @ ("info" | "note" | "warning" | "tip") => {
+            vec![
+                json!({ "type": "panel", "attrs": { "panelType": kind }, "content": titled(body()) }),
+            ]
+        }
+        "panel" => vec![json!({ "type": "blockquote", "content": titled(body()) })],
+        "expand" => {
+            let title = node.macro_param("title");
+            vec![json!({ "type": "expand", "attrs": { "title": title }, "content": body() })]
+        }
+        _ => body(),
+    }
+}
+
+/// Convert an inline storage node, appending ADF inline nodes to `out`.
+fn storage_inline(node: &XmlNode, marks: &[serde_json::Value], out: &mut Vec<serde_json::Value>) {
+    use serde_json::json;
+
+    let with_mark = |mark: serde_json::Value| {
+        let mut marks = marks.to_vec();
+        marks.push(mark);
+        marks
+    };
+    let recurse = |marks: &[serde_json::Value], out: &mut Vec<serde_json::Value>| {
+        for child in node.children() {
+            storage_inline(child, marks, out);
+        }
+    };
+
+    match node {
+        XmlNode::Text(text) => {
+            let mut collapsed = String::with_capacity(text.len());
+            for c in text.chars() {
+                if c.is_whitespace() {
+                    if !collapsed.ends_with(' ') {
+                        collapsed.push(' ');
+                    }
+                } else {
+                    collapsed.push(c);
+                }
+            }
+            let after_space = out
+                .last()
+                .and_then(|n| n.get("text"))
+                .and_then(|t| t.as_str())
+                .is_some_and(|t| t.ends_with(' '));
+            if after_space {
+                collapsed = collapsed.trim_start().to_string();
+            }
+            if collapsed.is_empty() {
+                return;
+            }
+            if marks.is_empty() {
+                out.push(json!({ "type": "text", "text": collapsed }));
+            } else {
+                out.push(json!({ "type": "text", "text": collapsed, "marks": marks }));
+            }
+        }
+        XmlNode::Element { name, .. } => match name.as_str() {
+            "strong" | "b" => recurse(&with_mark(json!({ "type": "strong" })), out),
+            "em" | "i" => recurse(&with_mark(json!({ "type": "em" })), out),
+            "del" | "s" | "strike" => recurse(&with_mark(json!({ "type": "strike" })), out),
+            "code" => recurse(&with_mark(json!({ "type": "code" })), out),
+            "a" => match node.attr("href") {
+                Some(href) => recurse(
+                    &with_mark(json!({ "type": "link", "attrs": { "href": href } })),
+                    out,
+                ),
+                None => recurse(marks, out),
+            },
+            "br" => out.push(json!({ "type": "hardBreak" })),
+            "ac:link" => storage_link(node, marks, out),
+            "ac:image" => {
+                let url = node
+                    .child("ri:url")
+                    .and_then(|u| u.attr("ri:value"))
+                    .map(str::to_string)
+                    .or_else(|| {
+                        node.child("ri:attachment")
+                            .and_then(|a| a.attr("ri:filename"))
+                            .map(|f| format!("attachment:{}", encode_link_part(f)))
+                    });
+                if let Some(url) = url {
+                    out.push(json!({
+                        "type": "mediaInline",
+                        "attrs": {
+                            "type": "external",
+                            "url": url,
+                            "alt": node.attr("ac:alt").unwrap_or("")
+                        }
+                    }));
+                }
+            }
+            "ac:emoticon" => {
+                let text = node
+                    .attr("ac:emoji-fallback")
+                    .or_else(|| node.attr("ac:name"))
+                    .unwrap_or("");
+                out.push(json!({ "type": "emoji", "attrs": { "text": text } }));
+            }
+            "ac:structured-macro" => {
+                if let Some(title) = node.macro_param("title") {
+                    out.push(json!({ "type": "status", "attrs": { "text": title } }));
+                }
+            }
+            "ac:parameter" | "ac:plain-text-body" | "ac:task-id" => {}
+            "time" => {
+                if let Some(date) = node.attr("datetime") {
+                    out.push(json!({ "type": "text", "text": date, "marks": marks }));
+                }
+            }
+            _ => recurse(marks, out),
+        },
+    }
+}
 
-    // Clean up multiple newlines
-    while output.contains("\n\n\n") {
-        output = output.replace("\n\n\n", "\n\n");
+/// `ac:link` targets a page, attachment or user. Pages and attachments become
+/// `page:` / `attachment:` links, users an `@` mention.
+fn storage_link(node: &XmlNode, marks: &[serde_json::Value], out: &mut Vec<serde_json::Value>) {
+    use serde_json::json;
+
+    if let Some(user) = node.child("ri:user") {
+        let id = user
+            .attr("ri:account-id")
+            .or_else(|| user.attr("ri:username"))
+            .or_else(|| user.attr("ri:userkey"))
+            .unwrap_or("unknown");
+        out.push(json!({ "type": "text", "text": format!("@{id}"), "marks": marks }));
+        return;
     }
 
-    output.trim().to_string()
+    let (href, default_text) = if let Some(page) = node.child("ri:page") {
+        let title = page.attr("ri:content-title").unwrap_or("");
+        let href = match page.attr("ri:space-key") {
+            Some(space) => format!(
+                "page:{}/{}",
+                encode_link_part(space),
+                encode_link_part(title)
+            ),
+            None => format!("page:{}", encode_link_part(title)),
+        };
+        (href, title.to_string())
+    } else if let Some(attachment) = node.child("ri:attachment") {
+        let file = attachment.attr("ri:filename").unwrap_or("");
+        (
+            format!("attachment:{}", encode_link_part(file)),
+            file.to_string(),
+        )
+    } else {
+        let anchor = node.attr("ac:anchor").unwrap_or("");
+        (format!("#{anchor}"), anchor.to_string())
+    };
+
+    let mut link_marks = marks.to_vec();
+    link_marks.push(json!({ "type": "link", "attrs": { "href": href } }));
+    let start = out.len();
+    if let Some(body) = node.child("ac:link-body") {
+        for child in body.children() {
+            storage_inline(child, &link_marks, out);
+        }
+    } else if let Some(body) = node.child("ac:plain-text-link-body") {
+        storage_inline(&XmlNode::Text(body.text()), &link_marks, out);
+    }
+    if out.len() == start && !default_text.is_empty() {
+        out.push(json!({ "type": "text", "text": default_text, "marks": link_marks }));
+    }
+}
+
+/// Percent-encode the characters that would end a Markdown link destination
+/// or split a `page:SPACE/Title` reference.
+fn encode_link_part(part: &str) -> String {
+    let mut out = String::with_capacity(part.len());
+    for c in part.chars() {
+        match c {
+            '%' | '/' | '(' | ')' | '<' | '>' | ' ' => out.push_str(&format!("%{:02X}", c as u32)),
+            _ => out.push(c),
+        }
+    }
+    out
 }
 
 /// Convert Markdown to Confluence storage format.
@@ -100,8 +658,13 @@ pub(crate) fn markdown_to_storage(markdown: &str) -> String {
     let mut code_block: Option<(String, String)> = None;
     let mut image: Option<(String, String)> = None;
     let mut in_table_head = false;
+    // Open lists (true for task lists) and the closing markup of open links.
+    let mut lists: Vec<bool> = Vec::new();
+    let mut links: Vec<&str> = Vec::new();
+    let mut task_id = 0;
+    let events: Vec<Event> = parser.collect();
 
-    for event in parser {
+    for (index, event) in events.iter().cloned().enumerate() {
         if let Some((_, body)) = code_block.as_mut() {
             match event {
                 Event::End(TagEnd::CodeBlock) => {
@@ -124,10 +687,16 @@ pub(crate) fn markdown_to_storage(markdown: &str) -> String {
             match event {
                 Event::End(TagEnd::Image) => {
                     let (url, alt_text) = image.take().expect("image is open");
+                    let target = match url.strip_prefix("attachment:") {
+                        Some(file) => format!(
+                            "<ri:attachment ri:filename=\"{}\" />",
+                            html_escape(&decode_link_part(file))
+                        ),
+                        None => format!("<ri:url ri:value=\"{}\" />", html_escape(&url)),
+                    };
                     result.push_str(&format!(
-                        "<ac:image ac:alt=\"{}\"><ri:url ri:value=\"{}\" /></ac:image>",
-                        html_escape(&alt_text),
-                        html_escape(&url)
+                        "<ac:image ac:alt=\"{}\">{target}</ac:image>",
+                        html_escape(&alt_text)
                     ));
                 }
                 Event::Text(text) | Event::Code(text) => alt_text.push_str(&text),
@@ -152,19 +721,63 @@ pub(crate) fn markdown_to_storage(markdown: &str) -> String {
                     code_block = Some((language, String::new()));
                 }
                 Tag::List(Some(start)) => {
+                    lists.push(false);
                     if start == 1 {
                         result.push_str("<ol>");
                     } else {
                         result.push_str(&format!("<ol start=\"{start}\">"));
                     }
                 }
-                Tag::List(None) => result.push_str("<ul>"),
-                Tag::Item => result.push_str("<li>"),
+                Tag::List(None) => {
+                    let task = task_marker(&events, index + 1).is_some();
+                    lists.push(task);
+                    result.push_str(if task { "<ac:task-list>" } else { "<ul>" });
+                }
+                Tag::Item => {
+                    if lists.last() == Some(&true) {
+                        task_id += 1;
+                        let status = if task_marker(&events, index).unwrap_or(false) {
+                            "complete"
+                        } else {
+                            "incomplete"
+                        };
+                        result.push_str(&format!(
+                            "<ac:task><ac:task-id>{task_id}</ac:task-id>\
+                             <ac:task-status>{status}</ac:task-status><ac:task-body>"
+                        ));
+                    } else {
+                        result.push_str("<li>");
+                    }
+                }
                 Tag::Emphasis => result.push_str("<em>"),
                 Tag::Strong => result.push_str("<strong>"),
                 Tag::Strikethrough => result.push_str("<del>"),
                 Tag::Link { dest_url, .. } => {
-                    result.push_str(&format!("<a href=\"{}\">", html_escape(&dest_url)));
+                    if let Some(page) = dest_url.strip_prefix("page:") {
+                        let (space, title) = match page.split_once('/') {
+                            Some((space, title)) => (Some(space), title),
+                            None => (None, page),
+                        };
+                        let space = space
+                            .map(|s| {
+                                format!(" ri:space-key=\"{}\"", html_escape(&decode_link_part(s)))
+                            })
+                            .unwrap_or_default();
+                        result.push_str(&format!(
+                            "<ac:link><ri:page{space} ri:content-title=\"{}\" /><ac:link-body>",
+                            html_escape(&decode_link_part(title))
+                        ));
+                        links.push("</ac:link-body></ac:link>");
+                    } else if let Some(file) = dest_url.strip_prefix("attachment:") {
+                        result.push_str(&format!(
+                            "<ac:link><ri:attachment ri:filename=\"{}\" /><ac:link-body>",
+                            html_escape(&decode_link_part(file))
+                        ));
+                        links.push("</ac:link-body></ac:link>");
+                    } else {
+                        result.push_str(&format!("<a href=\"{}\">", html_escape(&dest_url)));
+                        links.push("</a>");
+                    }
                 }
                 Tag::Image { dest_url, .. } => {
                     image = Some((dest_url.to_string(), String::new()));
@@ -187,13 +800,25 @@ pub(crate) fn markdown_to_storage(markdown: &str) -> String {
                 }
                 TagEnd::BlockQuote(_) => result.push_str("</blockquote>"),
                 TagEnd::CodeBlock => {}
-                TagEnd::List(true) => result.push_str("</ol>"),
-                TagEnd::List(false) => result.push_str("</ul>"),
-                TagEnd::Item => result.push_str("</li>"),
+                TagEnd::List(true) => {
+                    lists.pop();
+                    result.push_str("</ol>");
+                }
+                TagEnd::List(false) => {
+                    let task = lists.pop().unwrap_or(false);
+                    result.push_str(if task { "</ac:task-list>" } else { "</ul>" });
+                }
+                TagEnd::Item => {
+                    if lists.last() == Some(&true) {
+                        result.push_str("</ac:task-body></ac:task>");
+                    } else {
+                        result.push_str("</li>");
+                    }
+                }
                 TagEnd::Emphasis => result.push_str("</em>"),
                 TagEnd::Strong => result.push_str("</strong>"),
                 TagEnd::Strikethrough => result.push_str("</del>"),
-                TagEnd::Link => result.push_str("</a>"),
+                TagEnd::Link => result.push_str(links.pop().unwrap_or("</a>")),
                 TagEnd::Image => {}
                 TagEnd::Table => result.push_str("</tbody></table>"),
                 TagEnd::TableHead => in_table_head = false,
@@ -225,6 +850,7 @@ pub(crate) fn markdown_to_storage(markdown: &str) -> String {
             Event::SoftBreak => result.push('\n'),
             Event::HardBreak => result.push_str("<br/>"),
             Event::Rule => result.push_str("<hr/>"),
+            Event::TaskListMarker(_) if lists.last() == Some(&true) => {}
             Event::TaskListMarker(checked) => {
                 result.push_str(if checked { "[x] " } else { "[ ] " });
             }
@@ -234,6 +860,27 @@ pub(crate) fn markdown_to_storage(markdown: &str) -> String {
     result
 }
 
+/// The task-list checkbox of the list item starting at `events[index]`, if any.
+fn task_marker(events: &[pulldown_cmark::Event], index: usize) -> Option<bool> {
+    use pulldown_cmark::{Event, Tag};
+
+    for event in events.get(index..).unwrap_or(&[]) {
+        match event {
+            Event::Start(Tag::Item | Tag::Paragraph) => continue,
+            Event::TaskListMarker(checked) => return Some(*checked),
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// Inverse of `encode_link_part`.
+fn decode_link_part(part: &str) -> String {
+    urlencoding::decode(part)
+        .map(|p| p.into_owned())
+        .unwrap_or_else(|_| part.to_string())
+}
+
 fn looks_like_confluence_storage(input: &str) -> bool {
     let trimmed = input.trim_start();
     let lower = trimmed.to_ascii_lowercase();
@@ -403,10 +1050,82 @@ echo hi
     }
 
     #[test]
-    fn test_storage_to_text_correct() {
-        // Test: CDATA blocks are completely preserved, even if they contain comparative operators
+    fn storage_to_markdown_preserves_cdata_in_code_macros() {
         let storage_xml = "<ac:structured-macro ac:name=\"code\"><ac:plain-text-body><![CDATA[if x < 5 && y > 3 { println!(\"hi\"); }]]></ac:plain-text-body></ac:structured-macro>";
-        let plain_text = storage_to_text(storage_xml);
-        assert_eq!(plain_text, "if x < 5 && y > 3 { println!(\"hi\"); }");
+        let markdown = storage_to_markdown(storage_xml);
+        assert_eq!(
+            markdown,
+            "```\nif x < 5 && y > 3 { println!(\"hi\"); }\n```"
+        );
+    }
+
+    #[test]
+    fn storage_to_markdown_renders_rich_content() {
+        let storage = r#"<h2>Setup</h2>
+<p>Read the <a href="https://example.com/docs">docs</a> &amp; <strong>then</strong> <em>run</em>:</p>
+<ac:structured-macro ac:name="code" ac:schema-version="1"><ac:parameter ac:name="language">bash</ac:parameter><ac:plain-text-body><![CDATA[make install
+]]></ac:plain-text-body></ac:structured-macro>
+<ul><li>one<ul><li>nested</li></ul></li><li><p>two</p></li></ul>
+<ol start="3"><li>three</li></ol>
+<table><tbody><tr><th><p>Key</p></th><th><p>Value</p></th></tr><tr><td><p>a</p></td><td><p>1</p></td></tr></tbody></table>
+<ac:structured-macro ac:name="info"><ac:rich-text-body><p>Heads up</p></ac:rich-text-body></ac:structured-macro>
+<hr/>"#;
+
+        let markdown = storage_to_markdown(storage);
+
+        assert_eq!(
+            markdown,
+            "## Setup\n\n\
+             Read the [docs](https://example.com/docs) & **then** *run*:\n\n\
+             ```bash\nmake install\n```\n\n\
+             - one\n  - nested\n- two\n\n\
+             3. three\n\n\
+             | Key | Value |\n| --- | --- |\n| a | 1 |\n\n\
+             > **Info:** Heads up\n\n\
+             ---"
+        );
+    }
+
+    #[test]
+    fn storage_to_markdown_maps_links_images_and_tasks() {
+        let storage = r#"<ac:task-list>
+<ac:task><ac:task-id>1</ac:task-id><ac:task-status>complete</ac:task-status><ac:task-body>Ship it</ac:task-body></ac:task>
+<ac:task><ac:task-id>2</ac:task-id><ac:task-status>incomplete</ac:task-status><ac:task-body>Announce</ac:task-body></ac:task>
+</ac:task-list>
+<p>See <ac:link><ri:page ri:space-key="DOCS" ri:content-title="Install Guide" /><ac:plain-text-link-body><![CDATA[install]]></ac:plain-text-link-body></ac:link>, <ac:link><ri:attachment ri:filename="spec v2.pdf" /></ac:link> and <ac:link><ri:user ri:account-id="abc123" /></ac:link>.</p>
+<p><ac:image ac:alt="diagram"><ri:attachment ri:filename="arch.png" /></ac:image></p>"#;
+
+        let markdown = storage_to_markdown(storage);
+
+        assert_eq!(
+            markdown,
+            "- [x] Ship it\n- [ ] Announce\n\n\
+             See [install](page:DOCS/Install%20Guide), [spec v2.pdf](attachment:spec%20v2.pdf) and @abc123.\n\n\
+             ![diagram](attachment:arch.png)"
+        );
+    }
+
+    #[test]
+    fn storage_markdown_round_trip_keeps_confluence_links_and_tasks() {
+        let markdown = "- [x] Ship it\n- [ ] Announce\n\n\
+                        See [install](page:DOCS/Install%20Guide) and [spec](attachment:spec%20v2.pdf).\n\n\
+                        ![diagram](attachment:arch.png)";
+
+        let storage = markdown_to_storage(markdown);
+
+        assert!(storage.contains("<ac:task-list><ac:task><ac:task-id>1</ac:task-id><ac:task-status>complete</ac:task-status><ac:task-body>Ship it</ac:task-body></ac:task>"));
+        assert!(storage.contains(
+            "<ac:task-status>incomplete</ac:task-status><ac:task-body>Announce</ac:task-body>"
+        ));
+        assert!(storage.contains(
+            "<ac:link><ri:page ri:space-key=\"DOCS\" ri:content-title=\"Install Guide\" /><ac:link-body>install</ac:link-body></ac:link>"
+        ));
+        assert!(storage.contains(
+            "<ac:link><ri:attachment ri:filename=\"spec v2.pdf\" /><ac:link-body>spec</ac:link-body></ac:link>"
+        ));
+        assert!(storage.contains(
+            "<ac:image ac:alt=\"diagram\"><ri:attachment ri:filename=\"arch.png\" /></ac:image>"
+        ));
+        assert_eq!(storage_to_markdown(&storage), markdown);
     }
 }
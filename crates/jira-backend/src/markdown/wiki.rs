@@ -0,0 +1,991 @@
+//! Jira wiki markup, the rich-text format of Jira Server / Data Center.
+//!
+//! Both directions pivot through ADF, so Server/DC shares the Markdown reader
+//! and writer used for Cloud: [`markdown_to_wiki`] renders the document built
+//! by `markdown_to_adf` as wiki markup, and [`wiki_to_markdown`] parses wiki
+//! markup into ADF and hands it to `adf_to_markdown`.
+
+use serde_json::{Value, json};
+
+use super::adf::{adf_to_markdown, attr, children, is_inline, is_list, markdown_to_adf, node_type};
+
+/// Wiki block macros recognised at the start of a line.
+const BLOCK_MACROS: &[&str] = &[
+    "code", "noformat", "quote", "panel", "info", "note", "warning", "tip",
+];
+
+/// Wiki emoticons and the characters they render as.
+const EMOTICONS: &[(&str, &str)] = &[
+    ("(/)", "✅"),
+    ("(x)", "❌"),
+    ("(!)", "⚠️"),
+    ("(i)", "ℹ️"),
+    ("(?)", "❓"),
+    ("(y)", "👍"),
+    ("(n)", "👎"),
+    ("(on)", "💡"),
+    ("(off)", "💡"),
+    ("(*)", "⭐"),
+];
+
+/// Task list items have no wiki equivalent; they are written as bullets
+/// starting with one of these markers and read back as tasks.
+const TASK_DONE: &str = "(/) ";
+const TASK_TODO: &str = "( ) ";
+
+/// Convert Markdown to Jira wiki markup.
+///
+/// Native `[~username]` mentions pass through untouched.
+pub(crate) fn markdown_to_wiki(text: &str) -> String {
+    render_blocks(children(&markdown_to_adf(text)))
+        .trim()
+        .to_string()
+}
+
+/// Convert Jira wiki markup to Markdown.
+///
+/// Headings, lists, tables, `{code}`/`{noformat}`, `{quote}` and `bq.`,
+/// panels, links, images, mentions and the inline marks are mapped; other
+/// macros are kept as text.
+pub(crate) fn wiki_to_markdown(text: &str) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    let doc = json!({ "type": "doc", "version": 1, "content": parse_blocks(&lines) });
+    adf_to_markdown(&doc)
+}
+
+// ==================== ADF -> wiki ====================
+
+fn render_blocks(nodes: &[Value]) -> String {
+    let mut blocks = Vec::new();
+    let mut inline_run: Vec<Value> = Vec::new();
+    for node in nodes {
+        if is_inline(node) {
+            inline_run.push(node.clone());
+            continue;
+        }
+        if !inline_run.is_empty() {
+            blocks.push(render_inline(&std::mem::take(&mut inline_run)));
+        }
+        let rendered = render_block(node);
+        if !rendered.is_empty() {
+            blocks.push(rendered);
+        }
+    }
+    if !inline_run.is_empty() {
+        blocks.push(render_inline(&inline_run));
+    }
+    blocks.join("\n\n")
+}
+
+fn render_block(node: &Value) -> String {
+    match node_type(node) {
+        "paragraph" => escape_line_start(&render_inline(children(node))),
+        "heading" => {
+            let level = node
+                .get("attrs")
+                .and_then(|a| a.get("level"))
+                .and_then(|l| l.as_u64())
+                .unwrap_or(1)
+                .clamp(1, 6);
+            format!("h{level}. {}", render_inline(children(node)))
+        }
+        "codeBlock" => {
+            let code: String = children(node)
+                .iter()
+                .filter_map(|n| n.get("text").and_then(|t| t.as_str()))
+                .collect();
+            let code = code.trim_end_matches('\n');
+            // `{code}` without a language highlights as Java; plain blocks
+            // use `{noformat}` instead.
+            match attr(node, "language") {
+                Some(lang) => format!("{{code:{lang}}}\n{code}\n{{code}}"),
+                None => format!("{{noformat}}\n{code}\n{{noformat}}"),
+            }
+        }
+        "blockquote" => format!("{{quote}}\n{}\n{{quote}}", render_blocks(children(node))),
+        "panel" => {
+            let name = match attr(node, "panelType").unwrap_or("info") {
+                "note" => "note",
+                "warning" | "error" => "warning",
+                "tip" | "success" => "tip",
+                _ => "info",
+            };
+            format!("{{{name}}}\n{}\n{{{name}}}", render_blocks(children(node)))
+        }
+        "expand" | "nestedExpand" => {
+            let body = render_blocks(children(node));
+            match attr(node, "title") {
+                Some(title) => format!("*{}*\n\n{body}", escape_text(title)),
+                None => body,
+            }
+        }
+        "bulletList" | "orderedList" | "taskList" | "decisionList" => {
+            render_list(node, "").join("\n")
+        }
+        "rule" => "----".to_string(),
+        "table" => render_table(node),
+        "mediaSingle" | "mediaGroup" => children(node)
+            .iter()
+            .map(render_media)
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "media" => render_media(node),
+        "blockCard" | "embedCard" => attr(node, "url")
+            .map(|url| format!("[{url}]"))
+            .unwrap_or_default(),
+        _ => render_blocks(children(node)),
+    }
+}
+
+/// Render a list as wiki item lines. Nesting is expressed by the marker
+/// path (`*`, `#*`, …), so nested lists extend `prefix`.
+fn render_list(node: &Value, prefix: &str) -> Vec<String> {
+    let marker = if node_type(node) == "orderedList" {
+        "#"
+    } else {
+        "*"
+    };
+    let prefix = format!("{prefix}{marker}");
+    let mut lines = Vec::new();
+
+    for item in children(node) {
+        if is_list(item) {
+            lines.extend(render_list(item, &prefix));
+            continue;
+        }
+        let mut body = Vec::new();
+        let mut nested = Vec::new();
+        let mut inline_run: Vec<Value> = Vec::new();
+        for child in children(item) {
+            if is_inline(child) {
+                inline_run.push(child.clone());
+            } else if is_list(child) {
+                nested.extend(render_list(child, &prefix));
+            } else {
+                body.push(render_block(child));
+            }
+        }
+        if !inline_run.is_empty() {
+            body.insert(0, render_inline(&inline_run));
+        }
+        let task = match (node_type(item), attr(item, "state")) {
+            ("taskItem", Some("DONE")) => TASK_DONE,
+            ("taskItem", _) => TASK_TODO,
+            _ => "",
+        };
+        // Wiki list items are single lines; `\\` forces the line breaks.
+        lines.push(format!("{prefix} {task}{}", body.join(" \\\\ ")));
+        lines.extend(nested);
+    }
+    lines
+}
+
+fn render_table(node: &Value) -> String {
+    children(node)
+        .iter()
+        .map(|row| {
+            let mut line = String::new();
+            let mut delimiter = "|";
+            for cell in children(row) {
+                delimiter = if node_type(cell) == "tableHeader" {
+                    "||"
+                } else {
+                    "|"
+                };
+                let text = render_blocks(children(cell))
+                    .replace("\n\n", " \\\\ ")
+                    .replace('\n', " ");
+                line.push_str(delimiter);
+                line.push_str(if text.is_empty() { " " } else { &text });
+            }
+            line.push_str(delimiter);
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_media(node: &Value) -> String {
+    match (attr(node, "type"), attr(node, "url")) {
+        (Some("external"), Some(url)) => format!("!{url}!"),
+        _ => {
+            let name = attr(node, "alt")
+                .or_else(|| attr(node, "id"))
+                .unwrap_or("file");
+            format!("[^{name}]")
+        }
+    }
+}
+
+fn render_inline(nodes: &[Value]) -> String {
+    let mut out = String::new();
+    for node in merge_text(nodes) {
+        match node_type(&node) {
+            "text" => out.push_str(&render_text(&node)),
+            "hardBreak" => out.push('\n'),
+            "mention" => {
+                let id = attr(&node, "id")
+                    .or_else(|| attr(&node, "text"))
+                    .unwrap_or("unknown");
+                out.push_str(&format!("[~{}]", id.trim_start_matches('@')));
+            }
+            "emoji" => out.push_str(
+                attr(&node, "text")
+                    .or_else(|| attr(&node, "shortName"))
+                    .unwrap_or(""),
+            ),
+            "status" => {
+                if let Some(text) = attr(&node, "text") {
+                    out.push_str(&format!("{{{{{text}}}}}"));
+                }
+            }
+            "inlineCard" => {
+                if let Some(url) = attr(&node, "url") {
+                    out.push_str(&format!("[{url}]"));
+                }
+            }
+            "date" => {
+                let date = attr(&node, "timestamp")
+                    .and_then(|ts| ts.parse::<i64>().ok())
+                    .and_then(chrono::DateTime::from_timestamp_millis)
+                    .map(|d| d.format("%Y-%m-%d").to_string());
+                if let Some(date) = date {
+                    out.push_str(&date);
+                }
+            }
+            "mediaInline" => out.push_str(&render_media(&node)),
+            _ => out.push_str(&render_inline(children(&node))),
+        }
+    }
+    out
+}
+
+/// Merge adjacent text nodes with the same marks. pulldown-cmark splits text
+/// at brackets, and a `[~username]` mention has to be escaped as one piece.
+fn merge_text(nodes: &[Value]) -> Vec<Value> {
+    let mut merged: Vec<Value> = Vec::with_capacity(nodes.len());
+    for node in nodes {
+        if let Some(last) = merged.last_mut()
+            && node_type(last) == "text"
+            && node_type(node) == "text"
+            && last.get("marks") == node.get("marks")
+        {
+            let text = format!(
+                "{}{}",
+                last["text"].as_str().unwrap_or(""),
+                node["text"].as_str().unwrap_or("")
+            );
+            last["text"] = Value::String(text);
+            continue;
+        }
+        merged.push(node.clone());
+    }
+    merged
+}
+
+fn render_text(node: &Value) -> String {
+    let text = node.get("text").and_then(|t| t.as_str()).unwrap_or("");
+    let marks: Vec<&Value> = node
+        .get("marks")
+        .and_then(|m| m.as_array())
+        .map(|m| m.iter().collect())
+        .unwrap_or_default();
+    let has = |name: &str| marks.iter().any(|m| node_type(m) == name);
+
+    // Wiki marks only apply when the delimiters hug the text.
+    let core = text.trim();
+    if core.is_empty() {
+        return text.to_string();
+    }
+    let lead = &text[..text.len() - text.trim_start().len()];
+    let trail = &text[text.trim_end().len()..];
+
+    let mut inner = if has("code") {
+        format!("{{{{{core}}}}}")
+    } else {
+        escape_text(core)
+    };
+    if has("em") {
+        inner = format!("_{inner}_");
+    }
+    if has("strong") {
+        inner = format!("*{inner}*");
+    }
+    if has("strike") {
+        inner = format!("-{inner}-");
+    }
+    if has("underline") {
+        inner = format!("+{inner}+");
+    }
+    if let Some(link) = marks.iter().find(|m| node_type(m) == "link")
+        && let Some(href) = attr(link, "href")
+    {
+        inner = if core == href {
+            format!("[{href}]")
+        } else {
+            format!("[{inner}|{href}]")
+        };
+    }
+    format!("{lead}{inner}{trail}")
+}
+
+/// Backslash-escape characters that would otherwise start wiki markup.
+/// Mark characters are only escaped where they could open or close a mark,
+/// so hyphenated words and `snake_case` stay readable; `[~username]`
+/// mentions are kept as they are.
+fn escape_text(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '['
+            && chars.get(i + 1) == Some(&'~')
+            && let Some(len) = chars[i..].iter().position(|&c| c == ']')
+        {
+            out.extend(&chars[i..=i + len]);
+            i += len + 1;
+            continue;
+        }
+
+        let prev = i.checked_sub(1).map(|j| chars[j]);
+        let next = chars.get(i + 1).copied();
+        let word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+        let solid = |c: Option<char>| c.is_some_and(|c| !c.is_whitespace());
+        let escape = match c {
+            '\\' | '{' | '}' | '[' | ']' | '|' => true,
+            '!' => solid(next),
+            '*' | '_' | '-' | '+' | '^' | '~' => {
+                (!word(prev) && solid(next)) || (solid(prev) && !word(next))
+            }
+            _ => false,
+        };
+        if escape {
+            out.push('\\');
+        }
+        out.push(c);
+        i += 1;
+    }
+    out
+}
+
+/// Escape the start of a paragraph that would read as a heading, list,
+/// quote or rule.
+fn escape_line_start(text: &str) -> String {
+    let markers = text.len() - text.trim_start_matches(['*', '#']).len();
+    let list = markers > 0 && text[markers..].starts_with(' ');
+    let heading = text.len() >= 4
+        && text.starts_with('h')
+        && matches!(text.as_bytes()[1], b'1'..=b'6')
+        && text[2..].starts_with(". ");
+    if list || heading || text.starts_with("- ") || text.starts_with("bq. ") {
+        format!("\\{text}")
+    } else {
+        text.to_string()
+    }
+}
+
+// ==================== wiki -> ADF ====================
+
+fn parse_blocks(lines: &[&str]) -> Vec<Value> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i].trim_start();
+        if line.trim().is_empty() {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            i += 1;
+            continue;
+        }
+
+        if let Some((name, params, rest)) = block_macro(line) {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            let (body, next) = macro_body(lines, i, name, rest);
+            blocks.push(macro_block(name, params, &body));
+            i = next;
+            continue;
+        }
+        if let Some((level, text)) = heading(line) {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            blocks.push(json!({
+                "type": "heading",
+                "attrs": { "level": level },
+                "content": parse_inline(text.trim()),
+            }));
+            i += 1;
+            continue;
+        }
+        if let Some(text) = line.strip_prefix("bq. ") {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            blocks.push(json!({
+                "type": "blockquote",
+                "content": [{ "type": "paragraph", "content": parse_inline(text.trim()) }],
+            }));
+            i += 1;
+            continue;
+        }
+        if line.trim_end().len() >= 4 && line.trim_end().chars().all(|c| c == '-') {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            blocks.push(json!({ "type": "rule" }));
+            i += 1;
+            continue;
+        }
+        if list_item(line).is_some() {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            let mut items = Vec::new();
+            while let Some(item) = lines.get(i).and_then(|l| list_item(l.trim_start())) {
+                items.push(item);
+                i += 1;
+            }
+            blocks.extend(build_lists(&items, 0));
+            continue;
+        }
+        if line.starts_with('|') {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            let mut rows = Vec::new();
+            while let Some(row) = lines
+                .get(i)
+                .map(|l| l.trim())
+                .filter(|l| l.starts_with('|'))
+            {
+                rows.push(row);
+                i += 1;
+            }
+            blocks.push(parse_table(&rows));
+            continue;
+        }
+
+        paragraph.push(line);
+        i += 1;
+    }
+    flush_paragraph(&mut paragraph, &mut blocks);
+    blocks
+}
+
+/// A paragraph's lines, joined by hard breaks (wiki renders newlines).
+fn flush_paragraph(lines: &mut Vec<&str>, blocks: &mut Vec<Value>) {
+    if lines.is_empty() {
+        return;
+    }
+    let mut content = Vec::new();
+    for (i, line) in lines.drain(..).enumerate() {
+        if i > 0 {
+            content.push(json!({ "type": "hardBreak" }));
+        }
+        content.extend(parse_inline(line.trim()));
+    }
+    blocks.push(json!({ "type": "paragraph", "content": content }));
+}
+
+/// Split `{name:params}rest` when `name` is a block macro.
+fn block_macro(line: &str) -> Option<(&str, &str, &str)> {
+    let inner = line.strip_prefix('{')?;
+    let end = inner.find('}')?;
+    let (name, params) = inner[..end].split_once(':').unwrap_or((&inner[..end], ""));
+    BLOCK_MACROS
+        .contains(&name)
+        .then(|| (name, params, &inner[end + 1..]))
+}
+
+/// Collect a block macro's body up to its closing `{name}` tag, returning
+/// the body and the index of the line after it.
+fn macro_body(lines: &[&str], start: usize, name: &str, rest: &str) -> (String, usize) {
+    let close = format!("{{{name}}}");
+    if let Some(end) = rest.find(&close) {
+        return (rest[..end].to_string(), start + 1);
+    }
+
+    let mut body: Vec<&str> = Vec::new();
+    if !rest.trim().is_empty() {
+        body.push(rest);
+    }
+    for (i, line) in lines.iter().enumerate().skip(start + 1) {
+        if let Some(end) = line.find(&close) {
+            if !line[..end].trim().is_empty() {
+                body.push(&line[..end]);
+            }
+            return (body.join("\n"), i + 1);
+        }
+        body.push(line);
+    }
+    (body.join("\n"), lines.len())
+}
+
+fn macro_block(name: &str, params: &str, body: &str) -> Value {
+    let param = |key: &str| {
+        params
+            .split('|')
+            .find_map(|p| p.strip_prefix(key)?.strip_prefix('='))
+            .filter(|v| !v.is_empty())
+    };
+    let content = || parse_blocks(&body.lines().collect::<Vec<_>>());
+    let titled = |mut content: Vec<Value>| {
+        if let Some(title) = param("title") {
+            content.insert(
+                0,
+                json!({ "type": "paragraph", "content": [
+                    { "type": "text", "text": title, "marks": [{ "type": "strong" }] }
+                ]}),
+            );
+        }
+        content
+    };
+
+    match name {
+        "code" | "noformat" => {
+            let language = param("language").or_else(|| {
+                params
+                    .split('|')
+                    .next()
+                    .filter(|p| name == "code" && !p.is_empty() && !p.contains('='))
+            });
+            let code = body.trim_matches('\n');
+            let content = if code.is_empty() {
+                json!([])
+            } else {
+                json!([{ "type": "text", "text": code }])
+            };
+            match language {
+                Some(lang) => {
+                    json!({ "type": "codeBlock", "attrs": { "language": lang }, "content": content })
+                }
+                None => json!({ "type": "codeBlock", "content": content }),
+            }
+        }
+        "quote" => json!({ "type": "blockquote", "content": content() }),
+        "panel" => json!({ "type": "blockquote", "content": titled(content()) }),
+        kind => {
+            json!({ "type": "panel", "attrs": { "panelType": kind }, "content": titled(content()) })
+        }
+    }
+}
+
+fn heading(line: &str) -> Option<(u8, &str)> {
+    let rest = line.strip_prefix('h')?;
+    let level = rest
+        .chars()
+        .next()?
+        .to_digit(10)
+        .filter(|l| (1..=6).contains(l))?;
+    let text = rest[1..].strip_prefix('.')?;
+    (text.is_empty() || text.starts_with(' ')).then_some((level as u8, text))
+}
+
+/// A list line: its marker path and text.
+type ListLine<'a> = (String, &'a str);
+
+/// Parse a list line into its marker path (`-` normalised to `*`) and text.
+fn list_item(line: &str) -> Option<ListLine<'_>> {
+    let len = line.len() - line.trim_start_matches(['*', '#', '-']).len();
+    let markers = &line[..len];
+    if len == 0 || !line[len..].starts_with(' ') || (markers.contains('-') && markers != "-") {
+        return None;
+    }
+    Some((markers.replace('-', "*"), line[len..].trim()))
+}
+
+/// Build the lists at `depth` from consecutive list lines. Deeper lines nest
+/// under the preceding item; a change of marker starts a new list.
+fn build_lists(items: &[ListLine], depth: usize) -> Vec<Value> {
+    let marker_at = |markers: &str| markers.as_bytes().get(depth).copied();
+    let mut lists = Vec::new();
+    let mut i = 0;
+
+    while i < items.len() {
+        let kind = marker_at(&items[i].0);
+        let mut entries: Vec<(Option<&str>, Vec<ListLine>)> = Vec::new();
+        while let Some((markers, text)) = items.get(i).filter(|(m, _)| marker_at(m) == kind) {
+            if markers.len() == depth + 1 {
+                entries.push((Some(text), Vec::new()));
+            } else {
+                if entries.is_empty() {
+                    entries.push((None, Vec::new()));
+                }
+                if let Some((_, nested)) = entries.last_mut() {
+                    nested.push((markers.clone(), text));
+                }
+            }
+            i += 1;
+        }
+
+        let tasks = kind == Some(b'*')
+            && entries
+                .iter()
+                .all(|(text, _)| text.is_some_and(|t| task_state(t).is_some()));
+        let content: Vec<Value> = entries
+            .into_iter()
+            .map(|(text, nested)| {
+                let nested = build_lists(&nested, depth + 1);
+                if tasks && let Some((done, text)) = text.and_then(task_state) {
+                    let mut content = parse_inline(text);
+                    content.extend(nested);
+                    let state = if done { "DONE" } else { "TODO" };
+                    return json!({ "type": "taskItem", "attrs": { "state": state }, "content": content });
+                }
+                let mut content = Vec::new();
+                if let Some(text) = text {
+                    content.push(json!({ "type": "paragraph", "content": parse_inline(text) }));
+                }
+                content.extend(nested);
+                json!({ "type": "listItem", "content": content })
+            })
+            .collect();
+
+        let list_type = match kind {
+            _ if tasks => "taskList",
+            Some(b'#') => "orderedList",
+            _ => "bulletList",
+        };
+        lists.push(json!({ "type": list_type, "content": content }));
+    }
+    lists
+}
+
+fn task_state(text: &str) -> Option<(bool, &str)> {
+    if let Some(rest) = text.strip_prefix(TASK_DONE) {
+        Some((true, rest))
+    } else {
+        text.strip_prefix(TASK_TODO).map(|rest| (false, rest))
+    }
+}
+
+fn parse_table(rows: &[&str]) -> Value {
+    let rows: Vec<Value> = rows
+        .iter()
+        .map(|row| {
+            let cells: Vec<Value> = split_cells(row)
+                .into_iter()
+                .map(|(header, text)| {
+                    json!({
+                        "type": if header { "tableHeader" } else { "tableCell" },
+                        "content": [{ "type": "paragraph", "content": parse_inline(text.trim()) }],
+                    })
+                })
+                .collect();
+            json!({ "type": "tableRow", "content": cells })
+        })
+        .collect();
+    json!({ "type": "table", "content": rows })
+}
+
+/// Split a table row into `(is_header, text)` cells. `||` opens a header
+/// cell; pipes inside `[link|url]` and escaped pipes don't split.
+fn split_cells(row: &str) -> Vec<(bool, String)> {
+    let mut cells = Vec::new();
+    let mut current: Option<(bool, String)> = None;
+    let mut brackets = 0usize;
+    let mut chars = row.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some((_, text)) = current.as_mut() {
+                    text.push(c);
+                    text.extend(chars.next());
+                }
+                continue;
+            }
+            '[' => brackets += 1,
+            ']' => brackets = brackets.saturating_sub(1),
+            '|' if brackets == 0 => {
+                let header = chars.next_if_eq(&'|').is_some();
+                cells.extend(current.take());
+                current = Some((header, String::new()));
+                continue;
+            }
+            _ => {}
+        }
+        if let Some((_, text)) = current.as_mut() {
+            text.push(c);
+        }
+    }
+    // The closing delimiter opens an empty trailing cell.
+    cells.extend(current.filter(|(_, text)| !text.trim().is_empty()));
+    cells
+}
+
+fn parse_inline(text: &str) -> Vec<Value> {
+    let mut out = Vec::new();
+    inline_into(text, &[], &mut out);
+    out
+}
+
+fn inline_into(text: &str, marks: &[Value], out: &mut Vec<Value>) {
+    let with_mark = |mark: Value| {
+        let mut marks = marks.to_vec();
+        marks.push(mark);
+        marks
+    };
+    let mut buf = String::new();
+    let mut pos = 0;
+
+    while let Some(c) = text[pos..].chars().next() {
+        let rest = &text[pos..];
+        let prev = text[..pos].chars().next_back();
+
+        match c {
+            '\\' => {
+                if rest.starts_with("\\\\") {
+                    push_text(out, &std::mem::take(&mut buf), marks);
+                    out.push(json!({ "type": "hardBreak" }));
+                    pos += 2;
+                    continue;
+                }
+                if let Some(next) = rest[1..].chars().next() {
+                    buf.push(next);
+                    pos += 1 + next.len_utf8();
+                    continue;
+                }
+            }
+            '{' if rest.starts_with("{{") => {
+                if let Some(end) = rest[2..].find("}}") {
+                    push_text(out, &std::mem::take(&mut buf), marks);
+                    push_text(
+                        out,
+                        &rest[2..2 + end],
+                        &with_mark(json!({ "type": "code" })),
+                    );
+                    pos += end + 4;
+                    continue;
+                }
+            }
+            '{' => {
+                // Formatting-only macros like {color:red}…{color} are dropped.
+                if let Some(end) = rest.find('}') {
+                    let name = rest[1..end].split(':').next().unwrap_or("");
+                    if matches!(name, "color" | "anchor") {
+                        pos += end + 1;
+                        continue;
+                    }
+                }
+            }
+            '[' => {
+                if let Some(end) = rest.find(']') {
+                    push_text(out, &std::mem::take(&mut buf), marks);
+                    link(&rest[1..end], marks, out);
+                    pos += end + 1;
+                    continue;
+                }
+            }
+            '!' => {
+                if let Some(end) = rest[1..].find('!').map(|e| e + 1) {
+                    let inner = &rest[1..end];
+                    if !inner.is_empty() && inner.trim() == inner && !inner.contains('\n') {
+                        push_text(out, &std::mem::take(&mut buf), marks);
+                        let source = inner.split('|').next().unwrap_or(inner);
+                        out.push(if source.contains("://") {
+                            json!({ "type": "mediaInline", "attrs": { "type": "external", "url": source } })
+                        } else {
+                            json!({ "type": "mediaInline", "attrs": { "type": "file", "alt": source } })
+                        });
+                        pos += end + 1;
+                        continue;
+                    }
+                }
+            }
+            '(' => {
+                if let Some((code, emoji)) =
+                    EMOTICONS.iter().find(|(code, _)| rest.starts_with(code))
+                {
+                    push_text(out, &std::mem::take(&mut buf), marks);
+                    out.push(
+                        json!({ "type": "emoji", "attrs": { "shortName": code, "text": emoji } }),
+                    );
+                    pos += code.len();
+                    continue;
+                }
+            }
+            _ => {
+                if let Some(mark) = mark_for(c)
+                    && let Some(end) = closing_mark(text, pos, prev, c)
+                {
+                    push_text(out, &std::mem::take(&mut buf), marks);
+                    inline_into(&text[pos + 1..end], &with_mark(mark), out);
+                    pos = end + 1;
+                    continue;
+                }
+            }
+        }
+        buf.push(c);
+        pos += c.len_utf8();
+    }
+    push_text(out, &buf, marks);
+}
+
+fn mark_for(c: char) -> Option<Value> {
+    Some(match c {
+        '*' => json!({ "type": "strong" }),
+        '_' => json!({ "type": "em" }),
+        '-' => json!({ "type": "strike" }),
+        '+' => json!({ "type": "underline" }),
+        '^' => json!({ "type": "subsup", "attrs": { "type": "sup" } }),
+        '~' => json!({ "type": "subsup", "attrs": { "type": "sub" } }),
+        _ => return None,
+    })
+}
+
+/// Find the byte offset of the delimiter closing a mark opened at `pos`.
+/// Marks open after a non-word character and before a non-space, and close
+/// after a non-space and before a non-word character.
+fn closing_mark(text: &str, pos: usize, prev: Option<char>, delimiter: char) -> Option<usize> {
+    let word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    let next = text[pos + 1..].chars().next();
+    if word(prev) || prev == Some(delimiter) || next.is_none_or(char::is_whitespace) {
+        return None;
+    }
+    text[pos + 1..]
+        .char_indices()
+        .map(|(i, c)| (pos + 1 + i, c))
+        .skip(1)
+        .find(|&(i, c)| {
+            c == delimiter
+                && text[..i]
+                    .chars()
+                    .next_back()
+                    .is_some_and(|p| !p.is_whitespace())
+                && !word(text[i + 1..].chars().next())
+        })
+        .map(|(i, _)| i)
+}
+
+/// Convert the inside of `[...]`: mentions, attachments, and links.
+fn link(inner: &str, marks: &[Value], out: &mut Vec<Value>) {
+    if let Some(user) = inner.strip_prefix('~') {
+        let mention = match user.strip_prefix("accountid:") {
+            Some(id) => json!({ "id": id, "text": id }),
+            None => json!({ "id": user, "text": user, "username": true }),
+        };
+        out.push(json!({ "type": "mention", "attrs": mention }));
+        return;
+    }
+    if let Some(file) = inner.strip_prefix('^') {
+        out.push(json!({ "type": "mediaInline", "attrs": { "type": "file", "alt": file } }));
+        return;
+    }
+
+    let (label, target) = match inner.split_once('|') {
+        Some((label, target)) => (Some(label), target.trim()),
+        None => (None, inner.trim()),
+    };
+    let is_url = target.contains("://") || target.starts_with("mailto:");
+    match label {
+        Some(label) if !target.is_empty() => {
+            let mut marks = marks.to_vec();
+            marks.push(json!({ "type": "link", "attrs": { "href": target } }));
+            inline_into(label, &marks, out);
+        }
+        None if is_url => out.push(json!({ "type": "inlineCard", "attrs": { "url": target } })),
+        // Issue keys and anchors: keep the reference as text.
+        _ => push_text(out, label.unwrap_or(target), marks),
+    }
+}
+
+fn push_text(out: &mut Vec<Value>, text: &str, marks: &[Value]) {
+    if text.is_empty() {
+        return;
+    }
+    if let Some(last) = out.last_mut()
+        && node_type(last) == "text"
+        && last
+            .get("marks")
+            .and_then(|m| m.as_array())
+            .map_or(marks.is_empty(), |m| m.as_slice() == marks)
+    {
+        let joined = format!("{}{text}", last["text"].as_str().unwrap_or(""));
+        last["text"] = Value::String(joined);
+        return;
+    }
+    out.push(if marks.is_empty() {
+        json!({ "type": "text", "text": text })
+    } else {
+        json!({ "type": "text", "text": text, "marks": marks })
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markdown_renders_as_wiki_markup() {
+        let markdown = "## Steps\n\n\
+                        Run **make** with _care_, see [docs](https://example.com) and `cfg`.\n\n\
+                        - one\n  1. nested\n- two\n\n\
+                        ```rust\nfn main() {}\n```\n\n\
+                        > quoted\n\n\
+                        | A | B |\n| --- | --- |\n| 1 | 2 |";
+        assert_eq!(
+            markdown_to_wiki(markdown),
+            "h2. Steps\n\n\
+             Run *make* with _care_, see [docs|https://example.com] and {{cfg}}.\n\n\
+             * one\n*# nested\n* two\n\n\
+             {code:rust}\nfn main() {}\n{code}\n\n\
+             {quote}\nquoted\n{quote}\n\n\
+             ||A||B||\n|1|2|"
+        );
+    }
+
+    #[test]
+    fn markdown_to_wiki_escapes_markup_but_keeps_mentions() {
+        assert_eq!(
+            markdown_to_wiki("Ping [~jdoe] about {braces}, snake_case and -flags."),
+            "Ping [~jdoe] about \\{braces\\}, snake_case and \\-flags."
+        );
+        assert_eq!(markdown_to_wiki("\\* not a list"), "\\* not a list");
+    }
+
+    #[test]
+    fn wiki_renders_as_markdown() {
+        let wiki = "h1. Title\n\n\
+                    Some *bold*, _em_, -gone-, {{code}} and [a link|https://example.com].\n\
+                    Next line by [~jdoe].\n\n\
+                    * one\n** nested\n# first\n\n\
+                    {code:language=bash}\nmake install\n{code}\n\n\
+                    bq. wise words\n\n\
+                    ----";
+        assert_eq!(
+            wiki_to_markdown(wiki),
+            "# Title\n\n\
+             Some **bold**, *em*, ~~gone~~, `code` and [a link](https://example.com).\\\n\
+             Next line by [~jdoe].\n\n\
+             - one\n  - nested\n\n\
+             1. first\n\n\
+             ```bash\nmake install\n```\n\n\
+             > wise words\n\n\
+             ---"
+        );
+    }
+
+    #[test]
+    fn wiki_tables_panels_and_images() {
+        let wiki = "||Name||Link||\n|a|[docs|https://x.io/a?b=1|c]|\n\n\
+                    {warning:title=Careful}Mind the gap{warning}\n\n\
+                    !screenshot.png|thumbnail! and !https://x.io/logo.png!";
+        assert_eq!(
+            wiki_to_markdown(wiki),
+            "| Name | Link |\n| --- | --- |\n| a | [docs](https://x.io/a?b=1\\|c) |\n\n\
+             > **Warning:** **Careful**\n>\n> Mind the gap\n\n\
+             [attachment: screenshot.png] and ![](https://x.io/logo.png)"
+        );
+    }
+
+    #[test]
+    fn markdown_round_trips_through_wiki() {
+        let markdown = "# Plan\n\n\
+                        Ship **v2** with *care* and `flags`, see [docs](https://example.com).\n\n\
+                        - [x] write\n- [ ] review\n\n\
+                        1. first\n2. second\n\n\
+                        ```\nplain\n```\n\n\
+                        > quoted";
+        assert_eq!(wiki_to_markdown(&markdown_to_wiki(markdown)), markdown);
+    }
+
+    #[test]
+    fn plain_punctuation_is_left_alone() {
+        let wiki = "Dates like 2024-01-15, well-known words, a - b and wow!";
+        assert_eq!(wiki_to_markdown(wiki), wiki);
+        assert_eq!(markdown_to_wiki(wiki), wiki);
+    }
+}
@@ -22,6 +22,15 @@ pub struct JiraChangelogPage {
     pub values: Vec<JiraChangelogEntry>,
 }
 
+/// An issue's changelog as embedded by `expand=changelog`, the only way to
+/// read history on Jira Server / Data Center.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraExpandedChangelog {
+    #[serde(default)]
+    pub histories: Vec<JiraChangelogEntry>,
+}
+
 /// A single changelog entry: one author making one or more field changes at a
 /// single point in time.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -42,6 +42,13 @@ pub struct CreateJiraComment {
 }
 
 impl CreateJiraComment {
+    /// Create a comment from Markdown, converted to ADF
+    pub fn from_markdown(text: &str) -> Self {
+        Self {
+            body: crate::markdown::adf::markdown_to_adf(text),
+        }
+    }
+
     /// Create a simple plain text comment using ADF format
     pub fn from_text(text: &str) -> Self {
         Self {
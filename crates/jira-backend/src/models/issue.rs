@@ -27,7 +27,7 @@ pub struct JiraIssue {
 pub struct JiraIssueFields {
     /// Issue summary/title
     pub summary: String,
-    /// Issue description in ADF format
+    /// Issue description: ADF on Cloud, a wiki markup string on Server / DC
     pub description: Option<serde_json::Value>,
     /// Issue status
     pub status: JiraStatus,
@@ -232,6 +232,21 @@ pub struct JiraSearchResult {
     pub is_last: bool,
 }
 
+/// Search result response from the Jira Server / Data Center `/search`
+/// endpoint, which is offset-paged and reports a total.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraSearchPage {
+    #[serde(default)]
+    pub start_at: usize,
+    #[serde(default)]
+    pub max_results: usize,
+    #[serde(default)]
+    pub total: usize,
+    #[serde(default)]
+    pub issues: Vec<JiraIssue>,
+}
+
 /// Request to create an issue
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -247,7 +262,7 @@ pub struct CreateJiraIssueFields {
     pub project: ProjectId,
     /// Summary
     pub summary: String,
-    /// Description in ADF format
+    /// Description: ADF on Cloud, a wiki markup string on Server / DC
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<serde_json::Value>,
     /// Issue type
@@ -380,3 +395,10 @@ pub fn markdown_to_adf(text: &str) -> serde_json::Value {
 pub fn adf_to_text(adf: &serde_json::Value) -> String {
     crate::markdown::adf::adf_to_text(adf)
 }
+
+/// Convert an ADF document to Markdown.
+///
+/// Round-trips everything [`markdown_to_adf`] produces.
+pub fn adf_to_markdown(adf: &serde_json::Value) -> String {
+    crate::markdown::adf::adf_to_markdown(adf)
+}
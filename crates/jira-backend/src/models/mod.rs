@@ -6,6 +6,7 @@ pub mod issue;
 pub mod project;
 pub mod transitions;
 pub mod user;
+pub mod worklog;
 
 pub use changelog::*;
 pub use comment::*;
@@ -15,3 +16,4 @@ pub use issue::*;
 pub use project::*;
 pub use transitions::*;
 pub use user::*;
+pub use worklog::*;
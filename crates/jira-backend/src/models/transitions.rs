@@ -6,14 +6,20 @@ pub struct TransitionsResponse {
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Transition {
     pub id: String,
     pub name: String,
     pub to: TransitionTarget,
-    #[serde(default)]
+    /// Cloud only; Server / Data Center lists available transitions only
+    #[serde(default = "default_true")]
     pub is_available: bool,
 }
 
+fn default_true() -> bool {
+    true
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransitionTarget {
@@ -33,6 +39,9 @@ pub struct StatusCategory {
 #[derive(Debug, Serialize)]
 pub struct TransitionRequest {
     pub transition: TransitionId,
+    /// Fields set on the transition screen (e.g., resolution)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Serialize)]
@@ -6,6 +6,12 @@ use serde::{Deserialize, Serialize};
 pub struct JiraUser {
     /// User account ID (Jira Cloud uses account IDs, not usernames)
     pub account_id: Option<String>,
+    /// Username (Jira Server / Data Center)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Immutable user key (Jira Server / Data Center)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
     /// Display name
     pub display_name: Option<String>,
     /// Email address (may not be visible depending on privacy settings)
@@ -17,3 +23,14 @@ pub struct JiraUser {
     #[serde(rename = "self")]
     pub self_url: Option<String>,
 }
+
+impl JiraUser {
+    /// The identifier other requests reference this user by: the account ID
+    /// on Jira Cloud, the username on Jira Server / Data Center.
+    pub fn user_id(&self) -> Option<&str> {
+        self.account_id
+            .as_deref()
+            .or(self.name.as_deref())
+            .or(self.key.as_deref())
+    }
+}
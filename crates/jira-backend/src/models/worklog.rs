@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// Jira worklog entry
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraWorklog {
+    pub id: String,
+    pub time_spent_seconds: u64,
+}
+
+/// Request to add a worklog entry
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateJiraWorklog {
+    pub time_spent_seconds: u64,
+    /// Jira's timestamp format, e.g. "2026-05-01T09:00:00.000+0000"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started: Option<String>,
+    /// Comment: ADF on Cloud, a plain string on Server / DC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<serde_json::Value>,
+}
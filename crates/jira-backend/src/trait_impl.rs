@@ -2,19 +2,19 @@
 
 use tracker_core::{
     AttachmentUpload, Comment, CreateIssue, CreateProject, CreateTag, Issue, IssueAttachment,
-    IssueHistoryEvent, IssueLink, IssueLinkType, IssueTag, IssueTracker, Project,
-    ProjectCustomField, Result, SearchResult, TrackerError, UpdateIssue, User,
+    IssueHistoryEvent, IssueLink, IssueLinkType, IssueTag, IssueTracker, IssueTransition, LogWork,
+    Project, ProjectCustomField, Result, SearchResult, TrackerError, UpdateIssue, User, WorkItem,
 };
 
-use crate::client::JiraClient;
+use crate::client::{JiraClient, JiraDeployment};
 use crate::convert::{
     create_issue_to_jira, get_standard_custom_fields, jira_changelog_to_history_events,
     jira_field_to_project_custom_field, jira_issue_to_core, merge_fields, parse_jira_datetime,
     update_issue_to_jira,
 };
 use crate::models::{
-    CreateJiraIssueLink, IssueKeyRef, IssueLinkTypeName, ParentId, UpdateJiraIssue,
-    UpdateJiraIssueFields,
+    CreateJiraComment, CreateJiraIssueLink, CreateJiraWorklog, IssueKeyRef, IssueLinkTypeName,
+    ParentId, UpdateJiraIssue, UpdateJiraIssueFields,
 };
 
 impl IssueTracker for JiraClient {
@@ -32,6 +32,11 @@ impl IssueTracker for JiraClient {
         let jql = to_jql(query);
         let fields = self.get_fields_cached();
 
+        if self.deployment() == JiraDeployment::Server {
+            let (items, total) = self.search_offset_window(&jql, skip, limit, &fields)?;
+            return Ok(SearchResult::with_total(items, total));
+        }
+
         // /search/jql is cursor-based (`startAt` is silently ignored), so an
         // offset window has to be emulated: walk the token chain, discard
         // `skip` issues, then collect up to `limit`.
@@ -98,16 +103,25 @@ impl IssueTracker for JiraClient {
         Ok(SearchResult::from_items(items))
     }
 
-    fn get_issue_count(&self, _query: &str) -> Result<Option<u64>> {
+    fn get_issue_count(&self, query: &str) -> Result<Option<u64>> {
         // The new /search/jql endpoint does not return a total count,
         // and there is no separate count endpoint on Jira Cloud.
-        Ok(None)
+        if self.deployment() == JiraDeployment::Cloud {
+            return Ok(None);
+        }
+        let page = self.search_issues_page(&to_jql(query), 0, 0)?;
+        Ok(Some(page.total as u64))
     }
 
     fn search_all_issues(&self, query: &str, max_results: usize) -> Result<Vec<Issue>> {
         let jql = to_jql(query);
         let fields = self.get_fields_cached();
 
+        if self.deployment() == JiraDeployment::Server {
+            let (items, _) = self.search_offset_window(&jql, 0, max_results, &fields)?;
+            return Ok(items);
+        }
+
         // Native cursor walk: O(pages) requests, unlike the default
         // implementation which would re-walk the token chain from page 1
         // for every offset window.
@@ -172,7 +186,7 @@ impl IssueTracker for JiraClient {
 
     fn create_issue(&self, issue: &CreateIssue) -> Result<Issue> {
         let fields = self.get_fields_cached();
-        let jira_issue = create_issue_to_jira(issue, &fields)?;
+        let jira_issue = create_issue_to_jira(issue, &fields, self.deployment())?;
         let created = self.create_issue(&jira_issue)?;
         Ok(jira_issue_to_core(created, &fields))
     }
@@ -220,14 +234,14 @@ impl IssueTracker for JiraClient {
             || stripped.parent.is_some();
 
         if has_field_updates {
-            let jira_update = update_issue_to_jira(&stripped, &fields)?;
+            let jira_update = update_issue_to_jira(&stripped, &fields, self.deployment())?;
             self.update_issue(id, &jira_update)?;
         }
 
         // 3. POST the transition, if requested.
         if let Some(target) = status_target {
             let transition_id = self.resolve_transition_id(id, &target)?;
-            self.transition_issue(id, &transition_id)?;
+            self.transition_issue(id, &transition_id, None)?;
         }
 
         // 4. Re-fetch the fresh issue (matches current behavior).
@@ -238,6 +252,44 @@ impl IssueTracker for JiraClient {
         Ok(self.delete_issue(id)?)
     }
 
+    fn transition_issue(&self, id: &str, transition: &IssueTransition) -> Result<Issue> {
+        let transition_id = self.resolve_transition_id(id, &transition.state)?;
+        self.transition_issue(id, &transition_id, transition.resolution.as_deref())?;
+        Ok(jira_issue_to_core(
+            self.get_issue(id)?,
+            &self.get_fields_cached(),
+        ))
+    }
+
+    fn remove_issue_tag(&self, issue_id: &str, tag: &str) -> Result<()> {
+        let update = serde_json::json!({ "labels": [{ "remove": tag }] });
+        Ok(self.edit_issue_operations(issue_id, &update)?)
+    }
+
+    fn remove_issue_parent(&self, issue_id: &str) -> Result<()> {
+        let update = serde_json::json!({ "parent": [{ "set": { "none": true } }] });
+        Ok(self.edit_issue_operations(issue_id, &update)?)
+    }
+
+    fn log_work(&self, issue_id: &str, work: &LogWork) -> Result<WorkItem> {
+        let worklog = CreateJiraWorklog {
+            time_spent_seconds: work.minutes * 60,
+            started: work
+                .date
+                .map(|date| format!("{}T09:00:00.000+0000", date.format("%Y-%m-%d"))),
+            comment: work.text.as_deref().map(|text| match self.deployment() {
+                JiraDeployment::Cloud => CreateJiraComment::from_text(text).body,
+                JiraDeployment::Server => serde_json::Value::String(text.to_string()),
+            }),
+        };
+        let created = self.add_worklog(issue_id, &worklog)?;
+        Ok(WorkItem {
+            id: Some(created.id),
+            minutes: created.time_spent_seconds / 60,
+            text: work.text.clone(),
+        })
+    }
+
     fn list_issue_attachments(&self, issue_id: &str) -> Result<Vec<IssueAttachment>> {
         let issue = self.get_issue(issue_id)?;
         Ok(issue
@@ -354,6 +406,15 @@ impl IssueTracker for JiraClient {
             .collect())
     }
 
+    fn format_mention(&self, user: &User) -> String {
+        // Jira wiki mention syntax. On Cloud markdown_to_adf turns it into a
+        // mention node; Server / Data Center stores it as written.
+        match self.deployment() {
+            JiraDeployment::Cloud => format!("[~accountid:{}]", user.id),
+            JiraDeployment::Server => format!("[~{}]", user.id),
+        }
+    }
+
     fn get_issue_links(&self, issue_id: &str) -> Result<Vec<IssueLink>> {
         // Get the issue to retrieve its links
         let issue = self.get_issue(issue_id)?;
@@ -445,6 +506,42 @@ impl IssueTracker for JiraClient {
     }
 }
 
+impl JiraClient {
+    /// Collect up to `limit` issues starting at offset `skip` from the
+    /// offset-paged Server / Data Center search, with the total match count.
+    fn search_offset_window(
+        &self,
+        jql: &str,
+        skip: usize,
+        limit: usize,
+        fields: &[crate::models::JiraField],
+    ) -> Result<(Vec<Issue>, u64)> {
+        let mut items: Vec<Issue> = Vec::new();
+        let mut total = 0;
+        while items.len() < limit {
+            // The server may cap pages below what we ask for, so a short page
+            // isn't the end; `total` and an empty page are.
+            let page =
+                self.search_issues_page(jql, skip + items.len(), (limit - items.len()).min(100))?;
+            total = page.total;
+            if page.issues.is_empty() {
+                break;
+            }
+            let need = limit - items.len();
+            items.extend(
+                page.issues
+                    .into_iter()
+                    .take(need)
+                    .map(|i| jira_issue_to_core(i, fields)),
+            );
+            if skip + items.len() >= page.total {
+                break;
+            }
+        }
+        Ok((items, total as u64))
+    }
+}
+
 /// Give up after this many consecutive cursor pages that yield no new
 /// issues. Mid-stream pages may legitimately come back empty (or fully
 /// filtered), but an unbounded run of them means the server is walking us
@@ -518,8 +615,8 @@ fn jira_attachment_to_core(attachment: crate::models::JiraAttachment) -> IssueAt
         created,
         author: attachment.author.map(|author| {
             let login = author
-                .account_id
-                .clone()
+                .user_id()
+                .map(String::from)
                 .or_else(|| author.display_name.clone())
                 .unwrap_or_else(|| "unknown".to_string());
             tracker_core::CommentAuthor {
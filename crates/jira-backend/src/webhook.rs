@@ -0,0 +1,120 @@
+//! Normalize Jira issue and comment webhook deliveries.
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use tracker_core::{TrackerEvent, TrackerEventKind};
+
+const SOURCE: &str = "jira";
+
+/// Convert a delivery into a [`TrackerEvent`]. Jira names the event in the
+/// payload's `webhookEvent`; deletions and non-issue events yield `None`.
+pub fn parse_webhook(payload: &Value) -> Option<TrackerEvent> {
+    let issue = &payload["issue"];
+    let status_change = payload["changelog"]["items"]
+        .as_array()
+        .and_then(|items| items.iter().find(|item| item["field"] == "status"));
+
+    let kind = match payload["webhookEvent"].as_str()? {
+        "jira:issue_created" => TrackerEventKind::IssueCreated,
+        "jira:issue_updated" if status_change.is_some() => TrackerEventKind::IssueTransitioned,
+        // Older Jira versions report comments as an issue update
+        "jira:issue_updated" if payload["issue_event_type_name"] == "issue_commented" => {
+            TrackerEventKind::IssueCommented
+        }
+        "jira:issue_updated" => TrackerEventKind::IssueUpdated,
+        "comment_created" => TrackerEventKind::IssueCommented,
+        _ => return None,
+    };
+
+    let key = issue["key"].as_str()?;
+    let mut event = TrackerEvent::new(SOURCE, kind, key);
+    event.summary = issue["fields"]["summary"].as_str().map(String::from);
+    event.project = issue["fields"]["project"]["key"].as_str().map(String::from);
+    event.actor = user_name(&payload["user"]);
+    event.url = issue["self"]
+        .as_str()
+        .and_then(|api_url| api_url.split_once("/rest/api/"))
+        .map(|(base, _)| format!("{}/browse/{}", base, key));
+    event.at = payload["timestamp"]
+        .as_i64()
+        .and_then(DateTime::<Utc>::from_timestamp_millis);
+
+    if let Some(change) = status_change {
+        event.from = change["fromString"].as_str().map(String::from);
+        event.to = change["toString"].as_str().map(String::from);
+    }
+    if kind == TrackerEventKind::IssueCommented {
+        let comment = &payload["comment"];
+        // Cloud may send an ADF document; only plain bodies are passed through
+        event.comment = comment["body"].as_str().map(String::from);
+        event.actor = user_name(&comment["author"]).or(event.actor);
+    }
+
+    Some(event)
+}
+
+fn user_name(user: &Value) -> Option<String> {
+    user["displayName"]
+        .as_str()
+        .or(user["name"].as_str())
+        .map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn payload(webhook_event: &str) -> Value {
+        json!({
+            "timestamp": 1777636800000i64,
+            "webhookEvent": webhook_event,
+            "user": { "displayName": "Jane Doe" },
+            "issue": {
+                "key": "PROJ-12",
+                "self": "https://acme.atlassian.net/rest/api/2/issue/10012",
+                "fields": { "summary": "Crash on start", "project": { "key": "PROJ" } }
+            }
+        })
+    }
+
+    #[test]
+    fn maps_status_changes_to_transitions() {
+        let mut payload = payload("jira:issue_updated");
+        payload["changelog"] = json!({
+            "items": [
+                { "field": "assignee", "fromString": null, "toString": "Jane Doe" },
+                { "field": "status", "fromString": "To Do", "toString": "In Progress" }
+            ]
+        });
+        let event = parse_webhook(&payload).unwrap();
+        assert_eq!(event.kind, TrackerEventKind::IssueTransitioned);
+        assert_eq!(event.issue, "PROJ-12");
+        assert_eq!(event.from.as_deref(), Some("To Do"));
+        assert_eq!(event.to.as_deref(), Some("In Progress"));
+        assert_eq!(
+            event.url.as_deref(),
+            Some("https://acme.atlassian.net/browse/PROJ-12")
+        );
+        assert_eq!(event.at, "2026-05-01T12:00:00Z".parse().ok());
+    }
+
+    #[test]
+    fn maps_created_updated_and_comment_events() {
+        let event = parse_webhook(&payload("jira:issue_created")).unwrap();
+        assert_eq!(event.kind, TrackerEventKind::IssueCreated);
+        assert_eq!(event.project.as_deref(), Some("PROJ"));
+
+        let event = parse_webhook(&payload("jira:issue_updated")).unwrap();
+        assert_eq!(event.kind, TrackerEventKind::IssueUpdated);
+
+        let mut comment = payload("comment_created");
+        comment["comment"] = json!({ "body": "On it", "author": { "displayName": "John Roe" } });
+        let event = parse_webhook(&comment).unwrap();
+        assert_eq!(event.kind, TrackerEventKind::IssueCommented);
+        assert_eq!(event.comment.as_deref(), Some("On it"));
+        assert_eq!(event.actor.as_deref(), Some("John Roe"));
+
+        assert!(parse_webhook(&payload("jira:issue_deleted")).is_none());
+    }
+}
@@ -241,6 +241,12 @@ impl IssueTracker for LinearClient {
             .collect())
     }
 
+    fn format_mention(&self, user: &User) -> String {
+        // Linear resolves mentions by the user's display name (their handle),
+        // not by email, which is what `login` holds.
+        format!("@{}", user.display_name)
+    }
+
     fn list_tags(&self) -> Result<Vec<IssueTag>> {
         let labels = if let Some(team) = self.default_team() {
             let team_id = self.resolve_team_id(team)?;
@@ -327,6 +333,26 @@ impl IssueTracker for LinearClient {
         Ok(())
     }
 
+    fn remove_issue_parent(&self, issue_id: &str) -> Result<()> {
+        let update = LinearIssueUpdateInput {
+            parent_id: Some(None),
+            ..Default::default()
+        };
+        self.update_issue(issue_id, &update)?;
+        Ok(())
+    }
+
+    fn remove_issue_tag(&self, issue_id: &str, tag: &str) -> Result<()> {
+        let existing = self.get_issue(issue_id)?;
+        let label = self.find_label(&existing.team.id, tag)?;
+        let update = LinearIssueUpdateInput {
+            removed_label_ids: Some(vec![label.id]),
+            ..Default::default()
+        };
+        self.update_issue(issue_id, &update)?;
+        Ok(())
+    }
+
     fn unlink_issues(&self, source: &str, link_id: &str) -> Result<()> {
         if link_id.starts_with("linear-parent:") {
             let update = LinearIssueUpdateInput {
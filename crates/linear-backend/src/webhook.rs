@@ -0,0 +1,118 @@
+//! Normalize Linear `Issue` and `Comment` webhook deliveries.
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use tracker_core::{TrackerEvent, TrackerEventKind};
+
+const SOURCE: &str = "linear";
+
+/// Convert a delivery into a [`TrackerEvent`]. `event` is the `Linear-Event`
+/// header; the payload's own `type` is used when it is missing. Removals and
+/// other entity types yield `None`.
+pub fn parse_webhook(event: Option<&str>, payload: &Value) -> Option<TrackerEvent> {
+    let entity = event.or(payload["type"].as_str())?;
+    let action = payload["action"].as_str()?;
+    let data = &payload["data"];
+
+    let (kind, issue) = match (entity, action) {
+        ("Issue", "create") => (TrackerEventKind::IssueCreated, data),
+        // `updatedFrom` holds the previous values of the changed fields
+        ("Issue", "update") if payload["updatedFrom"].get("stateId").is_some() => {
+            (TrackerEventKind::IssueTransitioned, data)
+        }
+        ("Issue", "update") => (TrackerEventKind::IssueUpdated, data),
+        ("Comment", "create") => (TrackerEventKind::IssueCommented, &data["issue"]),
+        _ => return None,
+    };
+
+    let id = issue["identifier"]
+        .as_str()
+        .or(data["issueId"].as_str())?
+        .to_string();
+    let mut tracker_event = TrackerEvent::new(SOURCE, kind, id);
+    tracker_event.summary = issue["title"].as_str().map(String::from);
+    tracker_event.project = issue["team"]["key"].as_str().map(String::from);
+    tracker_event.actor = payload["actor"]["name"].as_str().map(String::from);
+    tracker_event.url = payload["url"].as_str().map(String::from);
+    tracker_event.at = timestamp(&data["updatedAt"]).or(timestamp(&payload["createdAt"]));
+
+    match kind {
+        TrackerEventKind::IssueTransitioned => {
+            // Only the previous state ID is sent, not its name
+            tracker_event.to = data["state"]["name"].as_str().map(String::from);
+        }
+        TrackerEventKind::IssueCommented => {
+            tracker_event.comment = data["body"].as_str().map(String::from);
+            tracker_event.actor = data["user"]["name"]
+                .as_str()
+                .map(String::from)
+                .or(tracker_event.actor);
+        }
+        _ => {}
+    }
+
+    Some(tracker_event)
+}
+
+fn timestamp(value: &Value) -> Option<DateTime<Utc>> {
+    value.as_str()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn issue_payload(action: &str) -> Value {
+        json!({
+            "action": action,
+            "type": "Issue",
+            "createdAt": "2026-05-01T12:00:00.000Z",
+            "url": "https://linear.app/acme/issue/ENG-12/crash-on-start",
+            "actor": { "name": "Jane Doe" },
+            "data": {
+                "identifier": "ENG-12",
+                "title": "Crash on start",
+                "team": { "key": "ENG" },
+                "state": { "name": "In Progress" },
+                "updatedAt": "2026-05-01T12:00:00.000Z"
+            }
+        })
+    }
+
+    #[test]
+    fn maps_issue_state_changes_to_transitions() {
+        let mut payload = issue_payload("update");
+        payload["updatedFrom"] = json!({ "stateId": "abc", "updatedAt": "..." });
+        let event = parse_webhook(Some("Issue"), &payload).unwrap();
+        assert_eq!(event.kind, TrackerEventKind::IssueTransitioned);
+        assert_eq!(event.issue, "ENG-12");
+        assert_eq!(event.project.as_deref(), Some("ENG"));
+        assert_eq!(event.to.as_deref(), Some("In Progress"));
+
+        payload["updatedFrom"] = json!({ "priority": 2 });
+        let event = parse_webhook(None, &payload).unwrap();
+        assert_eq!(event.kind, TrackerEventKind::IssueUpdated);
+
+        assert!(parse_webhook(Some("Issue"), &issue_payload("remove")).is_none());
+    }
+
+    #[test]
+    fn maps_comments_to_their_issue() {
+        let payload = json!({
+            "action": "create",
+            "type": "Comment",
+            "data": {
+                "body": "Fixed in main",
+                "issueId": "uuid-1",
+                "user": { "name": "John Roe" },
+                "issue": { "identifier": "ENG-12", "title": "Crash on start" }
+            }
+        });
+        let event = parse_webhook(Some("Comment"), &payload).unwrap();
+        assert_eq!(event.kind, TrackerEventKind::IssueCommented);
+        assert_eq!(event.issue, "ENG-12");
+        assert_eq!(event.comment.as_deref(), Some("Fixed in main"));
+        assert_eq!(event.actor.as_deref(), Some("John Roe"));
+    }
+}
@@ -214,6 +214,9 @@ pub struct CachedQueryTemplate {
     pub description: String,
     pub query: String,
     pub backend: String,
+    /// Defined by the user in the `[queries]` config table (not built in)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub custom: bool,
 }
 
 /// Cached issue link type
@@ -867,12 +870,20 @@ impl TrackerCache {
     }
 
     /// Refresh cache from tracker API
+    ///
+    /// `saved_queries` are the user's `[queries]` templates; they are stored
+    /// alongside (and override) the built-in templates so issue counts are
+    /// cached for them too.
     pub fn refresh(
         client: &dyn IssueTracker,
         backend_type: &str,
         base_url: &str,
         default_project: Option<&str>,
+        saved_queries: &[CachedQueryTemplate],
     ) -> Result<Self> {
+        let mut query_templates = Self::get_query_templates(backend_type);
+        merge_query_templates(&mut query_templates, saved_queries);
+
         let mut cache = Self {
             updated_at: Some(chrono::Utc::now().to_rfc3339()),
             backend_metadata: Some(CachedBackendMetadata {
@@ -880,7 +891,7 @@ impl TrackerCache {
                 base_url: base_url.to_string(),
             }),
             default_project: default_project.map(|s| s.to_string()),
-            query_templates: Self::get_query_templates(backend_type),
+            query_templates,
             ..Self::default()
         };
 
@@ -1033,7 +1044,11 @@ impl TrackerCache {
                     template_index,
                     project_short_name: project.short_name.clone(),
                     template_name: template.name.clone(),
-                    query: template.query.replace("{PROJECT}", &project.short_name),
+                    query: expand_query_template(
+                        &template.query,
+                        Some(&project.short_name),
+                        &template.backend,
+                    ),
                 });
             }
         }
@@ -1080,8 +1095,15 @@ impl TrackerCache {
         backend_type: &str,
         base_url: &str,
         default_project: Option<&str>,
+        saved_queries: &[CachedQueryTemplate],
     ) -> Result<Self> {
-        let mut cache = Self::refresh(client, backend_type, base_url, default_project)?;
+        let mut cache = Self::refresh(
+            client,
+            backend_type,
+            base_url,
+            default_project,
+            saved_queries,
+        )?;
 
         // Fetch articles if knowledge base client is available
         if let Some(kb) = kb_client
@@ -1102,36 +1124,42 @@ impl TrackerCache {
                     description: "All unresolved issues in project".to_string(),
                     query: "project: {PROJECT} #Unresolved".to_string(),
                     backend: "youtrack".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "my_issues".to_string(),
                     description: "Issues assigned to current user".to_string(),
                     query: "project: {PROJECT} Assignee: me #Unresolved".to_string(),
                     backend: "youtrack".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "recent".to_string(),
                     description: "Recently updated issues".to_string(),
                     query: "project: {PROJECT} updated: -7d .. Today".to_string(),
                     backend: "youtrack".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "high_priority".to_string(),
                     description: "High priority unresolved issues".to_string(),
                     query: "project: {PROJECT} Priority: Critical,Major #Unresolved".to_string(),
                     backend: "youtrack".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "in_progress".to_string(),
                     description: "Issues currently in progress".to_string(),
                     query: "project: {PROJECT} State: {In Progress}".to_string(),
                     backend: "youtrack".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "bugs".to_string(),
                     description: "Bug issues".to_string(),
                     query: "project: {PROJECT} Type: Bug #Unresolved".to_string(),
                     backend: "youtrack".to_string(),
+                    custom: false,
                 },
             ],
             "jira" => vec![
@@ -1140,36 +1168,42 @@ impl TrackerCache {
                     description: "All unresolved issues in project".to_string(),
                     query: "project = {PROJECT} AND resolution IS EMPTY".to_string(),
                     backend: "jira".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "my_issues".to_string(),
                     description: "Issues assigned to current user".to_string(),
                     query: "project = {PROJECT} AND assignee = currentUser() AND resolution IS EMPTY".to_string(),
                     backend: "jira".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "recent".to_string(),
                     description: "Recently updated issues".to_string(),
                     query: "project = {PROJECT} AND updated >= -7d".to_string(),
                     backend: "jira".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "high_priority".to_string(),
                     description: "High priority unresolved issues".to_string(),
                     query: "project = {PROJECT} AND priority IN (Highest, High) AND resolution IS EMPTY".to_string(),
                     backend: "jira".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "in_progress".to_string(),
                     description: "Issues currently in progress".to_string(),
                     query: "project = {PROJECT} AND status = \"In Progress\"".to_string(),
                     backend: "jira".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "bugs".to_string(),
                     description: "Bug issues".to_string(),
                     query: "project = {PROJECT} AND issuetype = Bug AND resolution IS EMPTY".to_string(),
                     backend: "jira".to_string(),
+                    custom: false,
                 },
             ],
             "github" => vec![
@@ -1178,36 +1212,42 @@ impl TrackerCache {
                     description: "All open issues in repo".to_string(),
                     query: "repo:{PROJECT} is:issue state:open".to_string(),
                     backend: "github".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "my_issues".to_string(),
                     description: "Issues assigned to current user".to_string(),
                     query: "repo:{PROJECT} is:issue state:open assignee:@me".to_string(),
                     backend: "github".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "recent".to_string(),
                     description: "Recently updated issues".to_string(),
                     query: "repo:{PROJECT} is:issue sort:updated-desc".to_string(),
                     backend: "github".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "bugs".to_string(),
                     description: "Bug issues".to_string(),
                     query: "repo:{PROJECT} is:issue state:open label:bug".to_string(),
                     backend: "github".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "enhancements".to_string(),
                     description: "Enhancement/feature request issues".to_string(),
                     query: "repo:{PROJECT} is:issue state:open label:enhancement".to_string(),
                     backend: "github".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "no_assignee".to_string(),
                     description: "Unassigned open issues".to_string(),
                     query: "repo:{PROJECT} is:issue state:open no:assignee".to_string(),
                     backend: "github".to_string(),
+                    custom: false,
                 },
             ],
             "gitlab" => vec![
@@ -1216,36 +1256,42 @@ impl TrackerCache {
                     description: "All open issues in project".to_string(),
                     query: "state=opened".to_string(),
                     backend: "gitlab".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "my_issues".to_string(),
                     description: "Issues assigned to current user".to_string(),
                     query: "state=opened&assignee_username=@me".to_string(),
                     backend: "gitlab".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "recent".to_string(),
                     description: "Recently updated issues".to_string(),
                     query: "state=opened&order_by=updated_at&sort=desc".to_string(),
                     backend: "gitlab".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "bugs".to_string(),
                     description: "Bug issues".to_string(),
                     query: "state=opened&labels=bug".to_string(),
                     backend: "gitlab".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "high_priority".to_string(),
                     description: "High priority open issues".to_string(),
                     query: "state=opened&labels=priority::high".to_string(),
                     backend: "gitlab".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "no_assignee".to_string(),
                     description: "Unassigned open issues".to_string(),
                     query: "state=opened&assignee_id=None".to_string(),
                     backend: "gitlab".to_string(),
+                    custom: false,
                 },
             ],
             "linear" => vec![
@@ -1254,30 +1300,35 @@ impl TrackerCache {
                     description: "All unresolved issues in team".to_string(),
                     query: "project: {PROJECT} #Unresolved".to_string(),
                     backend: "linear".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "my_issues".to_string(),
                     description: "Issues assigned to current user".to_string(),
                     query: "project: {PROJECT} assignee: me #Unresolved".to_string(),
                     backend: "linear".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "in_progress".to_string(),
                     description: "Issues currently in progress".to_string(),
                     query: "project: {PROJECT} state: started".to_string(),
                     backend: "linear".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "bugs".to_string(),
                     description: "Bug issues".to_string(),
                     query: "project: {PROJECT} label: Bug #Unresolved".to_string(),
                     backend: "linear".to_string(),
+                    custom: false,
                 },
                 CachedQueryTemplate {
                     name: "high_priority".to_string(),
                     description: "Urgent and high priority unresolved issues".to_string(),
                     query: "project: {PROJECT} priority: High #Unresolved".to_string(),
                     backend: "linear".to_string(),
+                    custom: false,
                 },
             ],
             _ => Vec::new(),
@@ -1394,7 +1445,6 @@ impl TrackerCache {
     }
 
     /// Get users for a project (from cache)
-    #[allow(dead_code)]
     pub fn get_project_users(&self, short_name: &str) -> Option<&[CachedUser]> {
         self.project_users
             .iter()
@@ -1568,12 +1618,86 @@ impl TrackerCache {
     }
 }
 
+/// Merge `saved` templates into `templates`, replacing built-ins that share a
+/// name (case-insensitively) and appending the rest in order.
+pub fn merge_query_templates(
+    templates: &mut Vec<CachedQueryTemplate>,
+    saved: &[CachedQueryTemplate],
+) {
+    for template in saved {
+        match templates
+            .iter_mut()
+            .find(|t| tracker_core::unicode_eq_ignore_case(&t.name, &template.name))
+        {
+            Some(existing) => *existing = template.clone(),
+            None => templates.push(template.clone()),
+        }
+    }
+}
+
+/// Backend-native spelling of "the current user" in search queries.
+fn current_user_token(backend_type: &str) -> &'static str {
+    match backend_type {
+        "jira" => "currentUser()",
+        "github" | "gitlab" => "@me",
+        _ => "me",
+    }
+}
+
+/// Expand the placeholders a query template may contain:
+///
+/// - `{PROJECT}` - the project shortName (left as-is when `project` is `None`)
+/// - `{ME}` - the backend's current-user token (`me`, `currentUser()`, `@me`)
+/// - `{TODAY}` - today's UTC date as `YYYY-MM-DD`
+/// - `{TODAY-7d}` - a date in the past, using [`parse_duration`] units
+///
+/// Any other `{...}` text is kept verbatim, since YouTrack uses braces for
+/// multi-word values (`State: {In Progress}`).
+pub fn expand_query_template(query: &str, project: Option<&str>, backend_type: &str) -> String {
+    expand_query_template_at(query, project, backend_type, Utc::now())
+}
+
+fn expand_query_template_at(
+    query: &str,
+    project: Option<&str>,
+    backend_type: &str,
+    now: DateTime<Utc>,
+) -> String {
+    let mut out = String::with_capacity(query.len());
+    let mut rest = query;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+        let token = &rest[start + 1..start + len];
+        let replacement = match token {
+            "PROJECT" => project.map(str::to_string),
+            "ME" => Some(current_user_token(backend_type).to_string()),
+            "TODAY" => Some(now.format("%Y-%m-%d").to_string()),
+            _ => token
+                .strip_prefix("TODAY-")
+                .and_then(|offset| parse_duration(offset).ok())
+                .map(|offset| (now - offset).format("%Y-%m-%d").to_string()),
+        };
+        match replacement {
+            Some(value) => out.push_str(&value),
+            None => out.push_str(&rest[start..=start + len]),
+        }
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
 /// Parse a duration string like "1h", "30m", "1d" into a chrono Duration
 ///
 /// Supported formats:
 /// - `1h`, `2h` - hours
 /// - `30m`, `15min` - minutes
 /// - `1d` - days
+/// - `2w` - weeks
 /// - `60s` - seconds
 /// - `2` - defaults to hours
 pub fn parse_duration(s: &str) -> Result<Duration> {
@@ -1585,6 +1709,8 @@ pub fn parse_duration(s: &str) -> Result<Duration> {
     // Try to parse as a number with a suffix
     let (num_str, unit) = if s.ends_with("min") {
         (&s[..s.len() - 3], "m")
+    } else if s.ends_with('w') {
+        (&s[..s.len() - 1], "w")
     } else if s.ends_with('d') {
         (&s[..s.len() - 1], "d")
     } else if s.ends_with('h') {
@@ -1609,13 +1735,17 @@ pub fn parse_duration(s: &str) -> Result<Duration> {
         return Err(anyhow!("Duration must be positive"));
     }
 
-    match unit {
-        "d" => Ok(Duration::days(num)),
-        "h" => Ok(Duration::hours(num)),
-        "m" => Ok(Duration::minutes(num)),
-        "s" => Ok(Duration::seconds(num)),
-        _ => Err(anyhow!("Unknown duration unit: {}", unit)),
-    }
+    // Checked constructors, so an out-of-range value like `9999999999999w`
+    // is an error rather than a panic.
+    let duration = match unit {
+        "w" => Duration::try_weeks(num),
+        "d" => Duration::try_days(num),
+        "h" => Duration::try_hours(num),
+        "m" => Duration::try_minutes(num),
+        "s" => Duration::try_seconds(num),
+        _ => return Err(anyhow!("Unknown duration unit: {}", unit)),
+    };
+    duration.ok_or_else(|| anyhow!("Duration '{}' is out of range", s))
 }
 
 #[cfg(test)]
@@ -1649,6 +1779,73 @@ mod tests {
         assert_eq!(mode, 0o700);
     }
 
+    fn template(name: &str, query: &str, custom: bool) -> CachedQueryTemplate {
+        CachedQueryTemplate {
+            name: name.to_string(),
+            description: String::new(),
+            query: query.to_string(),
+            backend: "youtrack".to_string(),
+            custom,
+        }
+    }
+
+    #[test]
+    fn merge_query_templates_overrides_by_name_and_appends() {
+        let mut templates = vec![
+            template("unresolved", "project: {PROJECT} #Unresolved", false),
+            template("bugs", "Type: Bug", false),
+        ];
+        merge_query_templates(
+            &mut templates,
+            &[
+                template("BUGS", "Type: Bug Assignee: {ME}", true),
+                template("triage", "#Unresolved has: -Assignee", true),
+            ],
+        );
+        let names: Vec<&str> = templates.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["unresolved", "BUGS", "triage"]);
+        assert!(templates[1].custom);
+        assert_eq!(templates[1].query, "Type: Bug Assignee: {ME}");
+    }
+
+    #[test]
+    fn expand_query_template_substitutes_placeholders() {
+        let now = DateTime::parse_from_rfc3339("2026-03-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            expand_query_template_at(
+                "project = {PROJECT} AND assignee = {ME} AND updated >= {TODAY-7d}",
+                Some("PROJ"),
+                "jira",
+                now
+            ),
+            "project = PROJ AND assignee = currentUser() AND updated >= 2026-03-03"
+        );
+        assert_eq!(
+            expand_query_template_at("assignee:{ME} created:{TODAY}", None, "github", now),
+            "assignee:@me created:2026-03-10"
+        );
+    }
+
+    #[test]
+    fn expand_query_template_keeps_unknown_braces() {
+        let now = Utc::now();
+        assert_eq!(
+            expand_query_template_at(
+                "project: {PROJECT} State: {In Progress} {TODAY-x}",
+                None,
+                "youtrack",
+                now
+            ),
+            "project: {PROJECT} State: {In Progress} {TODAY-x}"
+        );
+        assert_eq!(
+            expand_query_template_at("Assignee: {ME} {unclosed", None, "youtrack", now),
+            "Assignee: me {unclosed"
+        );
+    }
+
     #[test]
     fn test_parse_duration_hours() {
         let d = parse_duration("1h").unwrap();
@@ -1682,6 +1879,18 @@ mod tests {
         assert_eq!(d.num_seconds(), 60);
     }
 
+    #[test]
+    fn test_parse_duration_weeks() {
+        let d = parse_duration("2w").unwrap();
+        assert_eq!(d.num_days(), 14);
+    }
+
+    #[test]
+    fn test_parse_duration_out_of_range() {
+        assert!(parse_duration(&format!("{}w", i64::MAX)).is_err());
+        assert!(parse_duration("99999999999999999999999d").is_err());
+    }
+
     #[test]
     fn test_parse_duration_long_forms() {
         // These use the simple suffixes
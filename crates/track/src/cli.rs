@@ -38,49 +38,7 @@ pub struct Cli {
     pub command: Commands,
 }
 
-#[derive(ValueEnum, Clone, Debug, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum Backend {
-    /// YouTrack issue tracker
-    #[default]
-    #[value(name = "youtrack", alias = "yt")]
-    YouTrack,
-    /// Jira issue tracker
-    #[value(name = "jira", alias = "j")]
-    Jira,
-    /// GitHub issue tracker
-    #[value(name = "github", alias = "gh")]
-    GitHub,
-    /// GitLab issue tracker
-    #[value(name = "gitlab", alias = "gl")]
-    GitLab,
-    /// Linear issue tracker
-    #[value(name = "linear", alias = "lin")]
-    Linear,
-}
-
-impl Backend {
-    /// All known backends, in stable display order.
-    pub const ALL: [Backend; 5] = [
-        Backend::YouTrack,
-        Backend::Jira,
-        Backend::GitHub,
-        Backend::GitLab,
-        Backend::Linear,
-    ];
-}
-
-impl std::fmt::Display for Backend {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Backend::YouTrack => write!(f, "youtrack"),
-            Backend::Jira => write!(f, "jira"),
-            Backend::GitHub => write!(f, "github"),
-            Backend::GitLab => write!(f, "gitlab"),
-            Backend::Linear => write!(f, "linear"),
-        }
-    }
-}
+pub use tracker_client::Backend;
 
 #[derive(ValueEnum, Clone, Debug, Copy, PartialEq, Eq)]
 pub enum OutputFormat {
@@ -202,7 +160,8 @@ pub enum Commands {
     /// Execute a JSON issue-operation plan against the selected backend
     Apply {
         /// Path to a JSON apply plan, or "-" to read from stdin
-        plan: PathBuf,
+        #[arg(required_unless_present = "rollback")]
+        plan: Option<PathBuf>,
         /// Parse, resolve, validate, and dedupe-check without mutating
         #[arg(long)]
         dry_run: bool,
@@ -215,6 +174,152 @@ pub enum Commands {
         /// Allow real delete_issue operations
         #[arg(long)]
         allow_delete: bool,
+        /// Undo the changes recorded in an apply state file (written by --resume)
+        ///
+        /// Created issues are closed, or deleted with --allow-delete; changed
+        /// fields, state, tags and parents are restored; added links are removed.
+        #[arg(long, value_name = "STATE", conflicts_with_all = ["plan", "resume"])]
+        rollback: Option<PathBuf>,
+        /// Run up to N independent operations at once (max 16)
+        ///
+        /// Operations wait for the ones defining refs they use and for earlier
+        /// operations on the same issue; a foreach waits for everything before
+        /// it. Results are still reported in plan order.
+        #[arg(long, value_name = "N", default_value_t = 1)]
+        concurrency: usize,
+    },
+    /// Serve tracker operations as MCP tools over stdio
+    ///
+    /// Exposes search, get, inspect, create, update, comment, link, articles,
+    /// and context as Model Context Protocol tools for agents. Writes go
+    /// through the same engine as `track apply`.
+    Mcp {
+        /// Expose the delete_issue tool
+        #[arg(long, conflicts_with = "read_only")]
+        allow_delete: bool,
+        /// Only expose tools that don't modify the tracker
+        #[arg(long)]
+        read_only: bool,
+    },
+    /// Generate release notes from issue keys in git history or a search query
+    ///
+    /// Scans `git log FROM..TO` for issue keys, fetches each issue, and groups
+    /// them by type or tag into Markdown (default) or JSON (-o json). Sections
+    /// can be configured under [changelog] in .track.toml.
+    Changelog {
+        /// Git revision to start after (e.g., the previous release tag)
+        #[arg(long, required_unless_present_any = ["query", "template"], conflicts_with_all = ["query", "template"])]
+        from: Option<String>,
+        /// Git revision to end at
+        #[arg(long, default_value = "HEAD")]
+        to: String,
+        /// Select issues with a search query instead of git history
+        #[arg(long, short = 'q', conflicts_with = "template")]
+        query: Option<String>,
+        /// Select issues with a query template or saved query instead of git history
+        #[arg(long, short = 'T')]
+        template: Option<String>,
+        /// Project for template substitution; in git mode, only keys from this project are used
+        #[arg(long, short = 'p')]
+        project: Option<String>,
+        /// Group issues by type or by tag (default: [changelog] group_by, then type)
+        #[arg(long, value_enum)]
+        group_by: Option<ChangelogGroupBy>,
+        /// Heading for the release notes (e.g., "v1.3.0")
+        #[arg(long)]
+        title: Option<String>,
+        /// Maximum number of issues in query mode
+        #[arg(long, default_value_t = 200)]
+        limit: usize,
+    },
+    /// Poll a query and print changes as they happen
+    ///
+    /// Reports issues entering or leaving the result set, state and assignee
+    /// changes, and new comments. With -o json each change is one JSON line.
+    /// The first poll only records a baseline unless --state has a snapshot
+    /// from an earlier run.
+    Watch {
+        /// Search query to watch
+        #[arg(
+            long,
+            short = 'q',
+            required_unless_present = "template",
+            conflicts_with = "template"
+        )]
+        query: Option<String>,
+        /// Query template or saved query to watch
+        #[arg(long, short = 'T')]
+        template: Option<String>,
+        /// Project for template substitution
+        #[arg(long, short = 'p')]
+        project: Option<String>,
+        /// Time between polls (e.g., 30s, 5m)
+        #[arg(long, default_value = "60s")]
+        interval: String,
+        /// Maximum number of issues per poll
+        #[arg(long, default_value_t = 100)]
+        limit: usize,
+        /// Shell command to run per change, with TRACK_EVENT (JSON),
+        /// TRACK_ISSUE_ID, and TRACK_CHANGE in its environment
+        #[arg(long, value_name = "COMMAND")]
+        exec: Option<String>,
+        /// Exit after this many changes (e.g., 1 to wait for the next change)
+        #[arg(long, value_name = "N")]
+        count: Option<usize>,
+        /// Poll once and exit; combine with --state to run from cron
+        #[arg(long)]
+        once: bool,
+        /// Persist the snapshot to this file between runs
+        #[arg(long, value_name = "PATH")]
+        state: Option<PathBuf>,
+    },
+    /// Declarative triage rules (stale issues, labeling, reminders)
+    ///
+    /// Each rule in a TOML file selects issues with a query plus client-side
+    /// conditions, and compiles its actions into an apply plan, so --dry-run,
+    /// --resume, and the JSON summary work as for `track apply`.
+    Rules {
+        #[command(subcommand)]
+        action: RulesCommands,
+    },
+    /// Make the tracker follow a YAML file of desired issues
+    ///
+    /// Issues in the file are keyed by stable IDs stored in a custom field
+    /// (key_field) or a description marker. Without --apply the changes and
+    /// the apply plan are printed; with --apply they run through `track apply`.
+    SyncFile {
+        /// Path to the YAML (or JSON) sync file
+        file: PathBuf,
+        /// Print the changes and apply plan without running them (the default)
+        #[arg(long, conflicts_with = "apply")]
+        plan: bool,
+        /// Run the changes through the apply engine
+        #[arg(long)]
+        apply: bool,
+        /// With --apply: resolve and validate the plan without mutating
+        #[arg(long, requires = "apply")]
+        dry_run: bool,
+        /// With --apply: validate custom fields against the project schema
+        #[arg(long, requires = "apply")]
+        validate: bool,
+        /// With --apply: run up to N independent operations at once (max 16)
+        #[arg(long, value_name = "N", default_value_t = 1)]
+        concurrency: usize,
+    },
+    /// Reports computed from issue history
+    Report {
+        #[command(subcommand)]
+        action: ReportCommands,
+    },
+    /// Receive tracker webhooks locally
+    Webhook {
+        #[command(subcommand)]
+        action: WebhookCommands,
+    },
+    /// Git integration: branch-per-issue workflow and commit-msg hook
+    Git {
+        #[command(subcommand)]
+        action: GitCommands,
     },
     /// Generate shell completions
     Completions {
@@ -254,10 +359,12 @@ pub enum Commands {
     },
     /// Open an issue or the tracker dashboard in your browser
     Open {
-        /// Issue ID to open (e.g., PROJ-123). If omitted, opens the dashboard.
+        /// Issue ID to open (e.g., PROJ-123). If omitted, opens the current git
+        /// branch's issue, or the dashboard when the branch names none.
         id: Option<String>,
     },
-    /// Shortcut: Get issue by ID (same as 'track issue get')
+    /// Shortcut: Get issue by ID (same as 'track issue get'), run a configured
+    /// alias, or run a `track-<name>` plugin found on PATH
     #[command(external_subcommand)]
     External(Vec<String>),
 }
@@ -315,6 +422,143 @@ pub enum ConfigCommands {
     Test,
 }
 
+#[derive(ValueEnum, Clone, Debug, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangelogGroupBy {
+    /// Group by the issue's Type field
+    #[default]
+    Type,
+    /// Group by tag (label)
+    Tag,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum WebhookCommands {
+    /// Run an HTTP server that turns webhook deliveries into events
+    ///
+    /// POST deliveries to /github, /gitlab, /jira, /linear or /youtrack (or /,
+    /// detected from headers). Signatures are checked against
+    /// [webhook.secrets]; each issue event is printed (one JSON line with
+    /// -o json) and passed to matching [[webhook.hooks]] and --exec.
+    Serve {
+        /// Port to listen on (0 picks a free port)
+        #[arg(long, default_value_t = 8787)]
+        port: u16,
+        /// Address to bind
+        #[arg(long, default_value = "127.0.0.1")]
+        bind: String,
+        /// Shell command to run per event, with TRACK_EVENT (JSON),
+        /// TRACK_ISSUE_ID, TRACK_EVENT_KIND, and TRACK_SOURCE in its environment
+        #[arg(long, value_name = "COMMAND")]
+        exec: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum RulesCommands {
+    /// Evaluate rules and execute their actions
+    Run {
+        /// Path to the rules file
+        file: PathBuf,
+        /// Only run the named rule (repeatable)
+        #[arg(long = "rule", value_name = "NAME")]
+        rules: Vec<String>,
+        /// Evaluate and report matching issues without mutating
+        #[arg(long)]
+        dry_run: bool,
+        /// Validate custom fields against project schema before writes
+        #[arg(long)]
+        validate: bool,
+        /// Explicit JSON resume state path
+        #[arg(long, value_name = "PATH")]
+        resume: Option<PathBuf>,
+    },
+    /// Print the apply plan the rules compile to, without executing it
+    Plan {
+        /// Path to the rules file
+        file: PathBuf,
+        /// Only include the named rule (repeatable)
+        #[arg(long = "rule", value_name = "NAME")]
+        rules: Vec<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ReportCommands {
+    /// Flow metrics: cycle time, lead time, time in state, throughput, and WIP
+    ///
+    /// Replays the status history of every matching issue. Cycle time runs
+    /// from the first status change to the last move into a resolved state;
+    /// lead time runs from creation. Only issues resolved within --since count
+    /// towards the percentiles and throughput.
+    Flow {
+        /// Search query selecting the issues to analyze
+        #[arg(
+            long,
+            short = 'q',
+            required_unless_present = "template",
+            conflicts_with = "template"
+        )]
+        query: Option<String>,
+        /// Query template or saved query selecting the issues to analyze
+        #[arg(long, short = 'T')]
+        template: Option<String>,
+        /// Project for template substitution
+        #[arg(long, short = 'p')]
+        project: Option<String>,
+        /// Reporting window (e.g., 30d, 12w)
+        #[arg(long, default_value = "30d")]
+        since: String,
+        /// Maximum number of issues to analyze
+        #[arg(long, default_value_t = 500)]
+        limit: usize,
+        /// Print one CSV row per issue instead of the summary
+        #[arg(long)]
+        csv: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum GitCommands {
+    /// Manage git hooks
+    Hook {
+        #[command(subcommand)]
+        action: GitHookCommands,
+    },
+    /// Check or prepend the issue key in a commit message file
+    /// (invoked by the installed commit-msg hook)
+    #[command(hide = true)]
+    CommitMsg {
+        /// Path to the commit message file
+        file: PathBuf,
+        /// Whether to prepend the branch's issue key or reject messages without one
+        #[arg(long, value_enum, default_value_t = CommitMsgMode::Prepend)]
+        mode: CommitMsgMode,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum GitHookCommands {
+    /// Install a commit-msg hook that adds or enforces the issue key
+    Install {
+        /// prepend: add the current branch's issue key when missing;
+        /// check: reject commit messages without an issue key
+        #[arg(long, value_enum, default_value_t = CommitMsgMode::Prepend)]
+        mode: CommitMsgMode,
+        /// Overwrite an existing commit-msg hook not installed by track
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(ValueEnum, Clone, Debug, Copy, PartialEq, Eq)]
+pub enum CommitMsgMode {
+    /// Prepend the current branch's issue key when the message lacks it
+    Prepend,
+    /// Reject commit messages that don't mention an issue key
+    Check,
+}
+
 #[derive(Subcommand, Debug)]
 pub enum CacheCommands {
     /// Refresh the local cache with current tracker data
@@ -394,8 +638,8 @@ pub enum IssueCommands {
     /// Get issue by ID
     #[command(visible_alias = "g")]
     Get {
-        /// Issue ID (e.g., PROJ-123)
-        id: String,
+        /// Issue ID (e.g., PROJ-123); defaults to the current git branch's issue
+        id: Option<String>,
         /// Show full context (subtasks, links, comments)
         #[arg(long)]
         full: bool,
@@ -410,7 +654,7 @@ pub enum IssueCommands {
         #[arg(
             long,
             short = 's',
-            required_unless_present = "json",
+            required_unless_present_any = ["json", "template"],
             conflicts_with = "json"
         )]
         summary: Option<String>,
@@ -450,8 +694,15 @@ pub enum IssueCommands {
         #[arg(long, requires = "validate")]
         dry_run: bool,
         /// JSON payload for issue creation
-        #[arg(long, conflicts_with_all = ["project", "summary", "description", "body_file", "fields", "state", "priority", "assignee", "tags", "parent", "validate", "dry_run"], value_name = "JSON")]
+        #[arg(long, conflicts_with_all = ["project", "summary", "description", "body_file", "fields", "state", "priority", "assignee", "tags", "parent", "validate", "dry_run", "template"], value_name = "JSON")]
         json: Option<String>,
+        /// Start from a template in .track/templates (name or path to a .md file);
+        /// explicit flags override the template's values
+        #[arg(long, value_name = "NAME")]
+        template: Option<String>,
+        /// Template variable (format: NAME=VALUE, can be repeated)
+        #[arg(long = "var", value_name = "NAME=VALUE", requires = "template")]
+        vars: Vec<String>,
     },
     /// Update existing issue(s) - supports comma-separated IDs for batch updates
     #[command(visible_alias = "u", group(
@@ -461,7 +712,8 @@ pub enum IssueCommands {
             .multiple(true)
     ))]
     Update {
-        /// Issue ID(s) - comma-separated for batch updates (e.g., PROJ-123 or PROJ-1,PROJ-2,PROJ-3)
+        /// Issue ID(s) - comma-separated for batch updates (e.g., PROJ-123 or PROJ-1,PROJ-2,PROJ-3);
+        /// defaults to the current git branch's issue
         #[arg(value_delimiter = ',')]
         ids: Vec<String>,
         /// New summary
@@ -506,6 +758,22 @@ pub enum IssueCommands {
         #[arg(long, conflicts_with_all = ["summary", "description", "body_file", "fields", "state", "priority", "assignee", "tags", "parent", "validate", "dry_run"], value_name = "JSON")]
         json: Option<String>,
     },
+    /// Edit an issue in $EDITOR as Markdown with YAML front matter
+    Edit {
+        /// Issue ID (e.g., PROJ-123); defaults to the current git branch's issue
+        id: Option<String>,
+        /// Validate custom fields against project schema before updating
+        #[arg(long)]
+        validate: bool,
+        /// Validate only, do not update the issue (requires --validate)
+        #[arg(long, requires = "validate")]
+        dry_run: bool,
+    },
+    /// Manage issue templates (.track/templates/*.md)
+    Template {
+        #[command(subcommand)]
+        action: IssueTemplateCommands,
+    },
     /// Search issues
     #[command(visible_alias = "s", visible_alias = "find")]
     Search {
@@ -534,7 +802,8 @@ pub enum IssueCommands {
     /// Inspect many issues at once with per-issue success/failure results
     #[command(visible_alias = "ix")]
     Inspect {
-        /// Issue ID(s) - comma-separated (e.g., PROJ-1,PROJ-2,PROJ-3)
+        /// Issue ID(s) - comma-separated (e.g., PROJ-1,PROJ-2,PROJ-3); defaults to
+        /// the current git branch's issue when no IDs, --ids, or query are given
         #[arg(value_delimiter = ',', conflicts_with_all = ["query", "template"])]
         ids: Vec<String>,
 
@@ -594,15 +863,17 @@ pub enum IssueCommands {
     },
     /// List attachments on an issue
     Attachments {
-        /// Issue ID (e.g., PROJ-123)
-        id: String,
+        /// Issue ID (e.g., PROJ-123); defaults to the current git branch's issue
+        id: Option<String>,
     },
     /// Upload attachment(s) to an issue
     Attach {
-        /// Issue ID (e.g., PROJ-123)
+        /// Issue ID (e.g., PROJ-123); may be omitted to attach to the current
+        /// git branch's issue, in which case this is the first file path
+        #[arg(value_name = "ID")]
         id: String,
         /// File path(s) to upload
-        #[arg(required = true, value_name = "PATH")]
+        #[arg(value_name = "PATH")]
         paths: Vec<PathBuf>,
         /// Override the uploaded filename; only valid with one file
         #[arg(long)]
@@ -620,8 +891,8 @@ pub enum IssueCommands {
     /// Add a comment to an issue
     #[command(visible_alias = "cmt")]
     Comment {
-        /// Issue ID (e.g., PROJ-123)
-        id: String,
+        /// Issue ID (e.g., PROJ-123); defaults to the current git branch's issue
+        id: Option<String>,
         /// Comment text
         #[arg(short = 'm', long = "message", required_unless_present = "body_file")]
         text: Option<String>,
@@ -640,11 +911,14 @@ pub enum IssueCommands {
         /// Suppress notifications where the backend supports it
         #[arg(long)]
         silent: bool,
+        /// Preview the comment with @mentions resolved, without posting it
+        #[arg(long, conflicts_with = "attach")]
+        dry_run: bool,
     },
     /// List comments on an issue
     Comments {
-        /// Issue ID (e.g., PROJ-123)
-        id: String,
+        /// Issue ID (e.g., PROJ-123); defaults to the current git branch's issue
+        id: Option<String>,
         /// Maximum number of comments to show
         #[arg(long, default_value_t = 10, conflicts_with = "all")]
         limit: usize,
@@ -655,8 +929,8 @@ pub enum IssueCommands {
     /// Show an issue's change history (field transition timeline)
     #[command(visible_alias = "hist")]
     History {
-        /// Issue ID (e.g., PROJ-123)
-        id: String,
+        /// Issue ID (e.g., PROJ-123); defaults to the current git branch's issue
+        id: Option<String>,
         /// Only show changes to this field (canonical name, e.g. "status")
         #[arg(long)]
         field: Option<String>,
@@ -666,10 +940,11 @@ pub enum IssueCommands {
     },
     /// Link two issues together
     Link {
-        /// Source issue ID (e.g., PROJ-123)
+        /// Source issue ID (e.g., PROJ-123); with a single ID, the source is
+        /// the current git branch's issue and this is the target
         source: String,
         /// Target issue ID (e.g., PROJ-456)
-        target: String,
+        target: Option<String>,
         /// Link type: relates, depends, duplicates, subtask
         #[arg(long = "type", short = 't', default_value = "relates")]
         link_type: String,
@@ -677,14 +952,16 @@ pub enum IssueCommands {
     /// Remove a link between issues
     #[command(visible_alias = "ul")]
     Unlink {
-        /// Source issue ID (e.g., PROJ-123)
+        /// Source issue ID (e.g., PROJ-123); with a single argument, the source
+        /// is the current git branch's issue and this is the link ID
         source: String,
         /// Link ID to remove (from `issue get --full` or `issue links` output)
-        link_id: String,
+        link_id: Option<String>,
     },
     /// Start work on issue(s) (set state to in-progress) - supports comma-separated IDs
     Start {
-        /// Issue ID(s) - comma-separated for batch (e.g., PROJ-123 or PROJ-1,PROJ-2,PROJ-3)
+        /// Issue ID(s) - comma-separated for batch (e.g., PROJ-123 or PROJ-1,PROJ-2,PROJ-3);
+        /// defaults to the current git branch's issue
         #[arg(value_delimiter = ',')]
         ids: Vec<String>,
         /// State field name; auto-detected from the project schema when omitted.
@@ -694,11 +971,16 @@ pub enum IssueCommands {
         /// State value for in-progress (default: "Develop")
         #[arg(long, default_value = "Develop")]
         state: String,
+        /// Create and check out a git branch for the issue, named from the
+        /// `[git] branch_template` config (default: "feature/{id}-{slug}")
+        #[arg(long)]
+        branch: bool,
     },
     /// Complete issue(s) (set state to done/resolved) - supports comma-separated IDs
     #[command(visible_alias = "done", visible_alias = "resolve")]
     Complete {
-        /// Issue ID(s) - comma-separated for batch (e.g., PROJ-123 or PROJ-1,PROJ-2,PROJ-3)
+        /// Issue ID(s) - comma-separated for batch (e.g., PROJ-123 or PROJ-1,PROJ-2,PROJ-3);
+        /// defaults to the current git branch's issue
         #[arg(value_delimiter = ',')]
         ids: Vec<String>,
         /// State field name; auto-detected from the project schema when omitted.
@@ -886,6 +1168,26 @@ pub enum TagCommands {
     },
 }
 
+#[derive(Subcommand, Debug)]
+pub enum IssueTemplateCommands {
+    /// List templates in the nearest .track/templates directory
+    #[command(visible_alias = "ls")]
+    List,
+    /// Show a template and its variables
+    Show {
+        /// Template name (e.g., bug) or path to a .md file
+        name: String,
+    },
+    /// Import GitHub issue templates (forms or Markdown) as track templates
+    Import {
+        /// A template file or an .github/ISSUE_TEMPLATE directory
+        path: PathBuf,
+        /// Overwrite existing templates with the same name
+        #[arg(long)]
+        force: bool,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 pub enum ArticleCommands {
     /// Get article by ID
@@ -971,6 +1273,33 @@ pub enum ArticleCommands {
         #[arg(long = "tag", short = 't')]
         tags: Vec<String>,
     },
+    /// Sync a directory of Markdown files with the knowledge base
+    ///
+    /// Directories become parent articles, local images are uploaded as
+    /// attachments, and an ID marker is written into each pushed file so
+    /// renames and moves are recognised. With --pull, tracker changes are
+    /// written back to the files instead.
+    Sync {
+        /// Directory of Markdown files
+        dir: PathBuf,
+        /// Project ID or shortName the articles belong to
+        #[arg(long, short = 'p')]
+        project: String,
+        /// Pull articles from the tracker into the directory instead of pushing
+        #[arg(long)]
+        pull: bool,
+        /// Show what would change without changing anything
+        #[arg(long)]
+        dry_run: bool,
+        /// Overwrite when both sides changed since the last sync
+        #[arg(long)]
+        force: bool,
+    },
+    /// Edit an article in $EDITOR as Markdown with YAML front matter
+    Edit {
+        /// Article ID
+        id: String,
+    },
     /// Delete article by ID
     #[command(visible_alias = "rm", visible_alias = "del")]
     Delete {
@@ -1081,7 +1410,7 @@ mod tests {
         match cli.command {
             Commands::Issue { action } => match action {
                 IssueCommands::Get { id, full } => {
-                    assert_eq!(id, "PROJ-1");
+                    assert_eq!(id.as_deref(), Some("PROJ-1"));
                     assert!(!full); // Default is false
                 }
                 _ => panic!("expected issue get"),
@@ -1182,6 +1511,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_issue_create_from_template_without_summary() {
+        let cli = Cli::parse_from([
+            "track",
+            "issue",
+            "create",
+            "--template",
+            "bug",
+            "--var",
+            "component=api",
+        ]);
+
+        match cli.command {
+            Commands::Issue { action } => match action {
+                IssueCommands::Create {
+                    summary,
+                    template,
+                    vars,
+                    ..
+                } => {
+                    assert!(summary.is_none());
+                    assert_eq!(template.as_deref(), Some("bug"));
+                    assert_eq!(vars, vec!["component=api"]);
+                }
+                _ => panic!("expected issue create"),
+            },
+            _ => panic!("expected issue command"),
+        }
+
+        assert!(Cli::try_parse_from(["track", "issue", "create", "--var", "a=b"]).is_err());
+    }
+
+    #[test]
+    fn parses_issue_edit_with_validation() {
+        let cli = Cli::parse_from([
+            "track",
+            "issue",
+            "edit",
+            "PROJ-1",
+            "--validate",
+            "--dry-run",
+        ]);
+
+        match cli.command {
+            Commands::Issue { action } => match action {
+                IssueCommands::Edit {
+                    id,
+                    validate,
+                    dry_run,
+                } => {
+                    assert_eq!(id.as_deref(), Some("PROJ-1"));
+                    assert!(validate);
+                    assert!(dry_run);
+                }
+                _ => panic!("expected issue edit"),
+            },
+            _ => panic!("expected issue command"),
+        }
+
+        assert!(Cli::try_parse_from(["track", "issue", "edit", "PROJ-1", "--dry-run"]).is_err());
+    }
+
     #[test]
     fn parses_article_attach_with_options() {
         let cli = Cli::parse_from([
@@ -1450,7 +1841,7 @@ mod tests {
         match cli.command {
             Commands::Issue { action } => match action {
                 IssueCommands::Comment { id, text, .. } => {
-                    assert_eq!(id, "PROJ-123");
+                    assert_eq!(id.as_deref(), Some("PROJ-123"));
                     assert_eq!(text.as_deref(), Some("This is a comment"));
                 }
                 _ => panic!("expected issue comment"),
@@ -1459,6 +1850,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_issue_comment_dry_run() {
+        let cli = Cli::parse_from([
+            "track",
+            "issue",
+            "comment",
+            "PROJ-123",
+            "-m",
+            "@alice please review",
+            "--dry-run",
+        ]);
+
+        match cli.command {
+            Commands::Issue { action } => match action {
+                IssueCommands::Comment { text, dry_run, .. } => {
+                    assert_eq!(text.as_deref(), Some("@alice please review"));
+                    assert!(dry_run);
+                }
+                _ => panic!("expected issue comment"),
+            },
+            _ => panic!("expected issue command"),
+        }
+    }
+
     #[test]
     fn parses_issue_attachments_command() {
         let cli = Cli::parse_from(["track", "issue", "attachments", "PROJ-123"]);
@@ -1466,7 +1881,7 @@ mod tests {
         match cli.command {
             Commands::Issue { action } => match action {
                 IssueCommands::Attachments { id } => {
-                    assert_eq!(id, "PROJ-123");
+                    assert_eq!(id.as_deref(), Some("PROJ-123"));
                 }
                 _ => panic!("expected issue attachments"),
             },
@@ -1543,7 +1958,7 @@ mod tests {
                     silent,
                     ..
                 } => {
-                    assert_eq!(id, "PROJ-123");
+                    assert_eq!(id.as_deref(), Some("PROJ-123"));
                     assert_eq!(text.as_deref(), Some("See attached"));
                     assert_eq!(attach, vec![PathBuf::from("evidence.log")]);
                     assert_eq!(name.as_deref(), Some("evidence.txt"));
@@ -1579,7 +1994,7 @@ mod tests {
         match cli.command {
             Commands::Issue { action } => match action {
                 IssueCommands::Comments { id, limit, all } => {
-                    assert_eq!(id, "PROJ-123");
+                    assert_eq!(id.as_deref(), Some("PROJ-123"));
                     assert_eq!(limit, 5);
                     assert!(!all);
                 }
@@ -1601,7 +2016,7 @@ mod tests {
                     link_type,
                 } => {
                     assert_eq!(source, "PROJ-123");
-                    assert_eq!(target, "PROJ-456");
+                    assert_eq!(target.as_deref(), Some("PROJ-456"));
                     assert_eq!(link_type, "relates");
                 }
                 _ => panic!("expected issue link"),
@@ -1624,7 +2039,7 @@ mod tests {
                     link_type,
                 } => {
                     assert_eq!(source, "PROJ-123");
-                    assert_eq!(target, "PROJ-456");
+                    assert_eq!(target.as_deref(), Some("PROJ-456"));
                     assert_eq!(link_type, "depends");
                 }
                 _ => panic!("expected issue link"),
@@ -1641,7 +2056,7 @@ mod tests {
             Commands::Issue { action } => match action {
                 IssueCommands::Unlink { source, link_id } => {
                     assert_eq!(source, "PROJ-123");
-                    assert_eq!(link_id, "142-3t/PROJ-456");
+                    assert_eq!(link_id.as_deref(), Some("142-3t/PROJ-456"));
                 }
                 _ => panic!("expected issue unlink"),
             },
@@ -1657,7 +2072,7 @@ mod tests {
             Commands::Issue { action } => match action {
                 IssueCommands::Unlink { source, link_id } => {
                     assert_eq!(source, "PROJ-123");
-                    assert_eq!(link_id, "abc123");
+                    assert_eq!(link_id.as_deref(), Some("abc123"));
                 }
                 _ => panic!("expected issue unlink via alias"),
             },
@@ -1671,10 +2086,16 @@ mod tests {
 
         match cli.command {
             Commands::Issue { action } => match action {
-                IssueCommands::Start { ids, field, state } => {
+                IssueCommands::Start {
+                    ids,
+                    field,
+                    state,
+                    branch,
+                } => {
                     assert_eq!(ids, vec!["PROJ-123"]);
                     assert_eq!(field, None, "field defaults to schema auto-resolution");
                     assert_eq!(state, "Develop");
+                    assert!(!branch);
                 }
                 _ => panic!("expected issue start"),
             },
@@ -1682,6 +2103,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_start_command_with_branch_and_no_id() {
+        let cli = Cli::parse_from(["track", "issue", "start", "--branch"]);
+
+        match cli.command {
+            Commands::Issue {
+                action: IssueCommands::Start { ids, branch, .. },
+            } => {
+                assert!(ids.is_empty(), "ID is inferred from the git branch");
+                assert!(branch);
+            }
+            _ => panic!("expected issue start"),
+        }
+    }
+
+    #[test]
+    fn parses_get_command_without_id() {
+        let cli = Cli::parse_from(["track", "issue", "get"]);
+
+        match cli.command {
+            Commands::Issue {
+                action: IssueCommands::Get { id, .. },
+            } => assert_eq!(id, None),
+            _ => panic!("expected issue get"),
+        }
+    }
+
+    #[test]
+    fn parses_git_hook_install_mode() {
+        let cli = Cli::parse_from(["track", "git", "hook", "install", "--mode", "check"]);
+
+        match cli.command {
+            Commands::Git {
+                action:
+                    GitCommands::Hook {
+                        action: GitHookCommands::Install { mode, force },
+                    },
+            } => {
+                assert_eq!(mode, CommitMsgMode::Check);
+                assert!(!force);
+            }
+            _ => panic!("expected git hook install"),
+        }
+    }
+
     #[test]
     fn parses_complete_command_with_custom_values() {
         let cli = Cli::parse_from([
@@ -1723,7 +2189,7 @@ mod tests {
         match cli.command {
             Commands::Issue { action } => match action {
                 IssueCommands::Get { id, full } => {
-                    assert_eq!(id, "PROJ-123");
+                    assert_eq!(id.as_deref(), Some("PROJ-123"));
                     assert!(full);
                 }
                 _ => panic!("expected issue get"),
@@ -2373,7 +2839,9 @@ mod tests {
 
         match cli.command {
             Commands::Issue { action } => match action {
-                IssueCommands::Start { ids, field, state } => {
+                IssueCommands::Start {
+                    ids, field, state, ..
+                } => {
                     assert_eq!(ids, vec!["PROJ-1", "PROJ-2", "PROJ-3"]);
                     assert_eq!(field, None, "field defaults to schema auto-resolution");
                     assert_eq!(state, "Develop");
@@ -2591,7 +3059,7 @@ mod tests {
                     body_file,
                     ..
                 } => {
-                    assert_eq!(id, "PROJ-1");
+                    assert_eq!(id.as_deref(), Some("PROJ-1"));
                     assert!(text.is_none());
                     assert_eq!(body_file, Some(PathBuf::from("comment.md")));
                 }
@@ -2857,12 +3325,16 @@ mod tests {
                 validate,
                 resume,
                 allow_delete,
+                rollback,
+                concurrency,
             } => {
-                assert_eq!(plan, PathBuf::from("plan.json"));
+                assert_eq!(plan, Some(PathBuf::from("plan.json")));
+                assert!(rollback.is_none());
                 assert!(!dry_run);
                 assert!(!validate);
                 assert!(resume.is_none());
                 assert!(!allow_delete);
+                assert_eq!(concurrency, 1);
             }
             _ => panic!("expected apply command"),
         }
@@ -2879,6 +3351,8 @@ mod tests {
             "--resume",
             "state.json",
             "--allow-delete",
+            "--concurrency",
+            "8",
         ]);
 
         match cli.command {
@@ -2888,14 +3362,47 @@ mod tests {
                 validate,
                 resume,
                 allow_delete,
+                concurrency,
+                ..
             } => {
-                assert_eq!(plan, PathBuf::from("-"));
+                assert_eq!(plan, Some(PathBuf::from("-")));
                 assert!(dry_run);
                 assert!(validate);
                 assert_eq!(resume, Some(PathBuf::from("state.json")));
                 assert!(allow_delete);
+                assert_eq!(concurrency, 8);
             }
             _ => panic!("expected apply command"),
         }
     }
+
+    #[test]
+    fn parses_apply_rollback_without_plan() {
+        let cli = Cli::parse_from([
+            "track",
+            "apply",
+            "--rollback",
+            "state.json",
+            "--allow-delete",
+        ]);
+
+        match cli.command {
+            Commands::Apply {
+                plan,
+                rollback,
+                allow_delete,
+                ..
+            } => {
+                assert!(plan.is_none());
+                assert_eq!(rollback, Some(PathBuf::from("state.json")));
+                assert!(allow_delete);
+            }
+            _ => panic!("expected apply command"),
+        }
+
+        assert!(Cli::try_parse_from(["track", "apply"]).is_err());
+        assert!(
+            Cli::try_parse_from(["track", "apply", "plan.json", "--rollback", "s.json"]).is_err()
+        );
+    }
 }
@@ -1,34 +1,132 @@
+use crate::cache;
 use crate::cli::OutputFormat;
+use crate::commands::apply_rollback::{
+    IssueAspects, UndoEntry, UndoRecord, build_rollback_plan, capture_article, capture_issue,
+};
+use crate::commands::apply_template::{interpolate_value, placeholders};
 use crate::commands::issue;
-use crate::output::output_json;
+use crate::output::{find_state_value, output_json};
 use anyhow::{Context, Result, anyhow, bail};
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet};
 use std::ffi::OsStr;
 use std::io::Read;
-use std::path::Path;
-use tracker_core::{CreateIssue, CustomFieldUpdate, Issue, IssueTracker, UpdateIssue};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use tracker_core::{
+    CreateArticle, CreateIssue, CustomFieldUpdate, Issue, IssueTracker, IssueTransition,
+    KnowledgeBase, LogWork, UpdateArticle, UpdateIssue, unicode_eq_ignore_case,
+};
 
 pub(crate) struct ApplyOptions<'a> {
-    pub(crate) plan_path: &'a Path,
     pub(crate) dry_run: bool,
     pub(crate) validate: bool,
     pub(crate) resume_path: Option<&'a Path>,
     pub(crate) allow_delete: bool,
     pub(crate) format: OutputFormat,
     pub(crate) default_project: Option<&'a str>,
+    /// Maximum operations to run at once; 1 runs the plan strictly in order
+    pub(crate) concurrency: usize,
 }
 
-pub fn handle_apply(client: &dyn IssueTracker, options: ApplyOptions<'_>) -> Result<()> {
-    let raw_plan = read_plan_bytes(options.plan_path)?;
-    let checksum = plan_checksum(&raw_plan);
-    let plan = parse_apply_plan(&raw_plan)?;
+pub fn handle_apply(
+    client: &dyn IssueTracker,
+    kb_client: &dyn KnowledgeBase,
+    plan_path: &Path,
+    options: ApplyOptions<'_>,
+) -> Result<()> {
+    let raw_plan = read_plan_bytes(plan_path)?;
+    apply_plan_bytes(client, kb_client, &raw_plan, options)
+}
+
+/// Undo the changes recorded in a resume state file by generating the
+/// inverse plan and running it like any other plan.
+pub fn handle_rollback(
+    client: &dyn IssueTracker,
+    kb_client: &dyn KnowledgeBase,
+    state_path: &Path,
+    options: ApplyOptions<'_>,
+) -> Result<()> {
+    let content = std::fs::read_to_string(state_path)
+        .with_context(|| format!("Failed to read apply state '{}'", state_path.display()))?;
+    let mut state: ApplyResumeState = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse apply state '{}'", state_path.display()))?;
+    if state.rolled_back {
+        bail!(
+            "Apply state '{}' has already been rolled back",
+            state_path.display()
+        );
+    }
+    if state.undo.is_empty() {
+        bail!(
+            "Apply state '{}' records no changes to roll back; only runs with --resume record them",
+            state_path.display()
+        );
+    }
+
+    let rollback = build_rollback_plan(client, &state.undo, options.allow_delete)?;
+    let plan = serde_json::json!({"version": 1, "operations": rollback.operations});
+    let (mut output, error) = run_plan(client, kb_client, &serde_json::to_vec(&plan)?, &options)?;
+    output.warnings = rollback.warnings;
+    output_apply_result(&output, options.format)?;
+    if let Some(error) = error {
+        return Err(anyhow!(error));
+    }
+
+    if !options.dry_run {
+        state.rolled_back = true;
+        write_resume_state(Some(state_path), &state)?;
+    }
+    Ok(())
+}
+
+/// Execute a serialized apply plan. Plans generated by other commands (e.g.
+/// `track rules run`) go through here so they share dry-run, resume, and
+/// output handling with `track apply`.
+pub(crate) fn apply_plan_bytes(
+    client: &dyn IssueTracker,
+    kb_client: &dyn KnowledgeBase,
+    raw_plan: &[u8],
+    options: ApplyOptions<'_>,
+) -> Result<()> {
+    let (output, error) = run_plan(client, kb_client, raw_plan, &options)?;
+    output_apply_result(&output, options.format)?;
+    match error {
+        Some(error) => Err(anyhow!(error)),
+        None => Ok(()),
+    }
+}
+
+/// Execute a plan and return the JSON output instead of printing it, along
+/// with the first error if the plan failed. Used by `track mcp`, which
+/// reports results over its own protocol.
+pub(crate) fn apply_plan_value(
+    client: &dyn IssueTracker,
+    kb_client: &dyn KnowledgeBase,
+    plan: &serde_json::Value,
+    options: ApplyOptions<'_>,
+) -> Result<(serde_json::Value, Option<String>)> {
+    let raw_plan = serde_json::to_vec(plan)?;
+    let (output, error) = run_plan(client, kb_client, &raw_plan, &options)?;
+    Ok((serde_json::to_value(output)?, error))
+}
+
+fn run_plan(
+    client: &dyn IssueTracker,
+    kb_client: &dyn KnowledgeBase,
+    raw_plan: &[u8],
+    options: &ApplyOptions<'_>,
+) -> Result<(ApplyOutput, Option<String>)> {
+    let checksum = plan_checksum(raw_plan);
+    let plan = parse_apply_plan(raw_plan)?;
     let (state, resumed) = load_resume_state(options.resume_path, &checksum)?;
 
     if let Err(failure) = validate_reference_order(&plan) {
         let output = failure_output(&plan, options.dry_run, resumed, state.refs, failure);
-        output_apply_result(&output, options.format)?;
-        return Err(anyhow!(first_error(&output)));
+        let error = first_error(&output);
+        return Ok((output, Some(error)));
     }
 
     if !options.dry_run
@@ -37,7 +135,7 @@ pub fn handle_apply(client: &dyn IssueTracker, options: ApplyOptions<'_>) -> Res
             .operations
             .iter()
             .enumerate()
-            .find(|(_, op)| matches!(op, ApplyOperation::DeleteIssue { .. }))
+            .find(|(_, planned)| planned.operation.deletes())
     {
         let output = failure_output(
             &plan,
@@ -46,16 +144,20 @@ pub fn handle_apply(client: &dyn IssueTracker, options: ApplyOptions<'_>) -> Res
             state.refs,
             PreflightFailure {
                 index,
-                op: op.op_name().to_string(),
-                message: "delete_issue operations require --allow-delete".to_string(),
+                op: op.operation.op_name().to_string(),
+                message: format!(
+                    "{} operations require --allow-delete",
+                    op.operation.op_name()
+                ),
             },
         );
-        output_apply_result(&output, options.format)?;
-        return Err(anyhow!(first_error(&output)));
+        let error = first_error(&output);
+        return Ok((output, Some(error)));
     }
 
     let execution = execute_plan(ApplyExecution {
         client,
+        kb_client,
         plan: &plan,
         checksum,
         dry_run: options.dry_run,
@@ -64,18 +166,19 @@ pub fn handle_apply(client: &dyn IssueTracker, options: ApplyOptions<'_>) -> Res
         default_project: options.default_project,
         resumed,
         state,
+        concurrency: options.concurrency,
     })?;
 
-    output_apply_result(&execution.output, options.format)?;
-    if execution.output.success {
-        Ok(())
+    let error = if execution.output.success {
+        None
     } else {
-        Err(anyhow!(
+        Some(
             execution
                 .error
-                .unwrap_or_else(|| "apply failed".to_string())
-        ))
-    }
+                .unwrap_or_else(|| "apply failed".to_string()),
+        )
+    };
+    Ok((execution.output, error))
 }
 
 #[derive(Debug, Deserialize)]
@@ -83,15 +186,45 @@ struct ApplyPlan {
     version: u32,
     #[serde(default)]
     defaults: ApplyDefaults,
-    operations: Vec<ApplyOperation>,
+    operations: Vec<PlanOperation>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 struct ApplyDefaults {
+    #[serde(skip_serializing_if = "Option::is_none")]
     project: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     validate: Option<bool>,
 }
 
+/// An operation as written in the plan (after `vars`/`env` interpolation),
+/// kept next to its parsed form so `${refs.*}` and `${item.*}` placeholders
+/// can be filled in right before it runs.
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "serde_json::Value")]
+struct PlanOperation {
+    raw: serde_json::Value,
+    operation: ApplyOperation,
+}
+
+impl TryFrom<serde_json::Value> for PlanOperation {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: serde_json::Value) -> std::result::Result<Self, Self::Error> {
+        let operation = ApplyOperation::deserialize(&raw)?;
+        Ok(Self { raw, operation })
+    }
+}
+
+/// Upper bound on `--concurrency`, to stay clear of tracker rate limits.
+const MAX_APPLY_CONCURRENCY: usize = 16;
+
+/// Upper bound on issues a `foreach` expands over when the plan sets no `limit`.
+const DEFAULT_FOREACH_LIMIT: usize = 100;
+
+/// Prefix of the environment variables `${env.NAME}` may read.
+const ENV_VAR_PREFIX: &str = "TRACK_VAR_";
+
 #[derive(Debug, Deserialize)]
 #[serde(tag = "op", rename_all = "snake_case")]
 enum ApplyOperation {
@@ -154,6 +287,81 @@ enum ApplyOperation {
     DeleteIssue {
         issue: String,
     },
+    AddTag {
+        issue: String,
+        tag: String,
+    },
+    RemoveTag {
+        issue: String,
+        tag: String,
+    },
+    Attach {
+        issue: String,
+        path: PathBuf,
+        #[serde(default)]
+        name: Option<String>,
+        #[serde(default)]
+        mime_type: Option<String>,
+        #[serde(default)]
+        comment: Option<String>,
+    },
+    Unlink {
+        source: String,
+        link_id: String,
+    },
+    SetParent {
+        issue: String,
+        parent: String,
+    },
+    RemoveParent {
+        issue: String,
+    },
+    Transition {
+        issue: String,
+        state: String,
+        #[serde(default)]
+        resolution: Option<String>,
+    },
+    CreateArticle {
+        #[serde(default, rename = "ref")]
+        ref_name: Option<String>,
+        #[serde(default)]
+        project: Option<String>,
+        summary: String,
+        #[serde(default)]
+        content: Option<String>,
+        #[serde(default)]
+        parent: Option<String>,
+        #[serde(default)]
+        tags: Vec<String>,
+    },
+    UpdateArticle {
+        article: String,
+        #[serde(default)]
+        summary: Option<String>,
+        #[serde(default)]
+        content: Option<String>,
+        #[serde(default)]
+        tags: Vec<String>,
+    },
+    DeleteArticle {
+        article: String,
+    },
+    LogWork {
+        issue: String,
+        /// Time spent, e.g. "90m" or "2h"
+        duration: String,
+        #[serde(default)]
+        text: Option<String>,
+        #[serde(default)]
+        date: Option<chrono::NaiveDate>,
+    },
+    Foreach {
+        query: String,
+        #[serde(default)]
+        limit: Option<usize>,
+        operations: Vec<PlanOperation>,
+    },
 }
 
 impl ApplyOperation {
@@ -164,6 +372,30 @@ impl ApplyOperation {
             Self::Comment { .. } => "comment",
             Self::Link { .. } => "link",
             Self::DeleteIssue { .. } => "delete_issue",
+            Self::AddTag { .. } => "add_tag",
+            Self::RemoveTag { .. } => "remove_tag",
+            Self::Attach { .. } => "attach",
+            Self::Unlink { .. } => "unlink",
+            Self::SetParent { .. } => "set_parent",
+            Self::RemoveParent { .. } => "remove_parent",
+            Self::Transition { .. } => "transition",
+            Self::CreateArticle { .. } => "create_article",
+            Self::UpdateArticle { .. } => "update_article",
+            Self::DeleteArticle { .. } => "delete_article",
+            Self::LogWork { .. } => "log_work",
+            Self::Foreach { .. } => "foreach",
+        }
+    }
+
+    /// Whether this operation (or one nested in it) deletes issues or
+    /// articles, which needs `--allow-delete`.
+    fn deletes(&self) -> bool {
+        match self {
+            Self::DeleteIssue { .. } | Self::DeleteArticle { .. } => true,
+            Self::Foreach { operations, .. } => {
+                operations.iter().any(|nested| nested.operation.deletes())
+            }
+            _ => false,
         }
     }
 
@@ -172,6 +404,10 @@ impl ApplyOperation {
             Self::CreateIssue {
                 ref_name: Some(ref_name),
                 ..
+            }
+            | Self::CreateArticle {
+                ref_name: Some(ref_name),
+                ..
             } => Some(ref_name),
             _ => None,
         }
@@ -189,7 +425,23 @@ impl ApplyOperation {
             }
             Self::Comment { issue, .. } => vec![issue.as_str()],
             Self::Link { source, target, .. } => vec![source.as_str(), target.as_str()],
-            Self::DeleteIssue { issue } => vec![issue.as_str()],
+            Self::DeleteIssue { issue }
+            | Self::AddTag { issue, .. }
+            | Self::RemoveTag { issue, .. }
+            | Self::Attach { issue, .. }
+            | Self::RemoveParent { issue }
+            | Self::Transition { issue, .. }
+            | Self::LogWork { issue, .. } => vec![issue.as_str()],
+            Self::Unlink { source, .. } => vec![source.as_str()],
+            Self::SetParent { issue, parent } => vec![issue.as_str(), parent.as_str()],
+            Self::CreateArticle { parent, .. } => parent.iter().map(String::as_str).collect(),
+            Self::UpdateArticle { article, .. } | Self::DeleteArticle { article } => {
+                vec![article.as_str()]
+            }
+            Self::Foreach { operations, .. } => operations
+                .iter()
+                .flat_map(|nested| nested.operation.referenced_values())
+                .collect(),
         }
     }
 }
@@ -219,10 +471,18 @@ struct ApplyResumeState {
     plan_checksum: String,
     #[serde(default)]
     completed: BTreeSet<usize>,
+    /// Finished `foreach` steps, keyed by [`step_key`]
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    completed_steps: BTreeSet<String>,
     #[serde(default)]
     refs: BTreeMap<String, String>,
     #[serde(default)]
     results: Vec<ApplyOperationResult>,
+    /// Before-images and created objects, for `--rollback`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    undo: Vec<UndoEntry>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    rolled_back: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -232,12 +492,27 @@ struct ApplyOperationResult {
     status: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     issue: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    article: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "ref")]
     ref_name: Option<String>,
+    /// For steps expanded from a `foreach`: the matched issue and the
+    /// position of the nested operation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    item: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    step: Option<usize>,
+    /// For `foreach` itself: how many issues the query matched
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    matched: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     warnings: Vec<String>,
+    /// What `--rollback` needs to reverse this operation; moved into the
+    /// resume state rather than reported
+    #[serde(skip)]
+    undo: Option<UndoRecord>,
 }
 
 impl ApplyOperationResult {
@@ -247,9 +522,14 @@ impl ApplyOperationResult {
             op: op.to_string(),
             status: status.to_string(),
             issue: None,
+            article: None,
             ref_name: None,
+            item: None,
+            step: None,
+            matched: None,
             error: None,
             warnings: Vec::new(),
+            undo: None,
         }
     }
 
@@ -259,9 +539,14 @@ impl ApplyOperationResult {
             op: op.to_string(),
             status: "failed".to_string(),
             issue: None,
+            article: None,
             ref_name: None,
+            item: None,
+            step: None,
+            matched: None,
             error: Some(error.into()),
             warnings: Vec::new(),
+            undo: None,
         }
     }
 }
@@ -274,6 +559,13 @@ struct ApplyOutput {
     summary: ApplySummary,
     refs: BTreeMap<String, String>,
     results: Vec<ApplyOperationResult>,
+    /// Dry runs only: the operations that would run, with `vars`, `env`
+    /// and `foreach` expanded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    plan: Option<serde_json::Value>,
+    /// Rollbacks only: recorded changes that could not be reversed
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -285,6 +577,7 @@ struct ApplySummary {
 
 struct ApplyExecution<'a> {
     client: &'a dyn IssueTracker,
+    kb_client: &'a dyn KnowledgeBase,
     plan: &'a ApplyPlan,
     checksum: String,
     dry_run: bool,
@@ -293,15 +586,19 @@ struct ApplyExecution<'a> {
     default_project: Option<&'a str>,
     resumed: bool,
     state: ApplyResumeState,
+    concurrency: usize,
 }
 
 #[derive(Clone, Copy)]
 struct OperationContext<'a> {
     client: &'a dyn IssueTracker,
+    kb_client: &'a dyn KnowledgeBase,
     plan: &'a ApplyPlan,
     dry_run: bool,
     validate: bool,
     default_project: Option<&'a str>,
+    /// Record before-images for `--rollback` (real runs with a resume file)
+    capture: bool,
 }
 
 struct ExecutionResult {
@@ -316,85 +613,530 @@ struct PreflightFailure {
     message: String,
 }
 
-fn execute_plan(execution: ApplyExecution<'_>) -> Result<ExecutionResult> {
-    let mut refs = execution.state.refs.clone();
-    let mut completed = execution.state.completed.clone();
-    let mut state_results: BTreeMap<usize, ApplyOperationResult> = execution
-        .state
-        .results
-        .into_iter()
-        .map(|result| (result.index, result))
-        .collect();
-    let mut output_results = Vec::new();
+/// Progress of one plan run. Outside dry runs it is mirrored to the resume
+/// file after every completed operation or `foreach` step.
+struct PlanProgress<'a> {
+    resume_path: Option<&'a Path>,
+    checksum: &'a str,
+    dry_run: bool,
+    refs: BTreeMap<String, String>,
+    completed: BTreeSet<usize>,
+    completed_steps: BTreeSet<String>,
+    state_results: BTreeMap<(usize, Option<String>), ApplyOperationResult>,
+    output_results: Vec<ApplyOperationResult>,
+    /// Dry runs: operations as they will run, keyed by plan index
+    expanded: Vec<(usize, serde_json::Value)>,
+    undo: Vec<UndoEntry>,
+}
+
+impl PlanProgress<'_> {
+    /// Record a successful result, marking the operation (or `foreach` step)
+    /// done unless this is a dry run.
+    fn finish(&mut self, mut result: ApplyOperationResult) -> Result<()> {
+        if !self.dry_run {
+            if let Some(record) = result.undo.take() {
+                self.undo.push(UndoEntry {
+                    index: result.index,
+                    item: result.item.clone(),
+                    step: result.step,
+                    record,
+                });
+            }
+            match (&result.item, result.step) {
+                (Some(item), Some(step)) => {
+                    self.completed_steps
+                        .insert(step_key(result.index, item, step));
+                }
+                _ => {
+                    self.completed.insert(result.index);
+                }
+            }
+            self.state_results
+                .insert(state_result_key(&result), result.clone());
+            write_resume_state(
+                self.resume_path,
+                &ApplyResumeState {
+                    version: 1,
+                    plan_checksum: self.checksum.to_string(),
+                    completed: self.completed.clone(),
+                    completed_steps: self.completed_steps.clone(),
+                    refs: self.refs.clone(),
+                    results: self.state_results.values().cloned().collect(),
+                    undo: self.undo.clone(),
+                    rolled_back: false,
+                },
+            )?;
+        }
+        self.output_results.push(result);
+        Ok(())
+    }
+
+    /// Report a result finished by an earlier run, preferring the stored copy.
+    fn skip(&mut self, fallback: ApplyOperationResult) {
+        let mut result = self
+            .state_results
+            .get(&state_result_key(&fallback))
+            .cloned()
+            .unwrap_or(fallback);
+        result.status = "skipped".to_string();
+        self.output_results.push(result);
+    }
+
+    /// Record a failed result and return its message.
+    fn fail(&mut self, result: ApplyOperationResult) -> Option<String> {
+        let message = result.error.clone();
+        self.output_results.push(result);
+        message
+    }
+}
+
+fn execute_plan(mut execution: ApplyExecution<'_>) -> Result<ExecutionResult> {
     let operation_context = OperationContext {
         client: execution.client,
+        kb_client: execution.kb_client,
         plan: execution.plan,
         dry_run: execution.dry_run,
         validate: execution.validate,
         default_project: execution.default_project,
+        capture: execution.resume_path.is_some() && !execution.dry_run,
+    };
+    let mut progress = PlanProgress {
+        resume_path: execution.resume_path,
+        checksum: &execution.checksum,
+        dry_run: execution.dry_run,
+        refs: std::mem::take(&mut execution.state.refs),
+        completed: std::mem::take(&mut execution.state.completed),
+        completed_steps: std::mem::take(&mut execution.state.completed_steps),
+        state_results: std::mem::take(&mut execution.state.results)
+            .into_iter()
+            .map(|result| (state_result_key(&result), result))
+            .collect(),
+        output_results: Vec::new(),
+        expanded: Vec::new(),
+        undo: std::mem::take(&mut execution.state.undo),
     };
 
-    for (index, operation) in execution.plan.operations.iter().enumerate() {
-        if completed.contains(&index) {
-            let mut result = state_results.get(&index).cloned().unwrap_or_else(|| {
-                ApplyOperationResult::success(index, operation.op_name(), "skipped")
-            });
-            result.status = "skipped".to_string();
-            output_results.push(result);
+    let concurrency = execution.concurrency.clamp(1, MAX_APPLY_CONCURRENCY);
+    let failure = if concurrency == 1 {
+        execute_in_order(operation_context, &mut progress)?
+    } else {
+        execute_concurrently(operation_context, &mut progress, concurrency)?
+    };
+
+    Ok(ExecutionResult {
+        output: progress_output(failure.is_none(), &execution, progress),
+        error: failure,
+    })
+}
+
+/// Run operations one at a time in plan order, stopping at the first failure.
+fn execute_in_order(
+    context: OperationContext<'_>,
+    progress: &mut PlanProgress<'_>,
+) -> Result<Option<String>> {
+    for (index, planned) in context.plan.operations.iter().enumerate() {
+        if progress.completed.contains(&index) {
+            progress.skip(ApplyOperationResult::success(
+                index,
+                planned.operation.op_name(),
+                "skipped",
+            ));
             continue;
         }
 
-        match execute_operation(operation_context, operation, index, &mut refs) {
-            Ok(result) => {
-                if !execution.dry_run {
-                    completed.insert(index);
-                    state_results.insert(index, result.clone());
-                    write_resume_state(
-                        execution.resume_path,
-                        &execution.checksum,
-                        &completed,
-                        &refs,
-                        &state_results,
-                    )?;
+        if let Some(message) = execute_planned(context, progress, index, planned)? {
+            return Ok(Some(message));
+        }
+    }
+    Ok(None)
+}
+
+/// Run up to `concurrency` operations at once, starting each as soon as the
+/// operations it depends on have finished. After a failure no new operations
+/// start, but those already running are allowed to finish (and are recorded
+/// in the resume file).
+fn execute_concurrently(
+    context: OperationContext<'_>,
+    progress: &mut PlanProgress<'_>,
+    concurrency: usize,
+) -> Result<Option<String>> {
+    let operations = &context.plan.operations;
+    let dependencies = operation_dependencies(context.plan);
+    let mut done = BTreeSet::new();
+    let mut pending = BTreeSet::new();
+    for (index, planned) in operations.iter().enumerate() {
+        if progress.completed.contains(&index) {
+            progress.skip(ApplyOperationResult::success(
+                index,
+                planned.operation.op_name(),
+                "skipped",
+            ));
+            done.insert(index);
+        } else {
+            pending.insert(index);
+        }
+    }
+
+    thread::scope(|scope| {
+        let (tx, rx) = mpsc::channel();
+        let mut running = 0;
+        let mut failure = None;
+
+        loop {
+            while failure.is_none()
+                && running < concurrency
+                && let Some(index) = pending
+                    .iter()
+                    .copied()
+                    .find(|index| dependencies[*index].is_subset(&done))
+            {
+                pending.remove(&index);
+                let planned = &operations[index];
+                if matches!(planned.operation, ApplyOperation::Foreach { .. }) {
+                    // A foreach waits for everything before it and blocks
+                    // everything after it, so nothing else is running.
+                    failure = execute_planned(context, progress, index, planned)?;
+                    done.insert(index);
+                    continue;
                 }
-                output_results.push(result);
+
+                if context.dry_run {
+                    progress.expanded.push((index, planned.raw.clone()));
+                }
+                let mut refs = progress.refs.clone();
+                let tx = tx.clone();
+                scope.spawn(move || {
+                    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+                        resolve_operation(&planned.raw, &refs).and_then(|operation| {
+                            execute_operation(context, &operation, index, &mut refs)
+                        })
+                    }))
+                    .unwrap_or_else(|_| Err(anyhow!("Operation panicked")));
+                    let _ = tx.send((index, outcome, refs));
+                });
+                running += 1;
+            }
+
+            if running == 0 {
+                break;
+            }
+            let (index, outcome, refs) = rx
+                .recv()
+                .map_err(|_| anyhow!("apply worker exited without a result"))?;
+            running -= 1;
+            match outcome {
+                Ok(result) => {
+                    progress.refs.extend(refs);
+                    progress.finish(result)?;
+                    done.insert(index);
+                }
+                Err(error) => {
+                    let message = progress.fail(ApplyOperationResult::failed(
+                        index,
+                        operations[index].operation.op_name(),
+                        error.to_string(),
+                    ));
+                    failure = failure.or(message);
+                }
+            }
+        }
+
+        Ok(failure)
+    })
+}
+
+/// Run one top-level operation (or a whole `foreach`) on the current thread.
+/// Returns the failure message if it failed (already recorded in `progress`).
+fn execute_planned(
+    context: OperationContext<'_>,
+    progress: &mut PlanProgress<'_>,
+    index: usize,
+    planned: &PlanOperation,
+) -> Result<Option<String>> {
+    match &planned.operation {
+        ApplyOperation::Foreach {
+            query,
+            limit,
+            operations,
+        } => execute_foreach(context, progress, index, query, *limit, operations),
+        _ => {
+            if context.dry_run {
+                progress.expanded.push((index, planned.raw.clone()));
             }
-            Err(error) => {
-                let message = error.to_string();
-                output_results.push(ApplyOperationResult::failed(
+            let outcome = resolve_operation(&planned.raw, &progress.refs).and_then(|operation| {
+                execute_operation(context, &operation, index, &mut progress.refs)
+            });
+            match outcome {
+                Ok(result) => {
+                    progress.finish(result)?;
+                    Ok(None)
+                }
+                Err(error) => Ok(progress.fail(ApplyOperationResult::failed(
                     index,
-                    operation.op_name(),
-                    message.clone(),
-                ));
-                let output = build_output(
-                    false,
-                    execution.dry_run,
-                    execution.resumed,
-                    execution.plan.operations.len(),
-                    refs,
-                    output_results,
-                );
-                return Ok(ExecutionResult {
-                    output,
-                    error: Some(message),
-                });
+                    planned.operation.op_name(),
+                    error.to_string(),
+                ))),
             }
         }
     }
+}
 
-    let output = build_output(
-        true,
+/// For each operation, the earlier operations it has to wait for: the one
+/// defining a ref it uses, the previous one touching the same issue, article
+/// or ref, every earlier `create_issue` for a `create_issue` with `dedupe`
+/// (its query may match what they created), and, across a `foreach`,
+/// everything on the other side (its query may match issues any earlier
+/// operation changed).
+fn operation_dependencies(plan: &ApplyPlan) -> Vec<BTreeSet<usize>> {
+    let mut last_touch: BTreeMap<String, usize> = BTreeMap::new();
+    let mut barrier = None;
+    // Creates since the last deduped create, which already waited for the rest
+    let mut pending_creates: Vec<usize> = Vec::new();
+    let mut dependencies = Vec::with_capacity(plan.operations.len());
+
+    for (index, planned) in plan.operations.iter().enumerate() {
+        let mut before = BTreeSet::new();
+        if matches!(planned.operation, ApplyOperation::Foreach { .. }) {
+            before.extend(0..index);
+            barrier = Some(index);
+        } else {
+            before.extend(barrier);
+        }
+        if let ApplyOperation::CreateIssue { dedupe, .. } = &planned.operation {
+            if dedupe.is_some() {
+                before.extend(pending_creates.drain(..));
+            }
+            pending_creates.push(index);
+        }
+
+        let mut touched: Vec<String> = planned
+            .operation
+            .referenced_values()
+            .into_iter()
+            .filter(|value| local_ref_name(value).is_none())
+            .map(String::from)
+            .collect();
+        touched.extend(
+            used_ref_names(planned)
+                .into_iter()
+                .chain(planned.operation.defined_ref().map(String::from))
+                .map(|name| format!("${name}")),
+        );
+        for key in touched {
+            if let Some(previous) = last_touch.insert(key, index)
+                && previous != index
+            {
+                before.insert(previous);
+            }
+        }
+        dependencies.push(before);
+    }
+    dependencies
+}
+
+fn progress_output(
+    success: bool,
+    execution: &ApplyExecution<'_>,
+    mut progress: PlanProgress<'_>,
+) -> ApplyOutput {
+    // Concurrent runs finish operations out of order; report them in plan
+    // order. The sort is stable, so `foreach` steps keep their order.
+    progress.output_results.sort_by_key(|result| result.index);
+    progress.expanded.sort_by_key(|(index, _)| *index);
+    let mut output = build_output(
+        success,
         execution.dry_run,
         execution.resumed,
         execution.plan.operations.len(),
-        refs,
-        output_results,
+        progress.refs,
+        progress.output_results,
     );
+    if execution.dry_run {
+        output.plan = Some(serde_json::json!({
+            "version": execution.plan.version,
+            "defaults": execution.plan.defaults,
+            "operations": progress
+                .expanded
+                .into_iter()
+                .map(|(_, raw)| raw)
+                .collect::<Vec<_>>(),
+        }));
+    }
+    output
+}
 
-    Ok(ExecutionResult {
-        output,
-        error: None,
-    })
+/// Run `foreach`: search, then execute each nested operation once per
+/// matching issue with `${item.*}` filled in. Returns the failure message if
+/// a step failed (already recorded in `progress`).
+fn execute_foreach(
+    context: OperationContext<'_>,
+    progress: &mut PlanProgress<'_>,
+    index: usize,
+    query: &str,
+    limit: Option<usize>,
+    operations: &[PlanOperation],
+) -> Result<Option<String>> {
+    let limit = limit.unwrap_or(DEFAULT_FOREACH_LIMIT);
+    let issues = interpolate_refs(query, &progress.refs).and_then(|query| {
+        context
+            .client
+            .search_all_issues(&query, limit)
+            .with_context(|| format!("Failed to run foreach query '{}'", query))
+    });
+    let issues = match issues {
+        Ok(issues) => issues,
+        Err(error) => {
+            return Ok(progress.fail(ApplyOperationResult::failed(
+                index,
+                "foreach",
+                error.to_string(),
+            )));
+        }
+    };
+
+    for issue in &issues {
+        let item = issue_output_id(issue);
+        for (step, nested) in operations.iter().enumerate() {
+            let op_name = nested.operation.op_name();
+            let mut skipped = ApplyOperationResult::success(index, op_name, "skipped");
+            skipped.item = Some(item.clone());
+            skipped.step = Some(step);
+            if progress
+                .completed_steps
+                .contains(&step_key(index, &item, step))
+            {
+                progress.skip(skipped);
+                continue;
+            }
+
+            let outcome = expand_item(&nested.raw, issue).and_then(|raw| {
+                if context.dry_run {
+                    progress.expanded.push((index, raw.clone()));
+                }
+                let operation = resolve_operation(&raw, &progress.refs)?;
+                execute_operation(context, &operation, index, &mut progress.refs)
+            });
+            match outcome {
+                Ok(mut result) => {
+                    result.item = Some(item.clone());
+                    result.step = Some(step);
+                    progress.finish(result)?;
+                }
+                Err(error) => {
+                    let mut failed =
+                        ApplyOperationResult::failed(index, op_name, error.to_string());
+                    failed.item = Some(item);
+                    failed.step = Some(step);
+                    return Ok(progress.fail(failed));
+                }
+            }
+        }
+    }
+
+    let mut result = ApplyOperationResult::success(
+        index,
+        "foreach",
+        if context.dry_run {
+            "dry_run"
+        } else {
+            "expanded"
+        },
+    );
+    result.matched = Some(issues.len());
+    if issues.len() >= limit {
+        result.warnings.push(format!(
+            "foreach stopped at its limit of {limit} issues; raise `limit` to cover more"
+        ));
+    }
+    progress.finish(result)?;
+    Ok(None)
+}
+
+fn step_key(index: usize, item: &str, step: usize) -> String {
+    format!("{index}/{item}/{step}")
+}
+
+fn state_result_key(result: &ApplyOperationResult) -> (usize, Option<String>) {
+    let step = match (&result.item, result.step) {
+        (Some(item), Some(step)) => Some(format!("{item}/{step}")),
+        _ => None,
+    };
+    (result.index, step)
+}
+
+/// Fill in `${refs.*}` placeholders (the last interpolation pass) and parse
+/// the operation.
+fn resolve_operation(
+    raw: &serde_json::Value,
+    refs: &BTreeMap<String, String>,
+) -> Result<ApplyOperation> {
+    let mut raw = raw.clone();
+    interpolate_value(
+        &mut raw,
+        &|namespace, name| ref_placeholder(namespace, name, refs),
+        true,
+    )?;
+    ApplyOperation::deserialize(&raw).context("Invalid operation after interpolation")
+}
+
+fn interpolate_refs(text: &str, refs: &BTreeMap<String, String>) -> Result<String> {
+    super::apply_template::interpolate_str(
+        text,
+        &|namespace, name| ref_placeholder(namespace, name, refs),
+        true,
+    )
+}
+
+fn ref_placeholder(
+    namespace: &str,
+    name: &str,
+    refs: &BTreeMap<String, String>,
+) -> Result<Option<String>> {
+    match namespace {
+        "refs" => refs
+            .get(name)
+            .cloned()
+            .map(Some)
+            .ok_or_else(|| anyhow!("Unknown local ref '${{refs.{}}}'", name)),
+        "item" => bail!(
+            "'${{item.{}}}' can only be used inside foreach operations",
+            name
+        ),
+        _ => Ok(None),
+    }
+}
+
+/// Fill in `${item.*}` placeholders for one `foreach` match.
+fn expand_item(raw: &serde_json::Value, issue: &Issue) -> Result<serde_json::Value> {
+    let mut raw = raw.clone();
+    interpolate_value(
+        &mut raw,
+        &|namespace, name| item_placeholder(namespace, name, issue),
+        false,
+    )?;
+    Ok(raw)
+}
+
+fn item_placeholder(namespace: &str, name: &str, issue: &Issue) -> Result<Option<String>> {
+    if namespace != "item" {
+        return Ok(None);
+    }
+    let value = match name {
+        "id" => issue_output_id(issue),
+        "summary" => issue.summary.clone(),
+        "description" => issue.description.clone().unwrap_or_default(),
+        "project" => issue
+            .project
+            .short_name
+            .clone()
+            .unwrap_or_else(|| issue.project.id.clone()),
+        "state" => find_state_value(issue).unwrap_or_default().to_string(),
+        other => bail!(
+            "Unknown foreach item field '{}'; use id, summary, description, project, or state",
+            other
+        ),
+    };
+    Ok(Some(value))
 }
 
 fn execute_operation(
@@ -402,111 +1144,486 @@ fn execute_operation(
     operation: &ApplyOperation,
     index: usize,
     refs: &mut BTreeMap<String, String>,
+) -> Result<ApplyOperationResult> {
+    if !context.capture {
+        return run_operation(context, operation, index, refs);
+    }
+
+    // A failed capture only costs the rollback of this operation, so it is
+    // reported as a warning instead of blocking the change itself.
+    let before = capture_before(context, operation, refs);
+    let mut result = run_operation(context, operation, index, refs)?;
+    if result.status == "unchanged" {
+        return Ok(result);
+    }
+    match before {
+        Ok(before) => result.undo = undo_record(operation, &result, before),
+        Err(error) => result.warnings.push(format!(
+            "Could not record a before-image; --rollback will not undo this operation: {:#}",
+            error
+        )),
+    }
+    Ok(result)
+}
+
+/// Capture what an operation is about to change, for `--rollback`.
+fn capture_before(
+    context: OperationContext<'_>,
+    operation: &ApplyOperation,
+    refs: &BTreeMap<String, String>,
+) -> Result<Option<UndoRecord>> {
+    let issue_aspects = |issue: &str, aspects: IssueAspects| -> Result<Option<UndoRecord>> {
+        let issue_id = resolve_issue_ref(issue, refs)?;
+        capture_issue(context.client, &issue_id, &aspects).map(Some)
+    };
+
+    match operation {
+        ApplyOperation::UpdateIssue {
+            issue,
+            summary,
+            description,
+            fields,
+            custom_fields,
+            state,
+            priority,
+            assignee,
+            tags,
+            parent,
+        } => {
+            let mut field_names: Vec<String> = fields.keys().cloned().collect();
+            field_names.extend(
+                custom_fields
+                    .iter()
+                    .filter_map(|field| field["name"].as_str())
+                    .map(String::from),
+            );
+            if priority.is_some() {
+                field_names.push("Priority".to_string());
+            }
+            if assignee.is_some() {
+                field_names.push("Assignee".to_string());
+            }
+            issue_aspects(
+                issue,
+                IssueAspects {
+                    summary: summary.is_some(),
+                    description: description.is_some(),
+                    state: state.is_some(),
+                    fields: field_names,
+                    tags: !tags.is_empty(),
+                    parent: parent.is_some(),
+                },
+            )
+        }
+        ApplyOperation::AddTag { issue, .. } | ApplyOperation::RemoveTag { issue, .. } => {
+            issue_aspects(
+                issue,
+                IssueAspects {
+                    tags: true,
+                    ..Default::default()
+                },
+            )
+        }
+        ApplyOperation::SetParent { issue, .. } | ApplyOperation::RemoveParent { issue } => {
+            issue_aspects(
+                issue,
+                IssueAspects {
+                    parent: true,
+                    ..Default::default()
+                },
+            )
+        }
+        ApplyOperation::Transition { issue, .. } => issue_aspects(
+            issue,
+            IssueAspects {
+                state: true,
+                ..Default::default()
+            },
+        ),
+        ApplyOperation::Link {
+            source,
+            target,
+            link_type,
+        } => match link_type.to_lowercase().as_str() {
+            // Parent links are undone by restoring the child's parent.
+            "subtask" | "subtask-of" => issue_aspects(
+                source,
+                IssueAspects {
+                    parent: true,
+                    ..Default::default()
+                },
+            ),
+            "parent" | "parent-of" => issue_aspects(
+                target,
+                IssueAspects {
+                    parent: true,
+                    ..Default::default()
+                },
+            ),
+            _ => Ok(Some(UndoRecord::Linked {
+                source: resolve_issue_ref(source, refs)?,
+                target: resolve_issue_ref(target, refs)?,
+                link_type: link_type.clone(),
+            })),
+        },
+        ApplyOperation::UpdateArticle { article, .. } => {
+            let article_id = resolve_issue_ref(article, refs)?;
+            capture_article(context.kb_client, &article_id).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+/// The rollback record for a finished operation, given what was captured
+/// before it ran.
+fn undo_record(
+    operation: &ApplyOperation,
+    result: &ApplyOperationResult,
+    before: Option<UndoRecord>,
+) -> Option<UndoRecord> {
+    match operation {
+        ApplyOperation::CreateIssue { .. } if result.status == "created" => result
+            .issue
+            .clone()
+            .map(|issue| UndoRecord::CreatedIssue { issue }),
+        ApplyOperation::CreateArticle { .. } if result.status == "created" => result
+            .article
+            .clone()
+            .map(|article| UndoRecord::CreatedArticle { article }),
+        ApplyOperation::Comment { .. }
+        | ApplyOperation::DeleteIssue { .. }
+        | ApplyOperation::DeleteArticle { .. }
+        | ApplyOperation::Attach { .. }
+        | ApplyOperation::Unlink { .. }
+        | ApplyOperation::LogWork { .. } => Some(UndoRecord::Irreversible {
+            op: operation.op_name().to_string(),
+            target: result
+                .issue
+                .clone()
+                .or_else(|| result.article.clone())
+                .unwrap_or_default(),
+        }),
+        _ => before,
+    }
+}
+
+fn run_operation(
+    context: OperationContext<'_>,
+    operation: &ApplyOperation,
+    index: usize,
+    refs: &mut BTreeMap<String, String>,
 ) -> Result<ApplyOperationResult> {
     match operation {
         ApplyOperation::CreateIssue {
             ref_name,
             project,
             summary,
-            description,
-            fields,
-            custom_fields,
-            state,
-            priority,
-            assignee,
-            tags,
+            description,
+            fields,
+            custom_fields,
+            state,
+            priority,
+            assignee,
+            tags,
+            parent,
+            dedupe,
+        } => execute_create_issue(
+            context.client,
+            context.plan,
+            index,
+            context.dry_run,
+            context.validate,
+            context.default_project,
+            refs,
+            ref_name.as_deref(),
+            project.as_deref(),
+            summary,
+            description.as_deref(),
+            fields,
+            custom_fields,
+            state.as_deref(),
+            priority.as_deref(),
+            assignee.as_deref(),
+            tags,
+            parent.as_deref(),
+            dedupe.as_ref(),
+        ),
+        ApplyOperation::UpdateIssue {
+            issue,
+            summary,
+            description,
+            fields,
+            custom_fields,
+            state,
+            priority,
+            assignee,
+            tags,
+            parent,
+        } => execute_update_issue(
+            context.client,
+            context.plan,
+            index,
+            context.dry_run,
+            context.validate,
+            refs,
+            issue,
+            summary.as_deref(),
+            description.as_deref(),
+            fields,
+            custom_fields,
+            state.as_deref(),
+            priority.as_deref(),
+            assignee.as_deref(),
+            tags,
+            parent.as_deref(),
+        ),
+        ApplyOperation::Comment { issue, body } => {
+            let issue_id = resolve_issue_ref(issue, refs)?;
+            let mut result = ApplyOperationResult::success(
+                index,
+                operation.op_name(),
+                if context.dry_run {
+                    "dry_run"
+                } else {
+                    "commented"
+                },
+            );
+            result.issue = Some(issue_id.clone());
+            if !context.dry_run {
+                let scope = super::mentions::MentionScope::Issue(&issue_id);
+                let resolution = super::mentions::resolve_mentions(context.client, scope, body);
+                result.warnings = resolution.warnings;
+                context
+                    .client
+                    .add_comment(&issue_id, &resolution.text)
+                    .with_context(|| format!("Failed to comment on '{}'", issue_id))?;
+            }
+            Ok(result)
+        }
+        ApplyOperation::Link {
+            source,
+            target,
+            link_type,
+        } => {
+            let source_id = resolve_issue_ref(source, refs)?;
+            let target_id = resolve_issue_ref(target, refs)?;
+            let mut result = ApplyOperationResult::success(
+                index,
+                operation.op_name(),
+                if context.dry_run { "dry_run" } else { "linked" },
+            );
+            result.issue = Some(source_id.clone());
+            if !context.dry_run {
+                issue::link_issues_with_type(context.client, &source_id, &target_id, link_type)?;
+            }
+            Ok(result)
+        }
+        ApplyOperation::DeleteIssue { issue } => {
+            let issue_id = resolve_issue_ref(issue, refs)?;
+            let mut result = ApplyOperationResult::success(
+                index,
+                operation.op_name(),
+                if context.dry_run {
+                    "dry_run"
+                } else {
+                    "deleted"
+                },
+            );
+            result.issue = Some(issue_id.clone());
+            if !context.dry_run {
+                context
+                    .client
+                    .delete_issue(&issue_id)
+                    .with_context(|| format!("Failed to delete '{}'", issue_id))?;
+            }
+            Ok(result)
+        }
+        ApplyOperation::AddTag { issue, tag } => {
+            let issue_id = resolve_issue_ref(issue, refs)?;
+            let tag = require_value("tag", tag)?;
+            let mut result = issue_result(context, index, operation, "tagged", &issue_id);
+            if !context.dry_run {
+                // Backends treat `tags` on update as the full tag set, so
+                // send the existing tags along with the new one.
+                let existing = context
+                    .client
+                    .get_issue(&issue_id)
+                    .with_context(|| format!("Failed to fetch issue '{}'", issue_id))?;
+                let mut tags: Vec<String> = existing.tags.into_iter().map(|t| t.name).collect();
+                if tags
+                    .iter()
+                    .any(|existing| unicode_eq_ignore_case(existing, tag))
+                {
+                    result.status = "unchanged".to_string();
+                    return Ok(result);
+                }
+                tags.push(tag.to_string());
+                let update = UpdateIssue {
+                    tags,
+                    ..Default::default()
+                };
+                context
+                    .client
+                    .update_issue(&issue_id, &update)
+                    .with_context(|| format!("Failed to tag '{}' with '{}'", issue_id, tag))?;
+            }
+            Ok(result)
+        }
+        ApplyOperation::RemoveTag { issue, tag } => {
+            let issue_id = resolve_issue_ref(issue, refs)?;
+            let tag = require_value("tag", tag)?;
+            let result = issue_result(context, index, operation, "untagged", &issue_id);
+            if !context.dry_run {
+                context
+                    .client
+                    .remove_issue_tag(&issue_id, tag)
+                    .with_context(|| {
+                        format!("Failed to remove tag '{}' from '{}'", tag, issue_id)
+                    })?;
+            }
+            Ok(result)
+        }
+        ApplyOperation::Attach {
+            issue,
+            path,
+            name,
+            mime_type,
+            comment,
+        } => {
+            let issue_id = resolve_issue_ref(issue, refs)?;
+            let upload = super::attachments::build_attachment_upload(
+                std::slice::from_ref(path),
+                name.as_deref(),
+                mime_type.as_deref(),
+                comment.as_deref(),
+                false,
+                false,
+            )?;
+            let result = issue_result(context, index, operation, "attached", &issue_id);
+            if !context.dry_run {
+                context
+                    .client
+                    .add_issue_attachment(&issue_id, &upload)
+                    .with_context(|| {
+                        format!("Failed to attach '{}' to '{}'", path.display(), issue_id)
+                    })?;
+            }
+            Ok(result)
+        }
+        ApplyOperation::Unlink { source, link_id } => {
+            let source_id = resolve_issue_ref(source, refs)?;
+            let link_id = require_value("link_id", link_id)?;
+            let result = issue_result(context, index, operation, "unlinked", &source_id);
+            if !context.dry_run {
+                context
+                    .client
+                    .unlink_issues(&source_id, link_id)
+                    .with_context(|| {
+                        format!("Failed to unlink {} (link {})", source_id, link_id)
+                    })?;
+            }
+            Ok(result)
+        }
+        ApplyOperation::SetParent { issue, parent } => {
+            let issue_id = resolve_issue_ref(issue, refs)?;
+            let parent_id = resolve_issue_ref(parent, refs)?;
+            let result = issue_result(context, index, operation, "parent_set", &issue_id);
+            if !context.dry_run {
+                context
+                    .client
+                    .link_subtask(&issue_id, &parent_id)
+                    .with_context(|| {
+                        format!("Failed to set parent of '{}' to '{}'", issue_id, parent_id)
+                    })?;
+            }
+            Ok(result)
+        }
+        ApplyOperation::RemoveParent { issue } => {
+            let issue_id = resolve_issue_ref(issue, refs)?;
+            let result = issue_result(context, index, operation, "parent_removed", &issue_id);
+            if !context.dry_run {
+                context
+                    .client
+                    .remove_issue_parent(&issue_id)
+                    .with_context(|| format!("Failed to remove parent of '{}'", issue_id))?;
+            }
+            Ok(result)
+        }
+        ApplyOperation::Transition {
+            issue,
+            state,
+            resolution,
+        } => {
+            let issue_id = resolve_issue_ref(issue, refs)?;
+            let transition = IssueTransition {
+                state: require_value("state", state)?.to_string(),
+                resolution: resolution.clone(),
+            };
+            let mut result = issue_result(context, index, operation, "transitioned", &issue_id);
+            if !context.dry_run {
+                let updated = context
+                    .client
+                    .transition_issue(&issue_id, &transition)
+                    .with_context(|| {
+                        format!("Failed to transition '{}' to '{}'", issue_id, state)
+                    })?;
+                result.issue = Some(issue_output_id(&updated));
+            }
+            Ok(result)
+        }
+        ApplyOperation::CreateArticle {
+            ref_name,
+            project,
+            summary,
+            content,
             parent,
-            dedupe,
-        } => execute_create_issue(
-            context.client,
-            context.plan,
+            tags,
+        } => execute_create_article(
+            context,
             index,
-            context.dry_run,
-            context.validate,
-            context.default_project,
             refs,
             ref_name.as_deref(),
             project.as_deref(),
             summary,
-            description.as_deref(),
-            fields,
-            custom_fields,
-            state.as_deref(),
-            priority.as_deref(),
-            assignee.as_deref(),
-            tags,
+            content.as_deref(),
             parent.as_deref(),
-            dedupe.as_ref(),
+            tags,
         ),
-        ApplyOperation::UpdateIssue {
-            issue,
+        ApplyOperation::UpdateArticle {
+            article,
             summary,
-            description,
-            fields,
-            custom_fields,
-            state,
-            priority,
-            assignee,
-            tags,
-            parent,
-        } => execute_update_issue(
-            context.client,
-            context.plan,
-            index,
-            context.dry_run,
-            context.validate,
-            refs,
-            issue,
-            summary.as_deref(),
-            description.as_deref(),
-            fields,
-            custom_fields,
-            state.as_deref(),
-            priority.as_deref(),
-            assignee.as_deref(),
+            content,
             tags,
-            parent.as_deref(),
-        ),
-        ApplyOperation::Comment { issue, body } => {
-            let issue_id = resolve_issue_ref(issue, refs)?;
+        } => {
+            let article_id = resolve_issue_ref(article, refs)?;
+            if summary.is_none() && content.is_none() && tags.is_empty() {
+                bail!("update_article operation must include at least one field to update");
+            }
             let mut result = ApplyOperationResult::success(
                 index,
                 operation.op_name(),
                 if context.dry_run {
                     "dry_run"
                 } else {
-                    "commented"
+                    "updated"
                 },
             );
-            result.issue = Some(issue_id.clone());
+            result.article = Some(article_id.clone());
             if !context.dry_run {
+                let update = UpdateArticle {
+                    summary: summary.clone(),
+                    content: content.clone(),
+                    tags: tags.clone(),
+                };
                 context
-                    .client
-                    .add_comment(&issue_id, body)
-                    .with_context(|| format!("Failed to comment on '{}'", issue_id))?;
-            }
-            Ok(result)
-        }
-        ApplyOperation::Link {
-            source,
-            target,
-            link_type,
-        } => {
-            let source_id = resolve_issue_ref(source, refs)?;
-            let target_id = resolve_issue_ref(target, refs)?;
-            let mut result = ApplyOperationResult::success(
-                index,
-                operation.op_name(),
-                if context.dry_run { "dry_run" } else { "linked" },
-            );
-            result.issue = Some(source_id.clone());
-            if !context.dry_run {
-                issue::link_issues_with_type(context.client, &source_id, &target_id, link_type)?;
+                    .kb_client
+                    .update_article(&article_id, &update)
+                    .with_context(|| format!("Failed to update article '{}'", article_id))?;
             }
             Ok(result)
         }
-        ApplyOperation::DeleteIssue { issue } => {
-            let issue_id = resolve_issue_ref(issue, refs)?;
+        ApplyOperation::DeleteArticle { article } => {
+            let article_id = resolve_issue_ref(article, refs)?;
             let mut result = ApplyOperationResult::success(
                 index,
                 operation.op_name(),
@@ -516,16 +1633,154 @@ fn execute_operation(
                     "deleted"
                 },
             );
-            result.issue = Some(issue_id.clone());
+            result.article = Some(article_id.clone());
+            if !context.dry_run {
+                context
+                    .kb_client
+                    .delete_article(&article_id)
+                    .with_context(|| format!("Failed to delete article '{}'", article_id))?;
+            }
+            Ok(result)
+        }
+        ApplyOperation::LogWork {
+            issue,
+            duration,
+            text,
+            date,
+        } => {
+            let issue_id = resolve_issue_ref(issue, refs)?;
+            let minutes = parse_work_minutes(duration)?;
+            let work = LogWork {
+                minutes,
+                text: text.clone(),
+                date: *date,
+            };
+            let result = issue_result(context, index, operation, "logged", &issue_id);
             if !context.dry_run {
                 context
                     .client
-                    .delete_issue(&issue_id)
-                    .with_context(|| format!("Failed to delete '{}'", issue_id))?;
+                    .log_work(&issue_id, &work)
+                    .with_context(|| format!("Failed to log work on '{}'", issue_id))?;
             }
             Ok(result)
         }
+        ApplyOperation::Foreach { .. } => bail!("foreach operations cannot be nested"),
+    }
+}
+
+/// Result for an operation on a single existing issue.
+fn issue_result(
+    context: OperationContext<'_>,
+    index: usize,
+    operation: &ApplyOperation,
+    status: &str,
+    issue_id: &str,
+) -> ApplyOperationResult {
+    let status = if context.dry_run { "dry_run" } else { status };
+    let mut result = ApplyOperationResult::success(index, operation.op_name(), status);
+    result.issue = Some(issue_id.to_string());
+    result
+}
+
+fn require_value<'v>(name: &str, value: &'v str) -> Result<&'v str> {
+    let value = value.trim();
+    if value.is_empty() {
+        bail!("'{}' must not be empty", name);
+    }
+    Ok(value)
+}
+
+/// Parse a `log_work` duration into whole minutes.
+fn parse_work_minutes(duration: &str) -> Result<u64> {
+    let parsed = cache::parse_duration(duration)
+        .with_context(|| format!("Invalid duration '{}'", duration))?;
+    if parsed.num_seconds() % 60 != 0 {
+        bail!("duration '{}' must be a whole number of minutes", duration);
+    }
+    match u64::try_from(parsed.num_minutes()) {
+        Ok(minutes) if minutes > 0 => Ok(minutes),
+        _ => bail!("duration '{}' must be greater than zero", duration),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn execute_create_article(
+    context: OperationContext<'_>,
+    index: usize,
+    refs: &mut BTreeMap<String, String>,
+    ref_name: Option<&str>,
+    project: Option<&str>,
+    summary: &str,
+    content: Option<&str>,
+    parent: Option<&str>,
+    tags: &[String],
+) -> Result<ApplyOperationResult> {
+    let project_input = project
+        .or(context.plan.defaults.project.as_deref())
+        .or(context.default_project)
+        .ok_or_else(|| {
+            anyhow!(
+                "Project is required for create_article. Set operation.project, defaults.project, or config default_project."
+            )
+        })?;
+    // Confluence space IDs are numeric and used as-is; other backends take a
+    // project shortName that the issue tracker resolves (as in `track article create`).
+    let project_id = if project_input.chars().all(|c| c.is_ascii_digit()) {
+        project_input.to_string()
+    } else {
+        context
+            .client
+            .resolve_project_id(project_input)
+            .with_context(|| format!("Failed to resolve project '{}'", project_input))?
+    };
+    let parent = parent
+        .map(|parent| resolve_issue_ref(parent, refs))
+        .transpose()?;
+
+    if context.dry_run {
+        let article_id = ref_name.map(planned_ref_value);
+        if let (Some(ref_name), Some(article_id)) = (ref_name, article_id.as_ref()) {
+            refs.insert(ref_name.to_string(), article_id.clone());
+        }
+        let mut result = ApplyOperationResult::success(index, "create_article", "dry_run");
+        result.article = article_id;
+        result.ref_name = ref_name.map(String::from);
+        return Ok(result);
+    }
+
+    let parent_article_id = parent
+        .map(|parent| {
+            context
+                .kb_client
+                .get_article(&parent)
+                .map(|article| article.id)
+                .with_context(|| format!("Failed to resolve parent article '{}'", parent))
+        })
+        .transpose()?;
+    let create = CreateArticle {
+        project_id,
+        summary: summary.to_string(),
+        content: content.map(String::from),
+        parent_article_id,
+        tags: tags.to_vec(),
+    };
+    let article = context
+        .kb_client
+        .create_article(&create)
+        .context("Failed to create article")?;
+    let article_id = if article.id_readable.is_empty() {
+        article.id
+    } else {
+        article.id_readable
+    };
+    if let Some(ref_name) = ref_name {
+        refs.insert(ref_name.to_string(), article_id.clone());
     }
+
+    let mut result = ApplyOperationResult::success(index, "create_article", "created");
+    result.article = Some(article_id);
+    result.ref_name = ref_name.map(String::from);
+    Ok(result)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -830,7 +2085,18 @@ fn load_schema_for_detection(
 }
 
 fn parse_apply_plan(raw_plan: &[u8]) -> Result<ApplyPlan> {
-    let plan: ApplyPlan = serde_json::from_slice(raw_plan).context("Invalid JSON apply plan")?;
+    let mut value: serde_json::Value =
+        serde_json::from_slice(raw_plan).context("Invalid JSON apply plan")?;
+    let vars = plan_vars(&value)?;
+    if let Some(object) = value.as_object_mut() {
+        let resolve = |namespace: &str, name: &str| static_placeholder(namespace, name, &vars);
+        for section in ["defaults", "operations"] {
+            if let Some(section) = object.get_mut(section) {
+                interpolate_value(section, &resolve, false)?;
+            }
+        }
+    }
+    let plan: ApplyPlan = serde_json::from_value(value).context("Invalid JSON apply plan")?;
     if plan.version != 1 {
         bail!(
             "Unsupported apply plan version {}. Expected version 1.",
@@ -840,6 +2106,68 @@ fn parse_apply_plan(raw_plan: &[u8]) -> Result<ApplyPlan> {
     Ok(plan)
 }
 
+/// The plan's top-level `vars`, with `${env.*}` in their values filled in.
+fn plan_vars(plan: &serde_json::Value) -> Result<BTreeMap<String, String>> {
+    let Some(vars) = plan.get("vars") else {
+        return Ok(BTreeMap::new());
+    };
+    let vars = vars
+        .as_object()
+        .ok_or_else(|| anyhow!("Plan 'vars' must be an object"))?;
+    let env_only = |namespace: &str, name: &str| match namespace {
+        "env" => env_placeholder(name),
+        _ => Ok(None),
+    };
+
+    vars.iter()
+        .map(|(name, value)| {
+            let text = match value {
+                serde_json::Value::String(text) => text.clone(),
+                serde_json::Value::Number(number) => number.to_string(),
+                serde_json::Value::Bool(flag) => flag.to_string(),
+                _ => bail!(
+                    "Plan variable '{}' must be a string, number, or boolean",
+                    name
+                ),
+            };
+            let text = super::apply_template::interpolate_str(&text, &env_only, false)?;
+            Ok((name.clone(), text))
+        })
+        .collect()
+}
+
+/// First interpolation pass: `vars` and `env` are known when the plan is parsed.
+fn static_placeholder(
+    namespace: &str,
+    name: &str,
+    vars: &BTreeMap<String, String>,
+) -> Result<Option<String>> {
+    match namespace {
+        "vars" => vars
+            .get(name)
+            .cloned()
+            .map(Some)
+            .ok_or_else(|| anyhow!("Unknown plan variable '{}'", name)),
+        "env" => env_placeholder(name),
+        _ => Ok(None),
+    }
+}
+
+/// Plans can only read `TRACK_VAR_*` variables, so a plan cannot pull the
+/// tracker tokens (or any other secret in the environment) into a comment.
+fn env_placeholder(name: &str) -> Result<Option<String>> {
+    if !name.starts_with(ENV_VAR_PREFIX) {
+        bail!(
+            "Environment variable '{}' cannot be used in a plan; only {}* variables are readable",
+            name,
+            ENV_VAR_PREFIX
+        );
+    }
+    std::env::var(name)
+        .map(Some)
+        .map_err(|_| anyhow!("Environment variable '{}' is not set", name))
+}
+
 fn read_plan_bytes(path: &Path) -> Result<Vec<u8>> {
     if path.as_os_str() == OsStr::new("-") {
         let mut bytes = Vec::new();
@@ -865,14 +2193,73 @@ fn resolve_issue_ref(value: &str, refs: &BTreeMap<String, String>) -> Result<Str
 }
 
 fn local_ref_name(value: &str) -> Option<&str> {
-    value.strip_prefix('$').filter(|name| !name.is_empty())
+    // `${...}` is a placeholder, not a `$name` ref
+    value
+        .strip_prefix('$')
+        .filter(|name| !name.is_empty() && !name.starts_with('{'))
+}
+
+/// Local refs an operation uses, as `$name` values or `${refs.name}` placeholders.
+fn used_ref_names(planned: &PlanOperation) -> Vec<String> {
+    let mut names: Vec<String> = planned
+        .operation
+        .referenced_values()
+        .into_iter()
+        .filter_map(local_ref_name)
+        .map(String::from)
+        .collect();
+    names.extend(
+        placeholders(&planned.raw)
+            .into_iter()
+            .filter(|(namespace, _)| namespace == "refs")
+            .map(|(_, name)| name),
+    );
+    names
+}
+
+/// Structural checks for `foreach`: nested operations run once per match, so
+/// they cannot define refs or nest another `foreach`, and `${item.*}` only
+/// makes sense inside one.
+fn validate_foreach_structure(plan: &ApplyPlan) -> std::result::Result<(), PreflightFailure> {
+    for (index, planned) in plan.operations.iter().enumerate() {
+        let failure = |message: &str| PreflightFailure {
+            index,
+            op: planned.operation.op_name().to_string(),
+            message: message.to_string(),
+        };
+        match &planned.operation {
+            ApplyOperation::Foreach { operations, .. } => {
+                for nested in operations {
+                    if matches!(nested.operation, ApplyOperation::Foreach { .. }) {
+                        return Err(failure("foreach operations cannot be nested"));
+                    }
+                    if nested.operation.defined_ref().is_some() {
+                        return Err(failure("Operations inside foreach cannot define refs"));
+                    }
+                }
+            }
+            _ => {
+                if placeholders(&planned.raw)
+                    .iter()
+                    .any(|(namespace, _)| namespace == "item")
+                {
+                    return Err(failure(
+                        "'${item.*}' placeholders can only be used inside foreach operations",
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
 }
 
 fn validate_reference_order(plan: &ApplyPlan) -> std::result::Result<(), PreflightFailure> {
+    validate_foreach_structure(plan)?;
+
     let mut defined_refs = BTreeSet::new();
     let mut duplicate_refs = BTreeSet::new();
-    for operation in &plan.operations {
-        if let Some(ref_name) = operation.defined_ref()
+    for planned in &plan.operations {
+        if let Some(ref_name) = planned.operation.defined_ref()
             && !defined_refs.insert(ref_name.to_string())
         {
             duplicate_refs.insert(ref_name.to_string());
@@ -883,29 +2270,27 @@ fn validate_reference_order(plan: &ApplyPlan) -> std::result::Result<(), Preflig
         let index = plan
             .operations
             .iter()
-            .position(|op| op.defined_ref() == Some(duplicate.as_str()))
+            .position(|planned| planned.operation.defined_ref() == Some(duplicate.as_str()))
             .unwrap_or(0);
         return Err(PreflightFailure {
             index,
-            op: plan.operations[index].op_name().to_string(),
+            op: plan.operations[index].operation.op_name().to_string(),
             message: format!("Duplicate local ref '${}'", duplicate),
         });
     }
 
     let mut seen_refs = BTreeSet::new();
-    for (index, operation) in plan.operations.iter().enumerate() {
-        for value in operation.referenced_values() {
-            if let Some(ref_name) = local_ref_name(value)
-                && !seen_refs.contains(ref_name)
-            {
+    for (index, planned) in plan.operations.iter().enumerate() {
+        for ref_name in used_ref_names(planned) {
+            if !seen_refs.contains(&ref_name) {
                 return Err(PreflightFailure {
                     index,
-                    op: operation.op_name().to_string(),
+                    op: planned.operation.op_name().to_string(),
                     message: format!("Operation references '${}' before it is defined", ref_name),
                 });
             }
         }
-        if let Some(ref_name) = operation.defined_ref() {
+        if let Some(ref_name) = planned.operation.defined_ref() {
             seen_refs.insert(ref_name.to_string());
         }
     }
@@ -940,18 +2325,15 @@ fn new_resume_state(checksum: &str) -> ApplyResumeState {
         version: 1,
         plan_checksum: checksum.to_string(),
         completed: BTreeSet::new(),
+        completed_steps: BTreeSet::new(),
         refs: BTreeMap::new(),
         results: Vec::new(),
+        undo: Vec::new(),
+        rolled_back: false,
     }
 }
 
-fn write_resume_state(
-    resume_path: Option<&Path>,
-    checksum: &str,
-    completed: &BTreeSet<usize>,
-    refs: &BTreeMap<String, String>,
-    state_results: &BTreeMap<usize, ApplyOperationResult>,
-) -> Result<()> {
+fn write_resume_state(resume_path: Option<&Path>, state: &ApplyResumeState) -> Result<()> {
     let Some(path) = resume_path else {
         return Ok(());
     };
@@ -967,14 +2349,7 @@ fn write_resume_state(
         })?;
     }
 
-    let state = ApplyResumeState {
-        version: 1,
-        plan_checksum: checksum.to_string(),
-        completed: completed.clone(),
-        refs: refs.clone(),
-        results: state_results.values().cloned().collect(),
-    };
-    let json = serde_json::to_vec_pretty(&state).context("Failed to serialize resume state")?;
+    let json = serde_json::to_vec_pretty(state).context("Failed to serialize resume state")?;
     std::fs::write(path, json)
         .with_context(|| format!("Failed to write resume state '{}'", path.display()))?;
     Ok(())
@@ -995,6 +2370,8 @@ fn build_output(
         summary: summarize_results(total, &results),
         refs,
         results,
+        plan: None,
+        warnings: Vec::new(),
     }
 }
 
@@ -1055,7 +2432,15 @@ fn output_apply_text(output: &ApplyOutput) {
     println!("{} operation(s)", output.summary.total);
 
     for result in &output.results {
-        let issue = result.issue.as_deref().unwrap_or("-");
+        let issue = match result.matched {
+            Some(matched) => format!("{matched} matched"),
+            None => result
+                .issue
+                .as_deref()
+                .or(result.article.as_deref())
+                .unwrap_or("-")
+                .to_string(),
+        };
         let ref_name = result
             .ref_name
             .as_deref()
@@ -1077,6 +2462,21 @@ fn output_apply_text(output: &ApplyOutput) {
         }
     }
 
+    if let Some(operations) = output
+        .plan
+        .as_ref()
+        .and_then(|plan| plan["operations"].as_array())
+    {
+        println!("expanded operations:");
+        for operation in operations {
+            println!("  {operation}");
+        }
+    }
+
+    for warning in &output.warnings {
+        println!("warning: {warning}");
+    }
+
     if !output.refs.is_empty() {
         println!("refs:");
         for (name, value) in &output.refs {
@@ -1148,6 +2548,121 @@ mod tests {
         assert_eq!(plan.defaults.validate, Some(true));
     }
 
+    #[test]
+    fn plan_deserializes_issue_and_article_operations() {
+        let plan = parse_apply_plan(
+            br#"{
+                "version": 1,
+                "operations": [
+                    {"op": "add_tag", "issue": "DEMO-1", "tag": "triaged"},
+                    {"op": "remove_tag", "issue": "DEMO-1", "tag": "stale"},
+                    {"op": "attach", "issue": "DEMO-1", "path": "log.txt", "mime_type": "text/plain"},
+                    {"op": "unlink", "source": "DEMO-1", "link_id": "7"},
+                    {"op": "set_parent", "issue": "DEMO-1", "parent": "DEMO-2"},
+                    {"op": "remove_parent", "issue": "DEMO-1"},
+                    {"op": "transition", "issue": "DEMO-1", "state": "Done", "resolution": "Duplicate"},
+                    {"ref": "doc", "op": "create_article", "project": "DEMO", "summary": "Notes"},
+                    {"op": "update_article", "article": "$doc", "content": "Body"},
+                    {"op": "log_work", "issue": "DEMO-1", "duration": "1h", "date": "2026-05-01"}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let names: Vec<_> = plan
+            .operations
+            .iter()
+            .map(|planned| planned.operation.op_name())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "add_tag",
+                "remove_tag",
+                "attach",
+                "unlink",
+                "set_parent",
+                "remove_parent",
+                "transition",
+                "create_article",
+                "update_article",
+                "log_work"
+            ]
+        );
+        assert_eq!(plan.operations[7].operation.defined_ref(), Some("doc"));
+        assert!(validate_reference_order(&plan).is_ok());
+    }
+
+    #[test]
+    fn plan_vars_are_substituted_at_parse_time() {
+        let plan = parse_apply_plan(
+            br#"{
+                "version": 1,
+                "vars": {"team": "core", "points": 3},
+                "operations": [
+                    {"ref": "epic", "op": "create_issue", "project": "DEMO", "summary": "${vars.team} epic ($${literal})"},
+                    {"op": "comment", "issue": "DEMO-1", "body": "${vars.points} points, see ${refs.epic}"}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(plan.operations[0].raw["summary"], "core epic ($${literal})");
+        assert_eq!(plan.operations[1].raw["body"], "3 points, see ${refs.epic}");
+        assert!(validate_reference_order(&plan).is_ok());
+
+        let err = parse_apply_plan(
+            br#"{"version": 1, "operations": [{"op": "comment", "issue": "DEMO-1", "body": "${vars.nope}"}]}"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Unknown plan variable 'nope'"));
+    }
+
+    #[test]
+    fn plans_only_read_prefixed_env_vars_and_keep_other_dollar_text() {
+        let plan = parse_apply_plan(
+            br#"{"version": 1, "operations": [{"op": "comment", "issue": "DEMO-1", "body": "echo ${HOME} ${{ secrets.X }}"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            plan.operations[0].raw["body"],
+            "echo ${HOME} ${{ secrets.X }}"
+        );
+
+        let err = parse_apply_plan(
+            br#"{"version": 1, "operations": [{"op": "comment", "issue": "DEMO-1", "body": "${env.PATH}"}]}"#,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("only TRACK_VAR_* variables"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn foreach_structure_validation_rejects_misplaced_operations() {
+        let cases: [&[u8]; 4] = [
+            br#"{"version": 1, "operations": [{"op": "foreach", "query": "q", "operations": [{"op": "foreach", "query": "q", "operations": []}]}]}"#,
+            br#"{"version": 1, "operations": [{"op": "foreach", "query": "q", "operations": [{"ref": "x", "op": "create_issue", "project": "DEMO", "summary": "s"}]}]}"#,
+            br#"{"version": 1, "operations": [{"op": "comment", "issue": "${item.id}", "body": "hi"}]}"#,
+            br#"{"version": 1, "operations": [{"op": "comment", "issue": "DEMO-1", "body": "${refs.later}"}, {"ref": "later", "op": "create_issue", "project": "DEMO", "summary": "s"}]}"#,
+        ];
+
+        for case in cases {
+            let plan = parse_apply_plan(case).unwrap();
+            assert!(validate_reference_order(&plan).is_err());
+        }
+    }
+
+    #[test]
+    fn work_duration_converts_to_whole_minutes() {
+        assert_eq!(parse_work_minutes("90m").unwrap(), 90);
+        assert_eq!(parse_work_minutes("2h").unwrap(), 120);
+        assert!(parse_work_minutes("0m").is_err());
+        assert!(parse_work_minutes("30s").is_err());
+        assert!(parse_work_minutes("soon").is_err());
+    }
+
     #[test]
     fn field_object_conversion_preserves_string_and_array_values() {
         let mut fields = BTreeMap::new();
@@ -1234,6 +2749,50 @@ mod tests {
         assert!(failure.message.contains("before it is defined"));
     }
 
+    #[test]
+    fn dependencies_follow_refs_shared_issues_and_foreach() {
+        let plan = parse_apply_plan(
+            br#"{
+                "version": 1,
+                "operations": [
+                    {"ref": "a", "op": "create_issue", "project": "DEMO", "summary": "A"},
+                    {"ref": "b", "op": "create_issue", "project": "DEMO", "summary": "B"},
+                    {"op": "link", "source": "$a", "target": "DEMO-1", "type": "relates"},
+                    {"op": "comment", "issue": "DEMO-2", "body": "see ${refs.b}"},
+                    {"op": "add_tag", "issue": "DEMO-1", "tag": "x"},
+                    {"op": "foreach", "query": "tag: x", "operations": [
+                        {"op": "comment", "issue": "${item.id}", "body": "tagged"}
+                    ]},
+                    {"op": "create_issue", "project": "DEMO", "summary": "C"},
+                    {"op": "create_issue", "project": "DEMO", "summary": "D",
+                     "dedupe": {"query": "summary: D", "on_match": "reuse"}},
+                    {"op": "create_issue", "project": "DEMO", "summary": "E",
+                     "dedupe": {"query": "summary: E", "on_match": "reuse"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let dependencies: Vec<Vec<usize>> = operation_dependencies(&plan)
+            .into_iter()
+            .map(|before| before.into_iter().collect())
+            .collect();
+        assert_eq!(
+            dependencies,
+            vec![
+                vec![],
+                vec![],
+                vec![0],
+                vec![1],
+                vec![2],
+                vec![0, 1, 2, 3, 4],
+                vec![5],
+                vec![0, 1, 5, 6],
+                vec![5, 7]
+            ]
+        );
+    }
+
     #[test]
     fn resume_checksum_mismatch_is_rejected() {
         let dir =
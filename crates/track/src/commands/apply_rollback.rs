@@ -0,0 +1,560 @@
+//! Before-images and inverse plans for `track apply --rollback`.
+//!
+//! While a plan runs with `--resume`, every mutating operation records an
+//! [`UndoEntry`] in the state file: what it created, or the parts of an issue
+//! or article it was about to change. Rollback folds those entries down to the
+//! earliest recorded value per issue and aspect, then emits an ordinary apply
+//! plan that reverses them, so the undo runs through the same engine (and the
+//! same dry-run and `--allow-delete` rules) as the original plan.
+
+use crate::commands::apply_template::escape_placeholders;
+use crate::output::find_state_value;
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, BTreeSet};
+use tracker_core::{CustomField, Issue, IssueLink, IssueTracker, KnowledgeBase};
+
+/// One recorded change, tagged with the operation (and `foreach` step) that
+/// made it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct UndoEntry {
+    pub(crate) index: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) item: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) step: Option<usize>,
+    #[serde(flatten)]
+    pub(crate) record: UndoRecord,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "undo", rename_all = "snake_case")]
+pub(crate) enum UndoRecord {
+    CreatedIssue {
+        issue: String,
+    },
+    CreatedArticle {
+        article: String,
+    },
+    RestoreIssue {
+        issue: String,
+        before: IssueBeforeImage,
+    },
+    RestoreArticle {
+        article: String,
+        summary: String,
+        content: String,
+    },
+    Linked {
+        source: String,
+        target: String,
+        link_type: String,
+    },
+    /// Comments, attachments, removed links, work items and deletions have no
+    /// inverse the trackers expose; rollback reports them instead.
+    Irreversible {
+        op: String,
+        target: String,
+    },
+}
+
+/// Values an issue had before an operation changed them. Only the aspects
+/// the operation touched are recorded.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct IssueBeforeImage {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+    /// Field name to a string, an array, or `[]` when the field was unset
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    fields: BTreeMap<String, Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+    /// Parent issue ID, or empty when the issue had no parent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    parent: Option<String>,
+}
+
+impl IssueBeforeImage {
+    /// Keep the values already recorded and take the rest from `later`.
+    fn merge(&mut self, later: IssueBeforeImage) {
+        self.summary = self.summary.take().or(later.summary);
+        self.description = self.description.take().or(later.description);
+        self.state = self.state.take().or(later.state);
+        self.tags = self.tags.take().or(later.tags);
+        self.parent = self.parent.take().or(later.parent);
+        for (name, value) in later.fields {
+            self.fields.entry(name).or_insert(value);
+        }
+    }
+}
+
+/// Which parts of an issue an operation is about to change.
+#[derive(Debug, Default)]
+pub(crate) struct IssueAspects {
+    pub(crate) summary: bool,
+    pub(crate) description: bool,
+    pub(crate) state: bool,
+    pub(crate) fields: Vec<String>,
+    pub(crate) tags: bool,
+    pub(crate) parent: bool,
+}
+
+/// Fetch the current values of `aspects` so they can be restored later.
+pub(crate) fn capture_issue(
+    client: &dyn IssueTracker,
+    issue_id: &str,
+    aspects: &IssueAspects,
+) -> Result<UndoRecord> {
+    let issue = client
+        .get_issue(issue_id)
+        .with_context(|| format!("Failed to fetch issue '{}'", issue_id))?;
+
+    let mut before = IssueBeforeImage::default();
+    if aspects.summary {
+        before.summary = Some(issue.summary.clone());
+    }
+    if aspects.description {
+        before.description = Some(issue.description.clone().unwrap_or_default());
+    }
+    if aspects.state {
+        before.state = find_state_value(&issue).map(String::from);
+    }
+    for name in &aspects.fields {
+        let field = issue
+            .custom_fields
+            .iter()
+            .find(|field| field_name(field).eq_ignore_ascii_case(name));
+        match field {
+            // State set through `fields` is restored by transition like `state`.
+            Some(CustomField::State { value, .. }) => before.state = value.clone(),
+            Some(field) => {
+                if let Some(value) = field_value(field) {
+                    before.fields.insert(name.clone(), value);
+                }
+            }
+            None => {
+                before.fields.insert(name.clone(), json!([]));
+            }
+        }
+    }
+    if aspects.tags {
+        before.tags = Some(tag_names(&issue));
+    }
+    if aspects.parent {
+        before.parent = Some(current_parent(client, issue_id)?.unwrap_or_default());
+    }
+
+    Ok(UndoRecord::RestoreIssue {
+        issue: issue_id.to_string(),
+        before,
+    })
+}
+
+pub(crate) fn capture_article(
+    kb_client: &dyn KnowledgeBase,
+    article_id: &str,
+) -> Result<UndoRecord> {
+    let article = kb_client
+        .get_article(article_id)
+        .with_context(|| format!("Failed to fetch article '{}'", article_id))?;
+    Ok(UndoRecord::RestoreArticle {
+        article: article_id.to_string(),
+        summary: article.summary,
+        content: article.content.unwrap_or_default(),
+    })
+}
+
+/// Inverse operations for a recorded run, plus notes on what cannot be undone.
+#[derive(Debug, Default)]
+pub(crate) struct RollbackPlan {
+    pub(crate) operations: Vec<Value>,
+    pub(crate) warnings: Vec<String>,
+}
+
+/// Build the inverse plan for `entries` (in the order they were recorded).
+///
+/// Links are removed first, then issues and articles are restored to their
+/// earliest recorded values, then created articles and issues are deleted
+/// (with `allow_delete`) or closed, newest first.
+pub(crate) fn build_rollback_plan(
+    client: &dyn IssueTracker,
+    entries: &[UndoEntry],
+    allow_delete: bool,
+) -> Result<RollbackPlan> {
+    let mut created_issues = Vec::new();
+    let mut created_articles = Vec::new();
+    let mut links = Vec::new();
+    let mut issues: BTreeMap<String, IssueBeforeImage> = BTreeMap::new();
+    let mut articles: BTreeMap<String, (String, String)> = BTreeMap::new();
+    let mut rollback = RollbackPlan::default();
+
+    for entry in entries {
+        match &entry.record {
+            UndoRecord::CreatedIssue { issue } => created_issues.push(issue.clone()),
+            UndoRecord::CreatedArticle { article } => created_articles.push(article.clone()),
+            UndoRecord::RestoreIssue { issue, before } => match issues.get_mut(issue) {
+                Some(existing) => existing.merge(before.clone()),
+                None => {
+                    issues.insert(issue.clone(), before.clone());
+                }
+            },
+            UndoRecord::RestoreArticle {
+                article,
+                summary,
+                content,
+            } => {
+                articles
+                    .entry(article.clone())
+                    .or_insert_with(|| (summary.clone(), content.clone()));
+            }
+            UndoRecord::Linked {
+                source,
+                target,
+                link_type,
+            } => links.push((source.clone(), target.clone(), link_type.clone())),
+            UndoRecord::Irreversible { op, target } => rollback.warnings.push(format!(
+                "{} on {} (operation {}) cannot be rolled back",
+                op, target, entry.index
+            )),
+        }
+    }
+
+    let created: BTreeSet<&str> = created_issues.iter().map(String::as_str).collect();
+    let deleted = |issue: &str| allow_delete && created.contains(issue);
+
+    for (source, target, link_type) in links.iter().rev() {
+        if deleted(source) || deleted(target) {
+            continue;
+        }
+        match find_link_id(client, source, target, link_type)? {
+            Some(link_id) => rollback.operations.push(json!({
+                "op": "unlink",
+                "source": source,
+                "link_id": link_id
+            })),
+            None => rollback.warnings.push(format!(
+                "{} link from {} to {} no longer exists",
+                link_type, source, target
+            )),
+        }
+    }
+
+    for (issue, before) in &issues {
+        if created.contains(issue.as_str()) {
+            continue;
+        }
+        rollback
+            .operations
+            .extend(restore_issue_operations(client, issue, before)?);
+    }
+
+    for (article, (summary, content)) in &articles {
+        if allow_delete && created_articles.contains(article) {
+            continue;
+        }
+        rollback.operations.push(json!({
+            "op": "update_article",
+            "article": article,
+            "summary": summary,
+            "content": content
+        }));
+    }
+
+    for article in created_articles.iter().rev() {
+        if allow_delete {
+            rollback
+                .operations
+                .push(json!({"op": "delete_article", "article": article}));
+        } else {
+            rollback.warnings.push(format!(
+                "Article {} was created by the plan and is kept; pass --allow-delete to delete it",
+                article
+            ));
+        }
+    }
+
+    for issue in created_issues.iter().rev() {
+        if allow_delete {
+            rollback
+                .operations
+                .push(json!({"op": "delete_issue", "issue": issue}));
+        } else if let Some(operation) = close_operation(client, issue)? {
+            rollback.operations.push(operation);
+        }
+    }
+
+    for operation in &mut rollback.operations {
+        escape_placeholders(operation);
+    }
+    Ok(rollback)
+}
+
+fn restore_issue_operations(
+    client: &dyn IssueTracker,
+    issue_id: &str,
+    before: &IssueBeforeImage,
+) -> Result<Vec<Value>> {
+    let mut operations = Vec::new();
+
+    let mut update = serde_json::Map::new();
+    if let Some(summary) = &before.summary {
+        update.insert("summary".to_string(), json!(summary));
+    }
+    if let Some(description) = &before.description {
+        update.insert("description".to_string(), json!(description));
+    }
+    if !before.fields.is_empty() {
+        update.insert("fields".to_string(), json!(before.fields));
+    }
+    if !update.is_empty() {
+        update.insert("op".to_string(), json!("update_issue"));
+        update.insert("issue".to_string(), json!(issue_id));
+        operations.push(Value::Object(update));
+    }
+
+    if let Some(state) = &before.state {
+        operations.push(json!({"op": "transition", "issue": issue_id, "state": state}));
+    }
+
+    if let Some(tags) = &before.tags {
+        let current = client
+            .get_issue(issue_id)
+            .with_context(|| format!("Failed to fetch issue '{}'", issue_id))?;
+        let current = tag_names(&current);
+        for tag in current.iter().filter(|tag| !tags.contains(tag)) {
+            operations.push(json!({"op": "remove_tag", "issue": issue_id, "tag": tag}));
+        }
+        for tag in tags.iter().filter(|tag| !current.contains(tag)) {
+            operations.push(json!({"op": "add_tag", "issue": issue_id, "tag": tag}));
+        }
+    }
+
+    if let Some(parent) = &before.parent {
+        let current = current_parent(client, issue_id)?.unwrap_or_default();
+        if current != *parent {
+            if parent.is_empty() {
+                operations.push(json!({"op": "remove_parent", "issue": issue_id}));
+            } else {
+                operations.push(json!({"op": "set_parent", "issue": issue_id, "parent": parent}));
+            }
+        }
+    }
+
+    Ok(operations)
+}
+
+/// Close a created issue by moving it to the first resolved state of its
+/// project. Returns `None` if it is already resolved.
+fn close_operation(client: &dyn IssueTracker, issue_id: &str) -> Result<Option<Value>> {
+    let issue = client
+        .get_issue(issue_id)
+        .with_context(|| format!("Failed to fetch issue '{}'", issue_id))?;
+    let resolved = issue.custom_fields.iter().any(|field| {
+        matches!(
+            field,
+            CustomField::State {
+                is_resolved: true,
+                ..
+            }
+        )
+    });
+    if resolved {
+        return Ok(None);
+    }
+
+    let fields = client
+        .get_project_custom_fields(&issue.project.id)
+        .with_context(|| format!("Failed to load fields for project '{}'", issue.project.id))?;
+    let state = fields
+        .iter()
+        .flat_map(|field| &field.state_values)
+        .find(|value| value.is_resolved)
+        .map(|value| value.name.clone())
+        .ok_or_else(|| {
+            anyhow!(
+                "No resolved state found to close '{}'; pass --allow-delete to delete it instead",
+                issue_id
+            )
+        })?;
+    Ok(Some(
+        json!({"op": "transition", "issue": issue_id, "state": state}),
+    ))
+}
+
+/// The issue's parent, taken from its inward subtask/parent link.
+fn current_parent(client: &dyn IssueTracker, issue_id: &str) -> Result<Option<String>> {
+    let links = client
+        .get_issue_links(issue_id)
+        .with_context(|| format!("Failed to fetch links for '{}'", issue_id))?;
+    Ok(parent_from_links(&links))
+}
+
+/// The parent named by an issue's inward subtask/parent link, if any.
+pub(crate) fn parent_from_links(links: &[IssueLink]) -> Option<String> {
+    links
+        .iter()
+        .filter(|link| is_parent_link(link))
+        .find_map(|link| link.issues.first())
+        .map(|linked| {
+            linked
+                .id_readable
+                .clone()
+                .unwrap_or_else(|| linked.id.clone())
+        })
+}
+
+fn is_parent_link(link: &IssueLink) -> bool {
+    let inward = link
+        .direction
+        .as_deref()
+        .is_some_and(|direction| direction.eq_ignore_ascii_case("inward"));
+    let name = link.link_type.name.to_lowercase();
+    inward && (name.contains("subtask") || name.contains("parent"))
+}
+
+/// Find the ID of the link `link` created between `source` and `target`,
+/// preferring one whose type matches the plan's `type`.
+fn find_link_id(
+    client: &dyn IssueTracker,
+    source: &str,
+    target: &str,
+    link_type: &str,
+) -> Result<Option<String>> {
+    let links = client
+        .get_issue_links(source)
+        .with_context(|| format!("Failed to fetch links for '{}'", source))?;
+    Ok(find_link(&links, target, link_type).map(|link| link.id.clone()))
+}
+
+/// The link to `target` among `links`, preferring one whose type matches an
+/// apply-plan link `type` such as `"relates"` or `"depends"`.
+pub(crate) fn find_link<'l>(
+    links: &'l [IssueLink],
+    target: &str,
+    link_type: &str,
+) -> Option<&'l IssueLink> {
+    let to_target: Vec<&IssueLink> = links
+        .iter()
+        .filter(|link| {
+            link.issues
+                .iter()
+                .any(|linked| linked.id == target || linked.id_readable.as_deref() == Some(target))
+        })
+        .collect();
+    let wanted = link_type.to_lowercase();
+    let matching = to_target.iter().find(|link| {
+        let link_type = &link.link_type;
+        [
+            Some(&link_type.name),
+            link_type.source_to_target.as_ref(),
+            link_type.target_to_source.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|name| name.to_lowercase().contains(&wanted))
+    });
+    matching.or(to_target.first()).copied()
+}
+
+fn field_name(field: &CustomField) -> &str {
+    match field {
+        CustomField::SingleEnum { name, .. }
+        | CustomField::State { name, .. }
+        | CustomField::SingleUser { name, .. }
+        | CustomField::Text { name, .. }
+        | CustomField::MultiEnum { name, .. }
+        | CustomField::Unknown { name, .. } => name,
+    }
+}
+
+/// A field's value in apply-plan `fields` form; `None` for fields the plan
+/// format cannot write back.
+fn field_value(field: &CustomField) -> Option<Value> {
+    match field {
+        CustomField::SingleEnum { value, .. }
+        | CustomField::Text { value, .. }
+        | CustomField::State { value, .. } => Some(
+            value
+                .as_ref()
+                .map_or_else(|| json!([]), |value| json!(value)),
+        ),
+        CustomField::SingleUser { login, .. } => Some(
+            login
+                .as_ref()
+                .map_or_else(|| json!([]), |login| json!(login)),
+        ),
+        CustomField::MultiEnum { values, .. } => Some(json!(values)),
+        CustomField::Unknown { .. } => None,
+    }
+}
+
+fn tag_names(issue: &Issue) -> Vec<String> {
+    issue.tags.iter().map(|tag| tag.name.clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn restore(issue: &str, before: Value) -> UndoEntry {
+        UndoEntry {
+            index: 0,
+            item: None,
+            step: None,
+            record: UndoRecord::RestoreIssue {
+                issue: issue.to_string(),
+                before: serde_json::from_value(before).unwrap(),
+            },
+        }
+    }
+
+    #[test]
+    fn undo_entries_round_trip_through_state_json() {
+        let entry = restore("DEMO-1", json!({"summary": "Old", "parent": ""}));
+        let value = serde_json::to_value(&entry).unwrap();
+        assert_eq!(value["undo"], "restore_issue");
+        assert_eq!(value["before"]["summary"], "Old");
+        assert!(value.get("item").is_none());
+
+        let parsed: UndoEntry = serde_json::from_value(value).unwrap();
+        assert!(matches!(
+            parsed.record,
+            UndoRecord::RestoreIssue { ref issue, ref before }
+                if issue == "DEMO-1" && before.parent.as_deref() == Some("")
+        ));
+    }
+
+    #[test]
+    fn merge_keeps_the_earliest_value_per_aspect() {
+        let mut first: IssueBeforeImage = serde_json::from_value(json!({
+            "summary": "Original",
+            "fields": {"Priority": "Minor"}
+        }))
+        .unwrap();
+        let later: IssueBeforeImage = serde_json::from_value(json!({
+            "summary": "Intermediate",
+            "state": "Open",
+            "fields": {"Priority": "Major", "Type": "Bug"}
+        }))
+        .unwrap();
+
+        first.merge(later);
+
+        assert_eq!(first.summary.as_deref(), Some("Original"));
+        assert_eq!(first.state.as_deref(), Some("Open"));
+        assert_eq!(first.fields["Priority"], "Minor");
+        assert_eq!(first.fields["Type"], "Bug");
+    }
+
+    #[test]
+    fn restored_text_is_escaped_for_interpolation() {
+        let mut operation = json!({"op": "update_issue", "summary": "Costs ${vars.x}"});
+        escape_placeholders(&mut operation);
+        assert_eq!(operation["summary"], "Costs $${vars.x}");
+    }
+}
@@ -0,0 +1,209 @@
+//! `${namespace.name}` interpolation for apply plans.
+//!
+//! Plans are interpolated in passes: plan variables and environment values
+//! are substituted when the plan is parsed, `foreach` items when an operation
+//! is expanded, and local refs right before an operation runs. A pass leaves
+//! placeholders it does not know about in place for a later one; the final
+//! pass rejects anything left over and turns `$${` escapes into a literal `${`.
+//!
+//! Only `${vars|env|refs|item.name}` is a placeholder: other `${` text (shell
+//! `${HOME}`, GitHub Actions `${{ ... }}`, an unclosed `${`) is kept as-is.
+
+use anyhow::{Result, bail};
+use serde_json::Value;
+
+/// Namespaces that make `${namespace.name}` a placeholder.
+const NAMESPACES: [&str; 4] = ["vars", "env", "refs", "item"];
+
+/// Resolves one placeholder. `Ok(None)` defers it to a later pass.
+pub(crate) type Resolver<'a> = dyn Fn(&str, &str) -> Result<Option<String>> + 'a;
+
+/// Interpolate every string inside `value` (object keys are left alone).
+pub(crate) fn interpolate_value(
+    value: &mut Value,
+    resolve: &Resolver<'_>,
+    last: bool,
+) -> Result<()> {
+    match value {
+        Value::String(text) => {
+            *text = interpolate_str(text, resolve, last)?;
+        }
+        Value::Array(items) => {
+            for item in items {
+                interpolate_value(item, resolve, last)?;
+            }
+        }
+        Value::Object(map) => {
+            for item in map.values_mut() {
+                interpolate_value(item, resolve, last)?;
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+    Ok(())
+}
+
+/// Interpolate one string. With `last` set, unresolved placeholders are an
+/// error and escapes are unescaped; otherwise both are kept verbatim.
+pub(crate) fn interpolate_str(input: &str, resolve: &Resolver<'_>, last: bool) -> Result<String> {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find('$') {
+        output.push_str(&rest[..start]);
+        let tail = &rest[start..];
+
+        if let Some(after) = tail.strip_prefix("$${") {
+            output.push_str(if last { "${" } else { "$${" });
+            rest = after;
+            continue;
+        }
+        let Some((placeholder, namespace, name)) = split_placeholder(tail) else {
+            output.push('$');
+            rest = &tail[1..];
+            continue;
+        };
+        match resolve(namespace, name)? {
+            Some(value) => output.push_str(&value),
+            None if last => bail!("Unresolved placeholder '{}'", placeholder),
+            None => output.push_str(placeholder),
+        }
+        rest = &tail[placeholder.len()..];
+    }
+
+    output.push_str(rest);
+    Ok(output)
+}
+
+/// Every `(namespace, name)` placeholder in `value`, in document order.
+pub(crate) fn placeholders(value: &Value) -> Vec<(String, String)> {
+    let mut found = Vec::new();
+    collect_placeholders(value, &mut found);
+    found
+}
+
+fn collect_placeholders(value: &Value, found: &mut Vec<(String, String)>) {
+    match value {
+        Value::String(text) => {
+            let names = std::cell::RefCell::new(Vec::new());
+            let collect = |namespace: &str, name: &str| {
+                names
+                    .borrow_mut()
+                    .push((namespace.to_string(), name.to_string()));
+                Ok(None)
+            };
+            // Only gathering names here; unknown names are reported by the
+            // interpolation passes themselves.
+            let _ = interpolate_str(text, &collect, false);
+            found.extend(names.into_inner());
+        }
+        Value::Array(items) => items
+            .iter()
+            .for_each(|item| collect_placeholders(item, found)),
+        Value::Object(map) => map
+            .values()
+            .for_each(|item| collect_placeholders(item, found)),
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+/// The `${namespace.name}` placeholder `text` starts with, if any.
+fn split_placeholder(text: &str) -> Option<(&str, &str, &str)> {
+    let end = text.strip_prefix("${")?.find('}')? + 2;
+    let (namespace, name) = text[2..end].split_once('.')?;
+    let valid_name = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    (NAMESPACES.contains(&namespace) && valid_name).then_some((&text[..=end], namespace, name))
+}
+
+/// Escape every `${` inside `value` as `$${`, so text generated from tracker
+/// or user data reaches the tracker verbatim instead of being interpolated.
+pub(crate) fn escape_placeholders(value: &mut Value) {
+    match value {
+        Value::String(text) => {
+            if text.contains("${") {
+                *text = text.replace("${", "$${");
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(escape_placeholders),
+        Value::Object(map) => map.values_mut().for_each(escape_placeholders),
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn vars_only(namespace: &str, name: &str) -> Result<Option<String>> {
+        match namespace {
+            "vars" if name == "team" => Ok(Some("core".to_string())),
+            "vars" => bail!("Unknown plan variable '{}'", name),
+            _ => Ok(None),
+        }
+    }
+
+    #[test]
+    fn substitutes_known_and_defers_unknown_namespaces() {
+        let text = interpolate_str("${vars.team}: see ${refs.epic}", &vars_only, false).unwrap();
+        assert_eq!(text, "core: see ${refs.epic}");
+
+        let err = interpolate_str("see ${refs.epic}", &vars_only, true).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Unresolved placeholder '${refs.epic}'")
+        );
+    }
+
+    #[test]
+    fn escapes_survive_until_the_last_pass() {
+        let first = interpolate_str("cost $5, literal $${vars.team}", &vars_only, false).unwrap();
+        assert_eq!(first, "cost $5, literal $${vars.team}");
+        let last = interpolate_str(&first, &vars_only, true).unwrap();
+        assert_eq!(last, "cost $5, literal ${vars.team}");
+    }
+
+    #[test]
+    fn keeps_text_that_is_not_a_placeholder() {
+        for text in [
+            "echo ${HOME}",
+            "token: ${{ secrets.X }}",
+            "${vars.team",
+            "${other.name}",
+            "${vars.a b}",
+        ] {
+            assert_eq!(interpolate_str(text, &vars_only, true).unwrap(), text);
+        }
+        assert!(interpolate_str("${vars.missing}", &vars_only, false).is_err());
+    }
+
+    #[test]
+    fn escaped_values_come_out_verbatim() {
+        let mut value = json!({"body": "${vars.team} ${env.SECRET} $${x}", "n": 1});
+        escape_placeholders(&mut value);
+        interpolate_value(&mut value, &vars_only, true).unwrap();
+        assert_eq!(value["body"], "${vars.team} ${env.SECRET} $${x}");
+    }
+
+    #[test]
+    fn interpolates_nested_values_and_lists_placeholders() {
+        let mut value = json!({
+            "issue": "$epic",
+            "tags": ["${vars.team}", "${item.id}"],
+            "count": 3
+        });
+        assert_eq!(
+            placeholders(&value),
+            vec![
+                ("vars".to_string(), "team".to_string()),
+                ("item".to_string(), "id".to_string())
+            ]
+        );
+        interpolate_value(&mut value, &vars_only, false).unwrap();
+        assert_eq!(value["tags"], json!(["core", "${item.id}"]));
+        assert_eq!(value["issue"], "$epic");
+    }
+}
@@ -64,6 +64,24 @@ pub fn handle_article(
                 format,
             )
         }
+        ArticleCommands::Sync {
+            dir,
+            project,
+            pull,
+            dry_run,
+            force,
+        } => super::article_sync::handle_article_sync(
+            kb_client,
+            &super::article_sync::SyncOptions {
+                dir,
+                project,
+                pull: *pull,
+                dry_run: *dry_run,
+                force: *force,
+            },
+            format,
+        ),
+        ArticleCommands::Edit { id } => super::edit::handle_article_edit(kb_client, id, format),
         ArticleCommands::Delete { id } => handle_delete(kb_client, id),
         ArticleCommands::Tree { id } => handle_tree(kb_client, id, format),
         ArticleCommands::Move { id, parent } => {
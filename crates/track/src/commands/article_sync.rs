@@ -0,0 +1,929 @@
+//! Two-way sync between a directory of Markdown files and the knowledge base
+//! (`track article sync`).
+//!
+//! Each file is one article; a directory is the parent article of the files
+//! in it, backed by its `index.md`, `README.md` or a sibling `<dir>.md`. The
+//! first `# Heading` is the article title. Pushed files get an
+//! `<!-- track-article: ID -->` marker so renames and moves are recognised,
+//! and `.track-sync.json` remembers what was last synced for conflict
+//! detection.
+
+use super::attachments::build_attachment_upload;
+use super::git::slugify;
+use crate::cli::OutputFormat;
+use crate::output::output_json;
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tracker_core::{Article, CreateArticle, KnowledgeBase, UpdateArticle};
+
+const MARKER_PREFIX: &str = "<!-- track-article:";
+const MARKER_SUFFIX: &str = "-->";
+const STATE_FILE: &str = ".track-sync.json";
+const DIR_INDEX_FILES: [&str; 2] = ["index.md", "README.md"];
+const MAX_ARTICLES: usize = 10_000;
+
+pub struct SyncOptions<'a> {
+    pub dir: &'a Path,
+    pub project: &'a str,
+    pub pull: bool,
+    pub dry_run: bool,
+    pub force: bool,
+}
+
+/// What was last synced, per article ID.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SyncState {
+    #[serde(default)]
+    articles: BTreeMap<String, SyncedArticle>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SyncedArticle {
+    /// File path relative to the sync root, `/`-separated.
+    path: String,
+    /// The article's `updated` time after the last sync.
+    updated: DateTime<Utc>,
+    /// Hash of the file's title and content after the last sync.
+    hash: String,
+    /// Local image path (relative to the root) to its attachment reference.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    attachments: BTreeMap<String, String>,
+}
+
+/// One Markdown file in the sync directory.
+#[derive(Debug, Clone)]
+struct LocalDoc {
+    /// Relative to the sync root.
+    path: PathBuf,
+    id: Option<String>,
+    title: String,
+    content: String,
+    parent: Option<usize>,
+    /// Directory articles have no file until the first push writes one.
+    exists: bool,
+}
+
+impl LocalDoc {
+    fn hash(&self) -> String {
+        content_hash(&self.title, &self.content)
+    }
+
+    fn key(&self) -> String {
+        path_key(&self.path)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SyncAction {
+    action: &'static str,
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    article: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<String>,
+}
+
+pub fn handle_article_sync(
+    client: &dyn KnowledgeBase,
+    options: &SyncOptions,
+    format: OutputFormat,
+) -> Result<()> {
+    if !options.dir.is_dir() {
+        bail!("'{}' is not a directory", options.dir.display());
+    }
+    let mut state = load_state(options.dir)?;
+    let docs = scan_docs(options.dir)?;
+    let remote = client
+        .list_all_articles(Some(options.project), MAX_ARTICLES)
+        .with_context(|| format!("Failed to list articles in '{}'", options.project))?;
+
+    let mut actions = Vec::new();
+    let result = if options.pull {
+        pull(options, &docs, &remote, &mut state, &mut actions)
+    } else {
+        push(client, options, docs, &remote, &mut state, &mut actions)
+    };
+    // Save what did sync even when a later article failed.
+    if !options.dry_run {
+        save_state(options.dir, &state)?;
+    }
+    result?;
+
+    output_actions(&actions, options.dry_run, format)
+}
+
+// ========== Push ==========
+
+fn push(
+    client: &dyn KnowledgeBase,
+    options: &SyncOptions,
+    docs: Vec<LocalDoc>,
+    remote: &[Article],
+    state: &mut SyncState,
+    actions: &mut Vec<SyncAction>,
+) -> Result<()> {
+    let remote_by_id = index_remote(remote);
+    let mut ids: Vec<Option<String>> = Vec::with_capacity(docs.len());
+
+    for doc in &docs {
+        let parent_id = doc.parent.and_then(|parent| ids[parent].clone());
+        let action = |action, article: Option<&str>, detail: Option<String>| SyncAction {
+            action,
+            path: doc.key(),
+            article: article.map(str::to_string),
+            detail,
+        };
+
+        let Some(id) = doc.id.as_deref() else {
+            if options.dry_run {
+                actions.push(action("create", None, None));
+                ids.push(Some(format!("(new: {})", doc.key())));
+                continue;
+            }
+            let create = CreateArticle {
+                project_id: options.project.to_string(),
+                summary: doc.title.clone(),
+                content: Some(doc.content.clone()),
+                parent_article_id: parent_id,
+                tags: Vec::new(),
+            };
+            let mut article = client
+                .create_article(&create)
+                .with_context(|| format!("Failed to create article for '{}'", doc.key()))?;
+            let id = article.id_readable.clone();
+            write_marker(options.dir, doc, &id)?;
+            let mut synced = SyncedArticle {
+                path: doc.key(),
+                updated: article.updated,
+                hash: doc.hash(),
+                attachments: BTreeMap::new(),
+            };
+            let content = upload_images(client, options.dir, doc, &id, &mut synced)?;
+            if content != doc.content {
+                article = client
+                    .update_article(&id, &content_update(content))
+                    .with_context(|| format!("Failed to update article '{}'", id))?;
+                synced.updated = article.updated;
+            }
+            actions.push(action("create", Some(&id), None));
+            state.articles.insert(id.clone(), synced);
+            ids.push(Some(id));
+            continue;
+        };
+        ids.push(Some(id.to_string()));
+
+        let Some(article) = remote_by_id.get(id) else {
+            actions.push(action(
+                "missing",
+                Some(id),
+                Some("article no longer exists; remove the marker to recreate it".to_string()),
+            ));
+            continue;
+        };
+        let previous = state.articles.get(id).cloned();
+        let local_changed = previous
+            .as_ref()
+            .is_none_or(|synced| synced.hash != doc.hash());
+        let remote_changed = previous
+            .as_ref()
+            .is_some_and(|synced| article.updated > synced.updated);
+
+        let mut synced = previous.unwrap_or_else(|| SyncedArticle {
+            path: doc.key(),
+            updated: article.updated,
+            hash: doc.hash(),
+            attachments: BTreeMap::new(),
+        });
+        synced.path = doc.key();
+
+        if !references_parent(article, parent_id.as_deref()) {
+            actions.push(action("move", Some(id), parent_id.clone()));
+            if !options.dry_run {
+                let moved = client
+                    .move_article(id, parent_id.as_deref())
+                    .with_context(|| format!("Failed to move article '{}'", id))?;
+                synced.updated = moved.updated;
+            }
+        }
+
+        if local_changed && remote_changed && !options.force {
+            actions.push(action(
+                "conflict",
+                Some(id),
+                Some("changed locally and in the tracker; pull first or use --force".to_string()),
+            ));
+            state.articles.insert(id.to_string(), synced);
+            continue;
+        }
+        if !local_changed {
+            if remote_changed {
+                actions.push(action(
+                    "skip",
+                    Some(id),
+                    Some("changed in the tracker; run with --pull".to_string()),
+                ));
+            }
+            state.articles.insert(id.to_string(), synced);
+            continue;
+        }
+
+        let content = if options.dry_run {
+            rewrite_images(doc, &synced.attachments)
+        } else {
+            upload_images(client, options.dir, doc, id, &mut synced)?
+        };
+        let mut update = UpdateArticle::default();
+        if article.summary != doc.title {
+            update.summary = Some(doc.title.clone());
+        }
+        if article.content.as_deref().unwrap_or_default().trim_end() != content {
+            update.content = Some(content);
+        }
+        if update.summary.is_some() || update.content.is_some() {
+            actions.push(action("update", Some(id), None));
+            if !options.dry_run {
+                let updated = client
+                    .update_article(id, &update)
+                    .with_context(|| format!("Failed to update article '{}'", id))?;
+                synced.updated = updated.updated;
+            }
+        }
+        synced.hash = doc.hash();
+        state.articles.insert(id.to_string(), synced);
+    }
+    Ok(())
+}
+
+fn content_update(content: String) -> UpdateArticle {
+    UpdateArticle {
+        content: Some(content),
+        ..Default::default()
+    }
+}
+
+fn references_parent(article: &Article, parent_id: Option<&str>) -> bool {
+    match (&article.parent_article, parent_id) {
+        (None, None) => true,
+        (Some(parent), Some(id)) => parent.id == id || parent.id_readable.as_deref() == Some(id),
+        _ => false,
+    }
+}
+
+/// Upload local images not yet attached and return the content with their
+/// references pointing at the attachments.
+fn upload_images(
+    client: &dyn KnowledgeBase,
+    root: &Path,
+    doc: &LocalDoc,
+    id: &str,
+    synced: &mut SyncedArticle,
+) -> Result<String> {
+    let base = doc.path.parent().unwrap_or(Path::new(""));
+    for target in image_targets(&doc.content) {
+        let local = path_key(&normalize(&base.join(&target)));
+        if synced.attachments.contains_key(&local) {
+            continue;
+        }
+        let file = root.join(&local);
+        if !file.is_file() {
+            continue;
+        }
+        let upload = build_attachment_upload(&[file], None, None, None, false, false)?;
+        let uploaded = client
+            .add_article_attachment(id, &upload)
+            .with_context(|| format!("Failed to upload '{}' to article '{}'", local, id))?;
+        if let Some(attachment) = uploaded.into_iter().next() {
+            synced
+                .attachments
+                .insert(local, attachment.url.unwrap_or(attachment.name));
+        }
+    }
+    Ok(rewrite_images(doc, &synced.attachments))
+}
+
+/// The doc's content with local image paths replaced by attachment references.
+fn rewrite_images(doc: &LocalDoc, attachments: &BTreeMap<String, String>) -> String {
+    let base = doc.path.parent().unwrap_or(Path::new(""));
+    replace_image_targets(&doc.content, |target| {
+        attachments
+            .get(&path_key(&normalize(&base.join(target))))
+            .cloned()
+    })
+}
+
+// ========== Pull ==========
+
+fn pull(
+    options: &SyncOptions,
+    docs: &[LocalDoc],
+    remote: &[Article],
+    state: &mut SyncState,
+    actions: &mut Vec<SyncAction>,
+) -> Result<()> {
+    let mut local_by_id: HashMap<String, LocalDoc> = docs
+        .iter()
+        .filter_map(|doc| doc.id.clone().map(|id| (id, doc.clone())))
+        .collect();
+    let mut seen = HashSet::new();
+
+    for article in parents_first(remote) {
+        let id = article.id_readable.clone();
+        seen.insert(id.clone());
+        let local = local_by_id
+            .get(&id)
+            .or_else(|| local_by_id.get(&article.id))
+            .cloned();
+        let previous = state.articles.get(&id).cloned();
+        let mut synced = previous.clone().unwrap_or_else(|| SyncedArticle {
+            path: String::new(),
+            updated: article.updated,
+            hash: String::new(),
+            attachments: BTreeMap::new(),
+        });
+
+        // Attachment references go back to the local image paths.
+        let path = match &local {
+            Some(doc) => doc.path.clone(),
+            None => new_doc_path(article, &local_by_id, options.dir),
+        };
+        let base = path.parent().unwrap_or(Path::new("")).to_path_buf();
+        let by_reference: HashMap<&str, String> = synced
+            .attachments
+            .iter()
+            .map(|(local, reference)| (reference.as_str(), relative_to(local, &base)))
+            .collect();
+        let content = replace_image_targets(
+            article.content.as_deref().unwrap_or_default().trim_end(),
+            |target| by_reference.get(target).cloned(),
+        );
+        let pulled = LocalDoc {
+            path: path.clone(),
+            id: Some(id.clone()),
+            title: article.summary.clone(),
+            content,
+            parent: None,
+            exists: true,
+        };
+        let action = |action, detail: Option<String>| SyncAction {
+            action,
+            path: path_key(&path),
+            article: Some(id.clone()),
+            detail,
+        };
+
+        match &local {
+            Some(doc) => {
+                let remote_changed = previous
+                    .as_ref()
+                    .is_none_or(|synced| article.updated > synced.updated);
+                let local_changed = previous
+                    .as_ref()
+                    .is_none_or(|synced| synced.hash != doc.hash());
+                let differs = doc.title != pulled.title || doc.content != pulled.content;
+                if !remote_changed || !differs {
+                    synced.path = doc.key();
+                    synced.updated = article.updated;
+                    if !differs {
+                        synced.hash = doc.hash();
+                    }
+                    state.articles.insert(id.clone(), synced);
+                    continue;
+                }
+                if local_changed && !options.force {
+                    actions.push(action(
+                        "conflict",
+                        Some(
+                            "changed locally and in the tracker; push first or use --force"
+                                .to_string(),
+                        ),
+                    ));
+                    continue;
+                }
+                actions.push(action("pull", None));
+            }
+            None => actions.push(action("pull-new", None)),
+        }
+
+        if !options.dry_run {
+            let file = options.dir.join(&path);
+            if let Some(parent) = file.parent() {
+                std::fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create '{}'", parent.display()))?;
+            }
+            std::fs::write(&file, render_file(&id, &pulled.title, &pulled.content))
+                .with_context(|| format!("Failed to write '{}'", file.display()))?;
+        }
+        synced.path = path_key(&path);
+        synced.updated = article.updated;
+        synced.hash = pulled.hash();
+        state.articles.insert(id.clone(), synced);
+        local_by_id.insert(id, pulled);
+    }
+
+    for doc in docs {
+        if let Some(id) = &doc.id
+            && !seen.contains(id)
+        {
+            actions.push(SyncAction {
+                action: "missing",
+                path: doc.key(),
+                article: Some(id.clone()),
+                detail: Some("article no longer exists in the tracker".to_string()),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Remote articles ordered so each parent comes before its children.
+fn parents_first(remote: &[Article]) -> Vec<&Article> {
+    let ids: HashSet<&str> = remote
+        .iter()
+        .flat_map(|article| [article.id.as_str(), article.id_readable.as_str()])
+        .collect();
+    let mut placed: HashSet<&str> = HashSet::new();
+    let mut ordered = Vec::with_capacity(remote.len());
+    let mut pending: Vec<&Article> = remote.iter().collect();
+    while !pending.is_empty() {
+        let before = pending.len();
+        pending.retain(|article| {
+            let ready = article.parent_article.as_ref().is_none_or(|parent| {
+                let parent_ids = [Some(parent.id.as_str()), parent.id_readable.as_deref()];
+                !parent_ids.iter().flatten().any(|id| ids.contains(id))
+                    || parent_ids.iter().flatten().any(|id| placed.contains(id))
+            });
+            if ready {
+                placed.insert(article.id.as_str());
+                placed.insert(article.id_readable.as_str());
+                ordered.push(*article);
+            }
+            !ready
+        });
+        if pending.len() == before {
+            // A parent cycle; keep the remaining order.
+            ordered.append(&mut pending);
+        }
+    }
+    ordered
+}
+
+/// Where a pulled article without a local file goes: next to the files of
+/// its parent's directory, or at the root.
+fn new_doc_path(article: &Article, local: &HashMap<String, LocalDoc>, root: &Path) -> PathBuf {
+    let parent_doc = article.parent_article.as_ref().and_then(|parent| {
+        parent
+            .id_readable
+            .as_ref()
+            .and_then(|id| local.get(id))
+            .or_else(|| local.get(&parent.id))
+    });
+    let dir = match parent_doc {
+        Some(parent) => {
+            let file_name = parent.path.file_name().unwrap_or_default();
+            if DIR_INDEX_FILES
+                .iter()
+                .any(|index| file_name.eq_ignore_ascii_case(index))
+            {
+                parent.path.parent().unwrap_or(Path::new("")).to_path_buf()
+            } else {
+                // A leaf file `x.md` becomes the directory article of `x/`.
+                parent.path.with_extension("")
+            }
+        }
+        None => PathBuf::new(),
+    };
+    let stem = match slugify(&article.summary) {
+        slug if slug.is_empty() => slugify(&article.id_readable),
+        slug => slug,
+    };
+    let mut path = dir.join(format!("{}.md", stem));
+    if root.join(&path).exists() {
+        path = dir.join(format!("{}-{}.md", stem, slugify(&article.id_readable)));
+    }
+    path
+}
+
+// ========== Local files ==========
+
+/// Walk `root` for Markdown files, parents before children.
+fn scan_docs(root: &Path) -> Result<Vec<LocalDoc>> {
+    let mut docs = Vec::new();
+    scan_dir(root, Path::new(""), None, &mut docs)?;
+    Ok(docs)
+}
+
+fn scan_dir(
+    root: &Path,
+    rel_dir: &Path,
+    dir_doc: Option<usize>,
+    docs: &mut Vec<LocalDoc>,
+) -> Result<()> {
+    let dir = root.join(rel_dir);
+    let mut files = Vec::new();
+    let mut subdirs = Vec::new();
+    for entry in
+        std::fs::read_dir(&dir).with_context(|| format!("Failed to read '{}'", dir.display()))?
+    {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        if path.is_dir() {
+            subdirs.push(name);
+        } else if path.extension().is_some_and(|ext| ext == "md") {
+            files.push(name);
+        }
+    }
+    files.sort();
+    subdirs.sort();
+
+    // Files that stand for a subdirectory's article.
+    let mut claimed: HashSet<String> = HashSet::new();
+    let dir_file_of = |subdir: &str| -> Option<PathBuf> {
+        let sub = root.join(rel_dir).join(subdir);
+        DIR_INDEX_FILES
+            .iter()
+            .find(|index| sub.join(index).is_file())
+            .map(|index| rel_dir.join(subdir).join(index))
+    };
+    for subdir in &subdirs {
+        if dir_file_of(subdir).is_none() && files.contains(&format!("{}.md", subdir)) {
+            claimed.insert(format!("{}.md", subdir));
+        }
+    }
+    if let Some(index) = dir_doc
+        && docs[index].path.parent() == Some(rel_dir)
+        && let Some(name) = docs[index].path.file_name()
+    {
+        claimed.insert(name.to_string_lossy().to_string());
+    }
+
+    for file in files.iter().filter(|file| !claimed.contains(*file)) {
+        let path = rel_dir.join(file);
+        docs.push(read_doc(root, &path, dir_doc)?);
+    }
+    for subdir in &subdirs {
+        let index = dir_file_of(subdir);
+        let sibling = rel_dir.join(format!("{}.md", subdir));
+        let doc = match index {
+            Some(index) => read_doc(root, &index, dir_doc)?,
+            None if root.join(&sibling).is_file() => read_doc(root, &sibling, dir_doc)?,
+            None => LocalDoc {
+                path: rel_dir.join(subdir).join(DIR_INDEX_FILES[0]),
+                id: None,
+                title: humanize(subdir),
+                content: String::new(),
+                parent: dir_doc,
+                exists: false,
+            },
+        };
+        docs.push(doc);
+        let index = docs.len() - 1;
+        scan_dir(root, &rel_dir.join(subdir), Some(index), docs)?;
+    }
+    Ok(())
+}
+
+fn read_doc(root: &Path, path: &Path, parent: Option<usize>) -> Result<LocalDoc> {
+    let file = root.join(path);
+    let text = std::fs::read_to_string(&file)
+        .with_context(|| format!("Failed to read '{}'", file.display()))?;
+    let (id, title, content) = parse_file(&text);
+    let title = title.unwrap_or_else(|| {
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let is_index = DIR_INDEX_FILES
+            .iter()
+            .any(|index| path.file_name().is_some_and(|name| name == *index));
+        match path.parent().and_then(Path::file_name) {
+            Some(dir) if is_index => humanize(&dir.to_string_lossy()),
+            _ => humanize(&stem),
+        }
+    });
+    Ok(LocalDoc {
+        path: path.to_path_buf(),
+        id,
+        title,
+        content,
+        parent,
+        exists: true,
+    })
+}
+
+/// Split a file into its marker ID, `# Title` and remaining content.
+fn parse_file(text: &str) -> (Option<String>, Option<String>, String) {
+    let text = text.replace("\r\n", "\n");
+    let mut rest = text.trim_start_matches('\n');
+    let mut id = None;
+    if let Some(line) = rest.lines().next()
+        && let Some(marker) = line
+            .trim()
+            .strip_prefix(MARKER_PREFIX)
+            .and_then(|marker| marker.strip_suffix(MARKER_SUFFIX))
+    {
+        id = Some(marker.trim().to_string()).filter(|id| !id.is_empty());
+        rest = rest[line.len()..].trim_start_matches('\n');
+    }
+    let mut title = None;
+    if let Some(line) = rest.lines().next()
+        && let Some(heading) = line.strip_prefix("# ")
+    {
+        title = Some(heading.trim().to_string());
+        rest = rest[line.len()..].trim_start_matches('\n');
+    }
+    (id, title, rest.trim_end().to_string())
+}
+
+fn render_file(id: &str, title: &str, content: &str) -> String {
+    let mut file = format!("{} {} {}\n# {}\n", MARKER_PREFIX, id, MARKER_SUFFIX, title);
+    if !content.is_empty() {
+        file.push('\n');
+        file.push_str(content);
+        file.push('\n');
+    }
+    file
+}
+
+/// Record a new article's ID in its file, keeping the file as written.
+fn write_marker(root: &Path, doc: &LocalDoc, id: &str) -> Result<()> {
+    let file = root.join(&doc.path);
+    let text = if doc.exists {
+        let existing = std::fs::read_to_string(&file)
+            .with_context(|| format!("Failed to read '{}'", file.display()))?;
+        format!("{} {} {}\n{}", MARKER_PREFIX, id, MARKER_SUFFIX, existing)
+    } else {
+        render_file(id, &doc.title, &doc.content)
+    };
+    std::fs::write(&file, text).with_context(|| format!("Failed to write '{}'", file.display()))
+}
+
+/// Targets of `![alt](target)` images that point at local files.
+fn image_targets(content: &str) -> Vec<String> {
+    let mut targets = Vec::new();
+    replace_image_targets(content, |target| {
+        targets.push(target.to_string());
+        None
+    });
+    targets
+}
+
+/// Rewrite local `![alt](target)` image targets with `replace`; targets it
+/// returns `None` for are kept. URLs and absolute paths are never offered.
+fn replace_image_targets(content: &str, mut replace: impl FnMut(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(start) = rest.find("![") {
+        let Some(open) = rest[start..].find("](").map(|i| start + i + 2) else {
+            break;
+        };
+        let Some(close) = rest[open..].find(')').map(|i| open + i) else {
+            break;
+        };
+        out.push_str(&rest[..open]);
+        let inner = &rest[open..close];
+        // `![alt](path "title")`: only the path is the target.
+        let (target, title) = inner
+            .split_once(' ')
+            .map_or((inner, ""), |(target, title)| (target, title));
+        let local = !target.is_empty()
+            && !target.contains("://")
+            && !target.starts_with(['/', '#'])
+            && !target.starts_with("data:");
+        match local.then(|| replace(target)).flatten() {
+            Some(replacement) if title.is_empty() => out.push_str(&replacement),
+            Some(replacement) => {
+                out.push_str(&replacement);
+                out.push(' ');
+                out.push_str(title);
+            }
+            None => out.push_str(inner),
+        }
+        rest = &rest[close..];
+    }
+    out.push_str(rest);
+    out
+}
+
+// ========== Helpers ==========
+
+fn index_remote(remote: &[Article]) -> HashMap<&str, &Article> {
+    let mut by_id = HashMap::new();
+    for article in remote {
+        by_id.insert(article.id.as_str(), article);
+        by_id.insert(article.id_readable.as_str(), article);
+    }
+    by_id
+}
+
+fn content_hash(title: &str, content: &str) -> String {
+    Sha256::digest(format!("{}\n{}", title, content).as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// `/`-separated form of a relative path, used in state and output.
+fn path_key(path: &Path) -> String {
+    path.components()
+        .map(|part| part.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Resolve `.` and `..` in a relative path without touching the filesystem.
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for part in path.components() {
+        match part {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir => {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// `target` (relative to the root) as seen from `base` (also relative).
+fn relative_to(target: &str, base: &Path) -> String {
+    let target: Vec<&str> = target.split('/').collect();
+    let base: Vec<String> = base
+        .components()
+        .map(|part| part.as_os_str().to_string_lossy().to_string())
+        .collect();
+    let common = target
+        .iter()
+        .zip(&base)
+        .take_while(|(a, b)| **a == b.as_str())
+        .count();
+    let mut parts: Vec<&str> = vec![".."; base.len() - common];
+    parts.extend(&target[common..]);
+    parts.join("/")
+}
+
+fn humanize(name: &str) -> String {
+    let words = name.replace(['-', '_'], " ");
+    let mut chars = words.trim().chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn load_state(root: &Path) -> Result<SyncState> {
+    let path = root.join(STATE_FILE);
+    if !path.exists() {
+        return Ok(SyncState::default());
+    }
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read '{}'", path.display()))?;
+    serde_json::from_str(&content).with_context(|| format!("Invalid '{}'", path.display()))
+}
+
+fn save_state(root: &Path, state: &SyncState) -> Result<()> {
+    let path = root.join(STATE_FILE);
+    std::fs::write(&path, serde_json::to_string_pretty(state)? + "\n")
+        .with_context(|| format!("Failed to write '{}'", path.display()))
+}
+
+fn output_actions(actions: &[SyncAction], dry_run: bool, format: OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Json => output_json(&serde_json::json!({
+            "dry_run": dry_run,
+            "actions": actions,
+        })),
+        OutputFormat::Text => {
+            use colored::Colorize;
+            if actions.is_empty() {
+                println!("Everything is in sync");
+                return Ok(());
+            }
+            for action in actions {
+                let label = match action.action {
+                    "conflict" | "missing" => action.action.red().bold(),
+                    "skip" => action.action.yellow().bold(),
+                    _ => action.action.green().bold(),
+                };
+                let mut line = format!("{:<10} {}", label, action.path);
+                if let Some(article) = &action.article {
+                    line.push_str(&format!(" ({})", article.cyan()));
+                }
+                if let Some(detail) = &action.detail {
+                    line.push_str(&format!(": {}", detail));
+                }
+                println!("{}", line);
+            }
+            if dry_run {
+                println!("{}", "Dry run: nothing was changed".dimmed());
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let file = root.join(path);
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        std::fs::write(file, content).unwrap();
+    }
+
+    #[test]
+    fn scans_directories_as_parent_articles() {
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path();
+        write(root, "intro.md", "# Introduction\n\nHello");
+        write(root, "guide/README.md", "Guide overview");
+        write(
+            root,
+            "guide/setup.md",
+            "<!-- track-article: KB-A-7 -->\n# Set up\n\nSteps",
+        );
+        write(root, "api.md", "# API");
+        write(root, "api/auth.md", "Auth");
+        write(root, "faq/general.md", "FAQ");
+        write(root, ".track-sync.json", "{}");
+
+        let docs = scan_docs(root).unwrap();
+        let summary: Vec<_> = docs
+            .iter()
+            .map(|doc| {
+                (
+                    doc.key(),
+                    doc.title.as_str(),
+                    doc.parent.map(|parent| docs[parent].key()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("intro.md".to_string(), "Introduction", None),
+                ("api.md".to_string(), "API", None),
+                (
+                    "api/auth.md".to_string(),
+                    "Auth",
+                    Some("api.md".to_string())
+                ),
+                ("faq/index.md".to_string(), "Faq", None),
+                (
+                    "faq/general.md".to_string(),
+                    "General",
+                    Some("faq/index.md".to_string())
+                ),
+                ("guide/README.md".to_string(), "Guide", None),
+                (
+                    "guide/setup.md".to_string(),
+                    "Set up",
+                    Some("guide/README.md".to_string())
+                ),
+            ]
+        );
+        assert!(!docs[3].exists);
+        assert_eq!(docs[6].id.as_deref(), Some("KB-A-7"));
+        assert_eq!(docs[6].content, "Steps");
+    }
+
+    #[test]
+    fn image_targets_skip_urls_and_keep_titles() {
+        let content = "![a](img/a.png \"A\") ![b](https://x/b.png) ![c](../c.png)";
+        assert_eq!(image_targets(content), ["img/a.png", "../c.png"]);
+        let rewritten = replace_image_targets(content, |target| {
+            (target == "img/a.png").then(|| "/files/a.png".to_string())
+        });
+        assert_eq!(
+            rewritten,
+            "![a](/files/a.png \"A\") ![b](https://x/b.png) ![c](../c.png)"
+        );
+        assert_eq!(
+            relative_to("docs/img/a.png", Path::new("docs/guide")),
+            "../img/a.png"
+        );
+    }
+
+    #[test]
+    fn parse_and_render_file_round_trip() {
+        let file = render_file("KB-A-1", "Runbook", "Body\n\n## More");
+        assert_eq!(
+            parse_file(&file),
+            (
+                Some("KB-A-1".to_string()),
+                Some("Runbook".to_string()),
+                "Body\n\n## More".to_string()
+            )
+        );
+        assert_eq!(
+            parse_file("Just text\n"),
+            (None, None, "Just text".to_string())
+        );
+    }
+}
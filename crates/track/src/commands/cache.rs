@@ -62,8 +62,8 @@ pub fn handle_cache(
 
             // Cache works with any backend that implements IssueTracker
             let backend_type = backend.to_string();
-            let base_url = config.url.as_deref().unwrap_or("unknown");
-            let default_project = config.default_project.as_deref();
+            let base_url = config.connection.url.as_deref().unwrap_or("unknown");
+            let default_project = config.connection.default_project.as_deref();
 
             let cache = cache::TrackerCache::refresh_with_articles(
                 client,
@@ -71,6 +71,7 @@ pub fn handle_cache(
                 &backend_type,
                 base_url,
                 default_project,
+                &config.saved_query_templates(backend),
             )?;
             cache.save(None)?;
 
@@ -188,6 +189,11 @@ pub fn handle_cache(
         CacheCommands::Show => {
             let mut cache = cache::TrackerCache::load(None)?;
             cache.ensure_all_loaded()?;
+            // Saved queries added since the last refresh are still usable
+            cache::merge_query_templates(
+                &mut cache.query_templates,
+                &config.saved_query_templates(backend),
+            );
             match format {
                 cli::OutputFormat::Json => {
                     output_json(&cache)?;
@@ -294,7 +300,13 @@ pub fn handle_cache(
                         println!();
                         println!("{}:", "Query Templates".white().bold());
                         for qt in &cache.query_templates {
-                            println!("  {}: {}", qt.name.cyan(), qt.description.dimmed());
+                            let marker = if qt.custom { " (saved)" } else { "" };
+                            println!(
+                                "  {}{}: {}",
+                                qt.name.cyan(),
+                                marker.yellow(),
+                                qt.description.dimmed()
+                            );
                         }
                     }
 
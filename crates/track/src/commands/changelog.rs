@@ -0,0 +1,446 @@
+//! `track changelog` — release notes assembled from the issue keys in a git
+//! range, or from a search query for releases that aren't cut from git.
+//!
+//! Issues are fetched with a single search where the backend can query by
+//! key, falling back to one lookup per key (failures are reported as
+//! `skipped` rather than aborting), and grouped into sections by type or tag.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use tracker_core::{Issue, IssueTracker, unicode_eq_ignore_case};
+
+use crate::cache::CachedQueryTemplate;
+use crate::cli::{Backend, ChangelogGroupBy, OutputFormat};
+use crate::config::{ChangelogConfig, ChangelogSectionConfig};
+use crate::output::{find_field_value, output_json, output_progress};
+
+/// Section for issues that match no configured section (or have no type/tag).
+const OTHER_SECTION: &str = "Other";
+
+/// Arguments for `track changelog`.
+pub struct ChangelogArgs<'a> {
+    pub from: Option<&'a str>,
+    pub to: &'a str,
+    pub query: Option<&'a str>,
+    pub template: Option<&'a str>,
+    pub project: Option<&'a str>,
+    pub group_by: Option<ChangelogGroupBy>,
+    pub title: Option<&'a str>,
+    pub limit: usize,
+    pub saved_queries: &'a [CachedQueryTemplate],
+}
+
+#[derive(Debug, Serialize)]
+struct Changelog {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    sections: Vec<ChangelogSection>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    skipped: Vec<SkippedIssue>,
+}
+
+#[derive(Debug, Serialize)]
+struct ChangelogSection {
+    title: String,
+    issues: Vec<ChangelogEntry>,
+}
+
+#[derive(Debug, Serialize)]
+struct ChangelogEntry {
+    id: String,
+    summary: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    issue_type: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+}
+
+/// An issue key found in git history that couldn't be fetched.
+#[derive(Debug, Serialize)]
+struct SkippedIssue {
+    id: String,
+    error: String,
+}
+
+impl From<&Issue> for ChangelogEntry {
+    fn from(issue: &Issue) -> Self {
+        Self {
+            id: issue.id_readable.clone(),
+            summary: issue.summary.clone(),
+            issue_type: find_field_value(issue, "Type"),
+            tags: issue.tags.iter().map(|tag| tag.name.clone()).collect(),
+        }
+    }
+}
+
+pub fn handle_changelog(
+    client: &dyn IssueTracker,
+    args: &ChangelogArgs,
+    backend: Backend,
+    config: &ChangelogConfig,
+    default_project: Option<&str>,
+    format: OutputFormat,
+) -> Result<()> {
+    let (issues, skipped) = match args.from {
+        Some(from) => issues_from_git(client, from, args.to, args.project, backend, format)?,
+        None => {
+            let query = super::issue::resolve_search_query(
+                args.query,
+                args.template,
+                args.project,
+                default_project,
+                args.saved_queries,
+            )?;
+            let issues = client
+                .search_all_issues(&query, args.limit)
+                .with_context(|| format!("Failed to search issues with query '{}'", query))?;
+            (issues, Vec::new())
+        }
+    };
+
+    let group_by = args.group_by.or(config.group_by).unwrap_or_default();
+    let changelog = Changelog {
+        title: args.title.map(String::from),
+        sections: build_sections(&issues, group_by, &config.sections),
+        skipped,
+    };
+
+    match format {
+        OutputFormat::Json => output_json(&changelog),
+        OutputFormat::Text => {
+            for skipped in &changelog.skipped {
+                eprintln!("warning: skipped {}: {}", skipped.id, skipped.error);
+            }
+            print!("{}", render_markdown(&changelog));
+            Ok(())
+        }
+    }
+}
+
+fn issues_from_git(
+    client: &dyn IssueTracker,
+    from: &str,
+    to: &str,
+    project: Option<&str>,
+    backend: Backend,
+    format: OutputFormat,
+) -> Result<(Vec<Issue>, Vec<SkippedIssue>)> {
+    let range = format!("{}..{}", from, to);
+    let log = super::git::git(&[
+        "log",
+        "--reverse",
+        "--format=%B",
+        "--end-of-options",
+        &range,
+    ])?;
+    let ids = extract_issue_ids(&log, backend, project);
+
+    // One search for the whole range where the backend can query by key;
+    // keys it doesn't return (moved issues, typos) are fetched one by one
+    let mut found: HashMap<String, Issue> = HashMap::new();
+    if let Some(query) = batch_query(backend, &ids) {
+        output_progress(&format!("Fetching {} issues", ids.len()), format);
+        // A failed search (e.g. JQL rejecting an unknown key) falls back to
+        // per-issue lookups, which report each failure as skipped
+        if let Ok(results) = client.search_all_issues(&query, ids.len()) {
+            found.extend(
+                results
+                    .into_iter()
+                    .map(|issue| (issue.id_readable.clone(), issue)),
+            );
+        }
+    }
+
+    let mut issues: Vec<Issue> = Vec::new();
+    let mut skipped = Vec::new();
+    for (index, id) in ids.iter().enumerate() {
+        let fetched = match found.remove(id) {
+            Some(issue) => Ok(issue),
+            None => {
+                output_progress(
+                    &format!("Fetching {} ({}/{})", id, index + 1, ids.len()),
+                    format,
+                );
+                client.get_issue(id)
+            }
+        };
+        match fetched {
+            // Different keys can resolve to the same issue (e.g. moved issues)
+            Ok(issue) if issues.iter().any(|i| i.id_readable == issue.id_readable) => {}
+            Ok(issue) => issues.push(issue),
+            Err(e) => skipped.push(SkippedIssue {
+                id: id.clone(),
+                error: e.to_string(),
+            }),
+        }
+    }
+    Ok((issues, skipped))
+}
+
+/// A search query matching exactly `ids`, for backends whose query language
+/// can select issues by key.
+fn batch_query(backend: Backend, ids: &[String]) -> Option<String> {
+    if ids.is_empty() {
+        return None;
+    }
+    match backend {
+        Backend::YouTrack => Some(format!("issue id: {}", ids.join(", "))),
+        Backend::Jira => Some(format!("key in ({})", ids.join(", "))),
+        _ => None,
+    }
+}
+
+/// Issue references in commit messages, deduplicated in order of appearance.
+///
+/// GitHub and GitLab issues are referenced as `#123`; every other backend
+/// uses `KEY-123` keys (see [`super::open::is_issue_id`]), optionally
+/// restricted to one project's keys.
+fn extract_issue_ids(text: &str, backend: Backend, project: Option<&str>) -> Vec<String> {
+    let candidates = match backend {
+        Backend::GitHub | Backend::GitLab => find_hash_refs(text),
+        _ => super::git::find_issue_keys(text)
+            .into_iter()
+            .filter(|key| super::open::is_issue_id(key))
+            .filter(|key| {
+                project.is_none_or(|project| {
+                    key.split_once('-')
+                        .is_some_and(|(prefix, _)| prefix.eq_ignore_ascii_case(project))
+                })
+            })
+            .collect(),
+    };
+
+    let mut ids: Vec<String> = Vec::new();
+    for id in candidates {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    ids
+}
+
+/// Find `#123` references, returning the bare numbers.
+fn find_hash_refs(text: &str) -> Vec<String> {
+    let mut refs = Vec::new();
+    let mut prev_alnum = false;
+    for (i, c) in text.char_indices() {
+        if c == '#' && !prev_alnum {
+            let rest = &text[i + 1..];
+            let len = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            if len > 0 && !rest[len..].starts_with(|c: char| c.is_alphanumeric()) {
+                refs.push(rest[..len].to_string());
+            }
+        }
+        prev_alnum = c.is_alphanumeric();
+    }
+    refs
+}
+
+fn group_values(issue: &Issue, group_by: ChangelogGroupBy) -> Vec<String> {
+    match group_by {
+        ChangelogGroupBy::Type => find_field_value(issue, "Type").into_iter().collect(),
+        ChangelogGroupBy::Tag => issue.tags.iter().map(|tag| tag.name.clone()).collect(),
+    }
+}
+
+/// Group issues into sections. Configured sections keep their order; without
+/// them, each distinct type/tag becomes a section in order of appearance.
+/// Unmatched issues go to a trailing "Other" section and empty sections are
+/// dropped.
+fn build_sections(
+    issues: &[Issue],
+    group_by: ChangelogGroupBy,
+    configured: &[ChangelogSectionConfig],
+) -> Vec<ChangelogSection> {
+    let mut sections: Vec<ChangelogSection> = configured
+        .iter()
+        .map(|section| ChangelogSection {
+            title: section.title.clone(),
+            issues: Vec::new(),
+        })
+        .collect();
+    let mut other = Vec::new();
+
+    for issue in issues {
+        let values = group_values(issue, group_by);
+        let index = if configured.is_empty() {
+            values.first().map(|value| {
+                sections
+                    .iter()
+                    .position(|section| unicode_eq_ignore_case(&section.title, value))
+                    .unwrap_or_else(|| {
+                        sections.push(ChangelogSection {
+                            title: value.clone(),
+                            issues: Vec::new(),
+                        });
+                        sections.len() - 1
+                    })
+            })
+        } else {
+            configured.iter().position(|section| {
+                section.values.iter().any(|wanted| {
+                    values
+                        .iter()
+                        .any(|value| unicode_eq_ignore_case(value, wanted))
+                })
+            })
+        };
+
+        let entry = ChangelogEntry::from(issue);
+        match index {
+            Some(index) => sections[index].issues.push(entry),
+            None => other.push(entry),
+        }
+    }
+
+    if !other.is_empty() {
+        sections.push(ChangelogSection {
+            title: OTHER_SECTION.to_string(),
+            issues: other,
+        });
+    }
+    sections.retain(|section| !section.issues.is_empty());
+    sections
+}
+
+fn render_markdown(changelog: &Changelog) -> String {
+    let mut blocks = Vec::new();
+    if let Some(title) = &changelog.title {
+        blocks.push(format!("## {}\n", title));
+    }
+    if changelog.sections.is_empty() {
+        blocks.push("No issues found.\n".to_string());
+    }
+    for section in &changelog.sections {
+        let mut block = format!("### {}\n\n", section.title);
+        for entry in &section.issues {
+            block.push_str(&format!("- {} {}\n", entry.id, entry.summary));
+        }
+        blocks.push(block);
+    }
+    blocks.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use tracker_core::{CustomField, ProjectRef, Tag};
+
+    fn issue(id: &str, issue_type: Option<&str>, tags: &[&str]) -> Issue {
+        Issue {
+            id: id.to_lowercase(),
+            id_readable: id.to_string(),
+            summary: format!("Summary of {}", id),
+            description: None,
+            project: ProjectRef {
+                id: "p1".to_string(),
+                name: None,
+                short_name: Some("PROJ".to_string()),
+            },
+            custom_fields: issue_type
+                .map(|value| CustomField::SingleEnum {
+                    name: "Type".to_string(),
+                    value: Some(value.to_string()),
+                })
+                .into_iter()
+                .collect(),
+            tags: tags
+                .iter()
+                .map(|name| Tag {
+                    id: name.to_string(),
+                    name: name.to_string(),
+                })
+                .collect(),
+            created: Utc::now(),
+            updated: Utc::now(),
+            resolved: None,
+        }
+    }
+
+    #[test]
+    fn extract_issue_ids_uses_backend_reference_style() {
+        let log = "PROJ-1: Add login\n\nRefs proj-2, OTHER-3 and PROJ-1 again (#12)\n";
+        assert_eq!(
+            extract_issue_ids(log, Backend::YouTrack, None),
+            vec!["PROJ-1", "PROJ-2", "OTHER-3"]
+        );
+        assert_eq!(
+            extract_issue_ids(log, Backend::Jira, Some("proj")),
+            vec!["PROJ-1", "PROJ-2"]
+        );
+        assert_eq!(
+            extract_issue_ids("Fix crash (#12), see #7 and a#9\n", Backend::GitHub, None),
+            vec!["12", "7"]
+        );
+    }
+
+    #[test]
+    fn batch_query_selects_keys_where_supported() {
+        let ids = vec!["PROJ-1".to_string(), "PROJ-2".to_string()];
+        assert_eq!(
+            batch_query(Backend::YouTrack, &ids).as_deref(),
+            Some("issue id: PROJ-1, PROJ-2")
+        );
+        assert_eq!(
+            batch_query(Backend::Jira, &ids).as_deref(),
+            Some("key in (PROJ-1, PROJ-2)")
+        );
+        assert_eq!(batch_query(Backend::GitHub, &ids), None);
+        assert_eq!(batch_query(Backend::Jira, &[]), None);
+    }
+
+    #[test]
+    fn build_sections_groups_by_distinct_type_with_other_last() {
+        let issues = vec![
+            issue("PROJ-1", Some("Bug"), &[]),
+            issue("PROJ-2", None, &[]),
+            issue("PROJ-3", Some("Feature"), &[]),
+            issue("PROJ-4", Some("bug"), &[]),
+        ];
+        let sections = build_sections(&issues, ChangelogGroupBy::Type, &[]);
+        let titles: Vec<&str> = sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, vec!["Bug", "Feature", "Other"]);
+        assert_eq!(sections[0].issues.len(), 2);
+    }
+
+    #[test]
+    fn build_sections_uses_configured_sections_by_tag() {
+        let issues = vec![
+            issue("PROJ-1", None, &["ui", "Bug"]),
+            issue("PROJ-2", None, &["docs"]),
+            issue("PROJ-3", None, &["enhancement"]),
+        ];
+        let configured = vec![
+            ChangelogSectionConfig {
+                title: "Features".to_string(),
+                values: vec!["feature".to_string(), "enhancement".to_string()],
+            },
+            ChangelogSectionConfig {
+                title: "Bug Fixes".to_string(),
+                values: vec!["bug".to_string()],
+            },
+            ChangelogSectionConfig {
+                title: "Security".to_string(),
+                values: vec!["security".to_string()],
+            },
+        ];
+        let sections = build_sections(&issues, ChangelogGroupBy::Tag, &configured);
+        let titles: Vec<&str> = sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, vec!["Features", "Bug Fixes", "Other"]);
+
+        let markdown = render_markdown(&Changelog {
+            title: Some("v1.3.0".to_string()),
+            sections,
+            skipped: vec![],
+        });
+        assert_eq!(
+            markdown,
+            "## v1.3.0\n\n### Features\n\n- PROJ-3 Summary of PROJ-3\n\n### Bug Fixes\n\n- PROJ-1 Summary of PROJ-1\n\n### Other\n\n- PROJ-2 Summary of PROJ-2\n"
+        );
+    }
+}
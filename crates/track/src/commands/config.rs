@@ -1,5 +1,6 @@
 use anyhow::Result;
 use clap::ValueEnum;
+use tracker_client::{GitHubScope, JiraDeployment};
 use tracker_core::IssueTracker;
 
 use crate::{
@@ -20,10 +21,15 @@ enum ConfigKey {
     JiraUrl,
     JiraEmail,
     JiraToken,
+    JiraDeployment,
     GitHubToken,
     GitHubOwner,
     GitHubRepo,
     GitHubApiUrl,
+    GitHubProjectNumber,
+    GitHubScope,
+    GitHubRepoTopic,
+    GitHubRepoPattern,
     GitLabToken,
     GitLabUrl,
     GitLabProjectId,
@@ -33,10 +39,12 @@ enum ConfigKey {
     LinearUrl,
     LinearDefaultTeam,
     LinearDefaultProject,
+    GitBranchTemplate,
+    GitCommitPrefix,
 }
 
 impl ConfigKey {
-    const ALL: [Self; 23] = [
+    const ALL: [Self; 30] = [
         Self::Backend,
         Self::Url,
         Self::Token,
@@ -47,10 +55,15 @@ impl ConfigKey {
         Self::JiraUrl,
         Self::JiraEmail,
         Self::JiraToken,
+        Self::JiraDeployment,
         Self::GitHubToken,
         Self::GitHubOwner,
         Self::GitHubRepo,
         Self::GitHubApiUrl,
+        Self::GitHubProjectNumber,
+        Self::GitHubScope,
+        Self::GitHubRepoTopic,
+        Self::GitHubRepoPattern,
         Self::GitLabToken,
         Self::GitLabUrl,
         Self::GitLabProjectId,
@@ -60,6 +73,8 @@ impl ConfigKey {
         Self::LinearUrl,
         Self::LinearDefaultTeam,
         Self::LinearDefaultProject,
+        Self::GitBranchTemplate,
+        Self::GitCommitPrefix,
     ];
 
     fn parse(key: &str) -> Option<Self> {
@@ -81,10 +96,15 @@ impl ConfigKey {
             Self::JiraUrl => "jira.url",
             Self::JiraEmail => "jira.email",
             Self::JiraToken => "jira.token",
+            Self::JiraDeployment => "jira.deployment",
             Self::GitHubToken => "github.token",
             Self::GitHubOwner => "github.owner",
             Self::GitHubRepo => "github.repo",
             Self::GitHubApiUrl => "github.api_url",
+            Self::GitHubProjectNumber => "github.project_number",
+            Self::GitHubScope => "github.scope",
+            Self::GitHubRepoTopic => "github.repo_topic",
+            Self::GitHubRepoPattern => "github.repo_pattern",
             Self::GitLabToken => "gitlab.token",
             Self::GitLabUrl => "gitlab.url",
             Self::GitLabProjectId => "gitlab.project_id",
@@ -94,12 +114,17 @@ impl ConfigKey {
             Self::LinearUrl => "linear.url",
             Self::LinearDefaultTeam => "linear.default_team",
             Self::LinearDefaultProject => "linear.default_linear_project",
+            Self::GitBranchTemplate => "git.branch_template",
+            Self::GitCommitPrefix => "git.commit_prefix",
         }
     }
 
     fn value_type(self) -> &'static str {
         match self {
             Self::Backend => "youtrack | jira | github | gitlab | linear",
+            Self::JiraDeployment => "cloud | server",
+            Self::GitHubProjectNumber => "number",
+            Self::GitHubScope => "repo | org",
             _ => "string",
         }
     }
@@ -116,10 +141,23 @@ impl ConfigKey {
             Self::JiraUrl => "Jira-specific URL (overrides 'url' when backend=jira)",
             Self::JiraEmail => "Jira-specific email",
             Self::JiraToken => "Jira-specific token",
+            Self::JiraDeployment => {
+                "Jira product: cloud (default) or server for Server / Data Center"
+            }
             Self::GitHubToken => "GitHub personal access token",
             Self::GitHubOwner => "GitHub repository owner (user or organization)",
             Self::GitHubRepo => "GitHub repository name",
             Self::GitHubApiUrl => "GitHub API URL (defaults to https://api.github.com)",
+            Self::GitHubProjectNumber => {
+                "GitHub Projects board number whose fields are exposed as custom fields"
+            }
+            Self::GitHubScope => {
+                "GitHub scope: repo (default) or org for every repository of the owner"
+            }
+            Self::GitHubRepoTopic => "In org scope, only repositories with this topic",
+            Self::GitHubRepoPattern => {
+                "In org scope, only repositories whose name matches this regex"
+            }
             Self::GitLabToken => "GitLab personal access token",
             Self::GitLabUrl => "GitLab instance URL (e.g., https://gitlab.com)",
             Self::GitLabProjectId => "GitLab numeric project ID",
@@ -131,6 +169,10 @@ impl ConfigKey {
             Self::LinearUrl => "Linear workspace URL used by 'track open'",
             Self::LinearDefaultTeam => "Default Linear team key/name/id",
             Self::LinearDefaultProject => "Default Linear project association for issue create",
+            Self::GitBranchTemplate => {
+                "Branch name template for 'issue start --branch' (e.g., \"feature/{id}-{slug}\")"
+            }
+            Self::GitCommitPrefix => "Prefix the commit-msg hook prepends (e.g., \"{id}: \")",
         }
     }
 
@@ -155,61 +197,114 @@ impl ConfigKey {
                         value
                     )
                 })?;
-                cfg.backend = Some(backend);
+                cfg.connection.backend = Some(backend);
+            }
+            Self::Url => cfg.connection.url = Some(value.to_string()),
+            Self::Token => cfg.connection.token = Some(value.to_string()),
+            Self::Email => cfg.connection.email = Some(value.to_string()),
+            Self::DefaultProject => cfg.connection.default_project = Some(value.to_string()),
+            Self::YouTrackUrl => cfg.connection.youtrack.url = Some(value.to_string()),
+            Self::YouTrackToken => cfg.connection.youtrack.token = Some(value.to_string()),
+            Self::JiraUrl => cfg.connection.jira.url = Some(value.to_string()),
+            Self::JiraEmail => cfg.connection.jira.email = Some(value.to_string()),
+            Self::JiraToken => cfg.connection.jira.token = Some(value.to_string()),
+            Self::JiraDeployment => {
+                let deployment = match value.to_ascii_lowercase().as_str() {
+                    "cloud" => JiraDeployment::Cloud,
+                    "server" | "datacenter" | "data-center" | "dc" => JiraDeployment::Server,
+                    _ => anyhow::bail!(
+                        "Invalid Jira deployment '{}'. Valid: cloud, server (datacenter, dc)",
+                        value
+                    ),
+                };
+                cfg.connection.jira.deployment = Some(deployment);
+            }
+            Self::GitHubToken => cfg.connection.github.token = Some(value.to_string()),
+            Self::GitHubOwner => cfg.connection.github.owner = Some(value.to_string()),
+            Self::GitHubRepo => cfg.connection.github.repo = Some(value.to_string()),
+            Self::GitHubApiUrl => cfg.connection.github.api_url = Some(value.to_string()),
+            Self::GitHubProjectNumber => {
+                let number = value.parse().map_err(|_| {
+                    anyhow::anyhow!(
+                        "Invalid GitHub project number '{}': expected a number",
+                        value
+                    )
+                })?;
+                cfg.connection.github.project_number = Some(number);
+            }
+            Self::GitHubScope => {
+                let scope = match value.to_ascii_lowercase().as_str() {
+                    "repo" => GitHubScope::Repo,
+                    "org" | "organization" => GitHubScope::Org,
+                    _ => anyhow::bail!("Invalid GitHub scope '{}'. Valid: repo, org", value),
+                };
+                cfg.connection.github.scope = Some(scope);
             }
-            Self::Url => cfg.url = Some(value.to_string()),
-            Self::Token => cfg.token = Some(value.to_string()),
-            Self::Email => cfg.email = Some(value.to_string()),
-            Self::DefaultProject => cfg.default_project = Some(value.to_string()),
-            Self::YouTrackUrl => cfg.youtrack.url = Some(value.to_string()),
-            Self::YouTrackToken => cfg.youtrack.token = Some(value.to_string()),
-            Self::JiraUrl => cfg.jira.url = Some(value.to_string()),
-            Self::JiraEmail => cfg.jira.email = Some(value.to_string()),
-            Self::JiraToken => cfg.jira.token = Some(value.to_string()),
-            Self::GitHubToken => cfg.github.token = Some(value.to_string()),
-            Self::GitHubOwner => cfg.github.owner = Some(value.to_string()),
-            Self::GitHubRepo => cfg.github.repo = Some(value.to_string()),
-            Self::GitHubApiUrl => cfg.github.api_url = Some(value.to_string()),
-            Self::GitLabToken => cfg.gitlab.token = Some(value.to_string()),
-            Self::GitLabUrl => cfg.gitlab.url = Some(value.to_string()),
-            Self::GitLabProjectId => cfg.gitlab.project_id = Some(value.to_string()),
-            Self::GitLabNamespace => cfg.gitlab.namespace = Some(value.to_string()),
-            Self::LinearToken => cfg.linear.token = Some(value.to_string()),
-            Self::LinearApiUrl => cfg.linear.api_url = Some(value.to_string()),
-            Self::LinearUrl => cfg.linear.url = Some(value.to_string()),
-            Self::LinearDefaultTeam => cfg.linear.default_team = Some(value.to_string()),
+            Self::GitHubRepoTopic => cfg.connection.github.repo_topic = Some(value.to_string()),
+            Self::GitHubRepoPattern => cfg.connection.github.repo_pattern = Some(value.to_string()),
+            Self::GitLabToken => cfg.connection.gitlab.token = Some(value.to_string()),
+            Self::GitLabUrl => cfg.connection.gitlab.url = Some(value.to_string()),
+            Self::GitLabProjectId => cfg.connection.gitlab.project_id = Some(value.to_string()),
+            Self::GitLabNamespace => cfg.connection.gitlab.namespace = Some(value.to_string()),
+            Self::LinearToken => cfg.connection.linear.token = Some(value.to_string()),
+            Self::LinearApiUrl => cfg.connection.linear.api_url = Some(value.to_string()),
+            Self::LinearUrl => cfg.connection.linear.url = Some(value.to_string()),
+            Self::LinearDefaultTeam => cfg.connection.linear.default_team = Some(value.to_string()),
             Self::LinearDefaultProject => {
-                cfg.linear.default_linear_project = Some(value.to_string())
+                cfg.connection.linear.default_linear_project = Some(value.to_string())
             }
+            Self::GitBranchTemplate => cfg.git.branch_template = Some(value.to_string()),
+            Self::GitCommitPrefix => cfg.git.commit_prefix = Some(value.to_string()),
         }
         Ok(())
     }
 
     fn get_value(self, cfg: &Config) -> Option<String> {
         match self {
-            Self::Backend => cfg.backend.map(|b| b.to_string()),
-            Self::Url => cfg.url.clone(),
-            Self::Token => cfg.token.clone(),
-            Self::Email => cfg.email.clone(),
-            Self::DefaultProject => cfg.default_project.clone(),
-            Self::YouTrackUrl => cfg.youtrack.url.clone(),
-            Self::YouTrackToken => cfg.youtrack.token.clone(),
-            Self::JiraUrl => cfg.jira.url.clone(),
-            Self::JiraEmail => cfg.jira.email.clone(),
-            Self::JiraToken => cfg.jira.token.clone(),
-            Self::GitHubToken => cfg.github.token.clone(),
-            Self::GitHubOwner => cfg.github.owner.clone(),
-            Self::GitHubRepo => cfg.github.repo.clone(),
-            Self::GitHubApiUrl => cfg.github.api_url.clone(),
-            Self::GitLabToken => cfg.gitlab.token.clone(),
-            Self::GitLabUrl => cfg.gitlab.url.clone(),
-            Self::GitLabProjectId => cfg.gitlab.project_id.clone(),
-            Self::GitLabNamespace => cfg.gitlab.namespace.clone(),
-            Self::LinearToken => cfg.linear.token.clone(),
-            Self::LinearApiUrl => cfg.linear.api_url.clone(),
-            Self::LinearUrl => cfg.linear.url.clone(),
-            Self::LinearDefaultTeam => cfg.linear.default_team.clone(),
-            Self::LinearDefaultProject => cfg.linear.default_linear_project.clone(),
+            Self::Backend => cfg.connection.backend.map(|b| b.to_string()),
+            Self::Url => cfg.connection.url.clone(),
+            Self::Token => cfg.connection.token.clone(),
+            Self::Email => cfg.connection.email.clone(),
+            Self::DefaultProject => cfg.connection.default_project.clone(),
+            Self::YouTrackUrl => cfg.connection.youtrack.url.clone(),
+            Self::YouTrackToken => cfg.connection.youtrack.token.clone(),
+            Self::JiraUrl => cfg.connection.jira.url.clone(),
+            Self::JiraEmail => cfg.connection.jira.email.clone(),
+            Self::JiraToken => cfg.connection.jira.token.clone(),
+            Self::JiraDeployment => cfg.connection.jira.deployment.map(|d| {
+                match d {
+                    JiraDeployment::Cloud => "cloud",
+                    JiraDeployment::Server => "server",
+                }
+                .to_string()
+            }),
+            Self::GitHubToken => cfg.connection.github.token.clone(),
+            Self::GitHubOwner => cfg.connection.github.owner.clone(),
+            Self::GitHubRepo => cfg.connection.github.repo.clone(),
+            Self::GitHubApiUrl => cfg.connection.github.api_url.clone(),
+            Self::GitHubProjectNumber => {
+                cfg.connection.github.project_number.map(|n| n.to_string())
+            }
+            Self::GitHubScope => cfg.connection.github.scope.map(|s| {
+                match s {
+                    GitHubScope::Repo => "repo",
+                    GitHubScope::Org => "org",
+                }
+                .to_string()
+            }),
+            Self::GitHubRepoTopic => cfg.connection.github.repo_topic.clone(),
+            Self::GitHubRepoPattern => cfg.connection.github.repo_pattern.clone(),
+            Self::GitLabToken => cfg.connection.gitlab.token.clone(),
+            Self::GitLabUrl => cfg.connection.gitlab.url.clone(),
+            Self::GitLabProjectId => cfg.connection.gitlab.project_id.clone(),
+            Self::GitLabNamespace => cfg.connection.gitlab.namespace.clone(),
+            Self::LinearToken => cfg.connection.linear.token.clone(),
+            Self::LinearApiUrl => cfg.connection.linear.api_url.clone(),
+            Self::LinearUrl => cfg.connection.linear.url.clone(),
+            Self::LinearDefaultTeam => cfg.connection.linear.default_team.clone(),
+            Self::LinearDefaultProject => cfg.connection.linear.default_linear_project.clone(),
+            Self::GitBranchTemplate => cfg.git.branch_template.clone(),
+            Self::GitCommitPrefix => cfg.git.commit_prefix.clone(),
         }
     }
 }
@@ -518,17 +613,32 @@ pub fn handle_config_local(action: &cli::ConfigCommands, format: cli::OutputForm
                     // Top-level keys
                     show_value(
                         "backend",
-                        &global_cfg.backend.map(|b| b.to_string()),
-                        &project_cfg.backend.map(|b| b.to_string()),
+                        &global_cfg.connection.backend.map(|b| b.to_string()),
+                        &project_cfg.connection.backend.map(|b| b.to_string()),
+                        false,
+                    );
+                    show_value(
+                        "url",
+                        &global_cfg.connection.url,
+                        &project_cfg.connection.url,
+                        false,
+                    );
+                    show_value(
+                        "token",
+                        &global_cfg.connection.token,
+                        &project_cfg.connection.token,
+                        true,
+                    );
+                    show_value(
+                        "email",
+                        &global_cfg.connection.email,
+                        &project_cfg.connection.email,
                         false,
                     );
-                    show_value("url", &global_cfg.url, &project_cfg.url, false);
-                    show_value("token", &global_cfg.token, &project_cfg.token, true);
-                    show_value("email", &global_cfg.email, &project_cfg.email, false);
                     show_value(
                         "default_project",
-                        &global_cfg.default_project,
-                        &project_cfg.default_project,
+                        &global_cfg.connection.default_project,
+                        &project_cfg.connection.default_project,
                         false,
                     );
 
@@ -556,14 +666,14 @@ pub fn handle_config_local(action: &cli::ConfigCommands, format: cli::OutputForm
                         vec![
                             (
                                 "url",
-                                &global_cfg.youtrack.url,
-                                &project_cfg.youtrack.url,
+                                &global_cfg.connection.youtrack.url,
+                                &project_cfg.connection.youtrack.url,
                                 false,
                             ),
                             (
                                 "token",
-                                &global_cfg.youtrack.token,
-                                &project_cfg.youtrack.token,
+                                &global_cfg.connection.youtrack.token,
+                                &project_cfg.connection.youtrack.token,
                                 true,
                             ),
                         ],
@@ -571,19 +681,30 @@ pub fn handle_config_local(action: &cli::ConfigCommands, format: cli::OutputForm
                     show_backend_section(
                         "jira",
                         vec![
-                            ("url", &global_cfg.jira.url, &project_cfg.jira.url, false),
+                            (
+                                "url",
+                                &global_cfg.connection.jira.url,
+                                &project_cfg.connection.jira.url,
+                                false,
+                            ),
                             (
                                 "email",
-                                &global_cfg.jira.email,
-                                &project_cfg.jira.email,
+                                &global_cfg.connection.jira.email,
+                                &project_cfg.connection.jira.email,
                                 false,
                             ),
                             (
                                 "token",
-                                &global_cfg.jira.token,
-                                &project_cfg.jira.token,
+                                &global_cfg.connection.jira.token,
+                                &project_cfg.connection.jira.token,
                                 true,
                             ),
+                            (
+                                "deployment",
+                                &ConfigKey::JiraDeployment.get_value(&global_cfg),
+                                &ConfigKey::JiraDeployment.get_value(&project_cfg),
+                                false,
+                            ),
                         ],
                     );
                     show_backend_section(
@@ -591,26 +712,50 @@ pub fn handle_config_local(action: &cli::ConfigCommands, format: cli::OutputForm
                         vec![
                             (
                                 "token",
-                                &global_cfg.github.token,
-                                &project_cfg.github.token,
+                                &global_cfg.connection.github.token,
+                                &project_cfg.connection.github.token,
                                 true,
                             ),
                             (
                                 "owner",
-                                &global_cfg.github.owner,
-                                &project_cfg.github.owner,
+                                &global_cfg.connection.github.owner,
+                                &project_cfg.connection.github.owner,
                                 false,
                             ),
                             (
                                 "repo",
-                                &global_cfg.github.repo,
-                                &project_cfg.github.repo,
+                                &global_cfg.connection.github.repo,
+                                &project_cfg.connection.github.repo,
                                 false,
                             ),
                             (
                                 "api_url",
-                                &global_cfg.github.api_url,
-                                &project_cfg.github.api_url,
+                                &global_cfg.connection.github.api_url,
+                                &project_cfg.connection.github.api_url,
+                                false,
+                            ),
+                            (
+                                "project_number",
+                                &ConfigKey::GitHubProjectNumber.get_value(&global_cfg),
+                                &ConfigKey::GitHubProjectNumber.get_value(&project_cfg),
+                                false,
+                            ),
+                            (
+                                "scope",
+                                &ConfigKey::GitHubScope.get_value(&global_cfg),
+                                &ConfigKey::GitHubScope.get_value(&project_cfg),
+                                false,
+                            ),
+                            (
+                                "repo_topic",
+                                &global_cfg.connection.github.repo_topic,
+                                &project_cfg.connection.github.repo_topic,
+                                false,
+                            ),
+                            (
+                                "repo_pattern",
+                                &global_cfg.connection.github.repo_pattern,
+                                &project_cfg.connection.github.repo_pattern,
                                 false,
                             ),
                         ],
@@ -620,26 +765,26 @@ pub fn handle_config_local(action: &cli::ConfigCommands, format: cli::OutputForm
                         vec![
                             (
                                 "token",
-                                &global_cfg.gitlab.token,
-                                &project_cfg.gitlab.token,
+                                &global_cfg.connection.gitlab.token,
+                                &project_cfg.connection.gitlab.token,
                                 true,
                             ),
                             (
                                 "url",
-                                &global_cfg.gitlab.url,
-                                &project_cfg.gitlab.url,
+                                &global_cfg.connection.gitlab.url,
+                                &project_cfg.connection.gitlab.url,
                                 false,
                             ),
                             (
                                 "project_id",
-                                &global_cfg.gitlab.project_id,
-                                &project_cfg.gitlab.project_id,
+                                &global_cfg.connection.gitlab.project_id,
+                                &project_cfg.connection.gitlab.project_id,
                                 false,
                             ),
                             (
                                 "namespace",
-                                &global_cfg.gitlab.namespace,
-                                &project_cfg.gitlab.namespace,
+                                &global_cfg.connection.gitlab.namespace,
+                                &project_cfg.connection.gitlab.namespace,
                                 false,
                             ),
                         ],
@@ -649,32 +794,32 @@ pub fn handle_config_local(action: &cli::ConfigCommands, format: cli::OutputForm
                         vec![
                             (
                                 "token",
-                                &global_cfg.linear.token,
-                                &project_cfg.linear.token,
+                                &global_cfg.connection.linear.token,
+                                &project_cfg.connection.linear.token,
                                 true,
                             ),
                             (
                                 "api_url",
-                                &global_cfg.linear.api_url,
-                                &project_cfg.linear.api_url,
+                                &global_cfg.connection.linear.api_url,
+                                &project_cfg.connection.linear.api_url,
                                 false,
                             ),
                             (
                                 "url",
-                                &global_cfg.linear.url,
-                                &project_cfg.linear.url,
+                                &global_cfg.connection.linear.url,
+                                &project_cfg.connection.linear.url,
                                 false,
                             ),
                             (
                                 "default_team",
-                                &global_cfg.linear.default_team,
-                                &project_cfg.linear.default_team,
+                                &global_cfg.connection.linear.default_team,
+                                &project_cfg.connection.linear.default_team,
                                 false,
                             ),
                             (
                                 "default_linear_project",
-                                &global_cfg.linear.default_linear_project,
-                                &project_cfg.linear.default_linear_project,
+                                &global_cfg.connection.linear.default_linear_project,
+                                &project_cfg.connection.linear.default_linear_project,
                                 false,
                             ),
                         ],
@@ -696,8 +841,8 @@ pub fn handle_config_local(action: &cli::ConfigCommands, format: cli::OutputForm
             let level = if *global { "global" } else { "project" };
 
             if let Some(mut cfg) = loaded {
-                cfg.default_project = None;
-                cfg.backend = None;
+                cfg.connection.default_project = None;
+                cfg.connection.backend = None;
                 cfg.save(&config_path)?;
                 match format {
                     cli::OutputFormat::Json => {
@@ -823,7 +968,7 @@ pub fn handle_config(
         ConfigCommands::Test => {
             // Test connection by fetching current user info via projects list
             let projects = client.list_projects()?;
-            let url = config.url.as_deref().unwrap_or("unknown");
+            let url = config.connection.url.as_deref().unwrap_or("unknown");
 
             match format {
                 cli::OutputFormat::Json => {
@@ -1,7 +1,7 @@
 use crate::cache::{
     CachedBackendMetadata, CachedIssueCount, CachedLinkType, CachedProject, CachedQueryTemplate,
     CachedRecentIssue, CachedTag, ProjectFieldsCache, ProjectUsersCache, ProjectWorkflowHints,
-    TrackerCache,
+    TrackerCache, merge_query_templates,
 };
 use crate::cli::OutputFormat;
 use crate::output::output_json;
@@ -112,95 +112,19 @@ pub fn handle_context(
     backend_type: &str,
     base_url: &str,
     default_project: Option<&str>,
+    saved_queries: &[CachedQueryTemplate],
 ) -> Result<()> {
-    // Load or refresh cache
-    let mut cache = if refresh {
-        // Force refresh from API
-        let cache = TrackerCache::refresh(client, backend_type, base_url, default_project)
-            .context("Failed to refresh cache from API")?;
-        cache.save(None)?;
-        cache
-    } else {
-        // Try to load existing cache, refresh if empty
-        let mut loaded = TrackerCache::load(None).unwrap_or_default();
-        // Ensure we can assess emptiness correctly
-        let _ = loaded.ensure_projects();
-        if loaded.projects.is_empty() {
-            let cache = TrackerCache::refresh(client, backend_type, base_url, default_project)
-                .context("Failed to refresh cache from API")?;
-            cache.save(None)?;
-            cache
-        } else {
-            loaded
-        }
-    };
-
-    // Ensure full data for context output
-    cache.ensure_all_loaded()?;
-
-    // Build aggregated context
-    let mut context = AggregatedContext {
-        generated_at: chrono::Utc::now().to_rfc3339(),
-        backend: cache.backend_metadata.clone(),
-        default_project: default_project.map(|s| s.to_string()),
-        projects: cache.projects.clone(),
-        project_fields: cache.project_fields.clone(),
-        tags: cache.tags.clone(),
-        link_types: cache.link_types.clone(),
-        query_templates: cache.query_templates.clone(),
-        assignable_users: cache.project_users.clone(),
-        workflow_hints: cache.workflow_hints.clone(),
-        recent_issues: cache.recent_issues.clone(),
-        issue_counts: cache.issue_counts.clone(),
-        issues: None,
-    };
-
-    // Filter to specific project if requested
-    if let Some(proj) = project {
-        context
-            .projects
-            .retain(|p| p.short_name.eq_ignore_ascii_case(proj) || p.id == proj);
-        context
-            .project_fields
-            .retain(|pf| pf.project_short_name.eq_ignore_ascii_case(proj) || pf.project_id == proj);
-        context
-            .assignable_users
-            .retain(|pu| pu.project_short_name.eq_ignore_ascii_case(proj) || pu.project_id == proj);
-        context
-            .workflow_hints
-            .retain(|wh| wh.project_short_name.eq_ignore_ascii_case(proj) || wh.project_id == proj);
-        context
-            .recent_issues
-            .retain(|ri| ri.project_short_name.eq_ignore_ascii_case(proj));
-        context
-            .issue_counts
-            .retain(|ic| ic.project_short_name.eq_ignore_ascii_case(proj));
-    }
-
-    // Fetch unresolved issues if requested
-    if include_issues {
-        let target_project = project.or(default_project);
-        if let Some(proj) = target_project {
-            // Build query based on backend type
-            let query = match backend_type {
-                "jira" => format!("project = {} AND resolution IS EMPTY", proj),
-                "github" => format!("repo:{} is:issue state:open", proj),
-                "gitlab" => "state=opened".to_string(),
-                "linear" => format!("project: {} #Unresolved", proj),
-                _ => format!("project: {} #Unresolved", proj), // YouTrack default
-            };
-
-            match client.search_issues(&query, issue_limit, 0) {
-                Ok(result) => {
-                    context.issues = Some(result.items.iter().map(IssueSummary::from).collect());
-                }
-                Err(e) => {
-                    // Don't fail the whole command, just skip issues
-                    eprintln!("Warning: Failed to fetch issues: {}", e);
-                }
-            }
-        }
-    }
+    let context = build_context(
+        client,
+        project,
+        refresh,
+        include_issues,
+        issue_limit,
+        backend_type,
+        base_url,
+        default_project,
+        saved_queries,
+    )?;
 
     // Output
     match format {
@@ -429,3 +353,122 @@ pub fn handle_context(
 
     Ok(())
 }
+
+/// Load (or refresh) the cache and assemble the context, optionally with the
+/// project's unresolved issues.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn build_context(
+    client: &dyn IssueTracker,
+    project: Option<&str>,
+    refresh: bool,
+    include_issues: bool,
+    issue_limit: usize,
+    backend_type: &str,
+    base_url: &str,
+    default_project: Option<&str>,
+    saved_queries: &[CachedQueryTemplate],
+) -> Result<AggregatedContext> {
+    // Load or refresh cache
+    let mut cache = if refresh {
+        // Force refresh from API
+        let cache = TrackerCache::refresh(
+            client,
+            backend_type,
+            base_url,
+            default_project,
+            saved_queries,
+        )
+        .context("Failed to refresh cache from API")?;
+        cache.save(None)?;
+        cache
+    } else {
+        // Try to load existing cache, refresh if empty
+        let mut loaded = TrackerCache::load(None).unwrap_or_default();
+        // Ensure we can assess emptiness correctly
+        let _ = loaded.ensure_projects();
+        if loaded.projects.is_empty() {
+            let cache = TrackerCache::refresh(
+                client,
+                backend_type,
+                base_url,
+                default_project,
+                saved_queries,
+            )
+            .context("Failed to refresh cache from API")?;
+            cache.save(None)?;
+            cache
+        } else {
+            loaded
+        }
+    };
+
+    // Ensure full data for context output
+    cache.ensure_all_loaded()?;
+    merge_query_templates(&mut cache.query_templates, saved_queries);
+
+    // Build aggregated context
+    let mut context = AggregatedContext {
+        generated_at: chrono::Utc::now().to_rfc3339(),
+        backend: cache.backend_metadata.clone(),
+        default_project: default_project.map(|s| s.to_string()),
+        projects: cache.projects.clone(),
+        project_fields: cache.project_fields.clone(),
+        tags: cache.tags.clone(),
+        link_types: cache.link_types.clone(),
+        query_templates: cache.query_templates.clone(),
+        assignable_users: cache.project_users.clone(),
+        workflow_hints: cache.workflow_hints.clone(),
+        recent_issues: cache.recent_issues.clone(),
+        issue_counts: cache.issue_counts.clone(),
+        issues: None,
+    };
+
+    // Filter to specific project if requested
+    if let Some(proj) = project {
+        context
+            .projects
+            .retain(|p| p.short_name.eq_ignore_ascii_case(proj) || p.id == proj);
+        context
+            .project_fields
+            .retain(|pf| pf.project_short_name.eq_ignore_ascii_case(proj) || pf.project_id == proj);
+        context
+            .assignable_users
+            .retain(|pu| pu.project_short_name.eq_ignore_ascii_case(proj) || pu.project_id == proj);
+        context
+            .workflow_hints
+            .retain(|wh| wh.project_short_name.eq_ignore_ascii_case(proj) || wh.project_id == proj);
+        context
+            .recent_issues
+            .retain(|ri| ri.project_short_name.eq_ignore_ascii_case(proj));
+        context
+            .issue_counts
+            .retain(|ic| ic.project_short_name.eq_ignore_ascii_case(proj));
+    }
+
+    // Fetch unresolved issues if requested
+    if include_issues {
+        let target_project = project.or(default_project);
+        if let Some(proj) = target_project {
+            // Build query based on backend type
+            let query = match backend_type {
+                "jira" => format!("project = {} AND resolution IS EMPTY", proj),
+                "github" => format!("repo:{} is:issue state:open", proj),
+                "gitlab" => "state=opened".to_string(),
+                "linear" => format!("project: {} #Unresolved", proj),
+                _ => format!("project: {} #Unresolved", proj), // YouTrack default
+            };
+
+            match client.search_issues(&query, issue_limit, 0) {
+                Ok(result) => {
+                    context.issues = Some(result.items.iter().map(IssueSummary::from).collect());
+                }
+                Err(e) => {
+                    // Don't fail the whole command, just skip issues
+                    eprintln!("Warning: Failed to fetch issues: {}", e);
+                }
+            }
+        }
+    }
+
+    Ok(context)
+}
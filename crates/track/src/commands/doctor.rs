@@ -319,7 +319,7 @@ fn select_backends(cli: &Cli, all_backends: bool) -> Result<Vec<Backend>> {
     if all_backends {
         let raw = Config::load_raw(cli.config.clone())?;
         Ok(all_backends_selection(
-            raw.configured_backends(),
+            raw.connection.configured_backends(),
             cli.backend,
             effective_backend(cli),
         ))
@@ -387,7 +387,8 @@ fn audit_backend(
     let config = match Config::load(cli.config.clone(), backend) {
         Ok(mut c) => {
             if apply_cli_overrides {
-                c.merge_with_cli(cli.url.clone(), cli.token.clone());
+                c.connection
+                    .merge_overrides(cli.url.clone(), cli.token.clone());
             }
             Some(c)
         }
@@ -399,8 +400,10 @@ fn audit_backend(
 
     let config_info = ConfigInfo {
         source: config_source(cli),
-        url: config.as_ref().and_then(|c| c.url.clone()),
-        default_project: config.as_ref().and_then(|c| c.default_project.clone()),
+        url: config.as_ref().and_then(|c| c.connection.url.clone()),
+        default_project: config
+            .as_ref()
+            .and_then(|c| c.connection.default_project.clone()),
     };
 
     let Some(config) = config else {
@@ -413,7 +416,7 @@ fn audit_backend(
 
     // Check: config_valid (reuses Config::validate, which reports missing keys
     // without leaking secrets).
-    let config_valid = match config.validate(backend) {
+    let config_valid = match config.connection.validate(backend) {
         Ok(()) => {
             checks.push(CheckResult::ok("config_valid"));
             true
@@ -428,8 +431,8 @@ fn audit_backend(
     // works without real credentials, so remote checks proceed regardless of
     // config validity there.
     let client = if let Some(mock_dir) = tracker_mock::get_mock_dir() {
-        match tracker_mock::MockClient::new(&mock_dir) {
-            Ok(c) => Some(crate::BackendClient::Mock(c)),
+        match tracker_client::Client::mock(&mock_dir) {
+            Ok(c) => Some(c),
             Err(e) => {
                 checks.push(CheckResult::failed(
                     "auth_connectivity",
@@ -439,7 +442,7 @@ fn audit_backend(
             }
         }
     } else if config_valid {
-        match crate::build_client(backend, &config) {
+        match tracker_client::Client::build(backend, &config.connection) {
             Ok(c) => Some(c),
             Err(e) => {
                 checks.push(CheckResult::failed(
@@ -515,7 +518,7 @@ fn run_remote_checks(
     let mut target_project: Option<String> = opts
         .project
         .map(String::from)
-        .or_else(|| config.default_project.clone());
+        .or_else(|| config.connection.default_project.clone());
     let mut fallback_note = None;
     if target_project.is_none()
         && let Some(projects) = &projects
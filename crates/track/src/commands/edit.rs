@@ -0,0 +1,692 @@
+//! Editing issues and articles in `$EDITOR` as Markdown with YAML front
+//! matter, shared by `track issue edit` and `track article edit`.
+
+use super::apply_rollback::parent_from_links;
+use super::issue::{
+    build_custom_field_update, output_update_dry_run, state_field_name, validate_update,
+    verify_issue_update,
+};
+use crate::cli::OutputFormat;
+use crate::output::{output_result, output_verification_warnings};
+use anyhow::{Context, Result, bail};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tracker_core::{
+    CustomField, CustomFieldUpdate, Issue, IssueTracker, KnowledgeBase, ProjectCustomField,
+    UpdateArticle, UpdateIssue, unicode_eq_ignore_case,
+};
+
+const FRONT_MATTER_FENCE: &str = "---";
+
+/// Render `front` as YAML front matter followed by the Markdown `body`.
+pub(crate) fn render_document<T: Serialize>(front: &T, body: &str) -> Result<String> {
+    let yaml = serde_norway::to_string(front).context("Failed to render front matter")?;
+    let mut document = format!("{FRONT_MATTER_FENCE}\n{yaml}{FRONT_MATTER_FENCE}\n");
+    if !body.is_empty() {
+        document.push('\n');
+        document.push_str(body);
+        document.push('\n');
+    }
+    Ok(document)
+}
+
+/// Split an edited document back into its front matter and body.
+pub(crate) fn parse_document<T: DeserializeOwned>(document: &str) -> Result<(T, String)> {
+    let document = document.strip_prefix('\u{feff}').unwrap_or(document);
+    let Some(rest) = document.strip_prefix(FRONT_MATTER_FENCE).and_then(|rest| {
+        rest.strip_prefix('\n')
+            .or_else(|| rest.strip_prefix("\r\n"))
+    }) else {
+        bail!("The file must start with a '---' front matter block");
+    };
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == FRONT_MATTER_FENCE {
+            let front = serde_norway::from_str(&rest[..offset]).context("Invalid front matter")?;
+            let body = rest[offset + line.len()..].trim_matches(['\n', '\r']);
+            return Ok((front, body.to_string()));
+        }
+        offset += line.len();
+    }
+    bail!("The front matter block is not closed with '---'")
+}
+
+/// Front matter for `track issue edit`. `fields` holds the project's other
+/// custom fields; removing a key from it leaves that field untouched.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+struct IssueFrontMatter {
+    summary: String,
+    #[serde(default)]
+    state: Option<String>,
+    #[serde(default)]
+    priority: Option<String>,
+    #[serde(default)]
+    assignee: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    parent: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    fields: BTreeMap<String, serde_norway::Value>,
+}
+
+/// The changes an edited issue document makes. Tags and parent removal have
+/// no `UpdateIssue` form, so they are carried separately.
+#[derive(Debug, Default)]
+struct IssueEdit {
+    update: UpdateIssue,
+    removed_tags: Vec<String>,
+    remove_parent: bool,
+    warnings: Vec<String>,
+}
+
+impl IssueEdit {
+    fn has_update(&self) -> bool {
+        let update = &self.update;
+        update.summary.is_some()
+            || update.description.is_some()
+            || !update.custom_fields.is_empty()
+            || !update.tags.is_empty()
+            || update.parent.is_some()
+    }
+
+    fn is_empty(&self) -> bool {
+        !self.has_update() && self.removed_tags.is_empty() && !self.remove_parent
+    }
+}
+
+/// Open an issue in the editor and apply what the user changed.
+pub(crate) fn handle_issue_edit(
+    client: &dyn IssueTracker,
+    id: &str,
+    validate: bool,
+    dry_run: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let issue = client
+        .get_issue(id)
+        .with_context(|| format!("Failed to fetch issue '{}'", id))?;
+    let schema = client.get_project_custom_fields(&issue.project.id).ok();
+    let parent = client
+        .get_issue_links(id)
+        .ok()
+        .and_then(|links| parent_from_links(&links));
+    let (front, state_field) = issue_front_matter(&issue, schema.as_deref(), parent);
+    let document = render_document(&front, issue.description.as_deref().unwrap_or_default())?;
+
+    let (session, edited) = EditSession::open(id, &document)?;
+    let Some(edited) = edited else {
+        session.discard();
+        eprintln!("No changes to {}", id);
+        return Ok(());
+    };
+
+    session.finish(|| {
+        let (before, before_body) = parse_document::<IssueFrontMatter>(&document)?;
+        let (after, after_body) = parse_document::<IssueFrontMatter>(&edited)?;
+        let edit = diff_issue(
+            (&before, &before_body),
+            (&after, &after_body),
+            &state_field,
+            schema.as_deref(),
+        )?;
+        for warning in &edit.warnings {
+            eprintln!("Warning: {}", warning);
+        }
+        if edit.is_empty() {
+            eprintln!("No changes to {}", id);
+            return Ok(());
+        }
+
+        if dry_run {
+            let fields_validated = validate_update(client, id, &edit.update)?;
+            output_update_dry_run(id, fields_validated, format);
+            return Ok(());
+        }
+        if validate {
+            validate_update(client, id, &edit.update)?;
+        }
+        apply_issue_edit(client, id, &edit, &before_body, format)
+    })
+}
+
+fn apply_issue_edit(
+    client: &dyn IssueTracker,
+    id: &str,
+    edit: &IssueEdit,
+    original_description: &str,
+    format: OutputFormat,
+) -> Result<()> {
+    let mut updated = None;
+    if edit.has_update() {
+        let mut update = edit.update.clone();
+        if let Some(description) = update.description.as_deref() {
+            let scope = super::mentions::MentionScope::Issue(id);
+            update.description = Some(super::mentions::resolve_edited_text(
+                client,
+                scope,
+                original_description,
+                description,
+                format,
+            ));
+        }
+        let issue = client
+            .update_issue(id, &update)
+            .with_context(|| format!("Failed to update issue '{}'", id))?;
+        output_verification_warnings(&verify_issue_update(&update, &issue), format);
+        updated = Some(issue);
+    }
+    for tag in &edit.removed_tags {
+        client
+            .remove_issue_tag(id, tag)
+            .with_context(|| format!("Failed to remove tag '{}' from '{}'", tag, id))?;
+    }
+    if edit.remove_parent {
+        client
+            .remove_issue_parent(id)
+            .with_context(|| format!("Failed to remove parent of '{}'", id))?;
+    }
+
+    let issue = match updated {
+        Some(issue) if edit.removed_tags.is_empty() && !edit.remove_parent => issue,
+        _ => client
+            .get_issue(id)
+            .with_context(|| format!("Failed to fetch issue '{}'", id))?,
+    };
+    output_result(&issue, format)
+}
+
+/// Build the front matter for `issue`, returning it with the name of the
+/// project's state field.
+fn issue_front_matter(
+    issue: &Issue,
+    schema: Option<&[ProjectCustomField]>,
+    parent: Option<String>,
+) -> (IssueFrontMatter, String) {
+    let state_field = schema
+        .and_then(state_field_name)
+        .or_else(|| {
+            issue.custom_fields.iter().find_map(|field| match field {
+                CustomField::State { name, .. } => Some(name.as_str()),
+                _ => None,
+            })
+        })
+        .unwrap_or("State")
+        .to_string();
+    let find = |name: &str| {
+        issue
+            .custom_fields
+            .iter()
+            .find(|field| unicode_eq_ignore_case(field_name(field), name))
+    };
+    let text = |name: &str| {
+        find(name).and_then(|field| match field_yaml(field) {
+            Some(serde_norway::Value::String(value)) => Some(value),
+            _ => None,
+        })
+    };
+
+    let is_builtin = |name: &str| {
+        [state_field.as_str(), "Priority", "Assignee"]
+            .iter()
+            .any(|builtin| unicode_eq_ignore_case(builtin, name))
+    };
+    let names: Vec<&str> = match schema {
+        Some(schema) => schema.iter().map(|field| field.name.as_str()).collect(),
+        None => issue.custom_fields.iter().map(field_name).collect(),
+    };
+    let mut fields = BTreeMap::new();
+    for name in names.into_iter().filter(|name| !is_builtin(name)) {
+        let value = match find(name) {
+            Some(field) => field_yaml(field),
+            None => Some(serde_norway::Value::Null),
+        };
+        // Fields the backend could not type are left out: there is no way to
+        // write their raw value back.
+        if let Some(value) = value {
+            fields.insert(name.to_string(), value);
+        }
+    }
+
+    let front = IssueFrontMatter {
+        summary: issue.summary.clone(),
+        state: text(&state_field),
+        priority: text("Priority"),
+        assignee: text("Assignee"),
+        tags: issue.tags.iter().map(|tag| tag.name.clone()).collect(),
+        parent,
+        fields,
+    };
+    (front, state_field)
+}
+
+fn field_name(field: &CustomField) -> &str {
+    match field {
+        CustomField::SingleEnum { name, .. }
+        | CustomField::State { name, .. }
+        | CustomField::SingleUser { name, .. }
+        | CustomField::Text { name, .. }
+        | CustomField::MultiEnum { name, .. }
+        | CustomField::Unknown { name, .. } => name,
+    }
+}
+
+fn field_yaml(field: &CustomField) -> Option<serde_norway::Value> {
+    let text = |value: &Option<String>| {
+        value
+            .clone()
+            .map_or(serde_norway::Value::Null, serde_norway::Value::String)
+    };
+    match field {
+        CustomField::SingleEnum { value, .. }
+        | CustomField::Text { value, .. }
+        | CustomField::State { value, .. } => Some(text(value)),
+        CustomField::SingleUser { login, .. } => Some(text(login)),
+        CustomField::MultiEnum { values, .. } => Some(serde_norway::Value::Sequence(
+            values
+                .iter()
+                .cloned()
+                .map(serde_norway::Value::String)
+                .collect(),
+        )),
+        CustomField::Unknown { .. } => None,
+    }
+}
+
+/// Compute the minimal set of changes between the document as opened and as
+/// saved.
+fn diff_issue(
+    (before, before_body): (&IssueFrontMatter, &str),
+    (after, after_body): (&IssueFrontMatter, &str),
+    state_field: &str,
+    schema: Option<&[ProjectCustomField]>,
+) -> Result<IssueEdit> {
+    let mut edit = IssueEdit::default();
+
+    if after.summary != before.summary {
+        if after.summary.trim().is_empty() {
+            bail!("summary cannot be empty");
+        }
+        edit.update.summary = Some(after.summary.clone());
+    }
+    if after_body != before_body {
+        edit.update.description = Some(after_body.to_string());
+    }
+
+    let mut changed = |key: &str, before: &Option<String>, after: &Option<String>| {
+        let after = after.as_deref().map(str::trim).filter(|v| !v.is_empty());
+        if after == before.as_deref() {
+            return None;
+        }
+        if after.is_none() {
+            edit.warnings.push(format!(
+                "clearing '{}' is not supported; left unchanged",
+                key
+            ));
+        }
+        after.map(str::to_string)
+    };
+    let state = changed("state", &before.state, &after.state);
+    let priority = changed("priority", &before.priority, &after.priority);
+    let assignee = changed("assignee", &before.assignee, &after.assignee);
+    if let Some(value) = state {
+        edit.update.custom_fields.push(CustomFieldUpdate::State {
+            name: state_field.to_string(),
+            value,
+        });
+    }
+    if let Some(value) = priority {
+        edit.update
+            .custom_fields
+            .push(CustomFieldUpdate::SingleEnum {
+                name: "Priority".to_string(),
+                value,
+            });
+    }
+    if let Some(login) = assignee {
+        edit.update
+            .custom_fields
+            .push(CustomFieldUpdate::SingleUser {
+                name: "Assignee".to_string(),
+                login,
+            });
+    }
+
+    for (name, value) in &after.fields {
+        if before.fields.get(name) == Some(value) {
+            continue;
+        }
+        let (values, force_multi) = match value {
+            // An empty multi-value update clears the field.
+            serde_norway::Value::Null => (Vec::new(), true),
+            serde_norway::Value::String(text) if text.trim().is_empty() => (Vec::new(), true),
+            serde_norway::Value::Sequence(items) => (
+                items
+                    .iter()
+                    .map(|item| yaml_scalar(name, item))
+                    .collect::<Result<_>>()?,
+                true,
+            ),
+            scalar => (vec![yaml_scalar(name, scalar)?], false),
+        };
+        edit.update.custom_fields.push(build_custom_field_update(
+            name.clone(),
+            values,
+            force_multi,
+            schema,
+        ));
+    }
+
+    // Backends treat `tags` on update as the full tag set, so any addition
+    // sends the whole edited set (which also drops removed tags); removals
+    // alone go through `remove_issue_tag`.
+    if after.tags.iter().any(|tag| !before.tags.contains(tag)) {
+        for tag in &after.tags {
+            if !edit.update.tags.contains(tag) {
+                edit.update.tags.push(tag.clone());
+            }
+        }
+    } else {
+        for tag in &before.tags {
+            if !after.tags.contains(tag) {
+                edit.removed_tags.push(tag.clone());
+            }
+        }
+    }
+
+    let after_parent = after
+        .parent
+        .as_deref()
+        .map(str::trim)
+        .filter(|parent| !parent.is_empty());
+    if after_parent != before.parent.as_deref() {
+        match after_parent {
+            Some(parent) => edit.update.parent = Some(parent.to_string()),
+            None => edit.remove_parent = true,
+        }
+    }
+
+    Ok(edit)
+}
+
+fn yaml_scalar(field: &str, value: &serde_norway::Value) -> Result<String> {
+    match value {
+        serde_norway::Value::String(text) => Ok(text.clone()),
+        serde_norway::Value::Number(number) => Ok(number.to_string()),
+        serde_norway::Value::Bool(flag) => Ok(flag.to_string()),
+        _ => bail!("Field '{}' must be a value or a list of values", field),
+    }
+}
+
+/// Front matter for `track article edit`.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct ArticleFrontMatter {
+    summary: String,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// Open an article in the editor and apply what the user changed.
+pub(crate) fn handle_article_edit(
+    client: &dyn KnowledgeBase,
+    id: &str,
+    format: OutputFormat,
+) -> Result<()> {
+    let article = client
+        .get_article(id)
+        .with_context(|| format!("Failed to fetch article '{}'", id))?;
+    let front = ArticleFrontMatter {
+        summary: article.summary.clone(),
+        tags: article.tags.iter().map(|tag| tag.name.clone()).collect(),
+    };
+    let document = render_document(&front, article.content.as_deref().unwrap_or_default())?;
+
+    let (session, edited) = EditSession::open(id, &document)?;
+    let Some(edited) = edited else {
+        session.discard();
+        eprintln!("No changes to {}", id);
+        return Ok(());
+    };
+
+    session.finish(|| {
+        let (before, before_body) = parse_document::<ArticleFrontMatter>(&document)?;
+        let (after, after_body) = parse_document::<ArticleFrontMatter>(&edited)?;
+        let Some(update) = diff_article((&before, &before_body), (&after, &after_body))? else {
+            eprintln!("No changes to {}", id);
+            return Ok(());
+        };
+        let article = client
+            .update_article(id, &update)
+            .with_context(|| format!("Failed to update article '{}'", id))?;
+        output_result(&article, format)
+    })
+}
+
+fn diff_article(
+    (before, before_body): (&ArticleFrontMatter, &str),
+    (after, after_body): (&ArticleFrontMatter, &str),
+) -> Result<Option<UpdateArticle>> {
+    let mut update = UpdateArticle::default();
+    if after.summary != before.summary {
+        if after.summary.trim().is_empty() {
+            bail!("summary cannot be empty");
+        }
+        update.summary = Some(after.summary.clone());
+    }
+    if after_body != before_body {
+        update.content = Some(after_body.to_string());
+    }
+    // Article updates replace the tag set, but an empty set means "unchanged".
+    if after.tags != before.tags {
+        if after.tags.is_empty() {
+            bail!("Removing every tag from an article is not supported");
+        }
+        update.tags = after.tags.clone();
+    }
+
+    let changed = update.summary.is_some() || update.content.is_some() || !update.tags.is_empty();
+    Ok(changed.then_some(update))
+}
+
+/// The edit session for one document: a temp file the user's editor opens.
+/// The file is kept when something goes wrong so the edits are not lost.
+pub(crate) struct EditSession {
+    path: PathBuf,
+}
+
+impl EditSession {
+    /// Write `document` to a temp file named after `id` and open it in the
+    /// editor. Returns `None` if the user saved no changes.
+    pub(crate) fn open(id: &str, document: &str) -> Result<(Self, Option<String>)> {
+        let name: String = id
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect();
+        let path =
+            std::env::temp_dir().join(format!("track-edit-{}-{}.md", name, std::process::id()));
+        std::fs::write(&path, document)
+            .with_context(|| format!("Failed to write '{}'", path.display()))?;
+        let session = Self { path };
+
+        run_editor(&session.path)?;
+        let edited = std::fs::read_to_string(&session.path)
+            .with_context(|| format!("Failed to read '{}'", session.path.display()))?;
+        let changed = (edited != document).then_some(edited);
+        Ok((session, changed))
+    }
+
+    /// Run `apply` on the edits; on failure, point the user at the saved file.
+    pub(crate) fn finish<R>(self, apply: impl FnOnce() -> Result<R>) -> Result<R> {
+        match apply() {
+            Ok(result) => {
+                let _ = std::fs::remove_file(&self.path);
+                Ok(result)
+            }
+            Err(err) => {
+                Err(err.context(format!("Your edits are saved in '{}'", self.path.display())))
+            }
+        }
+    }
+
+    pub(crate) fn discard(self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// `$VISUAL`, then `$EDITOR`, then the platform default.
+fn editor_command() -> String {
+    ["VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| {
+            if cfg!(windows) {
+                "notepad".to_string()
+            } else {
+                "vi".to_string()
+            }
+        })
+}
+
+fn run_editor(path: &Path) -> Result<()> {
+    let editor = editor_command();
+    let path_arg = path.to_string_lossy();
+    let quoted = shlex::try_quote(&path_arg).context("Temp file path cannot be quoted")?;
+    // The editor value may carry arguments (e.g. "code --wait"), so it runs
+    // through the shell like git does.
+    let status = super::shell_command(&format!("{editor} {quoted}"))
+        .status()
+        .with_context(|| format!("Failed to start editor '{}'", editor))?;
+    if !status.success() {
+        bail!("Editor '{}' exited with {}", editor, status);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Front {
+        summary: String,
+        #[serde(default)]
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn documents_round_trip_front_matter_and_body() {
+        let front = Front {
+            summary: "Crash: on start".to_string(),
+            tags: vec!["bug".to_string()],
+        };
+        let document = render_document(&front, "## Steps\n\n---\n\n1. Open").unwrap();
+        assert!(document.starts_with("---\nsummary: 'Crash: on start'\n"));
+
+        let (parsed, body): (Front, String) = parse_document(&document).unwrap();
+        assert_eq!(parsed, front);
+        assert_eq!(body, "## Steps\n\n---\n\n1. Open");
+    }
+
+    #[test]
+    fn issue_diff_contains_only_what_changed() {
+        let before = IssueFrontMatter {
+            summary: "Login fails".to_string(),
+            state: Some("Open".to_string()),
+            priority: Some("Normal".to_string()),
+            assignee: Some("jane".to_string()),
+            tags: vec!["backend".to_string(), "api".to_string()],
+            parent: Some("PROJ-9".to_string()),
+            fields: BTreeMap::from([
+                ("Type".to_string(), serde_norway::Value::from("Bug")),
+                ("Labels".to_string(), serde_norway::Value::from(vec!["a"])),
+                ("Estimate".to_string(), serde_norway::Value::from("2d")),
+            ]),
+        };
+        let mut after = before.clone();
+        after.priority = Some("High".to_string());
+        after.assignee = None;
+        after.tags = vec!["backend".to_string(), "frontend".to_string()];
+        after.parent = None;
+        after.fields.remove("Type");
+        after.fields.insert(
+            "Labels".to_string(),
+            serde_norway::Value::from(vec!["a", "b"]),
+        );
+        after
+            .fields
+            .insert("Estimate".to_string(), serde_norway::Value::Null);
+
+        let edit = diff_issue((&before, "Body"), (&after, "Body"), "Stage", None).unwrap();
+
+        assert!(edit.update.summary.is_none());
+        assert!(edit.update.description.is_none());
+        assert_eq!(edit.update.custom_fields.len(), 3);
+        assert!(matches!(
+            &edit.update.custom_fields[0],
+            CustomFieldUpdate::SingleEnum { name, value } if name == "Priority" && value == "High"
+        ));
+        assert!(matches!(
+            &edit.update.custom_fields[1],
+            CustomFieldUpdate::MultiEnum { name, values } if name == "Estimate" && values.is_empty()
+        ));
+        assert!(matches!(
+            &edit.update.custom_fields[2],
+            CustomFieldUpdate::MultiEnum { name, values } if name == "Labels" && values == &["a", "b"]
+        ));
+        assert_eq!(edit.update.tags, vec!["backend", "frontend"]);
+        assert!(edit.removed_tags.is_empty());
+        assert!(edit.remove_parent);
+        assert_eq!(edit.warnings.len(), 1);
+        assert!(edit.warnings[0].contains("assignee"));
+
+        let mut untagged = before.clone();
+        untagged.tags = vec!["api".to_string()];
+        let edit = diff_issue((&before, "Body"), (&untagged, "Body"), "Stage", None).unwrap();
+        assert!(edit.update.tags.is_empty());
+        assert_eq!(edit.removed_tags, vec!["backend"]);
+
+        let unchanged = diff_issue((&before, "Body"), (&before, "Body"), "Stage", None).unwrap();
+        assert!(unchanged.is_empty());
+    }
+
+    #[test]
+    fn article_diff_replaces_tags_and_rejects_empty_summary() {
+        let before = ArticleFrontMatter {
+            summary: "Runbook".to_string(),
+            tags: vec!["ops".to_string()],
+        };
+        let after = ArticleFrontMatter {
+            summary: "Runbook".to_string(),
+            tags: vec!["ops".to_string(), "oncall".to_string()],
+        };
+        let update = diff_article((&before, "Old"), (&after, "New"))
+            .unwrap()
+            .unwrap();
+        assert!(update.summary.is_none());
+        assert_eq!(update.content.as_deref(), Some("New"));
+        assert_eq!(update.tags, vec!["ops", "oncall"]);
+
+        assert!(
+            diff_article((&before, "Old"), (&before, "Old"))
+                .unwrap()
+                .is_none()
+        );
+        let blank = ArticleFrontMatter {
+            summary: " ".to_string(),
+            tags: before.tags.clone(),
+        };
+        assert!(diff_article((&before, "Old"), (&blank, "Old")).is_err());
+    }
+
+    #[test]
+    fn parse_rejects_missing_or_unclosed_front_matter() {
+        let err = parse_document::<Front>("summary: x\n").unwrap_err();
+        assert!(err.to_string().contains("must start with"));
+        let err = parse_document::<Front>("---\nsummary: x\n").unwrap_err();
+        assert!(err.to_string().contains("not closed"));
+    }
+}
@@ -0,0 +1,217 @@
+//! Command aliases and external `track-<name>` plugins.
+//!
+//! Anything clap doesn't recognise lands in [`Commands::External`]. Before
+//! falling back to the issue-ID shortcut, the first word is checked against
+//! the `[aliases]` table (expanded git-style, then re-parsed) and then against
+//! `track-<name>` executables on `PATH`.
+
+use crate::cache::TrackerCache;
+use crate::cli::{Backend, Cli, Commands, OutputFormat};
+use crate::config::Config;
+use anyhow::{Context, Result, anyhow};
+use clap::Parser;
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Parse the process arguments, expanding any `[aliases]` entry used as the
+/// subcommand. Aliases may expand to other aliases; cycles are an error.
+pub fn parse_cli() -> Result<Cli> {
+    let mut argv: Vec<OsString> = std::env::args_os().collect();
+    let mut cli = Cli::parse_from(&argv);
+    let mut aliases = None;
+    let mut expanded: Vec<String> = Vec::new();
+
+    while let Commands::External(args) = &cli.command
+        && let Some(name) = args.first()
+    {
+        let aliases = aliases.get_or_insert_with(|| {
+            Config::load_raw(cli.config.clone())
+                .map(|config| config.aliases)
+                .unwrap_or_default()
+        });
+        let Some(expansion) = aliases.get(name) else {
+            break;
+        };
+        if expanded.contains(name) {
+            expanded.push(name.clone());
+            return Err(anyhow!("alias loop: {}", expanded.join(" -> ")));
+        }
+
+        let words = shlex::split(expansion)
+            .ok_or_else(|| anyhow!("alias '{}' has unbalanced quotes", name))?;
+        if words.is_empty() {
+            return Err(anyhow!("alias '{}' is empty", name));
+        }
+
+        argv = splice_alias(&argv, args.len(), words);
+        expanded.push(name.clone());
+        cli = Cli::parse_from(&argv);
+    }
+
+    Ok(cli)
+}
+
+/// Replace the alias name with its expansion. The external subcommand's
+/// arguments are always the tail of `argv`, so everything before them
+/// (global flags) is kept as-is.
+fn splice_alias(argv: &[OsString], external_len: usize, words: Vec<String>) -> Vec<OsString> {
+    let split = argv.len() - external_len;
+    argv[..split]
+        .iter()
+        .cloned()
+        .chain(words.into_iter().map(OsString::from))
+        .chain(argv[split + 1..].iter().cloned())
+        .collect()
+}
+
+/// Find a `track-<name>` executable on `PATH`.
+pub fn find_plugin(name: &str) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    find_plugin_in(name, &path)
+}
+
+fn find_plugin_in(name: &str, path: &OsStr) -> Option<PathBuf> {
+    if name.is_empty() || name.starts_with('-') || name.contains(['/', '\\']) {
+        return None;
+    }
+    let file_name = format!("track-{}{}", name, std::env::consts::EXE_SUFFIX);
+    std::env::split_paths(path)
+        .map(|dir| dir.join(&file_name))
+        .find(|candidate| is_executable(candidate))
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path)
+        .map(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+/// Connection context handed to a plugin.
+pub struct PluginContext<'a> {
+    pub backend: Backend,
+    pub config: &'a Config,
+    pub config_path: Option<&'a Path>,
+    pub format: OutputFormat,
+}
+
+/// Run a plugin with the remaining arguments and the resolved connection
+/// context in `TRACK_*` environment variables. A non-zero plugin exit code
+/// becomes track's exit code.
+pub fn run_plugin(plugin: &Path, args: &[String], ctx: PluginContext<'_>) -> Result<()> {
+    let mut cmd = Command::new(plugin);
+    cmd.args(args.iter().skip(1))
+        .env("TRACK_BACKEND", ctx.backend.to_string())
+        .env(
+            "TRACK_FORMAT",
+            match ctx.format {
+                OutputFormat::Text => "text",
+                OutputFormat::Json => "json",
+            },
+        );
+
+    if let Ok(exe) = std::env::current_exe() {
+        cmd.env("TRACK_BIN", exe);
+    }
+    if let Some(path) = ctx.config_path {
+        cmd.env("TRACK_CONFIG", path);
+    }
+    if let Some(url) = &ctx.config.connection.url {
+        cmd.env("TRACK_URL", url);
+    }
+    if let Some(project) = &ctx.config.connection.default_project {
+        cmd.env("TRACK_DEFAULT_PROJECT", project);
+    }
+    if let Ok(dir) = TrackerCache::resolved_cache_dir() {
+        let dir = std::env::current_dir()
+            .map(|cwd| cwd.join(&dir))
+            .unwrap_or(dir);
+        cmd.env("TRACK_CACHE_DIR", dir);
+    }
+    if ctx.config.plugins.pass_token
+        && let Some(token) = &ctx.config.connection.token
+    {
+        cmd.env("TRACK_TOKEN", token);
+    }
+
+    let status = cmd
+        .status()
+        .with_context(|| format!("Failed to run plugin '{}'", plugin.display()))?;
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn os_args(args: &[&str]) -> Vec<OsString> {
+        args.iter().map(OsString::from).collect()
+    }
+
+    #[test]
+    fn splice_alias_keeps_global_flags_and_trailing_args() {
+        let argv = os_args(&["track", "-o", "json", "mine", "--limit", "5"]);
+        let cli = Cli::parse_from(&argv);
+        let Commands::External(args) = &cli.command else {
+            panic!("expected external subcommand");
+        };
+
+        let spliced = splice_alias(
+            &argv,
+            args.len(),
+            vec![
+                "issue".into(),
+                "search".into(),
+                "-T".into(),
+                "my_open".into(),
+            ],
+        );
+        assert_eq!(
+            spliced,
+            os_args(&[
+                "track", "-o", "json", "issue", "search", "-T", "my_open", "--limit", "5"
+            ])
+        );
+        assert!(matches!(
+            Cli::parse_from(&spliced).command,
+            Commands::Issue { .. }
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn find_plugin_in_requires_executable_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let first = tempfile::TempDir::new().unwrap();
+        let second = tempfile::TempDir::new().unwrap();
+        let plain = first.path().join("track-hello");
+        std::fs::write(&plain, "#!/bin/sh\n").unwrap();
+        let script = second.path().join("track-hello");
+        std::fs::write(&script, "#!/bin/sh\n").unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let path = std::env::join_paths([first.path(), second.path()]).unwrap();
+        assert_eq!(find_plugin_in("hello", &path), Some(script));
+        assert_eq!(find_plugin_in("missing", &path), None);
+    }
+
+    #[test]
+    fn find_plugin_in_rejects_path_like_names() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().as_os_str();
+        assert_eq!(find_plugin_in("../hello", path), None);
+        assert_eq!(find_plugin_in("--help", path), None);
+        assert_eq!(find_plugin_in("", path), None);
+    }
+}
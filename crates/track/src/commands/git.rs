@@ -0,0 +1,555 @@
+//! Git integration: branch-per-issue workflow, issue inference from the
+//! current branch name, and the commit-msg hook.
+
+use anyhow::{Context, Result, anyhow};
+use clap::ValueEnum;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tracker_core::{Issue, IssueTracker};
+
+use crate::cli::{CommitMsgMode, GitCommands, GitHookCommands, OutputFormat};
+use crate::config::GitConfig;
+use crate::output::output_json;
+
+/// Branch template used when `[git] branch_template` isn't set.
+pub const DEFAULT_BRANCH_TEMPLATE: &str = "feature/{id}-{slug}";
+
+/// Commit prefix used when `[git] commit_prefix` isn't set.
+pub const DEFAULT_COMMIT_PREFIX: &str = "{id}: ";
+
+/// Marks hooks written by `track git hook install`, so re-installs can
+/// overwrite them without `--force`.
+const HOOK_MARKER: &str = "# Installed by track (track git hook install)";
+
+/// Slugs are cut at a word boundary once they exceed this many characters.
+const SLUG_MAX_LEN: usize = 40;
+
+/// Commit messages git generates or rewrites itself, left untouched by the hook.
+const SKIPPED_MESSAGE_PREFIXES: [&str; 4] = ["Merge ", "fixup! ", "squash! ", "amend! "];
+
+fn branch_template(config: &GitConfig) -> &str {
+    config
+        .branch_template
+        .as_deref()
+        .unwrap_or(DEFAULT_BRANCH_TEMPLATE)
+}
+
+fn commit_prefix(config: &GitConfig) -> &str {
+    config
+        .commit_prefix
+        .as_deref()
+        .unwrap_or(DEFAULT_COMMIT_PREFIX)
+}
+
+/// Run git and return its trimmed stdout.
+pub(crate) fn git(args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Name of the checked-out branch, or `None` outside a repository or on a
+/// detached HEAD.
+pub fn current_branch() -> Option<String> {
+    git(&["symbolic-ref", "--quiet", "--short", "HEAD"])
+        .ok()
+        .filter(|branch| !branch.is_empty())
+}
+
+/// Issue ID of the current branch, if its name contains one.
+pub fn current_issue_id(config: &GitConfig) -> Option<String> {
+    current_branch().and_then(|branch| issue_id_from_branch(&branch, branch_template(config)))
+}
+
+/// Use `id` if given, otherwise the issue ID from the current branch name.
+pub fn resolve_issue_id(id: Option<&str>, config: &GitConfig) -> Result<String> {
+    match id {
+        Some(id) => Ok(id.to_string()),
+        None => current_issue_id(config).ok_or_else(no_branch_issue_error),
+    }
+}
+
+/// Use `ids` if non-empty, otherwise the issue ID from the current branch name.
+pub fn resolve_issue_ids(ids: &[String], config: &GitConfig) -> Result<Vec<String>> {
+    if !ids.is_empty() {
+        return Ok(ids.to_vec());
+    }
+    Ok(vec![resolve_issue_id(None, config)?])
+}
+
+fn no_branch_issue_error() -> anyhow::Error {
+    match current_branch() {
+        Some(branch) => anyhow!(
+            "No issue ID given and none found in the current branch name '{}'",
+            branch
+        ),
+        None => anyhow!("No issue ID given and not on a git branch to infer one from"),
+    }
+}
+
+/// Extract an issue ID from a branch name.
+///
+/// When the branch starts with the template's literal text before `{id}`
+/// (e.g. `feature/`), the ID is read from that position, which also covers
+/// numeric GitHub/GitLab IDs and GitHub's `repo_123` (for `repo#123`).
+/// Otherwise the first `KEY-123` token anywhere in the name is used. Keys
+/// are upper-cased.
+pub fn issue_id_from_branch(branch: &str, template: &str) -> Option<String> {
+    if let Some((prefix, _)) = template.split_once("{id}")
+        && !prefix.contains('{')
+        && let Some(rest) = branch.strip_prefix(prefix)
+    {
+        if let Some(key) = leading_issue_key(rest) {
+            return Some(key);
+        }
+        if let Some(id) = leading_repo_issue(rest) {
+            return Some(id);
+        }
+        let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+        if !digits.is_empty() && !rest[digits.len()..].starts_with(|c: char| c.is_alphanumeric()) {
+            return Some(digits);
+        }
+    }
+    find_issue_key(branch)
+}
+
+/// Parse a `KEY-123` token at the start of `text`.
+fn leading_issue_key(text: &str) -> Option<String> {
+    if !text.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let key_len = text
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(text.len());
+    let rest = text[key_len..].strip_prefix('-')?;
+    let num_len = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    if num_len == 0 || rest[num_len..].starts_with(|c: char| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+    Some(format!(
+        "{}-{}",
+        text[..key_len].to_ascii_uppercase(),
+        &rest[..num_len]
+    ))
+}
+
+/// Parse a `repo_123` token at the start of `text` back into the GitHub ID
+/// `repo#123` it was written from (see [`branch_name`]).
+fn leading_repo_issue(text: &str) -> Option<String> {
+    let name_len = text
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(text.len());
+    let name = &text[..name_len];
+    name.match_indices('_').find_map(|(i, _)| {
+        let (repo, rest) = (&name[..i], &name[i + 1..]);
+        let num_len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let whole = !rest[num_len..].starts_with(|c: char| c.is_ascii_alphanumeric());
+        (!repo.is_empty() && num_len > 0 && whole).then(|| format!("{}#{}", repo, &rest[..num_len]))
+    })
+}
+
+/// Find the first `KEY-123` token in `text`.
+fn find_issue_key(text: &str) -> Option<String> {
+    find_issue_keys(text).into_iter().next()
+}
+
+/// Find every `KEY-123` token in `text`, in order of appearance.
+pub(crate) fn find_issue_keys(text: &str) -> Vec<String> {
+    let mut keys = Vec::new();
+    let mut prev_alnum = false;
+    for (i, c) in text.char_indices() {
+        if !prev_alnum && let Some(key) = leading_issue_key(&text[i..]) {
+            keys.push(key);
+        }
+        prev_alnum = c.is_alphanumeric();
+    }
+    keys
+}
+
+/// Whether `text` references `id` as a whole key, so `PROJ-1` is not found
+/// in `PROJ-12` and a numeric `42` not in `142`.
+fn references_issue(text: &str, id: &str) -> bool {
+    if let Some(key) = leading_issue_key(id) {
+        return find_issue_keys(text).contains(&key);
+    }
+    text.match_indices(id).any(|(i, _)| {
+        !text[..i].ends_with(|c: char| c.is_alphanumeric())
+            && !text[i + id.len()..].starts_with(|c: char| c.is_alphanumeric())
+    })
+}
+
+/// Build a branch name for `issue` from a template with `{id}`, `{slug}`
+/// and `{project}` placeholders.
+pub fn branch_name(template: &str, issue: &Issue) -> String {
+    // GitLab readable IDs look like "#12" and GitHub ones like
+    // "owner/repo#12"; the repository is kept as "repo_12" so org-scoped
+    // issues can be inferred from the branch again
+    let id = match issue.id_readable.rsplit_once('#') {
+        Some((path, number)) => match path.rsplit('/').next().filter(|repo| !repo.is_empty()) {
+            Some(repo) => format!("{}_{}", repo, number),
+            None => number.to_string(),
+        },
+        None => issue.id_readable.clone(),
+    };
+    template
+        .replace("{id}", &sanitize_ref_part(&id))
+        .replace("{slug}", &slugify(&issue.summary))
+        .replace(
+            "{project}",
+            &sanitize_ref_part(issue.project.short_name.as_deref().unwrap_or_default()),
+        )
+}
+
+fn sanitize_ref_part(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        .collect()
+}
+
+/// Lower-case `text`, join its words with `-`, and cut it at a word
+/// boundary after [`SLUG_MAX_LEN`] characters.
+pub(crate) fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    for word in text
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        if !slug.is_empty() && slug.len() + word.len() + 1 > SLUG_MAX_LEN {
+            break;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(&word.to_ascii_lowercase());
+    }
+    slug.truncate(SLUG_MAX_LEN);
+    slug
+}
+
+/// Create (or switch to) the branch for a single issue, for `issue start --branch`.
+pub fn start_issue_branch(
+    client: &dyn IssueTracker,
+    ids: &[String],
+    config: &GitConfig,
+) -> Result<()> {
+    let [id] = ids else {
+        return Err(anyhow!("--branch needs exactly one issue ID"));
+    };
+    let issue = client
+        .get_issue(id)
+        .with_context(|| format!("Failed to fetch issue '{}'", id))?;
+    let name = branch_name(branch_template(config), &issue);
+
+    if current_branch().as_deref() == Some(name.as_str()) {
+        eprintln!("Already on branch '{}'", name);
+        return Ok(());
+    }
+    let exists = git(&[
+        "rev-parse",
+        "--verify",
+        "--quiet",
+        &format!("refs/heads/{}", name),
+    ])
+    .is_ok();
+    if exists {
+        git(&["checkout", &name])?;
+        eprintln!("Switched to branch '{}'", name);
+    } else {
+        git(&["checkout", "-b", &name])?;
+        eprintln!("Switched to a new branch '{}'", name);
+    }
+    Ok(())
+}
+
+/// Handle `track git` commands. None of them need a backend connection.
+pub fn handle_git(action: &GitCommands, config: &GitConfig, format: OutputFormat) -> Result<()> {
+    match action {
+        GitCommands::Hook {
+            action: GitHookCommands::Install { mode, force },
+        } => install_hook(*mode, *force, format),
+        GitCommands::CommitMsg { file, mode } => handle_commit_msg(file, *mode, config),
+    }
+}
+
+fn mode_name(mode: CommitMsgMode) -> String {
+    mode.to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default()
+}
+
+fn install_hook(mode: CommitMsgMode, force: bool, format: OutputFormat) -> Result<()> {
+    // --git-path honours core.hooksPath and linked worktrees
+    let hooks_dir = PathBuf::from(git(&["rev-parse", "--git-path", "hooks"])?);
+    let hook = hooks_dir.join("commit-msg");
+
+    if hook.exists() && !force {
+        let existing = fs::read_to_string(&hook).unwrap_or_default();
+        if !existing.contains(HOOK_MARKER) {
+            return Err(anyhow!(
+                "A commit-msg hook already exists at {}. Use --force to replace it.",
+                hook.display()
+            ));
+        }
+    }
+
+    let mode = mode_name(mode);
+    let script = format!(
+        "#!/bin/sh\n{}\ncommand -v track >/dev/null 2>&1 || exit 0\nexec track git commit-msg --mode {} \"$1\"\n",
+        HOOK_MARKER, mode
+    );
+    fs::create_dir_all(&hooks_dir)
+        .with_context(|| format!("Failed to create {}", hooks_dir.display()))?;
+    fs::write(&hook, script).with_context(|| format!("Failed to write {}", hook.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&hook, fs::Permissions::from_mode(0o755))
+            .with_context(|| format!("Failed to make {} executable", hook.display()))?;
+    }
+
+    match format {
+        OutputFormat::Json => output_json(&serde_json::json!({
+            "success": true,
+            "hook": hook.display().to_string(),
+            "mode": mode,
+        }))?,
+        OutputFormat::Text => {
+            use colored::Colorize;
+            println!(
+                "Installed commit-msg hook at {} (mode: {})",
+                hook.display().to_string().cyan(),
+                mode
+            );
+        }
+    }
+    Ok(())
+}
+
+fn handle_commit_msg(file: &Path, mode: CommitMsgMode, config: &GitConfig) -> Result<()> {
+    let message = fs::read_to_string(file)
+        .with_context(|| format!("Failed to read commit message '{}'", file.display()))?;
+    let branch_id = current_issue_id(config);
+    match apply_commit_msg_rule(&message, branch_id.as_deref(), mode, commit_prefix(config))? {
+        Some(updated) => fs::write(file, updated)
+            .with_context(|| format!("Failed to write commit message '{}'", file.display())),
+        None => Ok(()),
+    }
+}
+
+/// Check or prefix a commit message. Returns the rewritten message when it
+/// changed. Comment lines are ignored, as git strips them.
+fn apply_commit_msg_rule(
+    message: &str,
+    branch_id: Option<&str>,
+    mode: CommitMsgMode,
+    prefix: &str,
+) -> Result<Option<String>> {
+    let content: Vec<&str> = message
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .collect();
+    let body = content.join("\n");
+    if body.trim().is_empty()
+        || SKIPPED_MESSAGE_PREFIXES
+            .iter()
+            .any(|skip| body.trim_start().starts_with(skip))
+    {
+        return Ok(None);
+    }
+
+    let has_key = match branch_id {
+        Some(id) => references_issue(&body, id),
+        None => find_issue_key(&body).is_some(),
+    };
+    if has_key {
+        return Ok(None);
+    }
+
+    match (mode, branch_id) {
+        (CommitMsgMode::Prepend, Some(id)) => Ok(Some(format!(
+            "{}{}",
+            prefix.replace("{id}", id),
+            message.trim_start()
+        ))),
+        (CommitMsgMode::Prepend, None) => Ok(None),
+        (CommitMsgMode::Check, Some(id)) => Err(anyhow!(
+            "Commit message must reference the branch's issue {}",
+            id
+        )),
+        (CommitMsgMode::Check, None) => Err(anyhow!(
+            "Commit message must reference an issue key (e.g., PROJ-123)"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use tracker_core::ProjectRef;
+
+    fn make_issue(id_readable: &str, summary: &str) -> Issue {
+        Issue {
+            id: "1".to_string(),
+            id_readable: id_readable.to_string(),
+            summary: summary.to_string(),
+            description: None,
+            project: ProjectRef {
+                id: "p1".to_string(),
+                name: Some("Project".to_string()),
+                short_name: Some("PROJ".to_string()),
+            },
+            custom_fields: vec![],
+            tags: vec![],
+            created: Utc::now(),
+            updated: Utc::now(),
+            resolved: None,
+        }
+    }
+
+    #[test]
+    fn issue_id_from_branch_reads_keys_and_template_positions() {
+        let template = DEFAULT_BRANCH_TEMPLATE;
+        assert_eq!(
+            issue_id_from_branch("feature/PROJ-123-add-login", template).as_deref(),
+            Some("PROJ-123")
+        );
+        assert_eq!(
+            issue_id_from_branch("feature/proj-7", template).as_deref(),
+            Some("PROJ-7")
+        );
+        assert_eq!(
+            issue_id_from_branch("feature/42-fix-crash", template).as_deref(),
+            Some("42")
+        );
+        assert_eq!(
+            issue_id_from_branch("alice/ENG-9_spike", template).as_deref(),
+            Some("ENG-9")
+        );
+        assert_eq!(
+            issue_id_from_branch("feature/my-api_12-fix-crash", template).as_deref(),
+            Some("my-api#12")
+        );
+        assert_eq!(issue_id_from_branch("main", template), None);
+        assert_eq!(issue_id_from_branch("release-2x", template), None);
+    }
+
+    #[test]
+    fn branch_name_fills_template() {
+        let issue = make_issue(
+            "PROJ-12",
+            "Fix: login fails when the password has \"quotes\"",
+        );
+        assert_eq!(
+            branch_name(DEFAULT_BRANCH_TEMPLATE, &issue),
+            "feature/PROJ-12-fix-login-fails-when-the-password-has"
+        );
+        assert_eq!(branch_name("{project}/{id}", &issue), "PROJ/PROJ-12");
+        assert_eq!(
+            branch_name("{id}-{slug}", &make_issue("#5", "Typo")),
+            "5-typo"
+        );
+
+        // Org-scoped GitHub IDs keep their repository
+        let branch = branch_name(
+            DEFAULT_BRANCH_TEMPLATE,
+            &make_issue("acme/api#12", "Fix crash"),
+        );
+        assert_eq!(branch, "feature/api_12-fix-crash");
+        assert_eq!(
+            issue_id_from_branch(&branch, DEFAULT_BRANCH_TEMPLATE).as_deref(),
+            Some("api#12")
+        );
+    }
+
+    #[test]
+    fn commit_msg_prepends_branch_issue() {
+        let updated = apply_commit_msg_rule(
+            "Add login form\n# comment\n",
+            Some("PROJ-1"),
+            CommitMsgMode::Prepend,
+            DEFAULT_COMMIT_PREFIX,
+        )
+        .unwrap();
+        assert_eq!(
+            updated.as_deref(),
+            Some("PROJ-1: Add login form\n# comment\n")
+        );
+
+        let unchanged = apply_commit_msg_rule(
+            "proj-1 Add login form",
+            Some("PROJ-1"),
+            CommitMsgMode::Prepend,
+            DEFAULT_COMMIT_PREFIX,
+        )
+        .unwrap();
+        assert_eq!(unchanged, None);
+
+        let prefix_of_other_key = apply_commit_msg_rule(
+            "Follow-up to PROJ-12",
+            Some("PROJ-1"),
+            CommitMsgMode::Prepend,
+            DEFAULT_COMMIT_PREFIX,
+        )
+        .unwrap();
+        assert_eq!(
+            prefix_of_other_key.as_deref(),
+            Some("PROJ-1: Follow-up to PROJ-12")
+        );
+
+        let numeric =
+            apply_commit_msg_rule("Fix #142", Some("42"), CommitMsgMode::Prepend, "#{id} ")
+                .unwrap();
+        assert_eq!(numeric.as_deref(), Some("#42 Fix #142"));
+
+        let fixup = apply_commit_msg_rule(
+            "fixup! Add login form",
+            Some("PROJ-1"),
+            CommitMsgMode::Prepend,
+            DEFAULT_COMMIT_PREFIX,
+        )
+        .unwrap();
+        assert_eq!(fixup, None);
+    }
+
+    #[test]
+    fn commit_msg_check_rejects_missing_key() {
+        assert!(
+            apply_commit_msg_rule("Tidy up", None, CommitMsgMode::Check, DEFAULT_COMMIT_PREFIX)
+                .is_err()
+        );
+        assert!(
+            apply_commit_msg_rule(
+                "Tidy up for OPS-4",
+                None,
+                CommitMsgMode::Check,
+                DEFAULT_COMMIT_PREFIX
+            )
+            .unwrap()
+            .is_none()
+        );
+        assert!(
+            apply_commit_msg_rule(
+                "Tidy up for OPS-4",
+                Some("PROJ-1"),
+                CommitMsgMode::Check,
+                DEFAULT_COMMIT_PREFIX
+            )
+            .is_err()
+        );
+    }
+}
@@ -422,49 +422,57 @@ fn create_config_and_finish(
 
     // Create config with backend and optional default project
     let mut config = Config {
-        backend: Some(backend),
-        url: Some(url.to_string()),
-        token: Some(token.to_string()),
-        email: effective_email,
-        default_project: validated_project
-            .as_ref()
-            .and_then(InitProject::default_project),
-        youtrack: Default::default(),
-        jira: Default::default(),
-        github: Default::default(),
-        gitlab: Default::default(),
-        linear: Default::default(),
+        connection: tracker_client::Config {
+            backend: Some(backend),
+            url: Some(url.to_string()),
+            token: Some(token.to_string()),
+            email: effective_email,
+            default_project: validated_project
+                .as_ref()
+                .and_then(InitProject::default_project),
+            youtrack: Default::default(),
+            jira: Default::default(),
+            github: Default::default(),
+            gitlab: Default::default(),
+            linear: Default::default(),
+        },
+        queries: Default::default(),
+        aliases: Default::default(),
+        plugins: Default::default(),
+        git: Default::default(),
+        changelog: Default::default(),
+        webhook: Default::default(),
     };
 
     match &validated_project {
         Some(InitProject::GitHub { owner, repo }) => {
-            config.url = None;
-            config.token = None;
-            config.github.owner = Some(owner.clone());
-            config.github.repo = Some(repo.clone());
-            config.github.token = Some(token.to_string());
-            config.github.api_url = Some(url.to_string());
+            config.connection.url = None;
+            config.connection.token = None;
+            config.connection.github.owner = Some(owner.clone());
+            config.connection.github.repo = Some(repo.clone());
+            config.connection.github.token = Some(token.to_string());
+            config.connection.github.api_url = Some(url.to_string());
         }
         Some(InitProject::GitLab { id, .. }) => {
-            config.url = None;
-            config.token = None;
-            config.gitlab.url = Some(url.to_string());
-            config.gitlab.token = Some(token.to_string());
-            config.gitlab.project_id = Some(id.clone());
+            config.connection.url = None;
+            config.connection.token = None;
+            config.connection.gitlab.url = Some(url.to_string());
+            config.connection.gitlab.token = Some(token.to_string());
+            config.connection.gitlab.project_id = Some(id.clone());
         }
         Some(InitProject::Default { short_name }) if backend == Backend::Linear => {
-            config.url = None;
-            config.token = None;
-            config.linear.url = Some(url.to_string());
-            config.linear.token = Some(token.to_string());
-            config.linear.default_team = Some(short_name.clone());
+            config.connection.url = None;
+            config.connection.token = None;
+            config.connection.linear.url = Some(url.to_string());
+            config.connection.linear.token = Some(token.to_string());
+            config.connection.linear.default_team = Some(short_name.clone());
         }
         Some(InitProject::Default { .. }) => {}
         None if backend == Backend::Linear => {
-            config.url = None;
-            config.token = None;
-            config.linear.url = Some(url.to_string());
-            config.linear.token = Some(token.to_string());
+            config.connection.url = None;
+            config.connection.token = None;
+            config.connection.linear.url = Some(url.to_string());
+            config.connection.linear.token = Some(token.to_string());
         }
         None => {}
     }
@@ -512,7 +520,7 @@ fn create_config_and_finish(
             if let Some(project) = &validated_project {
                 result["project"] = serde_json::json!(project.display_name());
             }
-            if let Some(default_project) = config.default_project.as_deref() {
+            if let Some(default_project) = config.connection.default_project.as_deref() {
                 result["default_project"] = serde_json::json!(default_project);
             }
             output_json(&result)?;
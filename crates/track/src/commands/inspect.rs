@@ -6,6 +6,7 @@
 //! is recorded in `errors` instead of aborting the run; `--strict` makes the
 //! command exit non-zero after reporting all results.
 
+use crate::cache::CachedQueryTemplate;
 use crate::cli::OutputFormat;
 use crate::output::{output_json, output_progress};
 use anyhow::{Context, Result, anyhow};
@@ -33,6 +34,7 @@ pub(crate) struct InspectArgs<'a> {
     pub include: &'a [String],
     pub jsonl: bool,
     pub strict: bool,
+    pub saved_queries: &'a [CachedQueryTemplate],
 }
 
 /// Which expensive context to fetch per issue. Default is all-off (fast).
@@ -114,6 +116,7 @@ pub(crate) fn handle_inspect(
             args.template,
             args.project,
             default_project,
+            args.saved_queries,
         )?;
         let issues = if args.all {
             let res = client
@@ -280,6 +283,24 @@ fn inspect_issue(
     InspectOutcome::Success(obj)
 }
 
+/// Inspect a single issue and return the flattened object, for callers that
+/// report results themselves (e.g. `track mcp`).
+pub(crate) fn inspect_issue_value(
+    client: &dyn IssueTracker,
+    id: &str,
+    include: &[String],
+    link_mappings: &HashMap<String, String>,
+) -> Result<Value> {
+    let includes = parse_includes(include)?;
+    let issue = client
+        .get_issue(id)
+        .with_context(|| format!("Failed to get issue '{}'", id))?;
+    match inspect_issue(client, id, issue, includes, link_mappings) {
+        InspectOutcome::Success(obj) => Ok(Value::Object(obj)),
+        InspectOutcome::Failure(failure) => Err(anyhow!(failure.error)),
+    }
+}
+
 fn validate_input_mode(args: &InspectArgs) -> Result<bool> {
     let query_mode = args.query.is_some() || args.template.is_some();
     if !query_mode {
@@ -1,5 +1,8 @@
-use crate::cache::TrackerCache;
+use crate::cache::{
+    CachedQueryTemplate, TrackerCache, expand_query_template, merge_query_templates,
+};
 use crate::cli::{IssueCommands, OutputFormat};
+use crate::config::GitConfig;
 use crate::output::{
     Displayable, output_json, output_list, output_page_hint, output_progress, output_result,
 };
@@ -33,8 +36,10 @@ struct SearchArgs<'a> {
     limit: usize,
     skip: usize,
     all: bool,
+    saved_queries: &'a [CachedQueryTemplate],
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_issue(
     client: &dyn IssueTracker,
     action: &IssueCommands,
@@ -42,9 +47,16 @@ pub fn handle_issue(
     default_project: Option<&str>,
     verbose: bool,
     link_mappings: &std::collections::HashMap<String, String>,
+    saved_queries: &[CachedQueryTemplate],
+    git_config: &GitConfig,
 ) -> Result<()> {
+    use super::git::{resolve_issue_id, resolve_issue_ids};
+
     match action {
-        IssueCommands::Get { id, full } => handle_get(client, id, *full, format),
+        IssueCommands::Get { id, full } => {
+            let id = resolve_issue_id(id.as_deref(), git_config)?;
+            handle_get(client, &id, *full, format)
+        }
         IssueCommands::Create {
             project,
             summary,
@@ -59,16 +71,31 @@ pub fn handle_issue(
             validate,
             dry_run,
             json,
+            template,
+            vars,
         } => {
             let resolved_desc = super::resolve_body(description.as_deref(), body_file.as_deref())?;
+            // Explicit flags win over the template's values; tags add up.
+            let rendered = template
+                .as_deref()
+                .map(|name| super::issue_template::render_named_template(name, vars))
+                .transpose()?
+                .unwrap_or_default();
+            let fields = super::issue_template::merge_fields(&rendered.fields, fields);
+            let mut all_tags = rendered.tags.clone();
+            all_tags.extend(
+                tags.iter()
+                    .filter(|tag| !rendered.tags.contains(tag))
+                    .cloned(),
+            );
             let args = IssueFieldArgs {
-                summary: summary.as_deref(),
-                description: resolved_desc.as_deref(),
-                fields,
-                state: state.as_deref(),
-                priority: priority.as_deref(),
-                assignee: assignee.as_deref(),
-                tags,
+                summary: summary.as_deref().or(rendered.summary.as_deref()),
+                description: resolved_desc.as_deref().or(rendered.description.as_deref()),
+                fields: &fields,
+                state: state.as_deref().or(rendered.state.as_deref()),
+                priority: priority.as_deref().or(rendered.priority.as_deref()),
+                assignee: assignee.as_deref().or(rendered.assignee.as_deref()),
+                tags: &all_tags,
                 parent: None, // parent is passed separately to handle_create
                 validate: *validate,
                 dry_run: *dry_run,
@@ -77,13 +104,16 @@ pub fn handle_issue(
             handle_create(
                 client,
                 &args,
-                project.as_deref(),
-                parent.as_deref(),
+                project.as_deref().or(rendered.project.as_deref()),
+                parent.as_deref().or(rendered.parent.as_deref()),
                 format,
                 default_project,
                 verbose,
             )
         }
+        IssueCommands::Template { action } => {
+            super::issue_template::handle_template(action, format)
+        }
         IssueCommands::Update {
             ids,
             summary,
@@ -113,7 +143,16 @@ pub fn handle_issue(
                 dry_run: *dry_run,
                 json: json.as_deref(),
             };
-            handle_update_batch(client, ids, &args, format, verbose)
+            let ids = resolve_issue_ids(ids, git_config)?;
+            handle_update_batch(client, &ids, &args, format, verbose)
+        }
+        IssueCommands::Edit {
+            id,
+            validate,
+            dry_run,
+        } => {
+            let id = resolve_issue_id(id.as_deref(), git_config)?;
+            super::edit::handle_issue_edit(client, &id, *validate, *dry_run, format)
         }
         IssueCommands::Search {
             query,
@@ -130,6 +169,7 @@ pub fn handle_issue(
                 limit: *limit,
                 skip: *skip,
                 all: *all,
+                saved_queries,
             };
             handle_search(client, &args, format, default_project)
         }
@@ -146,8 +186,18 @@ pub fn handle_issue(
             jsonl,
             strict,
         } => {
+            // Only fall back to the branch's issue when nothing else selects
+            // issues; without one, inspect explains its input modes itself
+            let ids =
+                if ids.is_empty() && ids_file.is_none() && query.is_none() && template.is_none() {
+                    super::git::current_issue_id(git_config)
+                        .into_iter()
+                        .collect()
+                } else {
+                    ids.clone()
+                };
             let args = super::inspect::InspectArgs {
-                ids,
+                ids: &ids,
                 ids_file: ids_file.as_deref(),
                 query: query.as_deref(),
                 template: template.as_deref(),
@@ -158,11 +208,15 @@ pub fn handle_issue(
                 include,
                 jsonl: *jsonl,
                 strict: *strict,
+                saved_queries,
             };
             super::inspect::handle_inspect(client, &args, format, default_project, link_mappings)
         }
         IssueCommands::Delete { ids } => handle_delete_batch(client, ids, format),
-        IssueCommands::Attachments { id } => handle_attachments(client, id, format),
+        IssueCommands::Attachments { id } => {
+            let id = resolve_issue_id(id.as_deref(), git_config)?;
+            handle_attachments(client, &id, format)
+        }
         IssueCommands::Attach {
             id,
             paths,
@@ -170,16 +224,19 @@ pub fn handle_issue(
             mime_type,
             comment,
             silent,
-        } => handle_attach(
-            client,
-            id,
-            paths,
-            name.as_deref(),
-            mime_type.as_deref(),
-            comment.as_deref(),
-            *silent,
-            format,
-        ),
+        } => {
+            let (id, paths) = split_attach_args(id, paths, git_config)?;
+            handle_attach(
+                client,
+                &id,
+                &paths,
+                name.as_deref(),
+                mime_type.as_deref(),
+                comment.as_deref(),
+                *silent,
+                format,
+            )
+        }
         IssueCommands::Comment {
             id,
             text,
@@ -188,12 +245,21 @@ pub fn handle_issue(
             name,
             mime_type,
             silent,
+            dry_run,
         } => {
+            let id = resolve_issue_id(id.as_deref(), git_config)?;
             let resolved_text = super::resolve_body(text.as_deref(), body_file.as_deref())?
                 .ok_or_else(|| anyhow!("Comment text is required"))?;
+            let scope = super::mentions::MentionScope::Issue(&id);
+            if *dry_run {
+                let resolution = super::mentions::resolve_mentions(client, scope, &resolved_text);
+                return super::mentions::output_preview(&id, &resolution, format);
+            }
+            let resolved_text =
+                super::mentions::resolve_text(client, scope, &resolved_text, format);
             handle_comment(
                 client,
-                id,
+                &id,
                 &resolved_text,
                 attach,
                 name.as_deref(),
@@ -203,26 +269,74 @@ pub fn handle_issue(
             )
         }
         IssueCommands::Comments { id, limit, all } => {
-            handle_comments(client, id, *limit, *all, format)
+            let id = resolve_issue_id(id.as_deref(), git_config)?;
+            handle_comments(client, &id, *limit, *all, format)
         }
         IssueCommands::History { id, field, since } => {
-            handle_history(client, id, field.as_deref(), since.as_deref(), format)
+            let id = resolve_issue_id(id.as_deref(), git_config)?;
+            handle_history(client, &id, field.as_deref(), since.as_deref(), format)
         }
         IssueCommands::Link {
             source,
             target,
             link_type,
-        } => handle_link(client, source, target, link_type, format),
-        IssueCommands::Unlink { source, link_id } => handle_unlink(client, source, link_id, format),
-        IssueCommands::Start { ids, field, state } => {
-            handle_state_transition_batch(client, ids, field.as_deref(), state, "started", format)
+        } => {
+            let (source, target) = match target {
+                Some(target) => (source.clone(), target.clone()),
+                None => (resolve_issue_id(None, git_config)?, source.clone()),
+            };
+            handle_link(client, &source, &target, link_type, format)
+        }
+        IssueCommands::Unlink { source, link_id } => {
+            let (source, link_id) = match link_id {
+                Some(link_id) => (source.clone(), link_id.clone()),
+                None => (resolve_issue_id(None, git_config)?, source.clone()),
+            };
+            handle_unlink(client, &source, &link_id, format)
+        }
+        IssueCommands::Start {
+            ids,
+            field,
+            state,
+            branch,
+        } => {
+            let ids = resolve_issue_ids(ids, git_config)?;
+            if *branch {
+                super::git::start_issue_branch(client, &ids, git_config)?;
+            }
+            handle_state_transition_batch(client, &ids, field.as_deref(), state, "started", format)
         }
         IssueCommands::Complete { ids, field, state } => {
-            handle_state_transition_batch(client, ids, field.as_deref(), state, "completed", format)
+            let ids = resolve_issue_ids(ids, git_config)?;
+            handle_state_transition_batch(
+                client,
+                &ids,
+                field.as_deref(),
+                state,
+                "completed",
+                format,
+            )
         }
     }
 }
 
+/// Split `issue attach [ID] PATH...` arguments. The first argument is a file
+/// rather than an issue ID when it is the only one or names an existing file;
+/// the issue then comes from the current git branch.
+fn split_attach_args(
+    first: &str,
+    rest: &[std::path::PathBuf],
+    git_config: &GitConfig,
+) -> Result<(String, Vec<std::path::PathBuf>)> {
+    if !rest.is_empty() && !std::path::Path::new(first).is_file() {
+        return Ok((first.to_string(), rest.to_vec()));
+    }
+    let id = super::git::resolve_issue_id(None, git_config)?;
+    let mut paths = vec![std::path::PathBuf::from(first)];
+    paths.extend(rest.iter().cloned());
+    Ok((id, paths))
+}
+
 /// Record issue access in the cache for LRU tracking
 fn record_issue_access(issue: &Issue) {
     // Load only the runtime shard, update, and save just that shard
@@ -381,10 +495,16 @@ fn handle_create(
             schema.as_deref(),
         )?;
 
+        let scope = super::mentions::MentionScope::Project {
+            key: &project_input,
+            id: &project_id,
+        };
         let create = CreateIssue {
             project_id: project_id.clone(),
             summary: summary.to_string(),
-            description: args.description.map(|s| s.to_string()),
+            description: args
+                .description
+                .map(|d| super::mentions::resolve_text(client, scope, d, format)),
             custom_fields,
             tags: args.tags.to_vec(),
             parent: parent.map(|s| s.to_string()),
@@ -444,7 +564,7 @@ fn handle_update(
     format: OutputFormat,
     verbose: bool,
 ) -> Result<()> {
-    let update = build_update(client, id, args)?;
+    let update = build_update(client, id, args, format)?;
 
     if args.dry_run {
         let fields_validated = validate_update(client, id, &update)?;
@@ -486,7 +606,12 @@ fn handle_update(
     Ok(())
 }
 
-fn build_update(client: &dyn IssueTracker, id: &str, args: &IssueFieldArgs) -> Result<UpdateIssue> {
+fn build_update(
+    client: &dyn IssueTracker,
+    id: &str,
+    args: &IssueFieldArgs,
+    format: OutputFormat,
+) -> Result<UpdateIssue> {
     let update = if let Some(payload) = args.json {
         parse_update_payload(payload)?
     } else {
@@ -510,7 +635,10 @@ fn build_update(client: &dyn IssueTracker, id: &str, args: &IssueFieldArgs) -> R
 
         UpdateIssue {
             summary: args.summary.map(|s| s.to_string()),
-            description: args.description.map(|s| s.to_string()),
+            description: args.description.map(|d| {
+                let scope = super::mentions::MentionScope::Issue(id);
+                super::mentions::resolve_text(client, scope, d, format)
+            }),
             custom_fields,
             tags: args.tags.to_vec(),
             parent: args.parent.map(|s| s.to_string()),
@@ -536,7 +664,7 @@ pub(crate) fn validate_update(
     Ok(update.custom_fields.len())
 }
 
-fn output_update_dry_run(id: &str, fields_validated: usize, format: OutputFormat) {
+pub(crate) fn output_update_dry_run(id: &str, fields_validated: usize, format: OutputFormat) {
     match format {
         OutputFormat::Json => {
             println!(
@@ -911,8 +1039,13 @@ fn handle_search(
     default_project: Option<&str>,
 ) -> Result<()> {
     // Resolve query from template if needed
-    let actual_query =
-        resolve_search_query(args.query, args.template, args.project, default_project)?;
+    let actual_query = resolve_search_query(
+        args.query,
+        args.template,
+        args.project,
+        default_project,
+        args.saved_queries,
+    )?;
 
     let (issues, inline_total) = if args.all {
         // Auto-paginate; backends with cursor-based search override
@@ -966,7 +1099,11 @@ fn try_cached_count(query: &str, skip: usize) -> Option<(u64, String)> {
     // Check if the query matches any expanded template
     for project in &cache.projects {
         for template in &cache.query_templates {
-            let expanded = template.query.replace("{PROJECT}", &project.short_name);
+            let expanded = expand_query_template(
+                &template.query,
+                Some(&project.short_name),
+                &template.backend,
+            );
             if unicode_eq_ignore_case(&expanded, query)
                 && let Some(count) = cache.get_issue_count(&project.short_name, &template.name)
             {
@@ -977,53 +1114,69 @@ fn try_cached_count(query: &str, skip: usize) -> Option<(u64, String)> {
     None
 }
 
-/// Resolve search query from either direct query or template
+/// Resolve search query from either direct query or template.
+///
+/// Templates are looked up among the cached built-ins overlaid with the
+/// user's `saved_queries` from config, so saved queries work without a
+/// cache refresh.
 pub(crate) fn resolve_search_query(
     query: Option<&str>,
     template: Option<&str>,
     project: Option<&str>,
     default_project: Option<&str>,
+    saved_queries: &[CachedQueryTemplate],
 ) -> Result<String> {
     match (query, template) {
         // Direct query provided
         (Some(q), _) => Ok(q.to_string()),
 
-        // Template provided - resolve from cache
+        // Template provided - resolve from cache and config
         (None, Some(tmpl)) => {
-            let cache = TrackerCache::load_all(None)?;
+            // Saved queries from config still work when the cache can't be read
+            let mut templates = match TrackerCache::load_all(None) {
+                Ok(cache) => cache.query_templates,
+                Err(e) => {
+                    eprintln!(
+                        "Warning: Failed to load cache, using saved queries from config only: {:#}",
+                        e
+                    );
+                    Vec::new()
+                }
+            };
+            merge_query_templates(&mut templates, saved_queries);
 
             // Find the template
-            let template_def = cache
-                .query_templates
+            let template_def = templates
                 .iter()
                 .find(|qt| unicode_eq_ignore_case(&qt.name, tmpl))
                 .ok_or_else(|| {
-                    let available: Vec<&str> = cache
-                        .query_templates
-                        .iter()
-                        .map(|t| t.name.as_str())
-                        .collect();
+                    let available: Vec<&str> = templates.iter().map(|t| t.name.as_str()).collect();
                     anyhow!(
                         "Template '{}' not found. Available templates: {}",
                         tmpl,
                         if available.is_empty() {
-                            "(none - run 'track cache refresh')".to_string()
+                            "(none - run 'track cache refresh' or add [queries] to config)"
+                                .to_string()
                         } else {
                             available.join(", ")
                         }
                     )
                 })?;
 
-            // Get project for substitution
-            let proj = project.or(default_project).ok_or_else(|| {
-                anyhow!(
+            // Get project for substitution, if the template needs one
+            let proj = project.or(default_project);
+            if proj.is_none() && template_def.query.contains("{PROJECT}") {
+                return Err(anyhow!(
                     "Project required for template '{}'. Use --project or set default with 'track config project <ID>'",
                     tmpl
-                )
-            })?;
+                ));
+            }
 
-            // Substitute {PROJECT} placeholder
-            Ok(template_def.query.replace("{PROJECT}", proj))
+            Ok(expand_query_template(
+                &template_def.query,
+                proj,
+                &template_def.backend,
+            ))
         }
 
         // Neither provided
@@ -1224,7 +1377,7 @@ fn handle_history(
 /// Parse a relative duration like `1d`, `24h`, `2w`, `30m`, or `45s` into a
 /// [`chrono::Duration`]. Accepts a single integer followed by one unit suffix
 /// (`s`, `m`, `h`, `d`, `w`).
-fn parse_since(input: &str) -> Result<chrono::Duration> {
+pub(crate) fn parse_since(input: &str) -> Result<chrono::Duration> {
     let s = input.trim();
     let split = s
         .find(|c: char| !c.is_ascii_digit())
@@ -1552,7 +1705,7 @@ fn handle_update_batch(
 
     for id in ids {
         let result = if args.dry_run {
-            build_update(client, id, args)
+            build_update(client, id, args, format)
                 .and_then(|update| validate_update(client, id, &update))
                 .map(|_| None)
         } else {
@@ -1591,7 +1744,7 @@ fn handle_update_single(
     args: &IssueFieldArgs,
     format: OutputFormat,
 ) -> Result<Issue> {
-    let update = build_update(client, id, args)?;
+    let update = build_update(client, id, args, format)?;
 
     if args.validate {
         validate_update(client, id, &update)?;
@@ -2199,14 +2352,14 @@ mod tests {
 
     #[test]
     fn resolve_query_returns_direct_query() {
-        let result = resolve_search_query(Some("project: PROJ"), None, None, None);
+        let result = resolve_search_query(Some("project: PROJ"), None, None, None, &[]);
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), "project: PROJ");
     }
 
     #[test]
     fn resolve_query_requires_query_or_template() {
-        let result = resolve_search_query(None, None, None, None);
+        let result = resolve_search_query(None, None, None, None, &[]);
         assert!(result.is_err());
         assert!(
             result
@@ -0,0 +1,859 @@
+//! Issue templates: `.track/templates/<name>.md` files whose front matter
+//! gives issue defaults and whose body holds `{{placeholders}}`.
+
+use super::edit::{parse_document, render_document};
+use crate::cli::{IssueTemplateCommands, OutputFormat};
+use crate::output::output_json;
+use anyhow::{Context, Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+const TEMPLATES_DIR: &str = ".track/templates";
+
+/// Front matter of a template file. Every string may contain placeholders.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TemplateFrontMatter {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) project: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) summary: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) state: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) priority: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) assignee: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) fields: BTreeMap<String, FieldValue>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) parent: Option<String>,
+    /// Prompts and defaults for placeholders.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) variables: BTreeMap<String, TemplateVariable>,
+}
+
+/// A field value in a template: one value or a list for multi-value fields.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum FieldValue {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TemplateVariable {
+    /// Shown when prompting for the value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) prompt: Option<String>,
+    /// Used when no value is given; a variable without one is required.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) default: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct IssueTemplate {
+    pub(crate) front: TemplateFrontMatter,
+    pub(crate) body: String,
+}
+
+/// A template with every placeholder filled in.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct RenderedTemplate {
+    pub(crate) project: Option<String>,
+    pub(crate) summary: Option<String>,
+    pub(crate) description: Option<String>,
+    pub(crate) state: Option<String>,
+    pub(crate) priority: Option<String>,
+    pub(crate) assignee: Option<String>,
+    /// `NAME=VALUE` pairs, in `--field` form.
+    pub(crate) fields: Vec<String>,
+    pub(crate) tags: Vec<String>,
+    pub(crate) parent: Option<String>,
+}
+
+impl IssueTemplate {
+    pub(crate) fn parse(document: &str) -> Result<Self> {
+        let (front, body) = parse_document(document)?;
+        Ok(Self { front, body })
+    }
+
+    /// Every placeholder name, in order of first use.
+    pub(crate) fn placeholders(&self) -> Vec<String> {
+        let front = &self.front;
+        let mut texts: Vec<&str> = [
+            &front.project,
+            &front.summary,
+            &front.state,
+            &front.priority,
+            &front.assignee,
+            &front.parent,
+        ]
+        .into_iter()
+        .flatten()
+        .map(String::as_str)
+        .collect();
+        for value in front.fields.values() {
+            match value {
+                FieldValue::One(value) => texts.push(value),
+                FieldValue::Many(values) => texts.extend(values.iter().map(String::as_str)),
+            }
+        }
+        texts.extend(front.tags.iter().map(String::as_str));
+        texts.push(&self.body);
+
+        let mut names = Vec::new();
+        for text in texts {
+            for name in placeholders(text) {
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        names
+    }
+
+    /// Fill in `values`, falling back to declared defaults. Returns the names
+    /// still missing as an error listing them.
+    pub(crate) fn render(&self, values: &BTreeMap<String, String>) -> Result<RenderedTemplate> {
+        let missing = self.missing(values);
+        if !missing.is_empty() {
+            bail!(
+                "Missing template variables: {}. Pass them with --var NAME=VALUE",
+                missing.join(", ")
+            );
+        }
+        let mut values = values.clone();
+        for (name, variable) in &self.front.variables {
+            if let Some(default) = &variable.default {
+                values
+                    .entry(name.clone())
+                    .or_insert_with(|| default.clone());
+            }
+        }
+
+        let fill = |text: &Option<String>| {
+            text.as_deref()
+                .map(|text| interpolate(text, &values))
+                .filter(|text| !text.trim().is_empty())
+        };
+        let front = &self.front;
+        let fields = front
+            .fields
+            .iter()
+            .map(|(name, value)| {
+                let value = match value {
+                    FieldValue::One(value) => interpolate(value, &values),
+                    FieldValue::Many(items) => items
+                        .iter()
+                        .map(|item| interpolate(item, &values))
+                        .collect::<Vec<_>>()
+                        .join(","),
+                };
+                (name, value)
+            })
+            .filter(|(_, value)| !value.trim().is_empty())
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+        let body = interpolate(&self.body, &values);
+
+        Ok(RenderedTemplate {
+            project: fill(&front.project),
+            summary: fill(&front.summary),
+            description: (!body.trim().is_empty()).then_some(body),
+            state: fill(&front.state),
+            priority: fill(&front.priority),
+            assignee: fill(&front.assignee),
+            fields,
+            tags: front
+                .tags
+                .iter()
+                .map(|tag| interpolate(tag, &values))
+                .filter(|tag| !tag.trim().is_empty())
+                .collect(),
+            parent: fill(&front.parent),
+        })
+    }
+
+    /// Placeholders with neither a given value nor a default.
+    pub(crate) fn missing(&self, values: &BTreeMap<String, String>) -> Vec<String> {
+        self.placeholders()
+            .into_iter()
+            .filter(|name| {
+                !values.contains_key(name)
+                    && self
+                        .front
+                        .variables
+                        .get(name)
+                        .is_none_or(|variable| variable.default.is_none())
+            })
+            .collect()
+    }
+}
+
+/// Load the named template and fill it in from `--var` values, prompting for
+/// anything missing when run interactively.
+pub(crate) fn render_named_template(name: &str, vars: &[String]) -> Result<RenderedTemplate> {
+    let path = find_template(name)?;
+    let template = read_template(&path)?;
+    let mut values = parse_vars(vars)?;
+
+    let missing = template.missing(&values);
+    if !missing.is_empty() && std::io::stdin().is_terminal() && std::io::stderr().is_terminal() {
+        let stdin = std::io::stdin();
+        let mut input = stdin.lock();
+        for name in missing {
+            let prompt = template
+                .front
+                .variables
+                .get(&name)
+                .and_then(|variable| variable.prompt.as_deref())
+                .unwrap_or(&name);
+            let value = prompt_value(&mut input, prompt)?;
+            values.insert(name, value);
+        }
+    }
+
+    template
+        .render(&values)
+        .with_context(|| format!("Failed to fill in template '{}'", name))
+}
+
+fn prompt_value(input: &mut impl BufRead, prompt: &str) -> Result<String> {
+    loop {
+        eprint!("{}: ", prompt);
+        std::io::stderr().flush().ok();
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            bail!("No value given for '{}'", prompt);
+        }
+        let value = line.trim();
+        if !value.is_empty() {
+            return Ok(value.to_string());
+        }
+    }
+}
+
+fn parse_vars(vars: &[String]) -> Result<BTreeMap<String, String>> {
+    vars.iter()
+        .map(|var| {
+            let (name, value) = var
+                .split_once('=')
+                .filter(|(name, _)| !name.trim().is_empty())
+                .ok_or_else(|| anyhow!("Invalid --var '{}': expected NAME=VALUE", var))?;
+            Ok((name.trim().to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// The names used in `{{name}}` placeholders of `text`.
+fn placeholders(text: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        let name = after[..end].trim();
+        if is_placeholder_name(name) {
+            names.push(name.to_string());
+        }
+        rest = &after[end + 2..];
+    }
+    names
+}
+
+/// Replace `{{name}}` placeholders that have a value; others stay as written.
+fn interpolate(text: &str, values: &BTreeMap<String, String>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        let name = after[..end].trim();
+        match values.get(name) {
+            Some(value) if is_placeholder_name(name) => out.push_str(value),
+            _ => out.push_str(&rest[start..start + 2 + end + 2]),
+        }
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.')
+}
+
+/// The nearest `.track/templates` directory at or above the current directory.
+fn templates_dir() -> Option<PathBuf> {
+    let cwd = std::env::current_dir().ok()?;
+    cwd.ancestors()
+        .map(|dir| dir.join(TEMPLATES_DIR))
+        .find(|dir| dir.is_dir())
+}
+
+/// Resolve a template name (`bug`) or path (`./bug.md`) to a file.
+fn find_template(name: &str) -> Result<PathBuf> {
+    let as_path = Path::new(name);
+    if name.ends_with(".md") || as_path.components().count() > 1 {
+        if as_path.is_file() {
+            return Ok(as_path.to_path_buf());
+        }
+        bail!("Template file '{}' not found", name);
+    }
+
+    let dir = templates_dir().ok_or_else(|| {
+        anyhow!(
+            "Template '{}' not found: no {} directory here or in a parent directory",
+            name,
+            TEMPLATES_DIR
+        )
+    })?;
+    let path = dir.join(format!("{}.md", name));
+    if !path.is_file() {
+        let available = list_templates(&dir)?;
+        bail!(
+            "Template '{}' not found in '{}'. Available: {}",
+            name,
+            dir.display(),
+            if available.is_empty() {
+                "none".to_string()
+            } else {
+                available.join(", ")
+            }
+        );
+    }
+    Ok(path)
+}
+
+fn read_template(path: &Path) -> Result<IssueTemplate> {
+    let document = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read '{}'", path.display()))?;
+    IssueTemplate::parse(&document)
+        .with_context(|| format!("Invalid template '{}'", path.display()))
+}
+
+fn list_templates(dir: &Path) -> Result<Vec<String>> {
+    let mut names: Vec<String> = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read '{}'", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+        .filter_map(|path| path.file_stem().map(|s| s.to_string_lossy().to_string()))
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+pub fn handle_template(action: &IssueTemplateCommands, format: OutputFormat) -> Result<()> {
+    match action {
+        IssueTemplateCommands::List => handle_list(format),
+        IssueTemplateCommands::Show { name } => handle_show(name, format),
+        IssueTemplateCommands::Import { path, force } => handle_import(path, *force, format),
+    }
+}
+
+fn handle_list(format: OutputFormat) -> Result<()> {
+    let mut templates = Vec::new();
+    if let Some(dir) = templates_dir() {
+        for name in list_templates(&dir)? {
+            let template = read_template(&dir.join(format!("{}.md", name)))?;
+            templates.push((name, template));
+        }
+    }
+
+    match format {
+        OutputFormat::Json => {
+            let list: Vec<_> = templates
+                .iter()
+                .map(|(name, template)| {
+                    serde_json::json!({
+                        "name": name,
+                        "description": template.front.description,
+                        "variables": template.placeholders(),
+                    })
+                })
+                .collect();
+            output_json(&list)?;
+        }
+        OutputFormat::Text => {
+            use colored::Colorize;
+            if templates.is_empty() {
+                println!("No issue templates (add them to {}/)", TEMPLATES_DIR);
+            }
+            for (name, template) in &templates {
+                match &template.front.description {
+                    Some(description) => println!("{}  {}", name.cyan().bold(), description),
+                    None => println!("{}", name.cyan().bold()),
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn handle_show(name: &str, format: OutputFormat) -> Result<()> {
+    let path = find_template(name)?;
+    let template = read_template(&path)?;
+    match format {
+        OutputFormat::Json => output_json(&serde_json::json!({
+            "name": name,
+            "path": path,
+            "front_matter": template.front,
+            "body": template.body,
+            "variables": template.placeholders(),
+        })),
+        OutputFormat::Text => {
+            use colored::Colorize;
+            println!("{} ({})", name.cyan().bold(), path.display());
+            let variables = template.placeholders();
+            if !variables.is_empty() {
+                println!("Variables:");
+                for variable in variables {
+                    let declared = template.front.variables.get(&variable);
+                    let mut line = format!("  {}", variable);
+                    if let Some(prompt) = declared.and_then(|v| v.prompt.as_deref()) {
+                        line.push_str(&format!(" - {}", prompt));
+                    }
+                    match declared.and_then(|v| v.default.as_deref()) {
+                        Some(default) => line.push_str(&format!(" [default: {}]", default)),
+                        None => line.push_str(&format!(" {}", "(required)".yellow())),
+                    }
+                    println!("{}", line);
+                }
+            }
+            println!();
+            print!("{}", render_document(&template.front, &template.body)?);
+            Ok(())
+        }
+    }
+}
+
+/// Import GitHub issue templates (forms or Markdown) from a file or from an
+/// `ISSUE_TEMPLATE` directory into `.track/templates/`.
+fn handle_import(path: &Path, force: bool, format: OutputFormat) -> Result<()> {
+    let sources: Vec<PathBuf> = if path.is_dir() {
+        let mut files: Vec<PathBuf> = std::fs::read_dir(path)
+            .with_context(|| format!("Failed to read '{}'", path.display()))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|file| is_github_template(file))
+            .collect();
+        files.sort();
+        files
+    } else {
+        vec![path.to_path_buf()]
+    };
+    if sources.is_empty() {
+        bail!("No GitHub issue templates found in '{}'", path.display());
+    }
+
+    let dir = match templates_dir() {
+        Some(dir) => dir,
+        None => std::env::current_dir()
+            .context("Failed to get current directory")?
+            .join(TEMPLATES_DIR),
+    };
+    let mut converted = Vec::new();
+    for source in &sources {
+        let content = std::fs::read_to_string(source)
+            .with_context(|| format!("Failed to read '{}'", source.display()))?;
+        let template = if source.extension().is_some_and(|ext| ext == "md") {
+            import_github_markdown(&content)
+        } else {
+            import_github_form(&content)
+        }
+        .with_context(|| format!("Failed to import '{}'", source.display()))?;
+        let name = source
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let target = dir.join(format!("{}.md", name));
+        if target.exists() && !force {
+            bail!(
+                "Template '{}' already exists; use --force to overwrite",
+                target.display()
+            );
+        }
+        converted.push((name, target, template));
+    }
+
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create '{}'", dir.display()))?;
+    for (_, target, template) in &converted {
+        std::fs::write(target, render_document(&template.front, &template.body)?)
+            .with_context(|| format!("Failed to write '{}'", target.display()))?;
+    }
+
+    match format {
+        OutputFormat::Json => {
+            let imported: Vec<_> = converted
+                .iter()
+                .map(|(name, target, _)| serde_json::json!({"name": name, "path": target}))
+                .collect();
+            output_json(&serde_json::json!({ "imported": imported }))?;
+        }
+        OutputFormat::Text => {
+            for (name, target, _) in &converted {
+                println!("Imported template '{}' to {}", name, target.display());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn is_github_template(path: &Path) -> bool {
+    let is_template = path
+        .extension()
+        .is_some_and(|ext| ext == "yml" || ext == "yaml" || ext == "md");
+    // config.yml configures the template chooser; it is not a template.
+    let is_config = path
+        .file_stem()
+        .is_some_and(|stem| stem.eq_ignore_ascii_case("config"));
+    path.is_file() && is_template && !is_config
+}
+
+/// A GitHub issue form (`.yml`); see GitHub's issue form syntax.
+#[derive(Debug, Deserialize)]
+struct GithubForm {
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    labels: Option<StringOrList>,
+    #[serde(default)]
+    assignees: Option<StringOrList>,
+    #[serde(default)]
+    body: Vec<GithubFormElement>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum StringOrList {
+    List(Vec<String>),
+    String(String),
+}
+
+impl StringOrList {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            Self::List(items) => items,
+            Self::String(items) => items
+                .split(',')
+                .map(|item| item.trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubFormElement {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    attributes: GithubFormAttributes,
+    #[serde(default)]
+    validations: GithubFormValidations,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct GithubFormAttributes {
+    #[serde(default)]
+    label: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    value: Option<String>,
+    #[serde(default)]
+    options: Vec<GithubFormOption>,
+    #[serde(default)]
+    default: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum GithubFormOption {
+    Label(String),
+    Checkbox { label: String },
+}
+
+impl GithubFormOption {
+    fn label(&self) -> &str {
+        match self {
+            Self::Label(label) | Self::Checkbox { label } => label,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct GithubFormValidations {
+    #[serde(default)]
+    required: bool,
+}
+
+/// Convert a GitHub issue form: each input, textarea and dropdown becomes a
+/// `### Label` section filled from a variable; checkboxes become a task list.
+fn import_github_form(content: &str) -> Result<IssueTemplate> {
+    let form: GithubForm = serde_norway::from_str(content).context("Invalid issue form")?;
+    let assignees = form
+        .assignees
+        .map(StringOrList::into_vec)
+        .unwrap_or_default();
+    let mut front = TemplateFrontMatter {
+        description: form.description,
+        summary: Some(format!("{}{{{{title}}}}", form.title.unwrap_or_default())),
+        tags: form.labels.map(StringOrList::into_vec).unwrap_or_default(),
+        assignee: (assignees.len() == 1).then(|| assignees[0].clone()),
+        ..Default::default()
+    };
+    front.variables.insert(
+        "title".to_string(),
+        TemplateVariable {
+            prompt: Some("Title".to_string()),
+            default: None,
+        },
+    );
+
+    let mut sections = Vec::new();
+    for (index, element) in form.body.into_iter().enumerate() {
+        let attributes = element.attributes;
+        let label = attributes.label.clone().unwrap_or_default();
+        match element.kind.as_str() {
+            "input" | "textarea" | "dropdown" => {
+                let name = element
+                    .id
+                    .clone()
+                    .unwrap_or_else(|| variable_name(&label, index));
+                let options: Vec<&str> = attributes.options.iter().map(|o| o.label()).collect();
+                let mut prompt = if label.is_empty() {
+                    name.clone()
+                } else {
+                    label.clone()
+                };
+                if !options.is_empty() {
+                    prompt.push_str(&format!(" ({})", options.join(", ")));
+                } else if let Some(description) = &attributes.description {
+                    prompt.push_str(&format!(" - {}", description));
+                }
+                let default = attributes
+                    .default
+                    .and_then(|index| options.get(index).map(|o| o.to_string()))
+                    .or(attributes.value.clone())
+                    .or_else(|| (!element.validations.required).then(String::new));
+                front.variables.insert(
+                    name.clone(),
+                    TemplateVariable {
+                        prompt: Some(prompt),
+                        default,
+                    },
+                );
+                sections.push(format!("### {}\n\n{{{{{}}}}}", label, name));
+            }
+            "checkboxes" => {
+                let items: Vec<String> = attributes
+                    .options
+                    .iter()
+                    .map(|option| format!("- [ ] {}", option.label()))
+                    .collect();
+                sections.push(format!("### {}\n\n{}", label, items.join("\n")));
+            }
+            // Markdown elements are instructions for whoever fills in the form.
+            _ => {}
+        }
+    }
+
+    Ok(IssueTemplate {
+        front,
+        body: sections.join("\n\n"),
+    })
+}
+
+/// A GitHub Markdown issue template: front matter with name, about, title,
+/// labels and assignees, then the body.
+#[derive(Debug, Deserialize)]
+struct GithubMarkdownFront {
+    #[serde(default)]
+    about: Option<String>,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    labels: Option<StringOrList>,
+    #[serde(default)]
+    assignees: Option<StringOrList>,
+}
+
+fn import_github_markdown(content: &str) -> Result<IssueTemplate> {
+    let (github, body): (GithubMarkdownFront, String) = parse_document(content)?;
+    let assignees = github
+        .assignees
+        .map(StringOrList::into_vec)
+        .unwrap_or_default();
+    let summary = github.title.filter(|title| !title.trim().is_empty());
+    let mut front = TemplateFrontMatter {
+        description: github.about,
+        summary: Some(format!("{}{{{{title}}}}", summary.unwrap_or_default())),
+        tags: github
+            .labels
+            .map(StringOrList::into_vec)
+            .unwrap_or_default(),
+        assignee: (assignees.len() == 1).then(|| assignees[0].clone()),
+        ..Default::default()
+    };
+    front.variables.insert(
+        "title".to_string(),
+        TemplateVariable {
+            prompt: Some("Title".to_string()),
+            default: None,
+        },
+    );
+    Ok(IssueTemplate { front, body })
+}
+
+fn variable_name(label: &str, index: usize) -> String {
+    let name: String = label
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>()
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_");
+    if name.is_empty() {
+        format!("field_{}", index + 1)
+    } else {
+        name
+    }
+}
+
+/// Merge template values under explicit `--field` arguments: a field given
+/// on the command line replaces the template's value for it.
+pub(crate) fn merge_fields(template: &[String], cli: &[String]) -> Vec<String> {
+    let name = |field: &str| {
+        field
+            .split_once('=')
+            .map(|(name, _)| name.trim().to_lowercase())
+    };
+    let overridden: BTreeSet<_> = cli.iter().filter_map(|field| name(field)).collect();
+    template
+        .iter()
+        .filter(|field| name(field).is_none_or(|name| !overridden.contains(&name)))
+        .chain(cli)
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUG: &str = "---\nproject: API\nsummary: '[{{component}}] {{title}}'\nfields:\n  Type: Bug\n  Component: '{{component}}'\ntags: [bug]\nvariables:\n  component:\n    prompt: Affected component\n    default: core\n---\n\n## Steps\n\n{{ steps }}\n\nKeep {{literal braces";
+
+    fn vars(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn renders_placeholders_with_values_and_defaults() {
+        let template = IssueTemplate::parse(BUG).unwrap();
+        assert_eq!(template.placeholders(), ["component", "title", "steps"]);
+
+        let rendered = template
+            .render(&vars(&[("title", "Crash"), ("steps", "Run it")]))
+            .unwrap();
+        assert_eq!(rendered.project.as_deref(), Some("API"));
+        assert_eq!(rendered.summary.as_deref(), Some("[core] Crash"));
+        assert_eq!(rendered.fields, ["Component=core", "Type=Bug"]);
+        assert_eq!(rendered.tags, ["bug"]);
+        assert_eq!(
+            rendered.description.as_deref(),
+            Some("## Steps\n\nRun it\n\nKeep {{literal braces")
+        );
+    }
+
+    #[test]
+    fn render_lists_every_missing_variable() {
+        let template = IssueTemplate::parse(BUG).unwrap();
+        let err = template.render(&BTreeMap::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Missing template variables: title, steps. Pass them with --var NAME=VALUE"
+        );
+    }
+
+    #[test]
+    fn imports_github_issue_form() {
+        let form = r#"
+name: Bug report
+description: File a bug report
+title: "[Bug]: "
+labels: ["bug", "triage"]
+body:
+  - type: markdown
+    attributes:
+      value: Thanks for taking the time!
+  - type: textarea
+    id: what-happened
+    attributes:
+      label: What happened?
+    validations:
+      required: true
+  - type: dropdown
+    id: browser
+    attributes:
+      label: Browser
+      options: [Firefox, Chrome]
+  - type: checkboxes
+    attributes:
+      label: Checks
+      options:
+        - label: I searched existing issues
+"#;
+        let template = import_github_form(form).unwrap();
+        assert_eq!(template.front.summary.as_deref(), Some("[Bug]: {{title}}"));
+        assert_eq!(template.front.tags, ["bug", "triage"]);
+        assert_eq!(
+            template.body,
+            "### What happened?\n\n{{what-happened}}\n\n### Browser\n\n{{browser}}\n\n### Checks\n\n- [ ] I searched existing issues"
+        );
+        assert_eq!(
+            template.front.variables["browser"],
+            TemplateVariable {
+                prompt: Some("Browser (Firefox, Chrome)".to_string()),
+                default: Some(String::new()),
+            }
+        );
+        assert_eq!(
+            template.missing(&BTreeMap::new()),
+            ["title", "what-happened"]
+        );
+    }
+
+    #[test]
+    fn cli_fields_override_template_fields() {
+        let merged = merge_fields(
+            &["Type=Bug".to_string(), "Component=core".to_string()],
+            &["component=api".to_string()],
+        );
+        assert_eq!(merged, ["Type=Bug", "component=api"]);
+    }
+}
@@ -0,0 +1,631 @@
+//! `track mcp` — serve tracker operations as Model Context Protocol tools.
+//!
+//! Speaks JSON-RPC 2.0 over stdio, one message per line, as MCP clients
+//! expect from a local server. Read tools return tracker-core models with
+//! output schemas derived from them; write tools are compiled into a
+//! one-operation apply plan so they get the same validation and the same
+//! delete safeguard as `track apply`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{BufRead, Write};
+
+use anyhow::{Context, Result};
+use schemars::{JsonSchema, schema_for};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use tracker_core::{Article, Issue, IssueTracker, KnowledgeBase};
+
+use crate::cache::CachedQueryTemplate;
+use crate::cli::OutputFormat;
+use crate::commands::apply::{ApplyOptions, apply_plan_value};
+use crate::commands::apply_template::escape_placeholders;
+use crate::commands::{context, inspect, issue};
+
+const PROTOCOL_VERSIONS: [&str; 3] = ["2025-06-18", "2025-03-26", "2024-11-05"];
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+pub(crate) struct McpOptions<'a> {
+    pub(crate) allow_delete: bool,
+    pub(crate) read_only: bool,
+    pub(crate) default_project: Option<&'a str>,
+    pub(crate) link_mappings: &'a HashMap<String, String>,
+    pub(crate) saved_queries: &'a [CachedQueryTemplate],
+    pub(crate) backend_type: &'a str,
+    pub(crate) base_url: &'a str,
+}
+
+pub(crate) fn handle_mcp(
+    issue_client: &dyn IssueTracker,
+    kb_client: &dyn KnowledgeBase,
+    options: McpOptions<'_>,
+) -> Result<()> {
+    let server = McpServer {
+        issue_client,
+        kb_client,
+        options,
+    };
+    serve(std::io::stdin().lock(), std::io::stdout().lock(), &server)
+}
+
+/// Answer requests until the client closes stdin.
+fn serve(reader: impl BufRead, mut writer: impl Write, server: &McpServer) -> Result<()> {
+    for line in reader.lines() {
+        let line = line.context("Failed to read from stdin")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match serde_json::from_str::<Value>(&line) {
+            Ok(message) => server.handle_message(&message),
+            Err(e) => Some(error_response(
+                Value::Null,
+                PARSE_ERROR,
+                &format!("Parse error: {}", e),
+            )),
+        };
+        if let Some(response) = response {
+            writeln!(writer, "{}", serde_json::to_string(&response)?)?;
+            writer.flush()?;
+        }
+    }
+    Ok(())
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+/// A failure that is reported as a JSON-RPC error rather than a tool result.
+struct ProtocolError {
+    code: i64,
+    message: String,
+}
+
+impl ProtocolError {
+    fn invalid_params(message: impl Into<String>) -> Self {
+        Self {
+            code: INVALID_PARAMS,
+            message: message.into(),
+        }
+    }
+}
+
+struct ToolDefinition {
+    name: &'static str,
+    description: &'static str,
+    input_schema: Value,
+    output_schema: Option<Value>,
+    writes: bool,
+}
+
+fn tool<I: JsonSchema>(name: &'static str, description: &'static str) -> ToolDefinition {
+    ToolDefinition {
+        name,
+        description,
+        input_schema: schema_value::<I>(),
+        output_schema: None,
+        writes: false,
+    }
+}
+
+impl ToolDefinition {
+    fn returns<O: JsonSchema>(mut self) -> Self {
+        self.output_schema = Some(schema_value::<O>());
+        self
+    }
+
+    fn writes(mut self) -> Self {
+        self.writes = true;
+        self
+    }
+}
+
+fn schema_value<T: JsonSchema>() -> Value {
+    let mut schema = serde_json::to_value(schema_for!(T)).unwrap_or_default();
+    if let Some(object) = schema.as_object_mut() {
+        object.remove("$schema");
+    }
+    schema
+}
+
+fn all_tools() -> Vec<ToolDefinition> {
+    vec![
+        tool::<SearchIssuesArgs>(
+            "search_issues",
+            "Search issues with the backend's query language or a saved query template",
+        )
+        .returns::<IssueSearchOutput>(),
+        tool::<IssueIdArgs>("get_issue", "Get one issue by its readable ID").returns::<Issue>(),
+        tool::<InspectIssueArgs>(
+            "inspect_issue",
+            "Get an issue together with its comments, links, subtasks, and history",
+        ),
+        tool::<CreateIssueArgs>("create_issue", "Create an issue").writes(),
+        tool::<UpdateIssueArgs>(
+            "update_issue",
+            "Update an issue's summary, description, state, fields, tags, or parent",
+        )
+        .writes(),
+        tool::<AddCommentArgs>("add_comment", "Add a comment to an issue").writes(),
+        tool::<LinkIssuesArgs>("link_issues", "Link two issues").writes(),
+        tool::<IssueIdArgs>("delete_issue", "Delete an issue permanently").writes(),
+        tool::<SearchArticlesArgs>(
+            "search_articles",
+            "Search knowledge base articles, or list a project's articles without a query",
+        )
+        .returns::<ArticleListOutput>(),
+        tool::<ArticleIdArgs>("get_article", "Get one knowledge base article").returns::<Article>(),
+        tool::<ContextArgs>(
+            "get_context",
+            "Projects, fields, workflow states, users, tags, link types, and query templates \
+             for the configured tracker. Call this first to learn valid values.",
+        ),
+    ]
+}
+
+/// Tool input for `search_issues`.
+#[derive(Debug, Deserialize, JsonSchema)]
+struct SearchIssuesArgs {
+    /// Query in the backend's native syntax
+    query: Option<String>,
+    /// Saved query or cached template name, used when `query` is absent
+    template: Option<String>,
+    /// Project substituted into the template
+    project: Option<String>,
+    /// Maximum number of issues to return
+    #[serde(default = "default_limit")]
+    limit: usize,
+    /// Number of issues to skip
+    #[serde(default)]
+    skip: usize,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+struct IssueSearchOutput {
+    /// Total matches reported by the backend, if known
+    total: Option<u64>,
+    issues: Vec<Issue>,
+}
+
+/// Tool input for `get_issue` and `delete_issue`.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+struct IssueIdArgs {
+    /// Readable issue ID (e.g., PROJ-123)
+    issue: String,
+}
+
+/// Tool input for `inspect_issue`.
+#[derive(Debug, Deserialize, JsonSchema)]
+struct InspectIssueArgs {
+    /// Readable issue ID (e.g., PROJ-123)
+    issue: String,
+    /// Context to include: comments, links, subtasks, history, or all
+    #[serde(default = "default_includes")]
+    include: Vec<String>,
+}
+
+/// A custom field value: one value, or several for multi-value fields.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum FieldValue {
+    Single(String),
+    Multi(Vec<String>),
+}
+
+/// Tool input for `create_issue`.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+struct CreateIssueArgs {
+    /// Project short name; defaults to the configured project
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    project: Option<String>,
+    summary: String,
+    /// Markdown description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    /// Custom fields by name (e.g., {"Type": "Bug"})
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    fields: BTreeMap<String, FieldValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    priority: Option<String>,
+    /// Assignee login
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    assignee: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    /// Parent issue ID, to create a subtask
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    parent: Option<String>,
+}
+
+/// Tool input for `update_issue`; omitted properties are left unchanged.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+struct UpdateIssueArgs {
+    /// Readable issue ID (e.g., PROJ-123)
+    issue: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    /// Markdown description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    /// Custom fields by name (e.g., {"Type": "Bug"})
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    fields: BTreeMap<String, FieldValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    priority: Option<String>,
+    /// Assignee login
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    assignee: Option<String>,
+    /// Tags to add; existing tags are kept
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    /// New parent issue ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    parent: Option<String>,
+}
+
+/// Tool input for `add_comment`.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+struct AddCommentArgs {
+    /// Readable issue ID (e.g., PROJ-123)
+    issue: String,
+    /// Markdown comment text
+    body: String,
+}
+
+/// Tool input for `link_issues`.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+struct LinkIssuesArgs {
+    source: String,
+    target: String,
+    /// Link type: relates (default), depends, required, duplicates,
+    /// duplicated-by, subtask, or parent
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    link_type: Option<String>,
+}
+
+/// Tool input for `search_articles`.
+#[derive(Debug, Deserialize, JsonSchema)]
+struct SearchArticlesArgs {
+    /// Query in the knowledge base's search syntax
+    query: Option<String>,
+    /// Project to list articles from when there is no query
+    project: Option<String>,
+    /// Maximum number of articles to return
+    #[serde(default = "default_limit")]
+    limit: usize,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+struct ArticleListOutput {
+    articles: Vec<Article>,
+}
+
+/// Tool input for `get_article`.
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ArticleIdArgs {
+    /// Article ID (e.g., PROJ-A-1)
+    article: String,
+}
+
+/// Tool input for `get_context`.
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ContextArgs {
+    /// Limit the context to one project
+    project: Option<String>,
+    /// Include the project's unresolved issues
+    #[serde(default)]
+    include_issues: bool,
+    /// Refresh the cache from the API instead of reusing it
+    #[serde(default)]
+    refresh: bool,
+}
+
+fn default_limit() -> usize {
+    20
+}
+
+fn default_includes() -> Vec<String> {
+    vec!["all".to_string()]
+}
+
+/// The result of a tool call, before it is wrapped into MCP content.
+struct ToolOutput {
+    value: Value,
+    is_error: bool,
+}
+
+impl ToolOutput {
+    fn ok(value: impl Serialize) -> Result<Self> {
+        Ok(Self {
+            value: serde_json::to_value(value)?,
+            is_error: false,
+        })
+    }
+}
+
+struct McpServer<'a> {
+    issue_client: &'a dyn IssueTracker,
+    kb_client: &'a dyn KnowledgeBase,
+    options: McpOptions<'a>,
+}
+
+impl McpServer<'_> {
+    /// Handle one JSON-RPC message; notifications get no response.
+    fn handle_message(&self, message: &Value) -> Option<Value> {
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            let id = message.get("id").cloned().unwrap_or(Value::Null);
+            return Some(error_response(id, INVALID_REQUEST, "Invalid request"));
+        };
+        // Requests carry an id; notifications (e.g. notifications/initialized) don't
+        let id = message.get("id")?.clone();
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+        let result = match method {
+            "initialize" => Ok(self.initialize(&params)),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": self.list_tools() })),
+            "tools/call" => self.call_tool(&params),
+            _ => Err(ProtocolError {
+                code: METHOD_NOT_FOUND,
+                message: format!("Method not found: {}", method),
+            }),
+        };
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(error) => error_response(id, error.code, &error.message),
+        })
+    }
+
+    fn initialize(&self, params: &Value) -> Value {
+        let requested = params["protocolVersion"].as_str().unwrap_or_default();
+        let version = PROTOCOL_VERSIONS
+            .iter()
+            .find(|version| **version == requested)
+            .unwrap_or(&PROTOCOL_VERSIONS[0]);
+        json!({
+            "protocolVersion": version,
+            "capabilities": { "tools": {} },
+            "serverInfo": { "name": "track", "version": env!("CARGO_PKG_VERSION") },
+            "instructions": "Call get_context first to learn projects, fields, and valid values."
+        })
+    }
+
+    fn enabled_tools(&self) -> impl Iterator<Item = ToolDefinition> + '_ {
+        all_tools().into_iter().filter(|tool| {
+            !(tool.writes && self.options.read_only)
+                && (tool.name != "delete_issue" || self.options.allow_delete)
+        })
+    }
+
+    fn list_tools(&self) -> Vec<Value> {
+        self.enabled_tools()
+            .map(|tool| {
+                let mut definition = json!({
+                    "name": tool.name,
+                    "description": tool.description,
+                    "inputSchema": tool.input_schema,
+                    "annotations": { "readOnlyHint": !tool.writes },
+                });
+                if let Some(output_schema) = tool.output_schema {
+                    definition["outputSchema"] = output_schema;
+                }
+                if tool.name == "delete_issue" {
+                    definition["annotations"]["destructiveHint"] = json!(true);
+                }
+                definition
+            })
+            .collect()
+    }
+
+    fn call_tool(&self, params: &Value) -> std::result::Result<Value, ProtocolError> {
+        let name = params["name"]
+            .as_str()
+            .ok_or_else(|| ProtocolError::invalid_params("Missing tool name"))?;
+        let tool = self
+            .enabled_tools()
+            .find(|tool| tool.name == name)
+            .ok_or_else(|| ProtocolError::invalid_params(format!("Unknown tool: {}", name)))?;
+        let arguments = match params.get("arguments") {
+            Some(Value::Null) | None => json!({}),
+            Some(arguments) => arguments.clone(),
+        };
+
+        let output = match name {
+            "search_issues" => self.search_issues(parse_args(arguments)?),
+            "get_issue" => self.get_issue(parse_args(arguments)?),
+            "inspect_issue" => self.inspect_issue(parse_args(arguments)?),
+            "create_issue" => {
+                self.apply_operation("create_issue", parse_args::<CreateIssueArgs>(arguments)?)
+            }
+            "update_issue" => self.update_issue(parse_args(arguments)?),
+            "add_comment" => {
+                self.apply_operation("comment", parse_args::<AddCommentArgs>(arguments)?)
+            }
+            "link_issues" => self.apply_operation("link", parse_args::<LinkIssuesArgs>(arguments)?),
+            "delete_issue" => {
+                self.apply_operation("delete_issue", parse_args::<IssueIdArgs>(arguments)?)
+            }
+            "search_articles" => self.search_articles(parse_args(arguments)?),
+            "get_article" => self.get_article(parse_args(arguments)?),
+            "get_context" => self.get_context(parse_args(arguments)?),
+            _ => unreachable!("every listed tool is dispatched"),
+        };
+
+        // Tool failures are results the model can read, not protocol errors
+        let output = output.unwrap_or_else(|e| ToolOutput {
+            value: json!({ "error": format!("{:#}", e) }),
+            is_error: true,
+        });
+        let text = serde_json::to_string_pretty(&output.value).unwrap_or_default();
+        let mut result = json!({
+            "content": [{ "type": "text", "text": text }],
+            "isError": output.is_error,
+        });
+        if tool.output_schema.is_some() && !output.is_error {
+            result["structuredContent"] = output.value;
+        }
+        Ok(result)
+    }
+
+    fn search_issues(&self, args: SearchIssuesArgs) -> Result<ToolOutput> {
+        let query = issue::resolve_search_query(
+            args.query.as_deref(),
+            args.template.as_deref(),
+            args.project.as_deref(),
+            self.options.default_project,
+            self.options.saved_queries,
+        )?;
+        let result = self
+            .issue_client
+            .search_issues(&query, args.limit, args.skip)
+            .context("Failed to search issues")?;
+        ToolOutput::ok(IssueSearchOutput {
+            total: result.total,
+            issues: result.items,
+        })
+    }
+
+    fn get_issue(&self, args: IssueIdArgs) -> Result<ToolOutput> {
+        let issue = self
+            .issue_client
+            .get_issue(&args.issue)
+            .with_context(|| format!("Failed to get issue '{}'", args.issue))?;
+        ToolOutput::ok(issue)
+    }
+
+    fn inspect_issue(&self, args: InspectIssueArgs) -> Result<ToolOutput> {
+        ToolOutput::ok(inspect::inspect_issue_value(
+            self.issue_client,
+            &args.issue,
+            &args.include,
+            self.options.link_mappings,
+        )?)
+    }
+
+    /// Tags go through `add_tag` operations, since an update's `tags`
+    /// replace the issue's whole tag set.
+    fn update_issue(&self, mut args: UpdateIssueArgs) -> Result<ToolOutput> {
+        let tags = std::mem::take(&mut args.tags);
+        let issue = args.issue.clone();
+        let mut update = serde_json::to_value(args)?;
+        let mut operations = Vec::new();
+        // `issue` alone is no change, unless there are no tags either
+        if update.as_object().is_some_and(|fields| fields.len() > 1) || tags.is_empty() {
+            update["op"] = json!("update_issue");
+            operations.push(update);
+        }
+        for tag in tags {
+            operations.push(json!({ "op": "add_tag", "issue": issue, "tag": tag }));
+        }
+        self.apply_operations(operations)
+    }
+
+    /// Run one write as a single-operation apply plan.
+    fn apply_operation(&self, op: &str, args: impl Serialize) -> Result<ToolOutput> {
+        let mut operation = serde_json::to_value(args)?;
+        operation["op"] = json!(op);
+        self.apply_operations(vec![operation])
+    }
+
+    /// Run writes as an apply plan. Tool arguments are literal text, so `${`
+    /// is escaped before the plan is interpolated.
+    fn apply_operations(&self, mut operations: Vec<Value>) -> Result<ToolOutput> {
+        operations.iter_mut().for_each(escape_placeholders);
+        let plan = json!({ "version": 1, "operations": operations });
+        let (value, error) = apply_plan_value(
+            self.issue_client,
+            self.kb_client,
+            &plan,
+            ApplyOptions {
+                dry_run: false,
+                validate: false,
+                resume_path: None,
+                allow_delete: self.options.allow_delete,
+                format: OutputFormat::Json,
+                default_project: self.options.default_project,
+                concurrency: 1,
+            },
+        )?;
+        Ok(ToolOutput {
+            value,
+            is_error: error.is_some(),
+        })
+    }
+
+    fn search_articles(&self, args: SearchArticlesArgs) -> Result<ToolOutput> {
+        let articles = match args.query.as_deref() {
+            Some(query) => self.kb_client.search_articles(query, args.limit, 0),
+            None => self.kb_client.list_articles(
+                args.project.as_deref().or(self.options.default_project),
+                args.limit,
+                0,
+            ),
+        }
+        .context("Failed to fetch articles")?;
+        ToolOutput::ok(ArticleListOutput { articles })
+    }
+
+    fn get_article(&self, args: ArticleIdArgs) -> Result<ToolOutput> {
+        let article = self
+            .kb_client
+            .get_article(&args.article)
+            .with_context(|| format!("Failed to get article '{}'", args.article))?;
+        ToolOutput::ok(article)
+    }
+
+    fn get_context(&self, args: ContextArgs) -> Result<ToolOutput> {
+        ToolOutput::ok(context::build_context(
+            self.issue_client,
+            args.project.as_deref(),
+            args.refresh,
+            args.include_issues,
+            10,
+            self.options.backend_type,
+            self.options.base_url,
+            self.options.default_project,
+            self.options.saved_queries,
+        )?)
+    }
+}
+
+fn parse_args<T: DeserializeOwned>(arguments: Value) -> std::result::Result<T, ProtocolError> {
+    serde_json::from_value(arguments)
+        .map_err(|e| ProtocolError::invalid_params(format!("Invalid arguments: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tool_schemas_describe_arguments_and_models() {
+        let tools = all_tools();
+        let create = tools.iter().find(|t| t.name == "create_issue").unwrap();
+        assert_eq!(create.input_schema["type"], "object");
+        assert_eq!(create.input_schema["required"], json!(["summary"]));
+        assert!(create.input_schema.get("$schema").is_none());
+        assert!(create.writes);
+
+        let get = tools.iter().find(|t| t.name == "get_issue").unwrap();
+        let output = get.output_schema.as_ref().unwrap();
+        assert!(output["properties"]["id_readable"].is_object());
+        assert!(!get.writes);
+    }
+
+    #[test]
+    fn invalid_arguments_are_protocol_errors() {
+        let error = parse_args::<IssueIdArgs>(json!({ "id": "DEMO-1" }))
+            .err()
+            .unwrap();
+        assert_eq!(error.code, INVALID_PARAMS);
+        assert!(error.message.contains("issue"), "{}", error.message);
+    }
+}
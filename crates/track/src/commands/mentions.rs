@@ -0,0 +1,423 @@
+//! Portable `@mentions` for comments and descriptions.
+//!
+//! `@login` and `@"Display Name"` are matched against the project's users
+//! (the cache first, then the backend) and rewritten into the backend's
+//! native mention syntax via [`IssueTracker::format_mention`]. Mentions that
+//! can't be matched to exactly one user are left as typed, with a warning.
+
+use serde::Serialize;
+use tracker_core::{IssueTracker, User};
+
+use crate::cache::TrackerCache;
+use crate::cli::OutputFormat;
+
+/// Where to look up users for mention resolution.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum MentionScope<'a> {
+    /// The project an existing issue belongs to.
+    Issue(&'a str),
+    /// A project, by short name and resolved backend ID.
+    Project { key: &'a str, id: &'a str },
+}
+
+/// A `@mention` found in text, with its byte range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MentionToken {
+    start: usize,
+    end: usize,
+    name: String,
+}
+
+/// A mention that was resolved to a user.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct ResolvedMention {
+    pub mention: String,
+    pub user: User,
+    pub replacement: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub(crate) struct MentionResolution {
+    pub text: String,
+    pub mentions: Vec<ResolvedMention>,
+    pub warnings: Vec<String>,
+}
+
+/// Resolve mentions in `text` and print any warnings, returning the rewritten text.
+pub(crate) fn resolve_text(
+    client: &dyn IssueTracker,
+    scope: MentionScope,
+    text: &str,
+    format: OutputFormat,
+) -> String {
+    resolve_edited_text(client, scope, "", text, format)
+}
+
+/// Like [`resolve_text`], for an edited version of `original`. Mentions that
+/// were already in `original` are left alone: they came from the backend's
+/// own rendering and must not be resolved a second time.
+pub(crate) fn resolve_edited_text(
+    client: &dyn IssueTracker,
+    scope: MentionScope,
+    original: &str,
+    text: &str,
+    format: OutputFormat,
+) -> String {
+    let resolution = resolve_new_mentions(client, scope, original, text);
+    crate::output::output_verification_warnings(&resolution.warnings, format);
+    resolution.text
+}
+
+/// Resolve mentions in `text`. Lookup failures become warnings rather than
+/// errors, so a comment is never blocked by an unreachable user directory.
+pub(crate) fn resolve_mentions(
+    client: &dyn IssueTracker,
+    scope: MentionScope,
+    text: &str,
+) -> MentionResolution {
+    resolve_new_mentions(client, scope, "", text)
+}
+
+fn resolve_new_mentions(
+    client: &dyn IssueTracker,
+    scope: MentionScope,
+    original: &str,
+    text: &str,
+) -> MentionResolution {
+    let existing: Vec<&str> = find_mentions(original)
+        .into_iter()
+        .map(|t| &original[t.start..t.end])
+        .collect();
+    let tokens: Vec<MentionToken> = find_mentions(text)
+        .into_iter()
+        .filter(|t| !existing.contains(&&text[t.start..t.end]))
+        .collect();
+    if tokens.is_empty() {
+        return MentionResolution {
+            text: text.to_string(),
+            ..Default::default()
+        };
+    }
+
+    let mut warnings = Vec::new();
+    let (key, project_id) = match scope {
+        MentionScope::Project { key, id } => (Some(key.to_string()), Some(id.to_string())),
+        MentionScope::Issue(id) => match client.get_issue(id) {
+            Ok(issue) => (issue.project.short_name, Some(issue.project.id)),
+            Err(e) => {
+                warnings.push(format!(
+                    "Could not look up users to resolve mentions: {}",
+                    e
+                ));
+                (None, None)
+            }
+        },
+    };
+
+    // Cached users first; go to the backend only when the cache can't
+    // resolve every mention (missing or stale).
+    let mut users = key.as_deref().map(cached_users).unwrap_or_default();
+    let all_cached = tokens
+        .iter()
+        .all(|t| matches!(match_user(&users, &t.name), Match::One(_)));
+    if !all_cached && let Some(project_id) = project_id.as_deref() {
+        match client.list_project_users(project_id) {
+            Ok(fetched) if !fetched.is_empty() => users = fetched,
+            Ok(_) => {}
+            Err(e) => warnings.push(format!(
+                "Could not look up users to resolve mentions: {}",
+                e
+            )),
+        }
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut mentions = Vec::new();
+    let mut last = 0;
+    for token in &tokens {
+        let raw = &text[token.start..token.end];
+        out.push_str(&text[last..token.start]);
+        last = token.end;
+        match match_user(&users, &token.name) {
+            Match::One(user) => {
+                let replacement = client.format_mention(user);
+                out.push_str(&replacement);
+                mentions.push(ResolvedMention {
+                    mention: raw.to_string(),
+                    user: user.clone(),
+                    replacement,
+                });
+            }
+            Match::Many(candidates) => {
+                out.push_str(raw);
+                let names: Vec<String> = candidates.iter().map(|u| describe_user(u)).collect();
+                warnings.push(format!(
+                    "Mention {} is ambiguous ({}); left as plain text",
+                    raw,
+                    names.join(", ")
+                ));
+            }
+            Match::None => {
+                out.push_str(raw);
+                warnings.push(format!(
+                    "Mention {} did not match any user{}; left as plain text",
+                    raw,
+                    key.as_deref()
+                        .map(|k| format!(" in project {}", k))
+                        .unwrap_or_default()
+                ));
+            }
+        }
+    }
+    out.push_str(&text[last..]);
+
+    MentionResolution {
+        text: out,
+        mentions,
+        warnings,
+    }
+}
+
+fn cached_users(project_key: &str) -> Vec<User> {
+    let Ok(mut cache) = TrackerCache::load(None) else {
+        return Vec::new();
+    };
+    let _ = cache.ensure_project_shard(project_key);
+    cache
+        .get_project_users(project_key)
+        .map(|users| {
+            users
+                .iter()
+                .map(|u| User {
+                    id: u.id.clone(),
+                    login: u.login.clone(),
+                    display_name: u.display_name.clone(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn describe_user(user: &User) -> String {
+    match user.login.as_deref() {
+        Some(login) if login != user.display_name => {
+            format!("{} ({})", user.display_name, login)
+        }
+        _ => user.display_name.clone(),
+    }
+}
+
+enum Match<'a> {
+    None,
+    One(&'a User),
+    Many(Vec<&'a User>),
+}
+
+/// Match a mention against users, from the most to the least specific rule:
+/// login or ID, full display name, then the local part of an email login or
+/// the display name without spaces. The first rule with any hit decides.
+/// Names with spaces need the quoted `@"Display Name"` form.
+fn match_user<'a>(users: &'a [User], name: &str) -> Match<'a> {
+    let eq = |a: &str, b: &str| a.to_lowercase() == b.to_lowercase();
+    let rules: [&dyn Fn(&User) -> bool; 3] = [
+        &|u| u.login.as_deref().is_some_and(|l| eq(l, name)) || u.id == name,
+        &|u| eq(&u.display_name, name),
+        &|u| {
+            u.login
+                .as_deref()
+                .and_then(|l| l.split_once('@'))
+                .is_some_and(|(local, _)| eq(local, name))
+                || eq(&u.display_name.replace(char::is_whitespace, ""), name)
+        },
+    ];
+
+    for rule in rules {
+        let mut hits: Vec<&User> = users.iter().filter(|u| rule(u)).collect();
+        hits.dedup_by(|a, b| a.id == b.id);
+        match hits.len() {
+            0 => continue,
+            1 => return Match::One(hits[0]),
+            _ => return Match::Many(hits),
+        }
+    }
+    Match::None
+}
+
+fn is_login_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '.' | '_' | '-')
+}
+
+/// Find `@login` and `@"Display Name"` mentions, skipping inline code,
+/// fenced code blocks, and `@` inside words (emails) or paths.
+fn find_mentions(text: &str) -> Vec<MentionToken> {
+    let mut tokens = Vec::new();
+    let mut in_fence = false;
+    let mut offset = 0;
+
+    for line in text.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+        if line.trim_start().starts_with("```") || line.trim_start().starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+
+        let mut code_run: Option<usize> = None;
+        let mut prev: Option<char> = None;
+        let mut chars = line.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            if c == '`' {
+                let mut run = 1;
+                while chars.next_if(|&(_, c)| c == '`').is_some() {
+                    run += 1;
+                }
+                code_run = match code_run {
+                    None => Some(run),
+                    Some(open) if open == run => None,
+                    other => other,
+                };
+                prev = Some('`');
+                continue;
+            }
+            let starts_mention = c == '@'
+                && code_run.is_none()
+                && !prev.is_some_and(|p| is_login_char(p) || matches!(p, '@' | '/' | '\\'));
+            prev = Some(c);
+            if !starts_mention {
+                continue;
+            }
+
+            let rest = &line[i + 1..];
+            let (name, len) = if let Some(quoted) = rest.strip_prefix('"') {
+                match quoted.find('"') {
+                    Some(end) if !quoted[..end].trim().is_empty() => {
+                        (quoted[..end].trim().to_string(), end + 2)
+                    }
+                    _ => continue,
+                }
+            } else {
+                let end = rest.find(|c: char| !is_login_char(c)).unwrap_or(rest.len());
+                // Sentence punctuation right after a login isn't part of it.
+                let login = rest[..end].trim_end_matches(['.', '-']);
+                if login.is_empty() {
+                    continue;
+                }
+                (login.to_string(), login.len())
+            };
+
+            tokens.push(MentionToken {
+                start: line_start + i,
+                end: line_start + i + 1 + len,
+                name,
+            });
+            while chars.next_if(|&(j, _)| j < i + 1 + len).is_some() {}
+            prev = line[..i + 1 + len].chars().next_back();
+        }
+    }
+    tokens
+}
+
+/// Print a comment preview with resolved mentions, for `--dry-run`.
+pub(crate) fn output_preview(
+    issue_id: &str,
+    resolution: &MentionResolution,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    match format {
+        OutputFormat::Json => {
+            let response = serde_json::json!({
+                "dry_run": true,
+                "issue": issue_id,
+                "text": resolution.text,
+                "mentions": resolution.mentions,
+                "warnings": resolution.warnings,
+            });
+            crate::output::output_json(&response)?;
+        }
+        OutputFormat::Text => {
+            use colored::Colorize;
+            crate::output::output_verification_warnings(&resolution.warnings, format);
+            println!(
+                "Comment preview for {} (not posted):",
+                issue_id.cyan().bold()
+            );
+            for line in resolution.text.lines() {
+                println!("  {}", line);
+            }
+            for mention in &resolution.mentions {
+                println!(
+                    "  {} {} → {}",
+                    mention.mention.green(),
+                    describe_user(&mention.user).dimmed(),
+                    mention.replacement
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: &str, login: &str, name: &str) -> User {
+        User {
+            id: id.to_string(),
+            login: Some(login.to_string()),
+            display_name: name.to_string(),
+        }
+    }
+
+    fn names(text: &str) -> Vec<String> {
+        find_mentions(text).into_iter().map(|t| t.name).collect()
+    }
+
+    #[test]
+    fn finds_login_and_quoted_mentions() {
+        assert_eq!(
+            names("Thanks @alice, over to @\"Bob Smith\" and @carol."),
+            vec!["alice", "Bob Smith", "carol"]
+        );
+    }
+
+    #[test]
+    fn skips_emails_code_and_paths() {
+        let text = "Mail bob@example.com, run `@alice` or\n```\n@carol\n```\nsee ./@scope/pkg";
+        assert!(names(text).is_empty());
+    }
+
+    #[test]
+    fn match_prefers_login_then_display_name() {
+        let users = vec![
+            user("1", "alice", "Alice Smith"),
+            user("2", "asmith", "Alan Smith"),
+            user("3", "bob@example.com", "Bob Jones"),
+        ];
+
+        assert!(matches!(match_user(&users, "ALICE"), Match::One(u) if u.id == "1"));
+        assert!(matches!(match_user(&users, "Alan Smith"), Match::One(u) if u.id == "2"));
+        assert!(matches!(match_user(&users, "bob"), Match::One(u) if u.id == "3"));
+        assert!(matches!(match_user(&users, "BobJones"), Match::One(u) if u.id == "3"));
+        assert!(matches!(match_user(&users, "Al"), Match::None));
+    }
+
+    #[test]
+    fn match_reports_ambiguous_names() {
+        let users = vec![
+            user("1", "sam@a.com", "Sam Lee"),
+            user("2", "sam@b.com", "Sam Park"),
+        ];
+        assert!(matches!(match_user(&users, "sam"), Match::Many(hits) if hits.len() == 2));
+    }
+
+    #[test]
+    fn first_names_do_not_match() {
+        let users = vec![user("1", "jdoe", "Jane Doe")];
+        assert!(matches!(match_user(&users, "Jane"), Match::None));
+        assert!(matches!(match_user(&users, "Jane Doe"), Match::One(_)));
+    }
+}
@@ -1,23 +1,39 @@
 pub mod apply;
+pub(crate) mod apply_rollback;
+pub(crate) mod apply_template;
 pub mod article;
+pub mod article_sync;
 pub(crate) mod attachments;
 pub mod bundle;
 pub mod cache;
+pub mod changelog;
 pub mod config;
 pub mod context;
 pub mod doctor;
+pub(crate) mod edit;
 pub mod eval;
+pub mod external;
 pub mod field;
+pub mod git;
 pub mod init;
 pub(crate) mod inspect;
 pub mod issue;
+pub mod issue_template;
+pub(crate) mod mcp;
+pub(crate) mod mentions;
 pub mod open;
 pub mod project;
+pub mod report;
+pub mod rules;
+pub mod sync_file;
 pub mod tags;
+pub mod watch;
+pub mod webhook;
 
 use anyhow::{Context, Result};
 use std::io::Read;
 use std::path::Path;
+use std::process::Command;
 
 /// Resolve text content from an inline string or a file path.
 ///
@@ -46,6 +62,20 @@ pub(crate) fn resolve_body(
     }
 }
 
+/// Build a command that runs `command` through the platform shell, for
+/// user-supplied hooks like `watch --exec`.
+pub(crate) fn shell_command(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
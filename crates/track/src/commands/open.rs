@@ -10,6 +10,7 @@ pub fn handle_open(id: Option<&str>, config: &Config, format: cli::OutputFormat)
     use colored::Colorize;
 
     let base_url = config
+        .connection
         .url
         .as_ref()
         .ok_or_else(|| anyhow::anyhow!("No URL configured"))?;
@@ -17,7 +18,11 @@ pub fn handle_open(id: Option<&str>, config: &Config, format: cli::OutputFormat)
     // Remove trailing slash from base URL if present
     let base_url = base_url.trim_end_matches('/');
 
-    let url = if let Some(issue_id) = id {
+    let branch_id = match id {
+        Some(_) => None,
+        None => super::git::current_issue_id(&config.git),
+    };
+    let url = if let Some(issue_id) = id.or(branch_id.as_deref()) {
         // Open specific issue: https://youtrack.example.com/issue/PROJ-123
         format!("{}/issue/{}", base_url, issue_id)
     } else {
@@ -60,8 +65,17 @@ pub fn handle_open(id: Option<&str>, config: &Config, format: cli::OutputFormat)
     Ok(())
 }
 
-/// Check if a string looks like an issue ID (e.g., PROJ-123)
+/// Check if a string looks like an issue ID (e.g., PROJ-123, or a GitHub
+/// `repo#123` / `owner/repo#123`)
 pub fn is_issue_id(s: &str) -> bool {
+    if let Some((repo, number)) = s.rsplit_once('#') {
+        return !repo.is_empty()
+            && repo
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/'))
+            && !number.is_empty()
+            && number.chars().all(|c| c.is_ascii_digit());
+    }
     let parts: Vec<&str> = s.split('-').collect();
     parts.len() == 2
         && !parts[0].is_empty()
@@ -231,6 +245,16 @@ mod tests {
         assert!(!is_issue_id("A-B-1"));
     }
 
+    #[test]
+    fn is_issue_id_valid_github_repo_ref() {
+        assert!(is_issue_id("api#123"));
+        assert!(is_issue_id("web-app#7"));
+        assert!(is_issue_id("acme/web.site#42"));
+        assert!(!is_issue_id("#123"));
+        assert!(!is_issue_id("api#"));
+        assert!(!is_issue_id("api#12a"));
+    }
+
     #[test]
     fn is_issue_id_invalid_non_digit_suffix() {
         assert!(!is_issue_id("PROJ-abc"));
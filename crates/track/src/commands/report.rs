@@ -0,0 +1,679 @@
+//! `track report flow` — flow metrics computed from status history.
+//!
+//! Each matching issue's history is fetched and replayed to find when work
+//! started (first status change) and when it was resolved (the last move into
+//! a state the project schema marks `is_resolved`). From those timestamps we
+//! derive cycle time, lead time, time spent in each state, weekly throughput,
+//! and weekly work-in-progress.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::Serialize;
+use tracker_core::{
+    CustomField, FIELD_STATUS, Issue, IssueHistoryEvent, IssueTracker, canonical_field_name,
+};
+
+use crate::cache::CachedQueryTemplate;
+use crate::cli::OutputFormat;
+use crate::output::{output_json, output_progress};
+
+/// Arguments for `track report flow`.
+pub struct FlowArgs<'a> {
+    pub query: Option<&'a str>,
+    pub template: Option<&'a str>,
+    pub project: Option<&'a str>,
+    pub since: &'a str,
+    pub limit: usize,
+    pub csv: bool,
+    pub saved_queries: &'a [CachedQueryTemplate],
+}
+
+/// Start and end of one issue's journey through the workflow.
+#[derive(Debug, Serialize)]
+struct IssueFlow {
+    id: String,
+    summary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+    created: DateTime<Utc>,
+    started: Option<DateTime<Utc>>,
+    resolved: Option<DateTime<Utc>>,
+    lead_time_days: Option<f64>,
+    cycle_time_days: Option<f64>,
+    /// Time spent in each unresolved state, in order of first entry.
+    #[serde(skip)]
+    time_in_state: Vec<(String, Duration)>,
+}
+
+#[derive(Debug, Serialize)]
+struct FlowReport {
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+    issues_analyzed: usize,
+    completed: usize,
+    cycle_time: DurationStats,
+    lead_time: DurationStats,
+    time_in_state: Vec<StateTime>,
+    weeks: Vec<WeekStats>,
+    issues: Vec<IssueFlow>,
+}
+
+/// Distribution of a duration across completed issues, in days.
+#[derive(Debug, Default, PartialEq, Serialize)]
+struct DurationStats {
+    count: usize,
+    mean_days: Option<f64>,
+    p50_days: Option<f64>,
+    p85_days: Option<f64>,
+    p95_days: Option<f64>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct StateTime {
+    state: String,
+    issues: usize,
+    mean_days: f64,
+    total_days: f64,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct WeekStats {
+    week_start: NaiveDate,
+    /// Issues resolved during the week.
+    completed: usize,
+    /// Issues started but not yet resolved at the end of the week.
+    wip: usize,
+}
+
+pub fn handle_flow(
+    client: &dyn IssueTracker,
+    args: &FlowArgs,
+    default_project: Option<&str>,
+    format: OutputFormat,
+) -> Result<()> {
+    let window = super::issue::parse_since(args.since)?;
+    let query = super::issue::resolve_search_query(
+        args.query,
+        args.template,
+        args.project,
+        default_project,
+        args.saved_queries,
+    )?;
+    let issues = client
+        .search_all_issues(&query, args.limit)
+        .with_context(|| format!("Failed to search issues with query '{}'", query))?;
+
+    let now = Utc::now();
+    let mut resolved_states: HashMap<String, Vec<String>> = HashMap::new();
+    let mut flows = Vec::with_capacity(issues.len());
+    for (index, issue) in issues.iter().enumerate() {
+        output_progress(
+            &format!(
+                "Fetching history for {} ({}/{})",
+                issue.id_readable,
+                index + 1,
+                issues.len()
+            ),
+            format,
+        );
+        let events = client
+            .get_issue_history(&issue.id_readable)
+            .with_context(|| format!("Failed to get history for issue '{}'", issue.id_readable))?;
+        let states = resolved_states
+            .entry(issue.project.id.clone())
+            .or_insert_with(|| project_resolved_states(client, &issue.project.id));
+        flows.push(analyze_issue(issue, &events, states, now));
+    }
+
+    let report = build_report(flows, now - window, now);
+
+    if args.csv {
+        print!("{}", render_csv(&report.issues));
+        return Ok(());
+    }
+    match format {
+        OutputFormat::Json => output_json(&report),
+        OutputFormat::Text => {
+            print_report(&report);
+            Ok(())
+        }
+    }
+}
+
+/// Names of the resolved states in a project's workflow. Backends without a
+/// state schema yield none; issues then fall back to their own state and
+/// `resolved` timestamp.
+fn project_resolved_states(client: &dyn IssueTracker, project_id: &str) -> Vec<String> {
+    client
+        .get_project_custom_fields(project_id)
+        .map(|fields| {
+            fields
+                .iter()
+                .flat_map(|field| &field.state_values)
+                .filter(|value| value.is_resolved)
+                .map(|value| value.name.to_lowercase())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The issue's current state value and whether it's resolved.
+fn current_state(issue: &Issue) -> Option<(&str, bool)> {
+    issue.custom_fields.iter().find_map(|field| match field {
+        CustomField::State {
+            value: Some(value),
+            is_resolved,
+            ..
+        } => Some((value.as_str(), *is_resolved)),
+        _ => None,
+    })
+}
+
+/// Replay an issue's status transitions.
+///
+/// The issue starts with the first status change and is resolved by the last
+/// move into a resolved state (a reopen clears it). Without status history,
+/// the backend's `resolved` timestamp gives lead time but no cycle time.
+fn analyze_issue(
+    issue: &Issue,
+    events: &[IssueHistoryEvent],
+    resolved_states: &[String],
+    now: DateTime<Utc>,
+) -> IssueFlow {
+    let state = current_state(issue);
+    let is_resolved = |name: &str| {
+        let name = name.to_lowercase();
+        resolved_states.contains(&name)
+            || state.is_some_and(|(current, resolved)| resolved && current.to_lowercase() == name)
+    };
+
+    let mut transitions: Vec<&IssueHistoryEvent> = events
+        .iter()
+        .filter(|event| canonical_field_name(&event.field) == FIELD_STATUS)
+        .collect();
+    transitions.sort_by_key(|event| event.at);
+
+    let started = transitions.first().map(|event| event.at);
+    let mut resolved = None;
+    let mut time_in_state: Vec<(String, Duration)> = Vec::new();
+    let mut add_time = |name: &str, duration: Duration| {
+        if is_resolved(name) {
+            return;
+        }
+        match time_in_state.iter_mut().find(|(state, _)| state == name) {
+            Some((_, total)) => *total += duration,
+            None => time_in_state.push((name.to_string(), duration)),
+        }
+    };
+
+    let mut current: Option<String> = transitions.first().and_then(|event| event.from.clone());
+    let mut entered = issue.created;
+    for event in &transitions {
+        if let Some(name) = &current {
+            add_time(name, event.at - entered);
+        }
+        current = event.to.clone();
+        entered = event.at;
+        resolved = current
+            .as_deref()
+            .filter(|name| is_resolved(name))
+            .map(|_| event.at);
+    }
+    if let Some(name) = &current {
+        add_time(name, now - entered);
+    }
+
+    if transitions.is_empty() {
+        resolved = issue.resolved;
+    }
+
+    IssueFlow {
+        id: issue.id_readable.clone(),
+        summary: issue.summary.clone(),
+        state: state.map(|(name, _)| name.to_string()),
+        created: issue.created,
+        started,
+        resolved,
+        lead_time_days: resolved.map(|at| days(at - issue.created)),
+        cycle_time_days: started.zip(resolved).map(|(from, to)| days(to - from)),
+        time_in_state,
+    }
+}
+
+fn build_report(issues: Vec<IssueFlow>, since: DateTime<Utc>, until: DateTime<Utc>) -> FlowReport {
+    let completed: Vec<&IssueFlow> = issues
+        .iter()
+        .filter(|flow| flow.resolved.is_some_and(|at| at >= since && at <= until))
+        .collect();
+
+    let cycle: Vec<f64> = completed
+        .iter()
+        .filter_map(|flow| flow.cycle_time_days)
+        .collect();
+    let lead: Vec<f64> = completed
+        .iter()
+        .filter_map(|flow| flow.lead_time_days)
+        .collect();
+
+    let mut time_in_state: Vec<StateTime> = Vec::new();
+    for flow in &completed {
+        for (state, duration) in &flow.time_in_state {
+            let index = time_in_state
+                .iter()
+                .position(|entry| &entry.state == state)
+                .unwrap_or_else(|| {
+                    time_in_state.push(StateTime {
+                        state: state.clone(),
+                        issues: 0,
+                        mean_days: 0.0,
+                        total_days: 0.0,
+                    });
+                    time_in_state.len() - 1
+                });
+            time_in_state[index].issues += 1;
+            time_in_state[index].total_days += days(*duration);
+        }
+    }
+    for entry in &mut time_in_state {
+        entry.mean_days = round(entry.total_days / entry.issues as f64);
+        entry.total_days = round(entry.total_days);
+    }
+
+    // Weeks before the first issue existed carry no information, so a long
+    // --since doesn't pad the series with empty rows.
+    let first_activity = issues.iter().map(|flow| flow.created).min();
+    let series_start = first_activity.map_or(since, |first| first.max(since));
+    let weeks = week_starts(series_start.date_naive(), until.date_naive())
+        .into_iter()
+        .map(|week_start| {
+            let start = week_start.and_hms_opt(0, 0, 0).unwrap().and_utc();
+            let end = (start + Duration::weeks(1)).min(until);
+            WeekStats {
+                week_start,
+                completed: completed
+                    .iter()
+                    .filter(|flow| flow.resolved.is_some_and(|at| at >= start && at < end))
+                    .count(),
+                wip: issues
+                    .iter()
+                    .filter(|flow| {
+                        flow.started.is_some_and(|at| at < end)
+                            && flow.resolved.is_none_or(|at| at >= end)
+                    })
+                    .count(),
+            }
+        })
+        .collect();
+
+    FlowReport {
+        since,
+        until,
+        issues_analyzed: issues.len(),
+        completed: completed.len(),
+        cycle_time: duration_stats(cycle),
+        lead_time: duration_stats(lead),
+        time_in_state,
+        weeks,
+        issues,
+    }
+}
+
+/// Mondays of every week overlapping `from..=to`.
+fn week_starts(from: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+    let mut week = from - Duration::days(from.weekday().num_days_from_monday() as i64);
+    let mut weeks = Vec::new();
+    while week <= to {
+        weeks.push(week);
+        week += Duration::weeks(1);
+    }
+    weeks
+}
+
+fn duration_stats(mut values: Vec<f64>) -> DurationStats {
+    if values.is_empty() {
+        return DurationStats::default();
+    }
+    values.sort_by(f64::total_cmp);
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    DurationStats {
+        count: values.len(),
+        mean_days: Some(round(mean)),
+        p50_days: Some(percentile(&values, 50.0)),
+        p85_days: Some(percentile(&values, 85.0)),
+        p95_days: Some(percentile(&values, 95.0)),
+    }
+}
+
+/// Nearest-rank percentile of sorted, non-empty values.
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    let rank = (pct / 100.0 * sorted.len() as f64).ceil() as usize;
+    round(sorted[rank.clamp(1, sorted.len()) - 1])
+}
+
+fn days(duration: Duration) -> f64 {
+    round(duration.num_seconds() as f64 / 86_400.0)
+}
+
+fn round(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+fn format_days(value: Option<f64>) -> String {
+    value.map_or_else(|| "-".to_string(), |days| format!("{:.1}d", days))
+}
+
+fn print_report(report: &FlowReport) {
+    use colored::Colorize;
+
+    println!(
+        "Flow report {} → {} ({} issues, {} completed)",
+        report.since.format("%Y-%m-%d"),
+        report.until.format("%Y-%m-%d"),
+        report.issues_analyzed,
+        report.completed
+    );
+    println!();
+    println!(
+        "{:<12} {:>5} {:>8} {:>8} {:>8} {:>8}",
+        "".bold(),
+        "count".bold(),
+        "mean".bold(),
+        "p50".bold(),
+        "p85".bold(),
+        "p95".bold()
+    );
+    for (label, stats) in [
+        ("Cycle time", &report.cycle_time),
+        ("Lead time", &report.lead_time),
+    ] {
+        println!(
+            "{:<12} {:>5} {:>8} {:>8} {:>8} {:>8}",
+            label,
+            stats.count,
+            format_days(stats.mean_days),
+            format_days(stats.p50_days),
+            format_days(stats.p85_days),
+            format_days(stats.p95_days)
+        );
+    }
+
+    if !report.time_in_state.is_empty() {
+        println!();
+        println!("{}", "Time in state (completed issues)".bold());
+        let width = report
+            .time_in_state
+            .iter()
+            .map(|entry| entry.state.chars().count())
+            .max()
+            .unwrap_or(0);
+        for entry in &report.time_in_state {
+            println!(
+                "  {:<width$}  mean {:>8}  total {:>8}  ({} issues)",
+                entry.state,
+                format_days(Some(entry.mean_days)),
+                format_days(Some(entry.total_days)),
+                entry.issues,
+                width = width
+            );
+        }
+    }
+
+    if !report.weeks.is_empty() {
+        println!();
+        println!(
+            "{:<12} {:>9} {:>5}",
+            "Week of".bold(),
+            "completed".bold(),
+            "wip".bold()
+        );
+        for week in &report.weeks {
+            println!(
+                "{:<12} {:>9} {:>5}",
+                week.week_start.to_string(),
+                week.completed,
+                week.wip
+            );
+        }
+    }
+}
+
+/// One row per analyzed issue, for spreadsheets.
+fn render_csv(issues: &[IssueFlow]) -> String {
+    let mut out =
+        String::from("id,summary,state,created,started,resolved,lead_time_days,cycle_time_days\n");
+    let timestamp = |at: Option<DateTime<Utc>>| at.map(|at| at.to_rfc3339()).unwrap_or_default();
+    let number = |days: Option<f64>| days.map(|days| days.to_string()).unwrap_or_default();
+    for flow in issues {
+        let row = [
+            csv_field(&flow.id),
+            csv_field(&flow.summary),
+            csv_field(flow.state.as_deref().unwrap_or_default()),
+            flow.created.to_rfc3339(),
+            timestamp(flow.started),
+            timestamp(flow.resolved),
+            number(flow.lead_time_days),
+            number(flow.cycle_time_days),
+        ];
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracker_core::ProjectRef;
+
+    fn at(date: &str) -> DateTime<Utc> {
+        format!("{}T00:00:00Z", date).parse().unwrap()
+    }
+
+    fn issue(id: &str, created: &str, state: &str, is_resolved: bool) -> Issue {
+        Issue {
+            id: id.to_lowercase(),
+            id_readable: id.to_string(),
+            summary: format!("Summary of {}", id),
+            description: None,
+            project: ProjectRef {
+                id: "p1".to_string(),
+                name: None,
+                short_name: Some("PROJ".to_string()),
+            },
+            custom_fields: vec![CustomField::State {
+                name: "State".to_string(),
+                value: Some(state.to_string()),
+                is_resolved,
+            }],
+            tags: vec![],
+            created: at(created),
+            updated: at(created),
+            resolved: None,
+        }
+    }
+
+    fn status(date: &str, from: &str, to: &str) -> IssueHistoryEvent {
+        IssueHistoryEvent {
+            at: at(date),
+            author: None,
+            field: "State".to_string(),
+            from: Some(from.to_string()),
+            to: Some(to.to_string()),
+        }
+    }
+
+    fn resolved() -> Vec<String> {
+        vec!["done".to_string()]
+    }
+
+    #[test]
+    fn analyze_issue_replays_status_transitions() {
+        // Newest-first, as most backends return history
+        let events = vec![
+            status("2026-04-13", "In Review", "Done"),
+            status("2026-04-10", "In Progress", "In Review"),
+            status("2026-04-06", "Open", "In Progress"),
+        ];
+        let flow = analyze_issue(
+            &issue("PROJ-1", "2026-04-01", "Done", true),
+            &events,
+            &resolved(),
+            at("2026-05-01"),
+        );
+
+        assert_eq!(flow.started, Some(at("2026-04-06")));
+        assert_eq!(flow.resolved, Some(at("2026-04-13")));
+        assert_eq!(flow.cycle_time_days, Some(7.0));
+        assert_eq!(flow.lead_time_days, Some(12.0));
+        assert_eq!(
+            flow.time_in_state,
+            vec![
+                ("Open".to_string(), Duration::days(5)),
+                ("In Progress".to_string(), Duration::days(4)),
+                ("In Review".to_string(), Duration::days(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn analyze_issue_reopen_clears_resolution_until_resolved_again() {
+        let events = vec![
+            status("2026-04-08", "Open", "In Progress"),
+            status("2026-04-09", "In Progress", "Done"),
+            status("2026-04-10", "Done", "In Progress"),
+            status("2026-04-20", "In Progress", "Done"),
+        ];
+        let flow = analyze_issue(
+            &issue("PROJ-2", "2026-04-03", "Done", true),
+            &events,
+            &resolved(),
+            at("2026-05-01"),
+        );
+        assert_eq!(flow.resolved, Some(at("2026-04-20")));
+        assert_eq!(flow.cycle_time_days, Some(12.0));
+        assert_eq!(
+            flow.time_in_state[1],
+            ("In Progress".to_string(), Duration::days(11))
+        );
+
+        let reopened = analyze_issue(
+            &issue("PROJ-2", "2026-04-03", "In Progress", false),
+            &events[..3],
+            &resolved(),
+            at("2026-05-01"),
+        );
+        assert_eq!(reopened.resolved, None);
+        assert_eq!(reopened.cycle_time_days, None);
+    }
+
+    #[test]
+    fn analyze_issue_falls_back_to_current_state_and_resolved_timestamp() {
+        // No schema: the issue's own resolved state name counts as resolved
+        let events = vec![
+            status("2026-04-02", "Open", "In Progress"),
+            status("2026-04-04", "In Progress", "Shipped"),
+        ];
+        let flow = analyze_issue(
+            &issue("PROJ-3", "2026-04-01", "Shipped", true),
+            &events,
+            &[],
+            at("2026-05-01"),
+        );
+        assert_eq!(flow.resolved, Some(at("2026-04-04")));
+
+        let mut no_history = issue("PROJ-4", "2026-04-01", "Done", true);
+        no_history.resolved = Some(at("2026-04-11"));
+        let flow = analyze_issue(&no_history, &[], &resolved(), at("2026-05-01"));
+        assert_eq!(flow.lead_time_days, Some(10.0));
+        assert_eq!(flow.cycle_time_days, None);
+    }
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        assert_eq!(percentile(&values, 50.0), 5.0);
+        assert_eq!(percentile(&values, 85.0), 9.0);
+        assert_eq!(percentile(&values, 95.0), 10.0);
+        assert_eq!(percentile(&[4.0], 95.0), 4.0);
+        assert_eq!(duration_stats(vec![]), DurationStats::default());
+    }
+
+    #[test]
+    fn build_report_counts_weekly_throughput_and_wip() {
+        let flows = vec![
+            analyze_issue(
+                &issue("PROJ-1", "2026-04-01", "Done", true),
+                &[
+                    status("2026-04-06", "Open", "In Progress"),
+                    status("2026-04-13", "In Progress", "Done"),
+                ],
+                &resolved(),
+                at("2026-04-30"),
+            ),
+            analyze_issue(
+                &issue("PROJ-2", "2026-04-08", "In Progress", false),
+                &[status("2026-04-09", "Open", "In Progress")],
+                &resolved(),
+                at("2026-04-30"),
+            ),
+        ];
+        let report = build_report(flows, at("2026-03-01"), at("2026-04-22"));
+
+        assert_eq!(report.completed, 1);
+        assert_eq!(report.cycle_time.p50_days, Some(7.0));
+        assert_eq!(
+            report.weeks,
+            vec![
+                WeekStats {
+                    week_start: NaiveDate::from_ymd_opt(2026, 3, 30).unwrap(),
+                    completed: 0,
+                    wip: 0,
+                },
+                WeekStats {
+                    week_start: NaiveDate::from_ymd_opt(2026, 4, 6).unwrap(),
+                    completed: 0,
+                    wip: 2,
+                },
+                WeekStats {
+                    week_start: NaiveDate::from_ymd_opt(2026, 4, 13).unwrap(),
+                    completed: 1,
+                    wip: 1,
+                },
+                WeekStats {
+                    week_start: NaiveDate::from_ymd_opt(2026, 4, 20).unwrap(),
+                    completed: 0,
+                    wip: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn render_csv_quotes_fields_when_needed() {
+        let flow = analyze_issue(
+            &issue("PROJ-1", "2026-04-01", "Open", false),
+            &[],
+            &resolved(),
+            at("2026-04-30"),
+        );
+        let flow = IssueFlow {
+            summary: "Fix \"login\", again".to_string(),
+            ..flow
+        };
+        assert_eq!(
+            render_csv(&[flow]),
+            "id,summary,state,created,started,resolved,lead_time_days,cycle_time_days\n\
+             PROJ-1,\"Fix \"\"login\"\", again\",Open,2026-04-01T00:00:00+00:00,,,,\n"
+        );
+    }
+}
@@ -0,0 +1,515 @@
+//! `track rules` — declarative triage rules evaluated client-side.
+//!
+//! A rules file lists queries with extra conditions (fields, tags, age, last
+//! activity) and the actions to take on every matching issue. Rules compile to
+//! an apply plan (see [`super::apply`]), so `--dry-run`, `--resume`, and the
+//! JSON summary behave exactly like `track apply`.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tracker_core::{Issue, IssueTracker, KnowledgeBase, unicode_eq_ignore_case};
+
+use crate::cache::{self, CachedQueryTemplate};
+use crate::cli::OutputFormat;
+use crate::commands::apply::{ApplyOptions, apply_plan_bytes};
+use crate::commands::apply_template::escape_placeholders;
+use crate::output::{find_field_value, find_state_value, output_json, output_progress};
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RulesFile {
+    #[serde(default)]
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Rule {
+    name: String,
+    #[serde(default)]
+    query: Option<String>,
+    /// Query template or saved query, as for `issue search -T`
+    #[serde(default)]
+    template: Option<String>,
+    #[serde(default)]
+    project: Option<String>,
+    #[serde(default = "default_rule_limit")]
+    limit: usize,
+    #[serde(default)]
+    when: Conditions,
+    then: Actions,
+}
+
+/// Client-side filters applied to the query results. All must hold.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Conditions {
+    /// Current state is one of these
+    #[serde(default)]
+    state: Option<OneOrMany>,
+    /// Field (by name) has one of the listed values
+    #[serde(default)]
+    fields: BTreeMap<String, OneOrMany>,
+    #[serde(default)]
+    unassigned: Option<bool>,
+    /// Every listed tag is present
+    #[serde(default)]
+    tags: Vec<String>,
+    /// None of the listed tags is present
+    #[serde(default)]
+    without_tags: Vec<String>,
+    /// Created longer ago than this (e.g. "90d")
+    #[serde(default)]
+    older_than: Option<String>,
+    /// Not updated for this long (e.g. "14d")
+    #[serde(default)]
+    inactive_for: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Actions {
+    #[serde(default)]
+    state: Option<String>,
+    #[serde(default)]
+    fields: BTreeMap<String, OneOrMany>,
+    #[serde(default)]
+    add_tags: Vec<String>,
+    #[serde(default)]
+    assign: Option<String>,
+    #[serde(default)]
+    comment: Option<String>,
+    #[serde(default)]
+    link: Option<LinkAction>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LinkAction {
+    target: String,
+    #[serde(default, rename = "type")]
+    link_type: Option<String>,
+}
+
+/// A field value written as a single string or a list of strings.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl OneOrMany {
+    pub(crate) fn values(&self) -> &[String] {
+        match self {
+            Self::One(value) => std::slice::from_ref(value),
+            Self::Many(values) => values,
+        }
+    }
+
+    pub(crate) fn to_json(&self) -> Value {
+        match self {
+            Self::One(value) => json!(value),
+            Self::Many(values) => json!(values),
+        }
+    }
+}
+
+fn default_rule_limit() -> usize {
+    100
+}
+
+/// Arguments shared by `track rules run` and `track rules plan`.
+pub struct RulesArgs<'a> {
+    pub file: &'a Path,
+    pub only: &'a [String],
+    pub saved_queries: &'a [CachedQueryTemplate],
+    pub default_project: Option<&'a str>,
+}
+
+/// Compile the rules and print the resulting apply plan without running it.
+pub fn handle_rules_plan(client: &dyn IssueTracker, args: &RulesArgs) -> Result<()> {
+    let plan = compile_rules(client, args, OutputFormat::Json)?;
+    output_json(&plan)
+}
+
+/// Compile the rules and execute the plan through `track apply`.
+pub fn handle_rules_run(
+    client: &dyn IssueTracker,
+    kb_client: &dyn KnowledgeBase,
+    args: &RulesArgs,
+    options: ApplyOptions<'_>,
+) -> Result<()> {
+    let plan = compile_rules(client, args, options.format)?;
+    let raw_plan = serde_json::to_vec(&plan).context("Failed to serialize rules plan")?;
+    apply_plan_bytes(client, kb_client, &raw_plan, options)
+}
+
+fn compile_rules(
+    client: &dyn IssueTracker,
+    args: &RulesArgs,
+    format: OutputFormat,
+) -> Result<Value> {
+    let rules = load_rules(args.file)?;
+    for name in args.only {
+        if !rules.iter().any(|rule| &rule.name == name) {
+            bail!("No rule named '{}' in '{}'", name, args.file.display());
+        }
+    }
+
+    let now = Utc::now();
+    let mut operations = Vec::new();
+    for rule in rules
+        .iter()
+        .filter(|rule| args.only.is_empty() || args.only.contains(&rule.name))
+    {
+        let query = super::issue::resolve_search_query(
+            rule.query.as_deref(),
+            rule.template.as_deref(),
+            rule.project.as_deref(),
+            args.default_project,
+            args.saved_queries,
+        )
+        .with_context(|| format!("Rule '{}'", rule.name))?;
+        let issues = client
+            .search_all_issues(&query, rule.limit)
+            .with_context(|| format!("Rule '{}': failed to search '{}'", rule.name, query))?;
+
+        let mut matched = 0;
+        for issue in &issues {
+            if rule_matches(&rule.when, issue, now)? {
+                matched += 1;
+                operations.extend(rule_operations(&rule.then, &issue_id(issue)));
+            }
+        }
+        output_progress(
+            &format!(
+                "Rule '{}': {} of {} issues matched",
+                rule.name,
+                matched,
+                issues.len()
+            ),
+            format,
+        );
+    }
+
+    // Rule values are literal text, not apply placeholders
+    operations.iter_mut().for_each(escape_placeholders);
+    Ok(json!({ "version": 1, "operations": operations }))
+}
+
+fn load_rules(path: &Path) -> Result<Vec<Rule>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read rules file '{}'", path.display()))?;
+    let file: RulesFile = toml::from_str(&content)
+        .with_context(|| format!("Invalid rules file '{}'", path.display()))?;
+    validate_rules(&file.rules)?;
+    Ok(file.rules)
+}
+
+fn validate_rules(rules: &[Rule]) -> Result<()> {
+    for (index, rule) in rules.iter().enumerate() {
+        if rules[..index].iter().any(|other| other.name == rule.name) {
+            bail!("Duplicate rule name '{}'", rule.name);
+        }
+        if rule.query.is_none() && rule.template.is_none() {
+            bail!("Rule '{}' needs a query or template", rule.name);
+        }
+        if rule_operations(&rule.then, "").is_empty() {
+            bail!("Rule '{}' has no actions", rule.name);
+        }
+        for (name, duration) in [
+            ("older_than", &rule.when.older_than),
+            ("inactive_for", &rule.when.inactive_for),
+        ] {
+            if let Some(duration) = duration {
+                cache::parse_duration(duration)
+                    .with_context(|| format!("Rule '{}': invalid {}", rule.name, name))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn rule_matches(when: &Conditions, issue: &Issue, now: DateTime<Utc>) -> Result<bool> {
+    if let Some(wanted) = &when.state
+        && !find_state_value(issue).is_some_and(|state| {
+            wanted
+                .values()
+                .iter()
+                .any(|wanted| unicode_eq_ignore_case(wanted, state))
+        })
+    {
+        return Ok(false);
+    }
+
+    for (name, wanted) in &when.fields {
+        let value = find_field_value(issue, name);
+        if !value.is_some_and(|value| {
+            wanted
+                .values()
+                .iter()
+                .any(|wanted| unicode_eq_ignore_case(wanted, &value))
+        }) {
+            return Ok(false);
+        }
+    }
+
+    if let Some(unassigned) = when.unassigned
+        && find_field_value(issue, "Assignee").is_none() != unassigned
+    {
+        return Ok(false);
+    }
+
+    let has_tag = |wanted: &str| {
+        issue
+            .tags
+            .iter()
+            .any(|tag| unicode_eq_ignore_case(&tag.name, wanted))
+    };
+    if !when.tags.iter().all(|tag| has_tag(tag)) || when.without_tags.iter().any(|tag| has_tag(tag))
+    {
+        return Ok(false);
+    }
+
+    if let Some(age) = &when.older_than
+        && issue.created > now - cache::parse_duration(age)?
+    {
+        return Ok(false);
+    }
+    if let Some(idle) = &when.inactive_for
+        && issue.updated > now - cache::parse_duration(idle)?
+    {
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
+/// Apply-plan operations for one matching issue: a single `update_issue` for
+/// field/state/assignee changes, one `add_tag` per tag (backends treat the
+/// update's `tags` as the full set), then `comment` and `link` as needed.
+fn rule_operations(then: &Actions, issue: &str) -> Vec<Value> {
+    let mut operations = Vec::new();
+
+    let mut update = serde_json::Map::new();
+    if let Some(state) = &then.state {
+        update.insert("state".to_string(), json!(state));
+    }
+    if !then.fields.is_empty() {
+        let fields: serde_json::Map<String, Value> = then
+            .fields
+            .iter()
+            .map(|(name, value)| (name.clone(), value.to_json()))
+            .collect();
+        update.insert("fields".to_string(), Value::Object(fields));
+    }
+    if let Some(assignee) = &then.assign {
+        update.insert("assignee".to_string(), json!(assignee));
+    }
+    if !update.is_empty() {
+        update.insert("op".to_string(), json!("update_issue"));
+        update.insert("issue".to_string(), json!(issue));
+        operations.push(Value::Object(update));
+    }
+
+    for tag in &then.add_tags {
+        operations.push(json!({ "op": "add_tag", "issue": issue, "tag": tag }));
+    }
+    if let Some(body) = &then.comment {
+        operations.push(json!({ "op": "comment", "issue": issue, "body": body }));
+    }
+    if let Some(link) = &then.link {
+        let mut op = json!({ "op": "link", "source": issue, "target": link.target });
+        if let Some(link_type) = &link.link_type {
+            op["type"] = json!(link_type);
+        }
+        operations.push(op);
+    }
+
+    operations
+}
+
+fn issue_id(issue: &Issue) -> String {
+    if issue.id_readable.is_empty() {
+        issue.id.clone()
+    } else {
+        issue.id_readable.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracker_core::{CustomField, ProjectRef, Tag};
+
+    fn at(date: &str) -> DateTime<Utc> {
+        format!("{}T00:00:00Z", date).parse().unwrap()
+    }
+
+    fn issue(state: &str, assignee: Option<&str>, tags: &[&str], updated: &str) -> Issue {
+        Issue {
+            id: "2-1".to_string(),
+            id_readable: "PROJ-1".to_string(),
+            summary: "Crash on start".to_string(),
+            description: None,
+            project: ProjectRef {
+                id: "p1".to_string(),
+                name: None,
+                short_name: Some("PROJ".to_string()),
+            },
+            custom_fields: vec![
+                CustomField::State {
+                    name: "State".to_string(),
+                    value: Some(state.to_string()),
+                    is_resolved: false,
+                },
+                CustomField::SingleEnum {
+                    name: "Type".to_string(),
+                    value: Some("Bug".to_string()),
+                },
+                CustomField::SingleUser {
+                    name: "Assignee".to_string(),
+                    login: assignee.map(String::from),
+                    display_name: None,
+                },
+            ],
+            tags: tags
+                .iter()
+                .map(|name| Tag {
+                    id: name.to_string(),
+                    name: name.to_string(),
+                })
+                .collect(),
+            created: at("2026-01-01"),
+            updated: at(updated),
+            resolved: None,
+        }
+    }
+
+    fn parse(rules: &str) -> Vec<Rule> {
+        let file: RulesFile = toml::from_str(rules).unwrap();
+        validate_rules(&file.rules).unwrap();
+        file.rules
+    }
+
+    #[test]
+    fn conditions_check_fields_tags_assignee_and_activity() {
+        let rules = parse(
+            r#"
+            [[rules]]
+            name = "triage"
+            query = "project: PROJ"
+            [rules.when]
+            state = ["Open", "Submitted"]
+            fields = { Type = "bug" }
+            unassigned = true
+            without_tags = ["triaged"]
+            inactive_for = "14d"
+            [rules.then]
+            add_tags = ["triaged"]
+            "#,
+        );
+        let when = &rules[0].when;
+        let now = at("2026-03-01");
+
+        assert!(rule_matches(when, &issue("open", None, &[], "2026-02-01"), now).unwrap());
+        assert!(!rule_matches(when, &issue("Fixed", None, &[], "2026-02-01"), now).unwrap());
+        assert!(!rule_matches(when, &issue("Open", Some("jane"), &[], "2026-02-01"), now).unwrap());
+        assert!(
+            !rule_matches(when, &issue("Open", None, &["Triaged"], "2026-02-01"), now).unwrap()
+        );
+        assert!(!rule_matches(when, &issue("Open", None, &[], "2026-02-20"), now).unwrap());
+    }
+
+    #[test]
+    fn actions_compile_to_apply_operations() {
+        let rules = parse(
+            r#"
+            [[rules]]
+            name = "close-stale"
+            query = "project: PROJ"
+            [rules.then]
+            state = "Won't Fix"
+            fields = { Priority = "Minor", Platform = ["macOS"] }
+            add_tags = ["stale"]
+            assign = "jane"
+            comment = "Closing after 90 days without activity."
+            link = { target = "PROJ-9", type = "duplicates" }
+            "#,
+        );
+
+        let operations = rule_operations(&rules[0].then, "PROJ-1");
+        assert_eq!(
+            operations,
+            vec![
+                json!({
+                    "op": "update_issue",
+                    "issue": "PROJ-1",
+                    "state": "Won't Fix",
+                    "fields": { "Platform": ["macOS"], "Priority": "Minor" },
+                    "assignee": "jane"
+                }),
+                json!({ "op": "add_tag", "issue": "PROJ-1", "tag": "stale" }),
+                json!({
+                    "op": "comment",
+                    "issue": "PROJ-1",
+                    "body": "Closing after 90 days without activity."
+                }),
+                json!({
+                    "op": "link",
+                    "source": "PROJ-1",
+                    "target": "PROJ-9",
+                    "type": "duplicates"
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn validation_rejects_rules_without_actions_or_query() {
+        let no_actions: RulesFile = toml::from_str(
+            r#"
+            [[rules]]
+            name = "noop"
+            query = "project: PROJ"
+            [rules.then]
+            "#,
+        )
+        .unwrap();
+        assert!(
+            validate_rules(&no_actions.rules)
+                .unwrap_err()
+                .to_string()
+                .contains("has no actions")
+        );
+
+        let no_query: RulesFile = toml::from_str(
+            r#"
+            [[rules]]
+            name = "tag"
+            [rules.then]
+            add_tags = ["x"]
+            "#,
+        )
+        .unwrap();
+        assert!(validate_rules(&no_query.rules).is_err());
+
+        let bad_age: RulesFile = toml::from_str(
+            r#"
+            [[rules]]
+            name = "old"
+            query = "project: PROJ"
+            when = { older_than = "3 months" }
+            then = { add_tags = ["old"] }
+            "#,
+        )
+        .unwrap();
+        assert!(validate_rules(&bad_age.rules).is_err());
+    }
+}
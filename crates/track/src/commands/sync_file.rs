@@ -0,0 +1,748 @@
+//! `track sync-file` — make the tracker follow a file of desired issues.
+//!
+//! The file lists issues under stable keys of its own choosing. Each key is
+//! stored on the tracker issue, either in a custom field (`key_field`) or in
+//! a `<!-- track-sync: KEY -->` marker at the end of the description, so
+//! later runs find the same issue again. The desired state is diffed against
+//! the issues the file's `query` returns and compiled to an apply plan (see
+//! [`super::apply`]): missing issues are created, managed values that drifted
+//! are updated, and missing links are added.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use tracker_core::{Issue, IssueTracker, KnowledgeBase, unicode_eq_ignore_case};
+
+use crate::cli::OutputFormat;
+use crate::commands::apply::{ApplyOptions, apply_plan_bytes};
+use crate::commands::apply_rollback::{find_link, parent_from_links};
+use crate::commands::apply_template::escape_placeholders;
+use crate::commands::rules::OneOrMany;
+use crate::output::{find_field_value, find_state_value, output_json, output_progress};
+
+const MARKER_PREFIX: &str = "<!-- track-sync:";
+const MARKER_SUFFIX: &str = "-->";
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SyncFile {
+    /// Search returning every issue this file manages
+    query: String,
+    /// Project for new issues; falls back to the default project
+    #[serde(default)]
+    project: Option<String>,
+    /// Custom field holding the key; without it the key goes in a
+    /// description marker
+    #[serde(default)]
+    key_field: Option<String>,
+    #[serde(default = "default_sync_limit")]
+    limit: usize,
+    #[serde(default)]
+    issues: Vec<DesiredIssue>,
+}
+
+/// One issue as the file wants it. Values left out are not managed.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DesiredIssue {
+    key: String,
+    summary: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    state: Option<String>,
+    #[serde(default)]
+    fields: BTreeMap<String, OneOrMany>,
+    /// The complete tag set: missing tags are added, others removed
+    #[serde(default)]
+    tags: Option<Vec<String>>,
+    /// Another key in the file or a tracker issue ID; empty for no parent
+    #[serde(default)]
+    parent: Option<String>,
+    /// Links to add if missing; existing links are never removed
+    #[serde(default)]
+    links: Vec<DesiredLink>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DesiredLink {
+    /// Another key in the file or a tracker issue ID
+    target: String,
+    #[serde(default = "default_link_type", rename = "type")]
+    link_type: String,
+}
+
+fn default_sync_limit() -> usize {
+    500
+}
+
+fn default_link_type() -> String {
+    "relates".to_string()
+}
+
+/// The outcome of diffing the file against the tracker.
+#[derive(Debug, Serialize)]
+struct SyncPlan {
+    changes: Vec<IssueChange>,
+    /// Tracker issues carrying a key the file no longer lists. They are
+    /// reported, never closed or deleted.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    unmanaged: Vec<UnmanagedIssue>,
+    plan: Value,
+}
+
+#[derive(Debug, Serialize)]
+struct IssueChange {
+    key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issue: Option<String>,
+    /// "create", "update", or "unchanged"
+    action: &'static str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Serialize)]
+struct FieldChange {
+    field: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    to: Option<String>,
+}
+
+impl FieldChange {
+    fn new(field: &str, from: Option<&str>, to: Option<&str>) -> Self {
+        Self {
+            field: field.to_string(),
+            from: from.map(String::from),
+            to: to.map(String::from),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct UnmanagedIssue {
+    key: String,
+    issue: String,
+}
+
+/// Diff the file against the tracker and print the changes and apply plan.
+pub fn handle_sync_plan(
+    client: &dyn IssueTracker,
+    path: &Path,
+    format: OutputFormat,
+) -> Result<()> {
+    let sync = build_sync_plan(client, path, format)?;
+    match format {
+        OutputFormat::Json => output_json(&sync),
+        OutputFormat::Text => {
+            output_sync_text(&sync);
+            Ok(())
+        }
+    }
+}
+
+/// Diff the file against the tracker and run the changes through `track apply`.
+pub fn handle_sync_apply(
+    client: &dyn IssueTracker,
+    kb_client: &dyn KnowledgeBase,
+    path: &Path,
+    options: ApplyOptions<'_>,
+) -> Result<()> {
+    let sync = build_sync_plan(client, path, options.format)?;
+    for unmanaged in &sync.unmanaged {
+        output_progress(
+            &format!(
+                "{} has key '{}', which is not in the file; leaving it alone",
+                unmanaged.issue, unmanaged.key
+            ),
+            options.format,
+        );
+    }
+    let raw_plan = serde_json::to_vec(&sync.plan).context("Failed to serialize sync plan")?;
+    apply_plan_bytes(client, kb_client, &raw_plan, options)
+}
+
+fn build_sync_plan(
+    client: &dyn IssueTracker,
+    path: &Path,
+    format: OutputFormat,
+) -> Result<SyncPlan> {
+    let file = load_sync_file(path)?;
+    let issues = client
+        .search_all_issues(&file.query, file.limit)
+        .with_context(|| format!("Failed to search '{}'", file.query))?;
+    if issues.len() >= file.limit {
+        bail!(
+            "Query '{}' returned {} issues, the file's limit; raise `limit` so no managed issue is missed",
+            file.query,
+            file.limit
+        );
+    }
+
+    let mut existing: BTreeMap<String, &Issue> = BTreeMap::new();
+    for issue in &issues {
+        let Some(key) = issue_key(issue, file.key_field.as_deref()) else {
+            continue;
+        };
+        if let Some(other) = existing.insert(key.clone(), issue) {
+            bail!(
+                "Key '{}' is on both {} and {}",
+                key,
+                issue_id(other),
+                issue_id(issue)
+            );
+        }
+    }
+    output_progress(
+        &format!(
+            "{} of {} issues from '{}' carry a sync key",
+            existing.len(),
+            issues.len(),
+            file.query
+        ),
+        format,
+    );
+
+    // Keys and parents/links resolve to the existing issue, or to the ref of
+    // the create operation that will make it.
+    let resolve = |value: &str| -> String {
+        match existing.get(value) {
+            Some(issue) => issue_id(issue),
+            None if file.issues.iter().any(|desired| desired.key == value) => format!("${value}"),
+            None => value.to_string(),
+        }
+    };
+
+    let mut creates = Vec::new();
+    let mut parents = Vec::new();
+    let mut updates = Vec::new();
+    let mut links = Vec::new();
+    let mut changes = Vec::new();
+
+    for desired in &file.issues {
+        let parent = desired
+            .parent
+            .as_deref()
+            .filter(|parent| !parent.is_empty())
+            .map(&resolve);
+        let link_targets: Vec<(String, &str)> = desired
+            .links
+            .iter()
+            .map(|link| (resolve(&link.target), link.link_type.as_str()))
+            .collect();
+
+        let Some(issue) = existing.get(desired.key.as_str()) else {
+            let mut create = create_operation(&file, desired);
+            match &parent {
+                Some(parent) if parent.starts_with('$') => parents.push(json!({
+                    "op": "set_parent",
+                    "issue": format!("${}", desired.key),
+                    "parent": parent
+                })),
+                Some(parent) => create["parent"] = json!(parent),
+                None => {}
+            }
+            creates.push(create);
+            for (target, link_type) in link_targets {
+                links.push(link_operation(
+                    &format!("${}", desired.key),
+                    &target,
+                    link_type,
+                ));
+            }
+            changes.push(IssueChange {
+                key: desired.key.clone(),
+                issue: None,
+                action: "create",
+                changes: Vec::new(),
+            });
+            continue;
+        };
+
+        let issue_id = issue_id(issue);
+        let (operations, mut field_changes) = update_operations(&file, desired, issue, &issue_id);
+        updates.extend(operations);
+
+        if desired.parent.is_some() || !link_targets.is_empty() {
+            let current_links = client
+                .get_issue_links(&issue_id)
+                .with_context(|| format!("Failed to fetch links for '{}'", issue_id))?;
+            if desired.parent.is_some() {
+                let current = parent_from_links(&current_links);
+                if current.as_deref() != parent.as_deref() {
+                    updates.push(match &parent {
+                        Some(parent) => {
+                            json!({"op": "set_parent", "issue": issue_id, "parent": parent})
+                        }
+                        None => json!({"op": "remove_parent", "issue": issue_id}),
+                    });
+                    field_changes.push(FieldChange::new(
+                        "parent",
+                        current.as_deref(),
+                        parent.as_deref(),
+                    ));
+                }
+            }
+            for (target, link_type) in link_targets {
+                if target.starts_with('$')
+                    || find_link(&current_links, &target, link_type).is_none()
+                {
+                    links.push(link_operation(&issue_id, &target, link_type));
+                    field_changes.push(FieldChange::new(
+                        "links",
+                        None,
+                        Some(&format!("{link_type} {target}")),
+                    ));
+                }
+            }
+        }
+
+        changes.push(IssueChange {
+            key: desired.key.clone(),
+            issue: Some(issue_id),
+            action: if field_changes.is_empty() {
+                "unchanged"
+            } else {
+                "update"
+            },
+            changes: field_changes,
+        });
+    }
+
+    let wanted: BTreeSet<&str> = file
+        .issues
+        .iter()
+        .map(|desired| desired.key.as_str())
+        .collect();
+    let unmanaged = existing
+        .iter()
+        .filter(|(key, _)| !wanted.contains(key.as_str()))
+        .map(|(key, issue)| UnmanagedIssue {
+            key: key.clone(),
+            issue: issue_id(issue),
+        })
+        .collect();
+
+    let mut operations: Vec<Value> = creates
+        .into_iter()
+        .chain(parents)
+        .chain(updates)
+        .chain(links)
+        .collect();
+    // File values are literal text, not apply placeholders
+    operations.iter_mut().for_each(escape_placeholders);
+    Ok(SyncPlan {
+        changes,
+        unmanaged,
+        plan: json!({ "version": 1, "operations": operations }),
+    })
+}
+
+fn load_sync_file(path: &Path) -> Result<SyncFile> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read sync file '{}'", path.display()))?;
+    // YAML is a superset of JSON, so JSON files parse too.
+    let file: SyncFile = serde_norway::from_str(&content)
+        .with_context(|| format!("Invalid sync file '{}'", path.display()))?;
+    validate_sync_file(&file)?;
+    Ok(file)
+}
+
+fn validate_sync_file(file: &SyncFile) -> Result<()> {
+    for (index, desired) in file.issues.iter().enumerate() {
+        let key = &desired.key;
+        if key.is_empty()
+            || !key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        {
+            bail!(
+                "Invalid key '{}'; use letters, digits, '-', '_' and '.'",
+                key
+            );
+        }
+        if file.issues[..index].iter().any(|other| &other.key == key) {
+            bail!("Duplicate key '{}'", key);
+        }
+        if desired.parent.as_deref() == Some(key.as_str()) {
+            bail!("Issue '{}' cannot be its own parent", key);
+        }
+    }
+    Ok(())
+}
+
+/// The sync key stored on a tracker issue, if it has one.
+fn issue_key(issue: &Issue, key_field: Option<&str>) -> Option<String> {
+    match key_field {
+        Some(field) => find_field_value(issue, field).filter(|key| !key.is_empty()),
+        None => {
+            let description = issue.description.as_deref()?;
+            let start = description.rfind(MARKER_PREFIX)? + MARKER_PREFIX.len();
+            let end = description[start..].find(MARKER_SUFFIX)? + start;
+            Some(description[start..end].trim().to_string()).filter(|key| !key.is_empty())
+        }
+    }
+}
+
+fn marker(key: &str) -> String {
+    format!("{MARKER_PREFIX} {key} {MARKER_SUFFIX}")
+}
+
+/// The description without its sync marker.
+fn strip_marker(description: &str) -> &str {
+    match description.rfind(MARKER_PREFIX) {
+        Some(start) => description[..start].trim_end(),
+        None => description.trim_end(),
+    }
+}
+
+/// The description to write: the file's text, plus the marker when keys live
+/// in descriptions.
+fn description_with_marker(file: &SyncFile, desired: &DesiredIssue) -> Option<String> {
+    let text = desired.description.as_deref().map(str::trim_end);
+    match (&file.key_field, text) {
+        (Some(_), text) => text.map(String::from),
+        (None, Some(text)) if !text.is_empty() => {
+            Some(format!("{}\n\n{}", text, marker(&desired.key)))
+        }
+        (None, _) => Some(marker(&desired.key)),
+    }
+}
+
+fn create_operation(file: &SyncFile, desired: &DesiredIssue) -> Value {
+    let mut fields: serde_json::Map<String, Value> = desired
+        .fields
+        .iter()
+        .map(|(name, value)| (name.clone(), value.to_json()))
+        .collect();
+    if let Some(key_field) = &file.key_field {
+        fields.insert(key_field.clone(), json!(desired.key));
+    }
+
+    let mut create = json!({
+        "ref": desired.key,
+        "op": "create_issue",
+        "summary": desired.summary,
+    });
+    if let Some(project) = &file.project {
+        create["project"] = json!(project);
+    }
+    if let Some(description) = description_with_marker(file, desired) {
+        create["description"] = json!(description);
+    }
+    if !fields.is_empty() {
+        create["fields"] = Value::Object(fields);
+    }
+    if let Some(state) = &desired.state {
+        create["state"] = json!(state);
+    }
+    if let Some(tags) = desired.tags.as_ref().filter(|tags| !tags.is_empty()) {
+        create["tags"] = json!(tags);
+    }
+    create
+}
+
+/// Operations that bring an existing issue's summary, description, state,
+/// fields, and tags in line with the file, and the changes they make.
+fn update_operations(
+    file: &SyncFile,
+    desired: &DesiredIssue,
+    issue: &Issue,
+    issue_id: &str,
+) -> (Vec<Value>, Vec<FieldChange>) {
+    let mut operations = Vec::new();
+    let mut changes = Vec::new();
+    let mut update = serde_json::Map::new();
+
+    if issue.summary != desired.summary {
+        update.insert("summary".to_string(), json!(desired.summary));
+        changes.push(FieldChange::new(
+            "summary",
+            Some(&issue.summary),
+            Some(&desired.summary),
+        ));
+    }
+
+    if let Some(text) = &desired.description {
+        let current = strip_marker(issue.description.as_deref().unwrap_or_default());
+        if current != text.trim_end() {
+            update.insert(
+                "description".to_string(),
+                json!(description_with_marker(file, desired)),
+            );
+            changes.push(FieldChange::new("description", None, None));
+        }
+    }
+
+    if let Some(state) = &desired.state {
+        let current = find_state_value(issue);
+        if !current.is_some_and(|current| unicode_eq_ignore_case(current, state)) {
+            update.insert("state".to_string(), json!(state));
+            changes.push(FieldChange::new("state", current, Some(state)));
+        }
+    }
+
+    let mut fields = serde_json::Map::new();
+    for (name, value) in &desired.fields {
+        let current = find_field_value(issue, name);
+        if !field_matches(current.as_deref(), value.values()) {
+            fields.insert(name.clone(), value.to_json());
+            changes.push(FieldChange::new(
+                name,
+                current.as_deref(),
+                Some(&value.values().join(", ")),
+            ));
+        }
+    }
+    if !fields.is_empty() {
+        update.insert("fields".to_string(), Value::Object(fields));
+    }
+
+    if !update.is_empty() {
+        update.insert("op".to_string(), json!("update_issue"));
+        update.insert("issue".to_string(), json!(issue_id));
+        operations.push(Value::Object(update));
+    }
+
+    if let Some(tags) = &desired.tags {
+        let has = |names: &[String], wanted: &str| {
+            names
+                .iter()
+                .any(|name| unicode_eq_ignore_case(name, wanted))
+        };
+        let current: Vec<String> = issue.tags.iter().map(|tag| tag.name.clone()).collect();
+        for tag in tags.iter().filter(|tag| !has(&current, tag)) {
+            operations.push(json!({"op": "add_tag", "issue": issue_id, "tag": tag}));
+            changes.push(FieldChange::new("tags", None, Some(tag)));
+        }
+        for tag in current.iter().filter(|tag| !has(tags, tag)) {
+            operations.push(json!({"op": "remove_tag", "issue": issue_id, "tag": tag}));
+            changes.push(FieldChange::new("tags", Some(tag), None));
+        }
+    }
+
+    (operations, changes)
+}
+
+/// Whether a field's current value (multi-values joined by ", ") is the
+/// wanted value or set of values, ignoring case and order.
+fn field_matches(current: Option<&str>, wanted: &[String]) -> bool {
+    let current: Vec<&str> = current
+        .map(|value| value.split(", ").filter(|part| !part.is_empty()).collect())
+        .unwrap_or_default();
+    current.len() == wanted.len()
+        && wanted.iter().all(|wanted| {
+            current
+                .iter()
+                .any(|current| unicode_eq_ignore_case(current, wanted))
+        })
+}
+
+fn link_operation(source: &str, target: &str, link_type: &str) -> Value {
+    json!({"op": "link", "source": source, "target": target, "type": link_type})
+}
+
+fn issue_id(issue: &Issue) -> String {
+    if issue.id_readable.is_empty() {
+        issue.id.clone()
+    } else {
+        issue.id_readable.clone()
+    }
+}
+
+fn output_sync_text(sync: &SyncPlan) {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for change in &sync.changes {
+        *counts.entry(change.action).or_default() += 1;
+        match change.action {
+            "create" => println!("+ {} (new issue)", change.key),
+            "update" => {
+                println!(
+                    "~ {} ({})",
+                    change.key,
+                    change.issue.as_deref().unwrap_or_default()
+                );
+                for field in &change.changes {
+                    match (&field.from, &field.to) {
+                        (Some(from), Some(to)) => {
+                            println!("    {}: {:?} -> {:?}", field.field, from, to)
+                        }
+                        (None, Some(to)) => println!("    {}: + {}", field.field, to),
+                        (Some(from), None) => println!("    {}: - {}", field.field, from),
+                        (None, None) => println!("    {}: changed", field.field),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    for unmanaged in &sync.unmanaged {
+        println!(
+            "? {} ({}) is not in the file; it will be left alone",
+            unmanaged.key, unmanaged.issue
+        );
+    }
+
+    let operations = sync.plan["operations"]
+        .as_array()
+        .map_or(0, |operations| operations.len());
+    println!(
+        "Plan: {} to create, {} to update, {} unchanged ({} operations).",
+        counts.get("create").copied().unwrap_or(0),
+        counts.get("update").copied().unwrap_or(0),
+        counts.get("unchanged").copied().unwrap_or(0),
+        operations
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracker_core::{CustomField, ProjectRef, Tag};
+
+    fn parse(content: &str) -> SyncFile {
+        let file: SyncFile = serde_norway::from_str(content).unwrap();
+        validate_sync_file(&file).unwrap();
+        file
+    }
+
+    fn issue(description: Option<&str>, tags: &[&str]) -> Issue {
+        Issue {
+            id: "2-1".to_string(),
+            id_readable: "PROJ-1".to_string(),
+            summary: "Auth overhaul".to_string(),
+            description: description.map(String::from),
+            project: ProjectRef {
+                id: "p1".to_string(),
+                name: None,
+                short_name: Some("PROJ".to_string()),
+            },
+            custom_fields: vec![
+                CustomField::State {
+                    name: "State".to_string(),
+                    value: Some("Open".to_string()),
+                    is_resolved: false,
+                },
+                CustomField::MultiEnum {
+                    name: "Platform".to_string(),
+                    values: vec!["Linux".to_string(), "macOS".to_string()],
+                },
+            ],
+            tags: tags
+                .iter()
+                .map(|name| Tag {
+                    id: name.to_string(),
+                    name: name.to_string(),
+                })
+                .collect(),
+            created: "2026-01-01T00:00:00Z".parse().unwrap(),
+            updated: "2026-01-01T00:00:00Z".parse().unwrap(),
+            resolved: None,
+        }
+    }
+
+    #[test]
+    fn keys_come_from_the_description_marker_or_key_field() {
+        let marked = issue(Some("Body\n\n<!-- track-sync: auth-epic -->"), &[]);
+        assert_eq!(issue_key(&marked, None).as_deref(), Some("auth-epic"));
+        assert_eq!(strip_marker(marked.description.as_deref().unwrap()), "Body");
+        assert_eq!(issue_key(&issue(Some("Body"), &[]), None), None);
+        assert_eq!(
+            issue_key(&marked, Some("Platform")).as_deref(),
+            Some("Linux, macOS")
+        );
+    }
+
+    #[test]
+    fn validation_rejects_bad_and_duplicate_keys() {
+        for (content, message) in [
+            (
+                "query: q\nissues:\n  - {key: 'a b', summary: s}",
+                "Invalid key",
+            ),
+            (
+                "query: q\nissues:\n  - {key: a, summary: s}\n  - {key: a, summary: t}",
+                "Duplicate key",
+            ),
+            (
+                "query: q\nissues:\n  - {key: a, summary: s, parent: a}",
+                "own parent",
+            ),
+        ] {
+            let file: SyncFile = serde_norway::from_str(content).unwrap();
+            let err = validate_sync_file(&file).unwrap_err();
+            assert!(err.to_string().contains(message), "{err}");
+        }
+    }
+
+    #[test]
+    fn updates_cover_only_drifted_managed_values() {
+        let file = parse(
+            r#"
+            query: "project: PROJ"
+            issues:
+              - key: auth-epic
+                summary: Auth overhaul
+                description: New body
+                state: open
+                fields:
+                  Platform: [macOS, Linux]
+                tags: [roadmap]
+            "#,
+        );
+        let current = issue(
+            Some("Old body\n\n<!-- track-sync: auth-epic -->"),
+            &["stale"],
+        );
+
+        let (operations, changes) = update_operations(&file, &file.issues[0], &current, "PROJ-1");
+
+        assert_eq!(
+            operations,
+            vec![
+                json!({
+                    "op": "update_issue",
+                    "issue": "PROJ-1",
+                    "description": "New body\n\n<!-- track-sync: auth-epic -->"
+                }),
+                json!({"op": "add_tag", "issue": "PROJ-1", "tag": "roadmap"}),
+                json!({"op": "remove_tag", "issue": "PROJ-1", "tag": "stale"}),
+            ]
+        );
+        let fields: Vec<&str> = changes.iter().map(|change| change.field.as_str()).collect();
+        assert_eq!(fields, vec!["description", "tags", "tags"]);
+    }
+
+    #[test]
+    fn creates_store_the_key_in_the_key_field_when_configured() {
+        let file = parse(
+            r#"
+            query: "project: PROJ"
+            project: PROJ
+            key_field: External ID
+            issues:
+              - key: auth-epic
+                summary: Auth overhaul
+                tags: [roadmap]
+            "#,
+        );
+
+        assert_eq!(
+            create_operation(&file, &file.issues[0]),
+            json!({
+                "ref": "auth-epic",
+                "op": "create_issue",
+                "project": "PROJ",
+                "summary": "Auth overhaul",
+                "fields": {"External ID": "auth-epic"},
+                "tags": ["roadmap"]
+            })
+        );
+    }
+}
@@ -0,0 +1,518 @@
+//! `track watch` — poll a query and report what changed between polls.
+//!
+//! Each poll runs the search and diffs every issue against the previous
+//! snapshot: issues entering or leaving the result set, state and assignee
+//! changes, and comments added since the issue was last seen. Comments are
+//! only fetched for issues whose `updated` timestamp moved, so a quiet query
+//! costs one search per interval.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracker_core::{Comment, Issue, IssueTracker};
+
+use crate::cache::{self, CachedQueryTemplate};
+use crate::cli::OutputFormat;
+use crate::commands::shell_command;
+use crate::output::{find_field_value, find_state_value};
+
+/// Arguments for `track watch`.
+pub struct WatchArgs<'a> {
+    pub query: Option<&'a str>,
+    pub template: Option<&'a str>,
+    pub project: Option<&'a str>,
+    pub interval: &'a str,
+    pub limit: usize,
+    pub exec: Option<&'a str>,
+    pub count: Option<usize>,
+    pub once: bool,
+    pub state_path: Option<&'a Path>,
+    pub saved_queries: &'a [CachedQueryTemplate],
+}
+
+/// What the watcher remembers about an issue between polls.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct IssueSnapshot {
+    summary: String,
+    updated: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    assignee: Option<String>,
+}
+
+impl From<&Issue> for IssueSnapshot {
+    fn from(issue: &Issue) -> Self {
+        Self {
+            summary: issue.summary.clone(),
+            updated: issue.updated,
+            state: find_state_value(issue).map(String::from),
+            assignee: find_field_value(issue, "Assignee"),
+        }
+    }
+}
+
+/// Snapshot persisted with `--state`, keyed by readable issue ID.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct WatchState {
+    query: String,
+    issues: BTreeMap<String, IssueSnapshot>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ChangeKind {
+    /// The issue started matching the query
+    New,
+    State,
+    Assignee,
+    Comment,
+    /// `updated` moved without a state, assignee, or comment change
+    Updated,
+    /// The issue no longer matches the query
+    Removed,
+}
+
+impl ChangeKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::New => "new",
+            Self::State => "state",
+            Self::Assignee => "assignee",
+            Self::Comment => "comment",
+            Self::Updated => "updated",
+            Self::Removed => "removed",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct WatchEvent {
+    at: DateTime<Utc>,
+    issue: String,
+    summary: String,
+    change: ChangeKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+}
+
+impl WatchEvent {
+    fn new(at: DateTime<Utc>, issue: &str, summary: &str, change: ChangeKind) -> Self {
+        Self {
+            at,
+            issue: issue.to_string(),
+            summary: summary.to_string(),
+            change,
+            from: None,
+            to: None,
+            author: None,
+            comment: None,
+        }
+    }
+}
+
+pub fn handle_watch(
+    client: &dyn IssueTracker,
+    args: &WatchArgs,
+    default_project: Option<&str>,
+    format: OutputFormat,
+) -> Result<()> {
+    let interval = cache::parse_duration(args.interval).context("Invalid --interval")?;
+    let interval = interval.to_std().context("--interval is out of range")?;
+    let query = super::issue::resolve_search_query(
+        args.query,
+        args.template,
+        args.project,
+        default_project,
+        args.saved_queries,
+    )?;
+
+    // A saved snapshot only makes sense for the query that produced it
+    let mut snapshot = match args.state_path {
+        Some(path) => load_state(path)?
+            .filter(|state| state.query == query)
+            .map(|state| state.issues),
+        None => None,
+    };
+    let mut emitted = 0;
+
+    loop {
+        let issues = match client.search_all_issues(&query, args.limit) {
+            Ok(issues) => issues,
+            // Transient failures shouldn't end a long-running watch
+            Err(e) if snapshot.is_some() && !args.once => {
+                eprintln!("warning: poll failed: {:#}", e);
+                std::thread::sleep(interval);
+                continue;
+            }
+            Err(e) => {
+                return Err(e)
+                    .with_context(|| format!("Failed to search issues with query '{}'", query));
+            }
+        };
+
+        let current: BTreeMap<String, IssueSnapshot> = issues
+            .iter()
+            .map(|issue| (issue.id_readable.clone(), IssueSnapshot::from(issue)))
+            .collect();
+
+        if let Some(previous) = &snapshot {
+            let events = poll_events(client, previous, &issues, Utc::now());
+            for (index, event) in events.iter().enumerate() {
+                emit(event, format)?;
+                if let Some(command) = args.exec {
+                    run_exec(command, event)?;
+                }
+                emitted += 1;
+                if args.count.is_some_and(|count| emitted >= count) {
+                    let pending = &events[index + 1..];
+                    let partial = partial_snapshot(previous, &current, pending);
+                    save_state(args.state_path, &query, &partial)?;
+                    return Ok(());
+                }
+            }
+        }
+
+        save_state(args.state_path, &query, &current)?;
+        snapshot = Some(current);
+        if args.once {
+            return Ok(());
+        }
+        std::thread::sleep(interval);
+    }
+}
+
+/// The snapshot to save when `--count` stops a poll early. Issues with events
+/// still pending keep their previous entry (or stay absent), so those changes
+/// are reported again on the next run instead of being lost.
+fn partial_snapshot(
+    previous: &BTreeMap<String, IssueSnapshot>,
+    current: &BTreeMap<String, IssueSnapshot>,
+    pending: &[WatchEvent],
+) -> BTreeMap<String, IssueSnapshot> {
+    let mut snapshot = current.clone();
+    for event in pending {
+        match previous.get(&event.issue) {
+            Some(before) => snapshot.insert(event.issue.clone(), before.clone()),
+            None => snapshot.remove(&event.issue),
+        };
+    }
+    snapshot
+}
+
+/// Diff the latest search results against the previous snapshot, fetching
+/// comments for issues that were updated since they were last seen.
+fn poll_events(
+    client: &dyn IssueTracker,
+    previous: &BTreeMap<String, IssueSnapshot>,
+    issues: &[Issue],
+    now: DateTime<Utc>,
+) -> Vec<WatchEvent> {
+    let mut events = Vec::new();
+    for issue in issues {
+        let before = previous.get(&issue.id_readable);
+        let comments = match before {
+            Some(before) if issue.updated > before.updated => {
+                match client.get_comments(&issue.id_readable) {
+                    Ok(comments) => comments,
+                    Err(e) => {
+                        eprintln!(
+                            "warning: failed to get comments for {}: {:#}",
+                            issue.id_readable, e
+                        );
+                        Vec::new()
+                    }
+                }
+            }
+            _ => Vec::new(),
+        };
+        events.extend(diff_issue(before, issue, &comments));
+    }
+
+    for (id, before) in previous {
+        if !issues.iter().any(|issue| &issue.id_readable == id) {
+            events.push(WatchEvent::new(
+                now,
+                id,
+                &before.summary,
+                ChangeKind::Removed,
+            ));
+        }
+    }
+    events
+}
+
+/// Changes to one issue since `before`. Only comments created after the
+/// previous `updated` timestamp are reported.
+fn diff_issue(
+    before: Option<&IssueSnapshot>,
+    issue: &Issue,
+    comments: &[Comment],
+) -> Vec<WatchEvent> {
+    let id = issue.id_readable.as_str();
+    let after = IssueSnapshot::from(issue);
+    let Some(before) = before else {
+        let mut event = WatchEvent::new(issue.updated, id, &after.summary, ChangeKind::New);
+        event.to = after.state;
+        return vec![event];
+    };
+    if after.updated <= before.updated {
+        return Vec::new();
+    }
+
+    let mut events = Vec::new();
+    for (kind, from, to) in [
+        (ChangeKind::State, &before.state, &after.state),
+        (ChangeKind::Assignee, &before.assignee, &after.assignee),
+    ] {
+        if from != to {
+            let mut event = WatchEvent::new(after.updated, id, &after.summary, kind);
+            event.from = from.clone();
+            event.to = to.clone();
+            events.push(event);
+        }
+    }
+
+    for comment in comments {
+        let Some(created) = comment.created.filter(|created| *created > before.updated) else {
+            continue;
+        };
+        let mut event = WatchEvent::new(created, id, &after.summary, ChangeKind::Comment);
+        event.author = comment
+            .author
+            .as_ref()
+            .map(|author| author.name.clone().unwrap_or_else(|| author.login.clone()));
+        event.comment = Some(comment.text.clone());
+        events.push(event);
+    }
+
+    if events.is_empty() {
+        events.push(WatchEvent::new(
+            after.updated,
+            id,
+            &after.summary,
+            ChangeKind::Updated,
+        ));
+    }
+    events
+}
+
+fn emit(event: &WatchEvent, format: OutputFormat) -> Result<()> {
+    match format {
+        // One compact object per line so the feed can be piped into jq
+        OutputFormat::Json => println!("{}", serde_json::to_string(event)?),
+        OutputFormat::Text => println!("{}", format_event(event)),
+    }
+    Ok(())
+}
+
+fn format_event(event: &WatchEvent) -> String {
+    use colored::Colorize;
+
+    let none = || "(none)".to_string();
+    let detail = match event.change {
+        ChangeKind::New => format!("new: {}", event.summary),
+        ChangeKind::State | ChangeKind::Assignee => format!(
+            "{}: {} → {}",
+            event.change.as_str(),
+            event.from.clone().unwrap_or_else(none),
+            event.to.clone().unwrap_or_else(none).bold()
+        ),
+        ChangeKind::Comment => format!(
+            "comment by {}: {}",
+            event
+                .author
+                .clone()
+                .unwrap_or_else(|| "unknown".to_string()),
+            event
+                .comment
+                .as_deref()
+                .and_then(|text| text.lines().next())
+                .unwrap_or_default()
+        ),
+        ChangeKind::Updated => "updated".to_string(),
+        ChangeKind::Removed => "no longer matches".dimmed().to_string(),
+    };
+    format!(
+        "{} {} {}",
+        event.at.format("%Y-%m-%d %H:%M:%S").to_string().dimmed(),
+        event.issue.cyan().bold(),
+        detail
+    )
+}
+
+/// Run the `--exec` hook through the shell with the event in the environment.
+/// A failing hook is reported but doesn't stop the watch.
+fn run_exec(command: &str, event: &WatchEvent) -> Result<()> {
+    let status = shell_command(command)
+        .env("TRACK_EVENT", serde_json::to_string(event)?)
+        .env("TRACK_ISSUE_ID", &event.issue)
+        .env("TRACK_CHANGE", event.change.as_str())
+        .status()
+        .with_context(|| format!("Failed to run --exec command '{}'", command))?;
+    if !status.success() {
+        eprintln!(
+            "warning: --exec command exited with {} for {}",
+            status, event.issue
+        );
+    }
+    Ok(())
+}
+
+fn load_state(path: &Path) -> Result<Option<WatchState>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read watch state '{}'", path.display()))?;
+    let state = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse watch state '{}'", path.display()))?;
+    Ok(Some(state))
+}
+
+fn save_state(
+    path: Option<&Path>,
+    query: &str,
+    issues: &BTreeMap<String, IssueSnapshot>,
+) -> Result<()> {
+    let Some(path) = path else {
+        return Ok(());
+    };
+    let state = WatchState {
+        query: query.to_string(),
+        issues: issues.clone(),
+    };
+    let json = serde_json::to_vec_pretty(&state).context("Failed to serialize watch state")?;
+    std::fs::write(path, json)
+        .with_context(|| format!("Failed to write watch state '{}'", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracker_core::{CommentAuthor, CustomField, ProjectRef};
+
+    fn at(time: &str) -> DateTime<Utc> {
+        format!("2026-05-01T{}:00Z", time).parse().unwrap()
+    }
+
+    fn issue(state: &str, assignee: Option<&str>, updated: &str) -> Issue {
+        Issue {
+            id: "2-1".to_string(),
+            id_readable: "PROJ-1".to_string(),
+            summary: "Checkout fails".to_string(),
+            description: None,
+            project: ProjectRef {
+                id: "p1".to_string(),
+                name: None,
+                short_name: Some("PROJ".to_string()),
+            },
+            custom_fields: vec![
+                CustomField::State {
+                    name: "State".to_string(),
+                    value: Some(state.to_string()),
+                    is_resolved: false,
+                },
+                CustomField::SingleUser {
+                    name: "Assignee".to_string(),
+                    login: assignee.map(String::from),
+                    display_name: None,
+                },
+            ],
+            tags: vec![],
+            created: at("08:00"),
+            updated: at(updated),
+            resolved: None,
+        }
+    }
+
+    fn comment(text: &str, created: &str) -> Comment {
+        Comment {
+            id: text.to_string(),
+            text: text.to_string(),
+            author: Some(CommentAuthor {
+                login: "jane".to_string(),
+                name: Some("Jane Doe".to_string()),
+            }),
+            created: Some(at(created)),
+        }
+    }
+
+    fn kinds(events: &[WatchEvent]) -> Vec<ChangeKind> {
+        events.iter().map(|event| event.change).collect()
+    }
+
+    #[test]
+    fn diff_issue_reports_new_issue_once() {
+        let events = diff_issue(None, &issue("Open", None, "09:00"), &[]);
+        assert_eq!(kinds(&events), vec![ChangeKind::New]);
+        assert_eq!(events[0].to.as_deref(), Some("Open"));
+    }
+
+    #[test]
+    fn diff_issue_reports_field_changes_and_new_comments() {
+        let before = IssueSnapshot::from(&issue("Open", None, "09:00"));
+        let after = issue("In Review", Some("jane"), "10:00");
+        let comments = [
+            comment("Old note", "08:30"),
+            comment("LGTM, merging\nDetails follow", "09:45"),
+        ];
+
+        let events = diff_issue(Some(&before), &after, &comments);
+        assert_eq!(
+            kinds(&events),
+            vec![ChangeKind::State, ChangeKind::Assignee, ChangeKind::Comment]
+        );
+        assert_eq!(events[0].from.as_deref(), Some("Open"));
+        assert_eq!(events[0].to.as_deref(), Some("In Review"));
+        assert_eq!(events[1].from, None);
+        assert_eq!(events[2].author.as_deref(), Some("Jane Doe"));
+        assert!(format_event(&events[2]).ends_with("comment by Jane Doe: LGTM, merging"));
+    }
+
+    #[test]
+    fn diff_issue_ignores_unchanged_and_falls_back_to_updated() {
+        let before = IssueSnapshot::from(&issue("Open", None, "09:00"));
+        assert!(diff_issue(Some(&before), &issue("Open", None, "09:00"), &[]).is_empty());
+
+        let events = diff_issue(Some(&before), &issue("Open", None, "09:30"), &[]);
+        assert_eq!(kinds(&events), vec![ChangeKind::Updated]);
+    }
+
+    #[test]
+    fn partial_snapshot_keeps_previous_entries_for_pending_events() {
+        let before = IssueSnapshot::from(&issue("Open", None, "09:00"));
+        let after = IssueSnapshot::from(&issue("Done", None, "10:00"));
+        let previous = BTreeMap::from([
+            ("PROJ-1".to_string(), before.clone()),
+            ("PROJ-2".to_string(), before.clone()),
+            ("PROJ-3".to_string(), before.clone()),
+        ]);
+        let current = BTreeMap::from([
+            ("PROJ-1".to_string(), after.clone()),
+            ("PROJ-2".to_string(), after.clone()),
+            ("PROJ-4".to_string(), after.clone()),
+        ]);
+        // PROJ-1's change was emitted; the rest were cut off by --count
+        let pending = [
+            WatchEvent::new(at("10:00"), "PROJ-2", "Checkout fails", ChangeKind::State),
+            WatchEvent::new(at("10:00"), "PROJ-4", "Checkout fails", ChangeKind::New),
+            WatchEvent::new(at("10:00"), "PROJ-3", "Checkout fails", ChangeKind::Removed),
+        ];
+
+        let saved = partial_snapshot(&previous, &current, &pending);
+        assert_eq!(saved.get("PROJ-1"), Some(&after));
+        assert_eq!(saved.get("PROJ-2"), Some(&before));
+        assert_eq!(saved.get("PROJ-3"), Some(&before));
+        assert!(!saved.contains_key("PROJ-4"));
+    }
+}
@@ -0,0 +1,510 @@
+//! `track webhook serve` — a small local receiver for tracker webhooks.
+//!
+//! Deliveries are POSTed to `/<source>` (or `/`, where the source is
+//! detected from its headers), verified against the secret configured under
+//! `[webhook.secrets]`, and normalized into a [`TrackerEvent`] by the
+//! backend's `webhook` module. Events are printed (one JSON line each with
+//! `-o json`) and handed to any matching `[[webhook.hooks]]` and `--exec`.
+//!
+//! The server handles one connection at a time and closes it after the
+//! response; it is meant to sit behind a tunnel or reverse proxy, not to face
+//! the internet on its own.
+
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use hmac::{Hmac, Mac};
+use serde_json::Value;
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+use tracker_core::{TrackerEvent, TrackerEventKind};
+
+use crate::cli::OutputFormat;
+use crate::commands::shell_command;
+use crate::config::WebhookConfig;
+
+const SOURCES: [&str; 5] = ["github", "gitlab", "jira", "linear", "youtrack"];
+const MAX_BODY_BYTES: usize = 10 * 1024 * 1024;
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct ServeOptions<'a> {
+    pub bind: &'a str,
+    pub port: u16,
+    pub exec: Option<&'a str>,
+}
+
+pub fn handle_webhook_serve(
+    config: &WebhookConfig,
+    options: &ServeOptions,
+    format: OutputFormat,
+) -> Result<()> {
+    let listener = TcpListener::bind((options.bind, options.port))
+        .with_context(|| format!("Failed to listen on {}:{}", options.bind, options.port))?;
+    let addr = listener.local_addr()?;
+
+    for source in config.secrets.keys() {
+        if !SOURCES.contains(&source.as_str()) {
+            eprintln!(
+                "warning: ignoring secret for unknown source '{}' (expected one of: {})",
+                source,
+                SOURCES.join(", ")
+            );
+        }
+    }
+    let unsigned: Vec<&str> = SOURCES
+        .iter()
+        .copied()
+        .filter(|source| !config.secrets.contains_key(*source))
+        .collect();
+    if !unsigned.is_empty() {
+        eprintln!(
+            "warning: no secret configured for {}; their deliveries are accepted unverified",
+            unsigned.join(", ")
+        );
+    }
+    eprintln!("Listening on http://{}", addr);
+
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("warning: failed to accept connection: {}", e);
+                continue;
+            }
+        };
+        if let Some(event) = serve_connection(stream, &config.secrets) {
+            emit(&event, format)?;
+            run_hooks(config, options.exec, &event);
+        }
+    }
+    Ok(())
+}
+
+/// An HTTP request as far as the receiver cares about it.
+#[derive(Debug)]
+struct Request {
+    method: String,
+    path: String,
+    /// Header names are lowercased
+    headers: BTreeMap<String, String>,
+    body: Vec<u8>,
+}
+
+impl Request {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+}
+
+/// What to answer a request with.
+#[derive(Debug, PartialEq)]
+enum Delivery {
+    /// A normalized issue event (200)
+    Event(Box<TrackerEvent>),
+    /// A valid delivery that isn't an issue change, like a ping (202)
+    Ignored,
+    /// Anything else, with its HTTP status
+    Rejected(u16, String),
+}
+
+/// Read one request, answer it, and return the event it carried, if any.
+/// The event is processed after the response is sent so slow hooks don't
+/// make the tracker time out and retry.
+fn serve_connection(stream: TcpStream, secrets: &BTreeMap<String, String>) -> Option<TrackerEvent> {
+    let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+    let mut reader = BufReader::new(&stream);
+    let (status, message, event) = match read_request(&mut reader) {
+        Ok(request) if request.method == "GET" => (200, "ok".to_string(), None),
+        Ok(request) => match dispatch(&request, secrets) {
+            Delivery::Event(event) => (200, "ok".to_string(), Some(*event)),
+            Delivery::Ignored => (202, "ignored".to_string(), None),
+            Delivery::Rejected(status, message) => {
+                eprintln!(
+                    "warning: rejected {} {}: {}",
+                    request.method, request.path, message
+                );
+                (status, message, None)
+            }
+        },
+        Err((status, message)) => (status, message, None),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason_phrase(status),
+        message.len(),
+        message
+    );
+    let mut stream = &stream;
+    if let Err(e) = stream.write_all(response.as_bytes()) {
+        eprintln!("warning: failed to send response: {}", e);
+    }
+    event
+}
+
+/// Parse a request, or return the status and message to reject it with.
+fn read_request(reader: &mut impl BufRead) -> std::result::Result<Request, (u16, String)> {
+    let bad_request = |message: &str| (400, message.to_string());
+
+    let mut line = String::new();
+    reader
+        .read_line(&mut line)
+        .map_err(|_| bad_request("unreadable request line"))?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(bad_request("malformed request line"));
+    };
+    let method = method.to_string();
+    let path = target.split('?').next().unwrap_or_default().to_string();
+
+    let mut headers = BTreeMap::new();
+    loop {
+        line.clear();
+        reader
+            .read_line(&mut line)
+            .map_err(|_| bad_request("unreadable headers"))?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            return Err(bad_request("malformed header"));
+        };
+        headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+    }
+
+    let mut request = Request {
+        method,
+        path,
+        headers,
+        body: Vec::new(),
+    };
+    match request.method.as_str() {
+        "GET" => return Ok(request),
+        "POST" => {}
+        _ => return Err((405, "only GET and POST are supported".into())),
+    }
+
+    if request.header("transfer-encoding").is_some() {
+        return Err((411, "chunked bodies are not supported".into()));
+    }
+    let length: usize = request
+        .header("content-length")
+        .ok_or_else(|| (411, "Content-Length is required".to_string()))?
+        .parse()
+        .map_err(|_| bad_request("invalid Content-Length"))?;
+    if length > MAX_BODY_BYTES {
+        return Err((413, "body too large".into()));
+    }
+    request.body = vec![0; length];
+    reader
+        .read_exact(&mut request.body)
+        .map_err(|_| bad_request("body shorter than Content-Length"))?;
+    Ok(request)
+}
+
+fn dispatch(request: &Request, secrets: &BTreeMap<String, String>) -> Delivery {
+    let path = request.path.trim_end_matches('/');
+    let payload: Value = match serde_json::from_slice(&request.body) {
+        Ok(payload) => payload,
+        Err(e) => return Delivery::Rejected(400, format!("invalid JSON: {}", e)),
+    };
+
+    let source = if path.is_empty() {
+        match detect_source(request, &payload) {
+            Some(source) => source,
+            None => return Delivery::Rejected(400, "could not detect webhook source".into()),
+        }
+    } else {
+        match SOURCES.iter().find(|source| path == format!("/{}", source)) {
+            Some(source) => *source,
+            None => return Delivery::Rejected(404, format!("unknown path '{}'", request.path)),
+        }
+    };
+
+    if let Some(secret) = secrets.get(source)
+        && !verify_signature(source, secret, request)
+    {
+        return Delivery::Rejected(401, format!("invalid {} signature", source));
+    }
+
+    let event = match source {
+        "github" => {
+            github_backend::webhook::parse_webhook(request.header("x-github-event"), &payload)
+        }
+        "gitlab" => {
+            gitlab_backend::webhook::parse_webhook(request.header("x-gitlab-event"), &payload)
+        }
+        "jira" => jira_backend::webhook::parse_webhook(&payload),
+        "linear" => {
+            linear_backend::webhook::parse_webhook(request.header("linear-event"), &payload)
+        }
+        _ => youtrack_backend::webhook::parse_webhook(&payload),
+    };
+    match event {
+        Some(event) => Delivery::Event(Box::new(event)),
+        None => Delivery::Ignored,
+    }
+}
+
+/// Work out who sent a delivery to `/` from the headers each tracker adds.
+fn detect_source(request: &Request, payload: &Value) -> Option<&'static str> {
+    if request.header("x-github-event").is_some() {
+        Some("github")
+    } else if request.header("x-gitlab-event").is_some() {
+        Some("gitlab")
+    } else if request.header("linear-event").is_some() {
+        Some("linear")
+    } else if request.header("x-atlassian-webhook-identifier").is_some()
+        || payload.get("webhookEvent").is_some()
+    {
+        Some("jira")
+    } else if request.header("x-track-webhook-token").is_some() {
+        Some("youtrack")
+    } else {
+        None
+    }
+}
+
+/// Check a delivery against the source's secret. GitHub, Jira and Linear
+/// sign the body with HMAC-SHA256; GitLab and YouTrack workflows send the
+/// shared token as-is.
+fn verify_signature(source: &str, secret: &str, request: &Request) -> bool {
+    let provided = match source {
+        "github" => request
+            .header("x-hub-signature-256")
+            .and_then(|value| value.strip_prefix("sha256=")),
+        "jira" => request
+            .header("x-hub-signature")
+            .and_then(|value| value.strip_prefix("sha256=")),
+        "linear" => request.header("linear-signature"),
+        "gitlab" => return token_matches(request.header("x-gitlab-token"), secret),
+        _ => return token_matches(request.header("x-track-webhook-token"), secret),
+    };
+    let Some(signature) = provided.and_then(|value| hex::decode(value).ok()) else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(&request.body);
+    mac.verify_slice(&signature).is_ok()
+}
+
+fn token_matches(token: Option<&str>, secret: &str) -> bool {
+    token.is_some_and(|token| bool::from(token.as_bytes().ct_eq(secret.as_bytes())))
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        202 => "Accepted",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        _ => "Error",
+    }
+}
+
+fn emit(event: &TrackerEvent, format: OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string(event)?),
+        OutputFormat::Text => println!("{}", format_event(event)),
+    }
+    Ok(())
+}
+
+fn format_event(event: &TrackerEvent) -> String {
+    use colored::Colorize;
+
+    let detail = match event.kind {
+        TrackerEventKind::IssueCreated => {
+            format!("created: {}", event.summary.as_deref().unwrap_or_default())
+        }
+        TrackerEventKind::IssueUpdated => "updated".to_string(),
+        TrackerEventKind::IssueTransitioned => format!(
+            "state: {} → {}",
+            event.from.as_deref().unwrap_or("?"),
+            event.to.as_deref().unwrap_or("?").bold()
+        ),
+        TrackerEventKind::IssueCommented => format!(
+            "comment by {}: {}",
+            event.actor.as_deref().unwrap_or("unknown"),
+            event
+                .comment
+                .as_deref()
+                .and_then(|text| text.lines().next())
+                .unwrap_or_default()
+        ),
+    };
+    format!(
+        "{} {} {}",
+        format!("[{}]", event.source).dimmed(),
+        event.issue.cyan().bold(),
+        detail
+    )
+}
+
+/// Run configured hooks and `--exec` with the event in the environment.
+/// Failures are reported but never stop the server.
+fn run_hooks(config: &WebhookConfig, exec: Option<&str>, event: &TrackerEvent) {
+    let commands = config
+        .hooks
+        .iter()
+        .filter(|hook| hook.matches(event))
+        .map(|hook| hook.command.as_str())
+        .chain(exec);
+    for command in commands {
+        let status = serde_json::to_string(event)
+            .map_err(anyhow::Error::from)
+            .and_then(|json| {
+                shell_command(command)
+                    .env("TRACK_EVENT", json)
+                    .env("TRACK_ISSUE_ID", &event.issue)
+                    .env("TRACK_EVENT_KIND", event.kind.as_str())
+                    .env("TRACK_SOURCE", &event.source)
+                    .status()
+                    .with_context(|| format!("Failed to run hook '{}'", command))
+            });
+        match status {
+            Ok(status) if status.success() => {}
+            Ok(status) => eprintln!(
+                "warning: hook '{}' exited with {} for {}",
+                command, status, event.issue
+            ),
+            Err(e) => eprintln!("warning: {:#}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn post(path: &str, headers: &[(&str, &str)], body: &str) -> Request {
+        Request {
+            method: "POST".to_string(),
+            path: path.to_string(),
+            headers: headers
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn reads_request_with_body() {
+        let raw =
+            "POST /github?x=1 HTTP/1.1\r\nX-GitHub-Event: issues\r\nContent-Length: 2\r\n\r\n{}";
+        let request = read_request(&mut Cursor::new(raw)).unwrap();
+        assert_eq!(request.path, "/github");
+        assert_eq!(request.header("x-github-event"), Some("issues"));
+        assert_eq!(request.body, b"{}");
+
+        let raw = "POST / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n";
+        assert!(matches!(read_request(&mut Cursor::new(raw)), Err((411, _))));
+    }
+
+    #[test]
+    fn verifies_signatures_before_parsing() {
+        let body = r#"{"action":"opened","issue":{"number":1,"title":"Hi"},"repository":{"full_name":"acme/app"}}"#;
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"s3cret").unwrap();
+        mac.update(body.as_bytes());
+        let signature = format!("sha256={}", hex::encode(mac.finalize().into_bytes()));
+        let secrets = BTreeMap::from([("github".to_string(), "s3cret".to_string())]);
+
+        let request = post(
+            "/",
+            &[
+                ("x-github-event", "issues"),
+                ("x-hub-signature-256", signature.as_str()),
+            ],
+            body,
+        );
+        let Delivery::Event(event) = dispatch(&request, &secrets) else {
+            panic!("expected an event");
+        };
+        assert_eq!(event.issue, "acme/app#1");
+        assert_eq!(event.kind, TrackerEventKind::IssueCreated);
+
+        let forged = post(
+            "/github",
+            &[
+                ("x-github-event", "issues"),
+                ("x-hub-signature-256", "sha256=00"),
+            ],
+            body,
+        );
+        assert!(matches!(
+            dispatch(&forged, &secrets),
+            Delivery::Rejected(401, _)
+        ));
+
+        let gitlab = post("/gitlab", &[("x-gitlab-event", "Push Hook")], "{}");
+        assert_eq!(dispatch(&gitlab, &secrets), Delivery::Ignored);
+        assert!(matches!(
+            dispatch(&post("/bitbucket", &[], "{}"), &secrets),
+            Delivery::Rejected(404, _)
+        ));
+    }
+
+    #[test]
+    fn normalizes_recorded_payloads() {
+        let fixtures =
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../fixtures/webhooks");
+        let cases = [
+            (
+                "github-issue-closed.json",
+                ("x-github-event", "issues"),
+                TrackerEventKind::IssueTransitioned,
+                "acme/app#42",
+            ),
+            (
+                "gitlab-issue-note.json",
+                ("x-gitlab-event", "Note Hook"),
+                TrackerEventKind::IssueCommented,
+                "#7",
+            ),
+            (
+                "jira-issue-transitioned.json",
+                ("x-atlassian-webhook-identifier", "1"),
+                TrackerEventKind::IssueTransitioned,
+                "PROJ-12",
+            ),
+            (
+                "linear-issue-created.json",
+                ("linear-event", "Issue"),
+                TrackerEventKind::IssueCreated,
+                "ENG-12",
+            ),
+            (
+                "youtrack-issue-commented.json",
+                ("x-track-webhook-token", "t"),
+                TrackerEventKind::IssueCommented,
+                "PROJ-12",
+            ),
+        ];
+        for (file, header, kind, issue) in cases {
+            let body = std::fs::read_to_string(fixtures.join(file)).unwrap();
+            let Delivery::Event(event) = dispatch(&post("/", &[header], &body), &BTreeMap::new())
+            else {
+                panic!("{} was not recognized", file);
+            };
+            assert_eq!(
+                (event.kind, event.issue.as_str()),
+                (kind, issue),
+                "{}",
+                file
+            );
+        }
+    }
+}
@@ -1,251 +1,228 @@
-use crate::cli::Backend;
+use crate::cache::CachedQueryTemplate;
+use crate::cli::{Backend, ChangelogGroupBy};
 use anyhow::{Result, anyhow};
-use figment::{
-    Figment,
-    providers::{Env, Format, Serialized, Toml},
-};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::fs::{self, OpenOptions};
 use std::path::{Path, PathBuf};
+use tracker_core::{TrackerEvent, TrackerEventKind};
 
 use std::io::Write;
 #[cfg(unix)]
 use std::os::unix::fs::OpenOptionsExt;
 
-/// Main configuration structure supporting multiple backends
+pub use tracker_client::config::resolve_backend;
+
+/// Main configuration structure: the backend connection settings shared with
+/// other tracker-client tools, plus the CLI's own tables
 #[derive(Debug, Deserialize, Serialize, Default, Clone)]
 pub struct Config {
-    /// Default backend to use (youtrack, jira, github, gitlab, or linear)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub backend: Option<Backend>,
-    /// Global URL override (applies to any backend)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
-    /// Global token override (applies to any backend)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
-    /// Email for authentication (required for Jira)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub email: Option<String>,
-    /// Default project shortName (e.g., "PROJ")
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_project: Option<String>,
-    /// YouTrack-specific configuration
-    #[serde(default, skip_serializing_if = "BackendConfig::is_empty")]
-    pub youtrack: BackendConfig,
-    /// Jira-specific configuration
-    #[serde(default, skip_serializing_if = "JiraConfig::is_empty")]
-    pub jira: JiraConfig,
-    /// GitHub-specific configuration
-    #[serde(default, skip_serializing_if = "GitHubConfig::is_empty")]
-    pub github: GitHubConfig,
-    /// GitLab-specific configuration
-    #[serde(default, skip_serializing_if = "GitLabConfig::is_empty")]
-    pub gitlab: GitLabConfig,
-    /// Linear-specific configuration
-    #[serde(default, skip_serializing_if = "LinearConfig::is_empty")]
-    pub linear: LinearConfig,
+    #[serde(flatten)]
+    pub connection: tracker_client::Config,
+    /// User-defined saved queries, usable anywhere a query template is
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub queries: BTreeMap<String, SavedQuery>,
+    /// Command aliases, e.g. `mine = "issue search -T my_open"`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aliases: BTreeMap<String, String>,
+    /// Settings for external `track-<name>` plugins
+    #[serde(default, skip_serializing_if = "PluginConfig::is_empty")]
+    pub plugins: PluginConfig,
+    /// Git integration settings
+    #[serde(default, skip_serializing_if = "GitConfig::is_empty")]
+    pub git: GitConfig,
+    /// Release notes settings for `track changelog`
+    #[serde(default, skip_serializing_if = "ChangelogConfig::is_empty")]
+    pub changelog: ChangelogConfig,
+    /// Secrets and hooks for `track webhook serve`
+    #[serde(default, skip_serializing_if = "WebhookConfig::is_empty")]
+    pub webhook: WebhookConfig,
 }
 
-/// Backend-specific configuration
+/// Settings from the `[plugins]` table.
 #[derive(Debug, Deserialize, Serialize, Default, Clone)]
-pub struct BackendConfig {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub link_mappings: HashMap<String, String>,
+pub struct PluginConfig {
+    /// Expose the resolved API token to plugins as `TRACK_TOKEN`
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pass_token: bool,
 }
 
-impl BackendConfig {
+impl PluginConfig {
     pub fn is_empty(&self) -> bool {
-        self.url.is_none() && self.token.is_none() && self.link_mappings.is_empty()
-    }
-
-    /// Connection-relevant keys only: cosmetic settings like link_mappings
-    /// must not make a backend count as configured.
-    pub fn has_connection_config(&self) -> bool {
-        self.url.is_some() || self.token.is_some()
+        !self.pass_token
     }
 }
 
-/// Jira-specific configuration
+/// Settings from the `[git]` table.
 #[derive(Debug, Deserialize, Serialize, Default, Clone)]
-pub struct JiraConfig {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
+pub struct GitConfig {
+    /// Branch name template for `issue start --branch`; supports `{id}`,
+    /// `{slug}` and `{project}` (default: "feature/{id}-{slug}")
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub email: Option<String>,
+    pub branch_template: Option<String>,
+    /// Prefix the commit-msg hook prepends; supports `{id}` (default: "{id}: ")
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub link_mappings: HashMap<String, String>,
+    pub commit_prefix: Option<String>,
 }
 
-impl JiraConfig {
+impl GitConfig {
     pub fn is_empty(&self) -> bool {
-        self.url.is_none()
-            && self.email.is_none()
-            && self.token.is_none()
-            && self.link_mappings.is_empty()
-    }
-
-    /// Connection-relevant keys only (excludes link_mappings).
-    pub fn has_connection_config(&self) -> bool {
-        self.url.is_some() || self.email.is_some() || self.token.is_some()
+        self.branch_template.is_none() && self.commit_prefix.is_none()
     }
 }
 
-/// GitHub-specific configuration
+/// Settings from the `[changelog]` table.
+///
+/// Without `sections`, `track changelog` makes one section per distinct type
+/// (or tag). With them, issues go to the first section listing one of their
+/// values and everything else lands in "Other".
 #[derive(Debug, Deserialize, Serialize, Default, Clone)]
-pub struct GitHubConfig {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
+pub struct ChangelogConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub owner: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub repo: Option<String>,
-    /// API URL (defaults to https://api.github.com)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub api_url: Option<String>,
+    pub group_by: Option<ChangelogGroupBy>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sections: Vec<ChangelogSectionConfig>,
 }
 
-impl GitHubConfig {
+impl ChangelogConfig {
     pub fn is_empty(&self) -> bool {
-        self.token.is_none()
-            && self.owner.is_none()
-            && self.repo.is_none()
-            && self.api_url.is_none()
-    }
-
-    /// Connection-relevant keys (GitHub has no cosmetic-only keys today, but
-    /// this keeps backend enumeration uniform across sections).
-    pub fn has_connection_config(&self) -> bool {
-        !self.is_empty()
+        self.group_by.is_none() && self.sections.is_empty()
     }
 }
 
-/// GitLab-specific configuration
+/// Settings from the `[webhook]` table.
+///
+/// `secrets` maps a source ("github", "gitlab", "jira", "linear", "youtrack")
+/// to the secret or token configured on the tracker's side. Deliveries from a
+/// source with a secret are rejected unless they carry a valid signature.
 #[derive(Debug, Deserialize, Serialize, Default, Clone)]
-pub struct GitLabConfig {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub project_id: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub namespace: Option<String>,
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub link_mappings: HashMap<String, String>,
+pub struct WebhookConfig {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub secrets: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hooks: Vec<WebhookHookConfig>,
 }
 
-impl GitLabConfig {
+impl WebhookConfig {
     pub fn is_empty(&self) -> bool {
-        self.token.is_none()
-            && self.url.is_none()
-            && self.project_id.is_none()
-            && self.namespace.is_none()
-            && self.link_mappings.is_empty()
-    }
-
-    /// Connection-relevant keys only (excludes link_mappings).
-    pub fn has_connection_config(&self) -> bool {
-        self.token.is_some()
-            || self.url.is_some()
-            || self.project_id.is_some()
-            || self.namespace.is_some()
+        self.secrets.is_empty() && self.hooks.is_empty()
     }
 }
 
-/// Linear-specific configuration
+/// A `[[webhook.hooks]]` entry: a shell command run for matching events.
 #[derive(Debug, Deserialize, Serialize, Default, Clone)]
-pub struct LinearConfig {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
-    /// Linear GraphQL API URL (defaults to https://api.linear.app/graphql)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub api_url: Option<String>,
-    /// Linear workspace/web URL used by `track open` (e.g. https://linear.app/acme)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
-    /// Default Linear team key/name/id. Falls back to top-level default_project.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_team: Option<String>,
-    /// Default Linear project association for issue creation.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_linear_project: Option<String>,
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub link_mappings: HashMap<String, String>,
+pub struct WebhookHookConfig {
+    pub command: String,
+    /// Event kinds to run for (e.g., "issue_transitioned"); empty means all
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<TrackerEventKind>,
+    /// Sources to run for (e.g., "github"); empty means all
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<String>,
 }
 
-impl LinearConfig {
-    pub fn is_empty(&self) -> bool {
-        self.token.is_none()
-            && self.api_url.is_none()
-            && self.url.is_none()
-            && self.default_team.is_none()
-            && self.default_linear_project.is_none()
-            && self.link_mappings.is_empty()
-    }
-
-    /// Connection-relevant keys only (excludes link_mappings).
-    pub fn has_connection_config(&self) -> bool {
-        self.token.is_some()
-            || self.api_url.is_some()
-            || self.url.is_some()
-            || self.default_team.is_some()
-            || self.default_linear_project.is_some()
+impl WebhookHookConfig {
+    pub fn matches(&self, event: &TrackerEvent) -> bool {
+        (self.events.is_empty() || self.events.contains(&event.kind))
+            && (self.sources.is_empty()
+                || self
+                    .sources
+                    .iter()
+                    .any(|source| source.eq_ignore_ascii_case(&event.source)))
     }
 }
 
-fn map_youtrack_env_key(key: &str) -> Option<&'static str> {
-    match key.to_ascii_lowercase().as_str() {
-        "url" => Some("youtrack.url"),
-        "token" => Some("youtrack.token"),
-        _ => None,
-    }
+/// A `[[changelog.sections]]` entry.
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct ChangelogSectionConfig {
+    /// Section heading (e.g., "Bug Fixes")
+    pub title: String,
+    /// Type values or tags (case-insensitive) that belong to this section
+    #[serde(default)]
+    pub values: Vec<String>,
 }
 
-fn map_jira_env_key(key: &str) -> Option<&'static str> {
-    match key.to_ascii_lowercase().as_str() {
-        "url" => Some("jira.url"),
-        "email" => Some("jira.email"),
-        "token" => Some("jira.token"),
-        _ => None,
-    }
+/// A saved query from a `[queries.<name>]` table.
+///
+/// `query` is the fallback used for every backend; the per-backend keys
+/// override it so one config can serve teams on different trackers. Queries
+/// may use the `{PROJECT}`, `{ME}`, `{TODAY}` and `{TODAY-7d}` placeholders
+/// (see [`crate::cache::expand_query_template`]). A bare string
+/// (`mine = "..."`) is shorthand for a table with only `query` set.
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+#[serde(from = "SavedQueryDef")]
+pub struct SavedQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub youtrack: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jira: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub github: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gitlab: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linear: Option<String>,
 }
 
-fn map_github_env_key(key: &str) -> Option<&'static str> {
-    match key.to_ascii_lowercase().as_str() {
-        "token" => Some("github.token"),
-        "owner" => Some("github.owner"),
-        "repo" => Some("github.repo"),
-        "api_url" => Some("github.api_url"),
-        _ => None,
-    }
+/// Accepted TOML shapes for a saved query.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SavedQueryDef {
+    Query(String),
+    Table {
+        description: Option<String>,
+        query: Option<String>,
+        youtrack: Option<String>,
+        jira: Option<String>,
+        github: Option<String>,
+        gitlab: Option<String>,
+        linear: Option<String>,
+    },
 }
 
-fn map_gitlab_env_key(key: &str) -> Option<&'static str> {
-    match key.to_ascii_lowercase().as_str() {
-        "token" => Some("gitlab.token"),
-        "url" => Some("gitlab.url"),
-        "project_id" => Some("gitlab.project_id"),
-        "namespace" => Some("gitlab.namespace"),
-        _ => None,
+impl From<SavedQueryDef> for SavedQuery {
+    fn from(def: SavedQueryDef) -> Self {
+        match def {
+            SavedQueryDef::Query(query) => SavedQuery {
+                query: Some(query),
+                ..Default::default()
+            },
+            SavedQueryDef::Table {
+                description,
+                query,
+                youtrack,
+                jira,
+                github,
+                gitlab,
+                linear,
+            } => SavedQuery {
+                description,
+                query,
+                youtrack,
+                jira,
+                github,
+                gitlab,
+                linear,
+            },
+        }
     }
 }
 
-fn map_linear_env_key(key: &str) -> Option<&'static str> {
-    match key.to_ascii_lowercase().as_str() {
-        "token" => Some("linear.token"),
-        "api_url" => Some("linear.api_url"),
-        "url" => Some("linear.url"),
-        "default_team" => Some("linear.default_team"),
-        "default_project" => Some("linear.default_linear_project"),
-        _ => None,
+impl SavedQuery {
+    /// The query text for `backend`: its own variant, else the shared `query`.
+    pub fn query_for(&self, backend: Backend) -> Option<&str> {
+        let variant = match backend {
+            Backend::YouTrack => &self.youtrack,
+            Backend::Jira => &self.jira,
+            Backend::GitHub => &self.github,
+            Backend::GitLab => &self.gitlab,
+            Backend::Linear => &self.linear,
+        };
+        variant.as_deref().or(self.query.as_deref())
     }
 }
 
@@ -254,7 +231,7 @@ impl Config {
         let mut config = Self::load_raw(config_path)?;
 
         // Merge backend-specific config with global config
-        config.apply_backend_config(backend);
+        config.connection.apply_backend_config(backend);
 
         Ok(config)
     }
@@ -265,238 +242,13 @@ impl Config {
     /// Use this when the per-backend sections themselves matter, e.g. to
     /// enumerate which backends are configured (`track doctor --all-backends`).
     pub fn load_raw(config_path: Option<PathBuf>) -> Result<Self> {
-        let mut figment = Figment::new().merge(Serialized::defaults(Config::default()));
-
-        let explicit_path = config_path.as_deref();
-        if let Some(path) = explicit_path
-            && !path.exists()
-        {
-            return Err(anyhow!("Config file not found: {}", path.display()));
-        }
-
-        for path in config_paths(explicit_path) {
-            if path.exists() {
-                figment = figment.merge(Toml::file(path));
-            }
-        }
-
-        // Support TRACKER_*, YOUTRACK_*, and JIRA_* environment variables
-        figment = figment
-            .merge(Env::prefixed("TRACKER_"))
-            .merge(Env::prefixed("YOUTRACK_").map(|key| {
-                // Map YOUTRACK_URL -> youtrack.url for nested config
-                if let Some(mapped) = map_youtrack_env_key(key.as_str()) {
-                    mapped.into()
-                } else {
-                    key.into()
-                }
-            }))
-            .merge(Env::prefixed("JIRA_").map(|key| {
-                // Map JIRA_URL -> jira.url for nested config
-                if let Some(mapped) = map_jira_env_key(key.as_str()) {
-                    mapped.into()
-                } else {
-                    key.into()
-                }
-            }))
-            .merge(Env::prefixed("GITHUB_").map(|key| {
-                if let Some(mapped) = map_github_env_key(key.as_str()) {
-                    mapped.into()
-                } else {
-                    key.into()
-                }
-            }))
-            .merge(Env::prefixed("GITLAB_").map(|key| {
-                if let Some(mapped) = map_gitlab_env_key(key.as_str()) {
-                    mapped.into()
-                } else {
-                    key.into()
-                }
-            }))
-            .merge(Env::prefixed("LINEAR_").map(|key| {
-                if let Some(mapped) = map_linear_env_key(key.as_str()) {
-                    mapped.into()
-                } else {
-                    key.into()
-                }
-            }));
-
-        let config: Config = figment
+        let config: Config = tracker_client::config::figment(config_path.as_deref())?
             .extract()
             .map_err(|e| anyhow!("Failed to load config: {}", e))?;
 
         Ok(config)
     }
 
-    /// Enumerate backends that have any configuration present.
-    ///
-    /// Must be called on a raw config (see [`Config::load_raw`]): once
-    /// `apply_backend_config` has collapsed a backend's section into the flat
-    /// url/token fields, that section is partially consumed. A backend counts
-    /// as configured when its nested section has connection-relevant keys
-    /// (link_mappings alone don't count), or when it is the default backend
-    /// and only flat url/token settings exist.
-    pub fn configured_backends(&self) -> Vec<Backend> {
-        let mut found = Vec::new();
-        if self.youtrack.has_connection_config() {
-            found.push(Backend::YouTrack);
-        }
-        if self.jira.has_connection_config() {
-            found.push(Backend::Jira);
-        }
-        if self.github.has_connection_config() {
-            found.push(Backend::GitHub);
-        }
-        if self.gitlab.has_connection_config() {
-            found.push(Backend::GitLab);
-        }
-        if self.linear.has_connection_config() {
-            found.push(Backend::Linear);
-        }
-
-        // Single-backend setups often only set the flat url/token keys; those
-        // belong to the default backend.
-        let default = self.get_backend();
-        if !found.contains(&default) && (self.url.is_some() || self.token.is_some()) {
-            found.push(default);
-        }
-
-        Backend::ALL
-            .into_iter()
-            .filter(|b| found.contains(b))
-            .collect()
-    }
-
-    /// Apply backend-specific configuration, falling back to global settings
-    fn apply_backend_config(&mut self, backend: Backend) {
-        match backend {
-            Backend::YouTrack => {
-                if let Some(u) = self.youtrack.url.take() {
-                    self.url = Some(u);
-                }
-                if let Some(t) = self.youtrack.token.take() {
-                    self.token = Some(t);
-                }
-            }
-            Backend::Jira => {
-                if let Some(u) = self.jira.url.take() {
-                    self.url = Some(u);
-                }
-                if let Some(e) = self.jira.email.take() {
-                    self.email = Some(e);
-                }
-                if let Some(t) = self.jira.token.take() {
-                    self.token = Some(t);
-                }
-            }
-            Backend::GitHub => {
-                if let Some(api_url) = self.github.api_url.take() {
-                    self.url = Some(api_url);
-                } else {
-                    // GitHub typically defaults to api.github.com.
-                    // If the global generic URL is set to a completely different service (like YouTrack/GitLab),
-                    // we should disregard it and use the GitHub default to prevent cross-contamination.
-                    let is_github_url = self
-                        .url
-                        .as_deref()
-                        .is_some_and(|u| u.to_lowercase().contains("github"));
-
-                    if !is_github_url {
-                        self.url = Some("https://api.github.com".to_string());
-                    }
-                }
-                if let Some(t) = self.github.token.take() {
-                    self.token = Some(t);
-                }
-            }
-            Backend::GitLab => {
-                if let Some(u) = self.gitlab.url.take() {
-                    self.url = Some(u);
-                }
-                if let Some(t) = self.gitlab.token.take() {
-                    self.token = Some(t);
-                }
-            }
-            Backend::Linear => {
-                if let Some(u) = self.linear.url.take() {
-                    self.url = Some(u);
-                } else {
-                    let is_linear_url = self
-                        .url
-                        .as_deref()
-                        .is_some_and(|u| u.to_lowercase().contains("linear"));
-                    if !is_linear_url {
-                        self.url = None;
-                    }
-                }
-                if let Some(t) = self.linear.token.take() {
-                    self.token = Some(t);
-                }
-                if self.default_project.is_none()
-                    && let Some(team) = self.linear.default_team.clone()
-                {
-                    self.default_project = Some(team);
-                }
-            }
-        }
-    }
-
-    pub fn merge_with_cli(&mut self, cli_url: Option<String>, cli_token: Option<String>) {
-        if let Some(url) = cli_url {
-            self.url = Some(url);
-        }
-        if let Some(token) = cli_token {
-            self.token = Some(token);
-        }
-    }
-
-    pub fn validate(&self, backend: Backend) -> Result<()> {
-        let backend_name = match backend {
-            Backend::YouTrack => "YouTrack",
-            Backend::Jira => "Jira",
-            Backend::GitHub => "GitHub",
-            Backend::GitLab => "GitLab",
-            Backend::Linear => "Linear",
-        };
-
-        if backend != Backend::Linear && self.url.is_none() {
-            return Err(anyhow!(
-                "{} URL not configured. Set via --url, TRACKER_URL env var, or config file",
-                backend_name
-            ));
-        }
-        if self.token.is_none() {
-            return Err(anyhow!(
-                "{} token not configured. Set via --token, TRACKER_TOKEN env var, or config file",
-                backend_name
-            ));
-        }
-        // Jira requires email for Basic Auth
-        if backend == Backend::Jira && self.email.is_none() {
-            return Err(anyhow!(
-                "Jira email not configured. Set via JIRA_EMAIL env var or config file"
-            ));
-        }
-        if backend == Backend::GitHub {
-            if self.github.owner.is_none() {
-                return Err(anyhow!(
-                    "GitHub owner not configured. Set via 'track config set github.owner <OWNER>' or GITHUB_OWNER env var"
-                ));
-            }
-            if self.github.repo.is_none() {
-                return Err(anyhow!(
-                    "GitHub repo not configured. Set via 'track config set github.repo <REPO>' or GITHUB_REPO env var"
-                ));
-            }
-        }
-        if backend == Backend::GitLab && self.gitlab.project_id.is_none() {
-            return Err(anyhow!(
-                "GitLab project_id not configured. Set via 'track config set gitlab.project_id <ID>' or GITLAB_PROJECT_ID env var"
-            ));
-        }
-        Ok(())
-    }
-
     /// Save configuration to a TOML file
     pub fn save(&self, path: &Path) -> Result<()> {
         let toml_string = toml::to_string_pretty(self)
@@ -550,7 +302,7 @@ impl Config {
     pub fn update_default_project(project_short_name: &str) -> Result<()> {
         let path = local_track_config_path()?;
         if let Some(mut config) = Self::load_local_track_toml()? {
-            config.default_project = Some(project_short_name.to_string());
+            config.connection.default_project = Some(project_short_name.to_string());
             config.save(&path)?;
             Ok(())
         } else {
@@ -564,7 +316,7 @@ impl Config {
     pub fn update_backend(backend: Backend) -> Result<()> {
         let path = local_track_config_path()?;
         if let Some(mut config) = Self::load_local_track_toml()? {
-            config.backend = Some(backend);
+            config.connection.backend = Some(backend);
             config.save(&path)?;
             Ok(())
         } else {
@@ -576,65 +328,40 @@ impl Config {
 
     /// Get the configured backend, defaulting to YouTrack
     pub fn get_backend(&self) -> Backend {
-        self.backend.unwrap_or_default()
+        self.connection.get_backend()
     }
 
-    /// Link-type mappings (canonical keyword -> backend link type name) for
-    /// the given backend. GitHub has no configurable link types.
-    pub fn link_mappings_for(&self, backend: Backend) -> &HashMap<String, String> {
-        static NO_MAPPINGS: std::sync::LazyLock<HashMap<String, String>> =
-            std::sync::LazyLock::new(HashMap::new);
-        match backend {
-            Backend::YouTrack => &self.youtrack.link_mappings,
-            Backend::Jira => &self.jira.link_mappings,
-            Backend::GitHub => &NO_MAPPINGS,
-            Backend::GitLab => &self.gitlab.link_mappings,
-            Backend::Linear => &self.linear.link_mappings,
-        }
+    /// Link-type mappings for the given backend
+    pub fn link_mappings_for(
+        &self,
+        backend: Backend,
+    ) -> &std::collections::HashMap<String, String> {
+        self.connection.link_mappings_for(backend)
     }
-}
 
-fn config_paths(explicit: Option<&Path>) -> Vec<PathBuf> {
-    let mut paths = Vec::new();
-
-    if let Some(path) = explicit {
-        paths.push(path.to_path_buf());
-        return paths;
-    }
-
-    // Load configs from lowest to highest priority
-    // Later entries override earlier ones in figment merge
-    // 1. Global: ~/.tracker-cli/.track.toml (lowest file priority)
-    if let Some(path) = get_global_config_path() {
-        paths.push(path);
-    }
-    // 2. Project: ./.track.toml (highest file priority)
-    if let Some(path) = get_local_track_config_path()
-        && !paths.contains(&path)
-    {
-        paths.push(path);
+    /// Saved queries from `[queries]` as query templates for `backend`.
+    ///
+    /// Entries with neither a variant for `backend` nor a shared `query` are
+    /// skipped, so a Jira-only query doesn't show up when running against
+    /// GitHub.
+    pub fn saved_query_templates(&self, backend: Backend) -> Vec<CachedQueryTemplate> {
+        self.queries
+            .iter()
+            .filter_map(|(name, saved)| {
+                let query = saved.query_for(backend)?;
+                Some(CachedQueryTemplate {
+                    name: name.clone(),
+                    description: saved
+                        .description
+                        .clone()
+                        .unwrap_or_else(|| "Saved query".to_string()),
+                    query: query.to_string(),
+                    backend: backend.to_string(),
+                    custom: true,
+                })
+            })
+            .collect()
     }
-
-    paths
-}
-
-/// Returns the path to the global config (~/.tracker-cli/.track.toml)
-fn get_global_config_path() -> Option<PathBuf> {
-    home_dir().map(|home| home.join(".tracker-cli").join(".track.toml"))
-}
-
-/// Returns the path to the local .track.toml file in the current directory
-fn get_local_track_config_path() -> Option<PathBuf> {
-    std::env::current_dir()
-        .ok()
-        .map(|dir| dir.join(".track.toml"))
-}
-
-/// Returns the user's home directory
-fn home_dir() -> Option<PathBuf> {
-    std::env::var_os("HOME")
-        .or_else(|| std::env::var_os("USERPROFILE"))
-        .map(PathBuf::from)
 }
 
 /// Returns the path where `track init` will create the config file
@@ -646,7 +373,7 @@ pub fn local_track_config_path() -> Result<PathBuf> {
 
 /// Returns the global config path (~/.tracker-cli/.track.toml)
 pub fn global_config_path() -> Option<PathBuf> {
-    get_global_config_path()
+    tracker_client::config::global_config_path()
 }
 
 /// Returns the global config path, creating the parent directory if needed
@@ -675,31 +402,14 @@ pub fn global_config_path_ensure() -> Result<PathBuf> {
 
 /// Returns true if a .track.toml exists in the current directory (project context)
 pub fn is_project_context() -> bool {
-    get_local_track_config_path()
+    tracker_client::config::local_config_path()
         .map(|p| p.exists())
         .unwrap_or(false)
 }
 
 /// Returns the global cache directory (~/.tracker-cli/cache/)
 pub fn global_cache_dir() -> Option<PathBuf> {
-    home_dir().map(|home| home.join(".tracker-cli").join("cache"))
-}
-
-/// Load backend from the full config chain (global -> project -> env)
-/// without requiring a backend argument.
-pub fn resolve_backend() -> Backend {
-    let mut figment = Figment::new().merge(Serialized::defaults(Config::default()));
-    for path in config_paths(None) {
-        if path.exists() {
-            figment = figment.merge(Toml::file(path));
-        }
-    }
-    figment = figment.merge(Env::prefixed("TRACKER_"));
-    figment
-        .extract::<Config>()
-        .ok()
-        .and_then(|c| c.backend)
-        .unwrap_or_default()
+    tracker_client::config::config_dir().map(|dir| dir.join("cache"))
 }
 
 #[cfg(test)]
@@ -718,255 +428,34 @@ mod tests {
     }
 
     #[test]
-    fn test_config_with_link_mappings() {
+    fn connection_settings_share_the_top_level_table() {
         let toml_str = r#"
 backend = "jira"
+default_project = "PROJ"
+
 [jira]
 url = "https://test.atlassian.net"
-email = "user@example.com"
-token = "secret"
 
 [jira.link_mappings]
 depends = "Requires"
-required = "Requires"
-"#;
-        let config: Config = toml::from_str(toml_str).unwrap();
-        assert_eq!(
-            config.jira.link_mappings.get("depends"),
-            Some(&"Requires".to_string())
-        );
-        assert_eq!(
-            config.jira.link_mappings.get("required"),
-            Some(&"Requires".to_string())
-        );
-        assert!(!config.jira.is_empty());
-    }
-
-    #[test]
-    fn test_config_without_link_mappings() {
-        let toml_str = r#"
-backend = "jira"
-[jira]
-url = "https://test.atlassian.net"
-email = "user@example.com"
-token = "secret"
-"#;
-        let config: Config = toml::from_str(toml_str).unwrap();
-        assert!(config.jira.link_mappings.is_empty());
-    }
-
-    #[test]
-    fn test_config_youtrack_link_mappings() {
-        let toml_str = r#"
-backend = "youtrack"
-[youtrack]
-url = "https://yt.example.com"
-token = "secret"
-
-[youtrack.link_mappings]
-depends = "Custom Depend"
-"#;
-        let config: Config = toml::from_str(toml_str).unwrap();
-        assert_eq!(
-            config.youtrack.link_mappings.get("depends"),
-            Some(&"Custom Depend".to_string())
-        );
-    }
 
-    #[test]
-    fn test_config_gitlab_link_mappings() {
-        let toml_str = r#"
-backend = "gitlab"
-[gitlab]
-url = "https://gitlab.com/api/v4"
-token = "secret"
-project_id = "123"
-
-[gitlab.link_mappings]
-duplicates = "blocks"
+[aliases]
+mine = "issue search -T my_open"
 "#;
         let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.get_backend(), Backend::Jira);
+        assert_eq!(config.connection.default_project.as_deref(), Some("PROJ"));
         assert_eq!(
-            config.gitlab.link_mappings.get("duplicates"),
-            Some(&"blocks".to_string())
-        );
-    }
-
-    #[test]
-    fn test_config_link_mappings_serialization_roundtrip() {
-        let mut config = Config::default();
-        config
-            .jira
-            .link_mappings
-            .insert("depends".to_string(), "Requires".to_string());
-        config.jira.url = Some("https://test.atlassian.net".to_string());
-
-        let toml_str = toml::to_string_pretty(&config).unwrap();
-        let parsed: Config = toml::from_str(&toml_str).unwrap();
-        assert_eq!(
-            parsed.jira.link_mappings.get("depends"),
+            config.link_mappings_for(Backend::Jira).get("depends"),
             Some(&"Requires".to_string())
         );
-    }
-
-    #[test]
-    fn test_backend_env_key_mappers_are_case_insensitive() {
-        assert_eq!(map_youtrack_env_key("URL"), Some("youtrack.url"));
-        assert_eq!(map_youtrack_env_key("Token"), Some("youtrack.token"));
-        assert_eq!(map_jira_env_key("EMAIL"), Some("jira.email"));
-        assert_eq!(map_github_env_key("OWNER"), Some("github.owner"));
-        assert_eq!(map_github_env_key("API_URL"), Some("github.api_url"));
-        assert_eq!(map_gitlab_env_key("PROJECT_ID"), Some("gitlab.project_id"));
-        assert_eq!(map_linear_env_key("TOKEN"), Some("linear.token"));
-        assert_eq!(map_linear_env_key("API_URL"), Some("linear.api_url"));
-        assert_eq!(
-            map_linear_env_key("DEFAULT_TEAM"),
-            Some("linear.default_team")
-        );
-        assert_eq!(
-            map_linear_env_key("DEFAULT_PROJECT"),
-            Some("linear.default_linear_project")
-        );
-        assert_eq!(map_linear_env_key("UNKNOWN"), None);
-    }
-
-    #[test]
-    fn test_validate_github_requires_owner_and_repo() {
-        let config = Config {
-            url: Some("https://api.github.com".to_string()),
-            token: Some("secret".to_string()),
-            github: GitHubConfig {
-                repo: Some("repo".to_string()),
-                ..Default::default()
-            },
-            ..Default::default()
-        };
-
-        let err = config.validate(Backend::GitHub).unwrap_err();
-        assert!(
-            err.to_string().contains("GitHub owner not configured"),
-            "expected missing owner error, got: {err}"
-        );
-
-        let config = Config {
-            url: Some("https://api.github.com".to_string()),
-            token: Some("secret".to_string()),
-            github: GitHubConfig {
-                owner: Some("org".to_string()),
-                ..Default::default()
-            },
-            ..Default::default()
-        };
-
-        let err = config.validate(Backend::GitHub).unwrap_err();
-        assert!(
-            err.to_string().contains("GitHub repo not configured"),
-            "expected missing repo error, got: {err}"
-        );
-    }
-
-    #[test]
-    fn test_configured_backends_from_sections() {
-        let toml_str = r#"
-backend = "youtrack"
-[youtrack]
-url = "https://yt.example.com"
-token = "yt-secret"
-
-[gitlab]
-url = "https://gitlab.com/api/v4"
-token = "gl-secret"
-project_id = "123"
-"#;
-        let config: Config = toml::from_str(toml_str).unwrap();
-        assert_eq!(
-            config.configured_backends(),
-            vec![Backend::YouTrack, Backend::GitLab]
-        );
-    }
-
-    #[test]
-    fn test_configured_backends_flat_keys_count_for_default_backend() {
-        let toml_str = r#"
-backend = "jira"
-url = "https://test.atlassian.net"
-token = "secret"
-"#;
-        let config: Config = toml::from_str(toml_str).unwrap();
-        assert_eq!(config.configured_backends(), vec![Backend::Jira]);
-    }
-
-    #[test]
-    fn test_configured_backends_flat_keys_default_to_youtrack() {
-        let toml_str = r#"
-url = "https://yt.example.com"
-token = "secret"
-"#;
-        let config: Config = toml::from_str(toml_str).unwrap();
-        assert_eq!(config.configured_backends(), vec![Backend::YouTrack]);
-    }
-
-    #[test]
-    fn test_configured_backends_empty_config() {
-        let config = Config::default();
-        assert!(config.configured_backends().is_empty());
-    }
-
-    #[test]
-    fn test_configured_backends_ignores_link_mappings_only_sections() {
-        // A cosmetic link_mappings table alone must not enumerate the backend
-        // (it has no url/token, so auditing it would always fail).
-        let toml_str = r#"
-[youtrack.link_mappings]
-"parent for" = "subtask of"
-
-[gitlab]
-url = "https://gitlab.com/api/v4"
-token = "gl-secret"
-project_id = "123"
-
-[linear.link_mappings]
-blocks = "blocked by"
-"#;
-        let config: Config = toml::from_str(toml_str).unwrap();
-        assert_eq!(config.configured_backends(), vec![Backend::GitLab]);
-    }
-
-    #[test]
-    fn test_configured_backends_stable_order() {
-        let toml_str = r#"
-[linear]
-token = "lin-secret"
+        assert_eq!(config.aliases.len(), 1);
 
-[jira]
-url = "https://test.atlassian.net"
-email = "user@example.com"
-token = "j-secret"
-
-[github]
-token = "gh-secret"
-owner = "org"
-repo = "repo"
-"#;
-        let config: Config = toml::from_str(toml_str).unwrap();
+        let parsed: Config = toml::from_str(&toml::to_string_pretty(&config).unwrap()).unwrap();
         assert_eq!(
-            config.configured_backends(),
-            vec![Backend::Jira, Backend::GitHub, Backend::Linear]
-        );
-    }
-
-    #[test]
-    fn test_validate_gitlab_requires_project_id() {
-        let config = Config {
-            url: Some("https://gitlab.com/api/v4".to_string()),
-            token: Some("secret".to_string()),
-            ..Default::default()
-        };
-
-        let err = config.validate(Backend::GitLab).unwrap_err();
-        assert!(
-            err.to_string().contains("GitLab project_id not configured"),
-            "expected missing project_id error, got: {err}"
+            parsed.connection.jira.url.as_deref(),
+            Some("https://test.atlassian.net")
         );
+        assert_eq!(parsed.aliases.len(), 1);
     }
 }
@@ -5,19 +5,12 @@ mod commands;
 mod config;
 mod output;
 
-use anyhow::{Result, anyhow};
-use clap::Parser;
-use cli::{Backend, Cli, Commands};
+use anyhow::Result;
+use cli::{Cli, Commands, ReportCommands, RulesCommands, WebhookCommands};
 use config::Config;
-use github_backend::GitHubClient;
-use gitlab_backend::GitLabClient;
-use jira_backend::{ConfluenceClient, JiraClient};
-use linear_backend::LinearClient;
 use output::output_error;
 use std::process::ExitCode;
 use tracker_core::{IssueTracker, KnowledgeBase};
-use tracker_mock::MockClient;
-use youtrack_backend::YouTrackClient;
 
 /// Debug builds of the command dispatch need more stack than Windows' 1 MiB
 /// main-thread default, so the CLI runs on a thread with an explicit size.
@@ -35,7 +28,13 @@ fn main() -> ExitCode {
 }
 
 fn cli_main() -> ExitCode {
-    let cli = Cli::parse();
+    let cli = match commands::external::parse_cli() {
+        Ok(cli) => cli,
+        Err(e) => {
+            output_error(&e, cli::OutputFormat::Text);
+            return ExitCode::from(1);
+        }
+    };
 
     // Initialize color mode based on CLI flag and environment
     color::init(cli.color);
@@ -104,6 +103,41 @@ fn run(cli: Cli) -> Result<()> {
         }
     }
 
+    // Handle issue template commands - local files, no API needed
+    if let Commands::Issue {
+        action: cli::IssueCommands::Template { action },
+    } = &cli.command
+    {
+        return commands::issue_template::handle_template(action, cli.format);
+    }
+
+    // Handle git commands - local repository work, no API needed
+    if let Commands::Git { action } = &cli.command {
+        let git_config = Config::load_raw(cli.config.clone())
+            .map(|config| config.git)
+            .unwrap_or_default();
+        return commands::git::handle_git(action, &git_config, cli.format);
+    }
+
+    // Handle webhook server - receives pushes, no API client needed
+    if let Commands::Webhook {
+        action: WebhookCommands::Serve { port, bind, exec },
+    } = &cli.command
+    {
+        let webhook_config = Config::load_raw(cli.config.clone())
+            .map(|config| config.webhook)
+            .unwrap_or_default();
+        return commands::webhook::handle_webhook_serve(
+            &webhook_config,
+            &commands::webhook::ServeOptions {
+                bind,
+                port: *port,
+                exec: exec.as_deref(),
+            },
+            cli.format,
+        );
+    }
+
     // Handle doctor command - audits one or many backends, so it doesn't fit
     // the single-client dispatch below
     if let Commands::Doctor {
@@ -125,14 +159,20 @@ fn run(cli: Cli) -> Result<()> {
     }
 
     // Handle external commands (shortcuts) early if they are clearly invalid
-    // to provide better error messages when config is missing
+    // to provide better error messages when config is missing. Aliases were
+    // already expanded while parsing; anything else that isn't an issue ID
+    // must be a `track-<name>` plugin.
+    let mut plugin = None;
     if let Commands::External(args) = &cli.command {
         if let Some(cmd) = args.first() {
             if !commands::open::is_issue_id(cmd) {
-                return Err(anyhow::anyhow!(
-                    "unrecognized subcommand '{}'. Run 'track --help' for usage.",
-                    cmd
-                ));
+                plugin = commands::external::find_plugin(cmd);
+                if plugin.is_none() {
+                    return Err(anyhow::anyhow!(
+                        "unrecognized subcommand '{}'. Run 'track --help' for usage.",
+                        cmd
+                    ));
+                }
             }
         } else {
             return Err(anyhow::anyhow!(
@@ -145,20 +185,26 @@ fn run(cli: Cli) -> Result<()> {
     let effective_backend = cli.backend.unwrap_or_else(config::resolve_backend);
 
     let mut config = Config::load(cli.config.clone(), effective_backend)?;
-    config.merge_with_cli(cli.url.clone(), cli.token.clone());
+    config
+        .connection
+        .merge_overrides(cli.url.clone(), cli.token.clone());
 
-    // Check if mock mode is enabled (before config validation, since mock mode
-    // doesn't need real backend credentials)
-    if let Some(mock_dir) = tracker_mock::get_mock_dir() {
-        let client = MockClient::new(&mock_dir)
-            .map_err(|e| anyhow::anyhow!("Failed to initialize mock client: {}", e))?;
-        return run_with_client(&client, &client, &cli, &config);
+    if let (Some(plugin), Commands::External(args)) = (&plugin, &cli.command) {
+        return commands::external::run_plugin(
+            plugin,
+            args,
+            commands::external::PluginContext {
+                backend: effective_backend,
+                config: &config,
+                config_path: cli.config.as_deref(),
+                format: cli.format,
+            },
+        );
     }
 
-    config.validate(effective_backend)?;
-
-    // Create the appropriate backend client
-    let client = build_client(effective_backend, &config)?;
+    // Mock mode needs no real backend credentials, so the config is only
+    // validated when connecting to a real backend
+    let client = tracker_client::connect_backend(effective_backend, &config.connection)?;
     run_with_client(
         client.issue_tracker(),
         client.knowledge_base(),
@@ -167,130 +213,6 @@ fn run(cli: Cli) -> Result<()> {
     )
 }
 
-/// A constructed backend client, exposing the issue-tracker and knowledge-base
-/// trait objects it implements. Jira is the only backend that splits the two
-/// across separate clients (Jira + Confluence).
-pub enum BackendClient {
-    YouTrack(YouTrackClient),
-    Jira {
-        issues: JiraClient,
-        confluence: ConfluenceClient,
-    },
-    GitHub(GitHubClient),
-    GitLab(GitLabClient),
-    Linear(LinearClient),
-    Mock(MockClient),
-}
-
-impl BackendClient {
-    pub fn issue_tracker(&self) -> &dyn IssueTracker {
-        match self {
-            BackendClient::YouTrack(c) => c,
-            BackendClient::Jira { issues, .. } => issues,
-            BackendClient::GitHub(c) => c,
-            BackendClient::GitLab(c) => c,
-            BackendClient::Linear(c) => c,
-            BackendClient::Mock(c) => c,
-        }
-    }
-
-    pub fn knowledge_base(&self) -> &dyn KnowledgeBase {
-        match self {
-            BackendClient::YouTrack(c) => c,
-            BackendClient::Jira { confluence, .. } => confluence,
-            BackendClient::GitHub(c) => c,
-            BackendClient::GitLab(c) => c,
-            BackendClient::Linear(c) => c,
-            BackendClient::Mock(c) => c,
-        }
-    }
-}
-
-/// Build a client for `backend` from an already backend-collapsed [`Config`]
-/// (i.e. one produced by `Config::load(_, backend)`).
-///
-/// The config should normally be validated with `config.validate(backend)`
-/// first; missing settings surface as errors rather than panics so callers
-/// like `track doctor` can probe multiple backends safely.
-pub fn build_client(backend: Backend, config: &Config) -> Result<BackendClient> {
-    let missing = |what: &str| anyhow!("{} not configured", what);
-    match backend {
-        Backend::YouTrack => {
-            let url = config.url.as_ref().ok_or_else(|| missing("YouTrack URL"))?;
-            let token = config
-                .token
-                .as_ref()
-                .ok_or_else(|| missing("YouTrack token"))?;
-            let client = YouTrackClient::new(url, token)
-                .with_link_mappings(config.youtrack.link_mappings.clone());
-            Ok(BackendClient::YouTrack(client))
-        }
-        Backend::Jira => {
-            let url = config.url.as_ref().ok_or_else(|| missing("Jira URL"))?;
-            let email = config.email.as_ref().ok_or_else(|| missing("Jira email"))?;
-            let token = config.token.as_ref().ok_or_else(|| missing("Jira token"))?;
-
-            let issues = JiraClient::new(url, email, token)
-                .with_link_mappings(config.jira.link_mappings.clone());
-            let confluence = ConfluenceClient::new(url, email, token);
-            Ok(BackendClient::Jira { issues, confluence })
-        }
-        Backend::GitHub => {
-            let owner = config
-                .github
-                .owner
-                .as_deref()
-                .ok_or_else(|| missing("GitHub owner"))?;
-            let repo = config
-                .github
-                .repo
-                .as_deref()
-                .ok_or_else(|| missing("GitHub repo"))?;
-            let token = config
-                .token
-                .as_ref()
-                .ok_or_else(|| missing("GitHub token"))?;
-            let client = if let Some(api_url) = config.url.as_deref() {
-                GitHubClient::with_base_url(api_url, owner, repo, token)
-            } else {
-                GitHubClient::new(owner, repo, token)
-            };
-            Ok(BackendClient::GitHub(client))
-        }
-        Backend::GitLab => {
-            let base_url = config.url.as_ref().ok_or_else(|| missing("GitLab URL"))?;
-            let token = config
-                .token
-                .as_ref()
-                .ok_or_else(|| missing("GitLab token"))?;
-            let project_id = config.gitlab.project_id.as_deref();
-            let client = GitLabClient::new(base_url, token, project_id)
-                .with_link_mappings(config.gitlab.link_mappings.clone());
-            Ok(BackendClient::GitLab(client))
-        }
-        Backend::Linear => {
-            let token = config
-                .token
-                .as_ref()
-                .ok_or_else(|| missing("Linear token"))?;
-            let api_url = config
-                .linear
-                .api_url
-                .as_deref()
-                .unwrap_or("https://api.linear.app/graphql");
-            let default_team = config
-                .linear
-                .default_team
-                .clone()
-                .or_else(|| config.default_project.clone());
-            let client = LinearClient::with_base_url(api_url, token)
-                .with_defaults(default_team, config.linear.default_linear_project.clone())
-                .with_link_mappings(config.linear.link_mappings.clone());
-            Ok(BackendClient::Linear(client))
-        }
-    }
-}
-
 /// Run commands with clients that implement the required traits
 fn run_with_client(
     issue_client: &dyn IssueTracker,
@@ -305,9 +227,11 @@ fn run_with_client(
                 issue_client,
                 action,
                 cli.format,
-                config.default_project.as_deref(),
+                config.connection.default_project.as_deref(),
                 cli.verbose,
                 config.link_mappings_for(backend),
+                &config.saved_query_templates(backend),
+                &config.git,
             )
         }
         Commands::Project { action } => {
@@ -366,8 +290,28 @@ fn run_with_client(
                 *issue_limit,
                 cli.format,
                 &backend_type,
-                config.url.as_deref().unwrap_or("unknown"),
-                config.default_project.as_deref(),
+                config.connection.url.as_deref().unwrap_or("unknown"),
+                config.connection.default_project.as_deref(),
+                &config.saved_query_templates(backend),
+            )
+        }
+        Commands::Mcp {
+            allow_delete,
+            read_only,
+        } => {
+            let backend = cli.backend.unwrap_or_else(|| config.get_backend());
+            commands::mcp::handle_mcp(
+                issue_client,
+                kb_client,
+                commands::mcp::McpOptions {
+                    allow_delete: *allow_delete,
+                    read_only: *read_only,
+                    default_project: config.connection.default_project.as_deref(),
+                    link_mappings: config.link_mappings_for(backend),
+                    saved_queries: &config.saved_query_templates(backend),
+                    backend_type: &backend.to_string(),
+                    base_url: config.connection.url.as_deref().unwrap_or("unknown"),
+                },
             )
         }
         Commands::Apply {
@@ -376,18 +320,180 @@ fn run_with_client(
             validate,
             resume,
             allow_delete,
-        } => commands::apply::handle_apply(
-            issue_client,
-            commands::apply::ApplyOptions {
-                plan_path: plan,
+            rollback,
+            concurrency,
+        } => {
+            let options = commands::apply::ApplyOptions {
                 dry_run: *dry_run,
                 validate: *validate,
                 resume_path: resume.as_deref(),
                 allow_delete: *allow_delete,
                 format: cli.format,
-                default_project: config.default_project.as_deref(),
-            },
-        ),
+                default_project: config.connection.default_project.as_deref(),
+                concurrency: *concurrency,
+            };
+            match (plan, rollback) {
+                (_, Some(state)) => {
+                    commands::apply::handle_rollback(issue_client, kb_client, state, options)
+                }
+                (Some(plan), None) => {
+                    commands::apply::handle_apply(issue_client, kb_client, plan, options)
+                }
+                (None, None) => unreachable!("clap requires a plan unless --rollback is set"),
+            }
+        }
+        Commands::Changelog {
+            from,
+            to,
+            query,
+            template,
+            project,
+            group_by,
+            title,
+            limit,
+        } => {
+            let backend = cli.backend.unwrap_or_else(|| config.get_backend());
+            commands::changelog::handle_changelog(
+                issue_client,
+                &commands::changelog::ChangelogArgs {
+                    from: from.as_deref(),
+                    to,
+                    query: query.as_deref(),
+                    template: template.as_deref(),
+                    project: project.as_deref(),
+                    group_by: *group_by,
+                    title: title.as_deref(),
+                    limit: *limit,
+                    saved_queries: &config.saved_query_templates(backend),
+                },
+                backend,
+                &config.changelog,
+                config.connection.default_project.as_deref(),
+                cli.format,
+            )
+        }
+        Commands::Watch {
+            query,
+            template,
+            project,
+            interval,
+            limit,
+            exec,
+            count,
+            once,
+            state,
+        } => {
+            let backend = cli.backend.unwrap_or_else(|| config.get_backend());
+            commands::watch::handle_watch(
+                issue_client,
+                &commands::watch::WatchArgs {
+                    query: query.as_deref(),
+                    template: template.as_deref(),
+                    project: project.as_deref(),
+                    interval,
+                    limit: *limit,
+                    exec: exec.as_deref(),
+                    count: *count,
+                    once: *once,
+                    state_path: state.as_deref(),
+                    saved_queries: &config.saved_query_templates(backend),
+                },
+                config.connection.default_project.as_deref(),
+                cli.format,
+            )
+        }
+        Commands::Rules { action } => {
+            let backend = cli.backend.unwrap_or_else(|| config.get_backend());
+            let saved_queries = config.saved_query_templates(backend);
+            let (file, rules) = match action {
+                RulesCommands::Run { file, rules, .. } | RulesCommands::Plan { file, rules } => {
+                    (file, rules)
+                }
+            };
+            let args = commands::rules::RulesArgs {
+                file,
+                only: rules,
+                saved_queries: &saved_queries,
+                default_project: config.connection.default_project.as_deref(),
+            };
+            match action {
+                RulesCommands::Run {
+                    dry_run,
+                    validate,
+                    resume,
+                    ..
+                } => commands::rules::handle_rules_run(
+                    issue_client,
+                    kb_client,
+                    &args,
+                    commands::apply::ApplyOptions {
+                        dry_run: *dry_run,
+                        validate: *validate,
+                        resume_path: resume.as_deref(),
+                        allow_delete: false,
+                        format: cli.format,
+                        default_project: config.connection.default_project.as_deref(),
+                        concurrency: 1,
+                    },
+                ),
+                RulesCommands::Plan { .. } => {
+                    commands::rules::handle_rules_plan(issue_client, &args)
+                }
+            }
+        }
+        Commands::SyncFile {
+            file,
+            apply,
+            dry_run,
+            validate,
+            concurrency,
+            ..
+        } => {
+            if *apply {
+                commands::sync_file::handle_sync_apply(
+                    issue_client,
+                    kb_client,
+                    file,
+                    commands::apply::ApplyOptions {
+                        dry_run: *dry_run,
+                        validate: *validate,
+                        resume_path: None,
+                        allow_delete: false,
+                        format: cli.format,
+                        default_project: config.connection.default_project.as_deref(),
+                        concurrency: *concurrency,
+                    },
+                )
+            } else {
+                commands::sync_file::handle_sync_plan(issue_client, file, cli.format)
+            }
+        }
+        Commands::Report { action } => match action {
+            ReportCommands::Flow {
+                query,
+                template,
+                project,
+                since,
+                limit,
+                csv,
+            } => {
+                let backend = cli.backend.unwrap_or_else(|| config.get_backend());
+                commands::report::handle_flow(
+                    issue_client,
+                    &commands::report::FlowArgs {
+                        query: query.as_deref(),
+                        template: template.as_deref(),
+                        project: project.as_deref(),
+                        since,
+                        limit: *limit,
+                        csv: *csv,
+                        saved_queries: &config.saved_query_templates(backend),
+                    },
+                    config.connection.default_project.as_deref(),
+                    cli.format,
+                )
+            }
+        },
         Commands::Completions { .. } => {
             unreachable!("Completions command should be handled before API validation")
         }
@@ -404,5 +510,11 @@ fn run_with_client(
         Commands::Doctor { .. } => {
             unreachable!("Doctor command should be handled before API validation")
         }
+        Commands::Git { .. } => {
+            unreachable!("Git command should be handled before API validation")
+        }
+        Commands::Webhook { .. } => {
+            unreachable!("Webhook command should be handled before API validation")
+        }
     }
 }
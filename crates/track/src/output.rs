@@ -260,7 +260,7 @@ fn custom_field_name(f: &CustomField) -> &str {
     }
 }
 
-fn find_field_value(issue: &Issue, name: &str) -> Option<String> {
+pub(crate) fn find_field_value(issue: &Issue, name: &str) -> Option<String> {
     issue
         .custom_fields
         .iter()
@@ -278,6 +278,16 @@ fn find_field_value(issue: &Issue, name: &str) -> Option<String> {
         })
 }
 
+/// The value of the issue's workflow state field, whatever the backend calls it.
+pub(crate) fn find_state_value(issue: &Issue) -> Option<&str> {
+    issue.custom_fields.iter().find_map(|f| match f {
+        CustomField::State {
+            value: Some(value), ..
+        } => Some(value.as_str()),
+        _ => None,
+    })
+}
+
 #[derive(Serialize)]
 pub struct JsonError {
     pub error: bool,
@@ -174,10 +174,16 @@ fn parse_stdout_json(output: &[u8]) -> Value {
     serde_json::from_slice(output).unwrap()
 }
 
-fn mock_call_methods(scenario: &Path) -> Vec<String> {
+fn mock_call_entries(scenario: &Path) -> Vec<Value> {
     let log = fs::read_to_string(scenario.join("call_log.jsonl")).unwrap_or_default();
     log.lines()
         .map(|line| serde_json::from_str::<Value>(line).unwrap())
+        .collect()
+}
+
+fn mock_call_methods(scenario: &Path) -> Vec<String> {
+    mock_call_entries(scenario)
+        .into_iter()
         .map(|entry| entry["method"].as_str().unwrap().to_string())
         .collect()
 }
@@ -778,3 +784,1094 @@ id = "DEMO-1"
 
     let _ = fs::remove_dir_all(&dir);
 }
+
+fn article_json(id: &str, summary: &str) -> Value {
+    json!({
+        "id": format!("internal-{id}"),
+        "id_readable": id,
+        "summary": summary,
+        "content": "Article content",
+        "project": {"id": "0-1", "name": "Demo Project", "short_name": "DEMO"},
+        "parent_article": null,
+        "has_children": false,
+        "tags": [],
+        "created": "2024-01-20T10:00:00Z",
+        "updated": "2024-01-20T10:00:00Z",
+        "reporter": null
+    })
+}
+
+#[test]
+fn apply_runs_tag_parent_transition_article_and_work_operations() {
+    let dir = temp_dir();
+    let manifest = base_manifest(
+        r#"
+[[responses]]
+method = "get_issue"
+file = "get_issue.json"
+[responses.args]
+id = "DEMO-1"
+
+[[responses]]
+method = "update_issue"
+file = "get_issue.json"
+[responses.args]
+id = "DEMO-1"
+tags = "existing,needs-review"
+
+[[responses]]
+method = "remove_issue_tag"
+file = "ok.json"
+[responses.args]
+issue_id = "DEMO-1"
+tag = "stale"
+
+[[responses]]
+method = "link_subtask"
+file = "ok.json"
+[responses.args]
+child = "DEMO-1"
+parent = "DEMO-2"
+
+[[responses]]
+method = "remove_issue_parent"
+file = "ok.json"
+[responses.args]
+issue_id = "DEMO-3"
+
+[[responses]]
+method = "unlink_issues"
+file = "ok.json"
+[responses.args]
+source = "DEMO-1"
+link_id = "link-7"
+
+[[responses]]
+method = "transition_issue"
+file = "get_issue.json"
+[responses.args]
+id = "DEMO-1"
+state = "Done"
+resolution = "Won't Do"
+
+[[responses]]
+method = "log_work"
+file = "work_item.json"
+[responses.args]
+issue_id = "DEMO-1"
+minutes = "90"
+date = "2026-05-01"
+
+[[responses]]
+method = "create_article"
+file = "article.json"
+[responses.args]
+project = "0-1"
+summary = "Runbook"
+
+[[responses]]
+method = "update_article"
+file = "article.json"
+[responses.args]
+id = "KB-A-3"
+"#,
+    );
+    let mut issue = issue_json("DEMO-1", "Tagged", "Open", "Normal");
+    issue["tags"] = json!([{"id": "t1", "name": "existing"}]);
+    let mut responses = standard_responses();
+    responses.extend([
+        ("get_issue.json", issue),
+        ("ok.json", Value::Null),
+        (
+            "work_item.json",
+            json!({"id": "w1", "minutes": 90, "text": "Pairing"}),
+        ),
+        ("article.json", article_json("KB-A-3", "Runbook")),
+    ]);
+    let scenario = write_scenario(&dir, &manifest, &responses);
+    let plan = write_plan(
+        &dir,
+        "plan.json",
+        json!({
+            "version": 1,
+            "defaults": {"project": "DEMO"},
+            "operations": [
+                {"op": "add_tag", "issue": "DEMO-1", "tag": "needs-review"},
+                {"op": "remove_tag", "issue": "DEMO-1", "tag": "stale"},
+                {"op": "set_parent", "issue": "DEMO-1", "parent": "DEMO-2"},
+                {"op": "remove_parent", "issue": "DEMO-3"},
+                {"op": "unlink", "source": "DEMO-1", "link_id": "link-7"},
+                {
+                    "op": "transition",
+                    "issue": "DEMO-1",
+                    "state": "Done",
+                    "resolution": "Won't Do"
+                },
+                {
+                    "op": "log_work",
+                    "issue": "DEMO-1",
+                    "duration": "90m",
+                    "text": "Pairing",
+                    "date": "2026-05-01"
+                },
+                {"ref": "runbook", "op": "create_article", "summary": "Runbook"},
+                {"op": "update_article", "article": "$runbook", "content": "Updated"}
+            ]
+        }),
+    );
+    let resume = dir.join("state.json");
+
+    let output = track_in(&dir, &scenario)
+        .args(["-o", "json", "apply"])
+        .arg(&plan)
+        .arg("--resume")
+        .arg(&resume)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json = parse_stdout_json(&output);
+
+    assert_eq!(json["success"], true);
+    assert_eq!(json["refs"]["runbook"], "KB-A-3");
+    let statuses: Vec<&str> = json["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| result["status"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![
+            "tagged",
+            "untagged",
+            "parent_set",
+            "parent_removed",
+            "unlinked",
+            "transitioned",
+            "logged",
+            "created",
+            "updated"
+        ]
+    );
+    assert_eq!(json["results"][7]["article"], "KB-A-3");
+    assert_eq!(json["results"][8]["article"], "KB-A-3");
+
+    let methods = mock_call_methods(&scenario);
+    for method in [
+        "update_issue",
+        "remove_issue_tag",
+        "link_subtask",
+        "remove_issue_parent",
+        "unlink_issues",
+        "transition_issue",
+        "log_work",
+        "create_article",
+        "update_article",
+    ] {
+        assert_eq!(method_count(&methods, method), 1, "{method}");
+    }
+
+    // A resumed run replays nothing
+    fs::write(scenario.join("call_log.jsonl"), "").unwrap();
+    let output = track_in(&dir, &scenario)
+        .args(["-o", "json", "apply"])
+        .arg(&plan)
+        .arg("--resume")
+        .arg(&resume)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json = parse_stdout_json(&output);
+    assert_eq!(json["resumed"], true);
+    assert_eq!(json["summary"]["by_status"]["skipped"], 9);
+    assert_eq!(json["results"][8]["article"], "KB-A-3");
+    assert!(mock_call_methods(&scenario).is_empty());
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn apply_dry_run_validates_new_operations_without_mutation() {
+    let dir = temp_dir();
+    let scenario = write_scenario(&dir, &base_manifest(""), &standard_responses());
+    fs::write(dir.join("notes.txt"), "notes").unwrap();
+    let plan = write_plan(
+        &dir,
+        "plan.json",
+        json!({
+            "version": 1,
+            "defaults": {"project": "DEMO"},
+            "operations": [
+                {"ref": "doc", "op": "create_article", "summary": "Design"},
+                {"op": "update_article", "article": "$doc", "summary": "Design v2"},
+                {"op": "attach", "issue": "DEMO-1", "path": "notes.txt"},
+                {"op": "log_work", "issue": "DEMO-1", "duration": "2h"},
+                {"op": "transition", "issue": "DEMO-1", "state": "Done"}
+            ]
+        }),
+    );
+
+    let output = track_in(&dir, &scenario)
+        .args(["-o", "json", "apply"])
+        .arg(&plan)
+        .arg("--dry-run")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json = parse_stdout_json(&output);
+    assert_eq!(json["summary"]["by_status"]["dry_run"], 5);
+    assert_eq!(json["refs"]["doc"], "planned:doc");
+    assert_eq!(json["results"][1]["article"], "planned:doc");
+    assert_eq!(
+        mock_call_methods(&scenario),
+        vec!["resolve_project_id".to_string()]
+    );
+
+    let plan = write_plan(
+        &dir,
+        "bad.json",
+        json!({
+            "version": 1,
+            "operations": [
+                {"op": "log_work", "issue": "DEMO-1", "duration": "30m"},
+                {"op": "attach", "issue": "DEMO-1", "path": "missing.txt"}
+            ]
+        }),
+    );
+    let output = track_in(&dir, &scenario)
+        .args(["-o", "json", "apply"])
+        .arg(&plan)
+        .arg("--dry-run")
+        .assert()
+        .failure()
+        .get_output()
+        .stdout
+        .clone();
+    let json = parse_stdout_json(&output);
+    assert_eq!(json["results"][0]["status"], "dry_run");
+    assert_eq!(json["results"][1]["status"], "failed");
+    assert!(
+        json["results"][1]["error"]
+            .as_str()
+            .unwrap()
+            .contains("missing.txt")
+    );
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+fn foreach_manifest() -> String {
+    base_manifest(
+        r#"
+[[responses]]
+method = "create_issue"
+file = "create_epic.json"
+[responses.args]
+project = "0-1"
+summary = "Blockers for core"
+
+[[responses]]
+method = "search_issues"
+file = "search_blocked.json"
+[responses.args]
+query = "project: DEMO tag: core-blocked"
+
+[[responses]]
+method = "add_comment"
+file = "comment_blocked_1.json"
+[responses.args]
+issue_id = "DEMO-1"
+text = "Blocked by DEMO-300 (First blocked, owner alice)"
+
+[[responses]]
+method = "add_comment"
+file = "comment_blocked_2.json"
+[responses.args]
+issue_id = "DEMO-2"
+text = "Blocked by DEMO-300 (Second blocked, owner alice)"
+"#,
+    )
+}
+
+fn foreach_responses() -> Vec<(&'static str, Value)> {
+    let mut responses = standard_responses();
+    responses.extend([
+        (
+            "create_epic.json",
+            issue_json("DEMO-300", "Blockers for core", "Open", "Major"),
+        ),
+        (
+            "search_blocked.json",
+            json!([
+                issue_json("DEMO-1", "First blocked", "Open", "Normal"),
+                issue_json("DEMO-2", "Second blocked", "Open", "Normal")
+            ]),
+        ),
+        ("comment_blocked_1.json", comment_json("comment-1", "ok")),
+        ("comment_blocked_2.json", comment_json("comment-2", "ok")),
+    ]);
+    responses
+}
+
+fn foreach_plan() -> Value {
+    json!({
+        "version": 1,
+        "vars": {"team": "core", "owner": "${env.TRACK_VAR_APPLY_TEST_OWNER}"},
+        "defaults": {"project": "DEMO"},
+        "operations": [
+            {
+                "ref": "epic",
+                "op": "create_issue",
+                "summary": "Blockers for ${vars.team}"
+            },
+            {
+                "op": "foreach",
+                "query": "project: DEMO tag: ${vars.team}-blocked",
+                "limit": 10,
+                "operations": [
+                    {
+                        "op": "comment",
+                        "issue": "${item.id}",
+                        "body": "Blocked by ${refs.epic} (${item.summary}, owner ${vars.owner})"
+                    }
+                ]
+            }
+        ]
+    })
+}
+
+#[test]
+fn apply_foreach_expands_nested_operations_with_vars_env_and_refs() {
+    let dir = temp_dir();
+    let scenario = write_scenario(&dir, &foreach_manifest(), &foreach_responses());
+    let plan = write_plan(&dir, "plan.json", foreach_plan());
+
+    let output = track_in(&dir, &scenario)
+        .env("TRACK_VAR_APPLY_TEST_OWNER", "alice")
+        .args(["-o", "json", "apply"])
+        .arg(&plan)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json = parse_stdout_json(&output);
+
+    assert_eq!(json["success"], true);
+    assert_eq!(json["refs"]["epic"], "DEMO-300");
+    assert_eq!(json["results"][1]["op"], "comment");
+    assert_eq!(json["results"][1]["item"], "DEMO-1");
+    assert_eq!(json["results"][1]["step"], 0);
+    assert_eq!(json["results"][2]["item"], "DEMO-2");
+    assert_eq!(json["results"][3]["op"], "foreach");
+    assert_eq!(json["results"][3]["status"], "expanded");
+    assert_eq!(json["results"][3]["matched"], 2);
+    assert!(json.get("plan").is_none());
+
+    let methods = mock_call_methods(&scenario);
+    assert_eq!(method_count(&methods, "search_issues"), 1);
+    assert_eq!(method_count(&methods, "add_comment"), 2);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn apply_foreach_dry_run_reports_expanded_plan() {
+    let dir = temp_dir();
+    let scenario = write_scenario(&dir, &foreach_manifest(), &foreach_responses());
+    let plan = write_plan(&dir, "plan.json", foreach_plan());
+
+    let output = track_in(&dir, &scenario)
+        .env("TRACK_VAR_APPLY_TEST_OWNER", "alice")
+        .args(["-o", "json", "apply"])
+        .arg(&plan)
+        .arg("--dry-run")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json = parse_stdout_json(&output);
+
+    assert_eq!(json["dry_run"], true);
+    let operations = json["plan"]["operations"].as_array().unwrap();
+    assert_eq!(operations.len(), 3);
+    assert_eq!(operations[0]["summary"], "Blockers for core");
+    assert_eq!(operations[1]["issue"], "DEMO-1");
+    assert_eq!(
+        operations[1]["body"],
+        "Blocked by ${refs.epic} (First blocked, owner alice)"
+    );
+    assert_eq!(operations[2]["issue"], "DEMO-2");
+    assert_eq!(json["results"][3]["status"], "dry_run");
+
+    let methods = mock_call_methods(&scenario);
+    assert_eq!(method_count(&methods, "search_issues"), 1);
+    assert_eq!(method_count(&methods, "create_issue"), 0);
+    assert_eq!(method_count(&methods, "add_comment"), 0);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn apply_rejects_unset_environment_placeholder_before_mutation() {
+    let dir = temp_dir();
+    let scenario = write_scenario(&dir, &foreach_manifest(), &foreach_responses());
+    let plan = write_plan(&dir, "plan.json", foreach_plan());
+
+    let output = track_in(&dir, &scenario)
+        .env_remove("TRACK_VAR_APPLY_TEST_OWNER")
+        .args(["-o", "json", "apply"])
+        .arg(&plan)
+        .assert()
+        .failure()
+        .get_output()
+        .stderr
+        .clone();
+    let stderr = String::from_utf8_lossy(&output);
+
+    assert!(stderr.contains("Environment variable 'TRACK_VAR_APPLY_TEST_OWNER' is not set"));
+    assert!(mock_call_methods(&scenario).is_empty());
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn apply_resume_skips_completed_foreach_steps() {
+    let dir = temp_dir();
+    let failing_manifest = foreach_manifest().replace(
+        "file = \"comment_blocked_2.json\"",
+        "file = \"comment_error.json\"\nstatus = 500",
+    );
+    let mut responses = foreach_responses();
+    responses.push((
+        "comment_error.json",
+        json!({"message": "temporary failure"}),
+    ));
+    let scenario = write_scenario(&dir, &failing_manifest, &responses);
+    let plan = write_plan(&dir, "plan.json", foreach_plan());
+    let resume = dir.join("state.json");
+
+    track_in(&dir, &scenario)
+        .env("TRACK_VAR_APPLY_TEST_OWNER", "alice")
+        .args(["-o", "json", "apply"])
+        .arg(&plan)
+        .arg("--resume")
+        .arg(&resume)
+        .assert()
+        .failure();
+
+    let state: Value = serde_json::from_str(&fs::read_to_string(&resume).unwrap()).unwrap();
+    assert_eq!(state["completed"], json!([0]));
+    assert_eq!(state["completed_steps"], json!(["1/DEMO-1/0"]));
+
+    fs::write(scenario.join("manifest.toml"), foreach_manifest()).unwrap();
+    let output = track_in(&dir, &scenario)
+        .env("TRACK_VAR_APPLY_TEST_OWNER", "alice")
+        .args(["-o", "json", "apply"])
+        .arg(&plan)
+        .arg("--resume")
+        .arg(&resume)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json = parse_stdout_json(&output);
+
+    assert_eq!(json["results"][0]["status"], "skipped");
+    assert_eq!(json["results"][1]["status"], "skipped");
+    assert_eq!(json["results"][1]["item"], "DEMO-1");
+    assert_eq!(json["results"][2]["status"], "commented");
+    assert_eq!(json["results"][3]["status"], "expanded");
+
+    let methods = mock_call_methods(&scenario);
+    assert_eq!(method_count(&methods, "create_issue"), 1);
+    assert_eq!(method_count(&methods, "add_comment"), 3);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+fn rollback_manifest() -> String {
+    base_manifest(
+        r#"
+[[responses]]
+method = "create_issue"
+file = "create_rollback.json"
+[responses.args]
+project = "0-1"
+summary = "Rollback scratch"
+
+[[responses]]
+method = "get_issue"
+file = "get_target.json"
+[responses.args]
+id = "DEMO-50"
+
+[[responses]]
+method = "get_issue"
+file = "get_scratch.json"
+[responses.args]
+id = "DEMO-60"
+
+[[responses]]
+method = "update_issue"
+file = "update_target.json"
+[responses.args]
+id = "DEMO-50"
+
+[[responses]]
+method = "link_issues"
+file = "ok.json"
+[responses.args]
+source = "DEMO-50"
+
+[[responses]]
+method = "add_comment"
+file = "comment_target.json"
+[responses.args]
+issue_id = "DEMO-50"
+
+[[responses]]
+method = "get_issue_links"
+file = "target_links.json"
+[responses.args]
+issue_id = "DEMO-50"
+
+[[responses]]
+method = "unlink_issues"
+file = "ok.json"
+[responses.args]
+source = "DEMO-50"
+link_id = "link-9"
+
+[[responses]]
+method = "transition_issue"
+file = "transition_target.json"
+[responses.args]
+id = "DEMO-50"
+state = "Open"
+
+[[responses]]
+method = "transition_issue"
+file = "transition_scratch.json"
+[responses.args]
+id = "DEMO-60"
+state = "Done"
+
+[[responses]]
+method = "remove_issue_tag"
+file = "ok.json"
+[responses.args]
+issue_id = "DEMO-50"
+tag = "triaged"
+"#,
+    )
+}
+
+#[test]
+fn apply_rollback_reverses_recorded_changes() {
+    let dir = temp_dir();
+    let target = issue_json("DEMO-50", "Original title", "Open", "Normal");
+    let scratch = issue_json("DEMO-60", "Rollback scratch", "Open", "Normal");
+    let mut responses = standard_responses();
+    responses.extend([
+        ("create_rollback.json", scratch.clone()),
+        ("get_target.json", target.clone()),
+        ("get_scratch.json", scratch.clone()),
+        (
+            "update_target.json",
+            issue_json("DEMO-50", "Renamed", "In Progress", "Major"),
+        ),
+        ("comment_target.json", comment_json("comment-1", "hello")),
+        ("transition_target.json", target.clone()),
+        (
+            "transition_scratch.json",
+            issue_json("DEMO-60", "Rollback scratch", "Done", "Normal"),
+        ),
+        (
+            "target_links.json",
+            json!([{
+                "id": "link-9",
+                "direction": "BOTH",
+                "link_type": {
+                    "id": "relates",
+                    "name": "Relates",
+                    "source_to_target": "relates to",
+                    "target_to_source": "relates to",
+                    "directed": false
+                },
+                "issues": [{"id": "internal-DEMO-60", "id_readable": "DEMO-60", "summary": null}]
+            }]),
+        ),
+        ("ok.json", Value::Null),
+    ]);
+    let scenario = write_scenario(&dir, &rollback_manifest(), &responses);
+    let plan = write_plan(
+        &dir,
+        "plan.json",
+        json!({
+            "version": 1,
+            "defaults": {"project": "DEMO"},
+            "operations": [
+                {"ref": "scratch", "op": "create_issue", "summary": "Rollback scratch"},
+                {
+                    "op": "update_issue",
+                    "issue": "DEMO-50",
+                    "summary": "Renamed",
+                    "fields": {"Priority": "Major", "State": "In Progress"}
+                },
+                {"op": "add_tag", "issue": "DEMO-50", "tag": "triaged"},
+                {"op": "link", "source": "DEMO-50", "target": "$scratch", "type": "relates"},
+                {"op": "comment", "issue": "DEMO-50", "body": "hello"}
+            ]
+        }),
+    );
+    let state_path = dir.join("state.json");
+
+    track_in(&dir, &scenario)
+        .args(["-o", "json", "apply"])
+        .arg(&plan)
+        .arg("--resume")
+        .arg(&state_path)
+        .assert()
+        .success();
+
+    let state: Value = serde_json::from_str(&fs::read_to_string(&state_path).unwrap()).unwrap();
+    let undo = state["undo"].as_array().unwrap();
+    assert_eq!(undo.len(), 5);
+    assert_eq!(undo[0]["undo"], "created_issue");
+    assert_eq!(undo[0]["issue"], "DEMO-60");
+    assert_eq!(undo[1]["undo"], "restore_issue");
+    assert_eq!(undo[1]["before"]["summary"], "Original title");
+    assert_eq!(undo[1]["before"]["state"], "Open");
+    assert_eq!(undo[1]["before"]["fields"]["Priority"], "Normal");
+    assert_eq!(undo[2]["before"]["tags"], json!([]));
+    assert_eq!(undo[3]["undo"], "linked");
+    assert_eq!(undo[3]["target"], "DEMO-60");
+    assert_eq!(undo[4]["undo"], "irreversible");
+
+    // The tracker now reflects the plan: renamed and tagged.
+    let mut changed = issue_json("DEMO-50", "Renamed", "In Progress", "Major");
+    changed["tags"] = json!([{"id": "tag-1", "name": "triaged"}]);
+    fs::write(
+        scenario.join("responses/get_target.json"),
+        serde_json::to_string(&changed).unwrap(),
+    )
+    .unwrap();
+    let mut fields = project_fields();
+    fields[0]["state_values"] = json!([
+        {"name": "Open", "is_resolved": false},
+        {"name": "In Progress", "is_resolved": false},
+        {"name": "Done", "is_resolved": true}
+    ]);
+    fs::write(
+        scenario.join("responses/project_custom_fields.json"),
+        serde_json::to_string(&fields).unwrap(),
+    )
+    .unwrap();
+
+    let output = track_in(&dir, &scenario)
+        .args(["-o", "json", "apply", "--rollback"])
+        .arg(&state_path)
+        .arg("--dry-run")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json = parse_stdout_json(&output);
+    let operations = json["plan"]["operations"].as_array().unwrap();
+    let ops: Vec<&str> = operations
+        .iter()
+        .map(|operation| operation["op"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        ops,
+        vec![
+            "unlink",
+            "update_issue",
+            "transition",
+            "remove_tag",
+            "transition"
+        ]
+    );
+    assert_eq!(operations[0]["link_id"], "link-9");
+    assert_eq!(operations[1]["summary"], "Original title");
+    assert_eq!(operations[1]["fields"]["Priority"], "Normal");
+    assert_eq!(operations[2]["state"], "Open");
+    assert_eq!(operations[4]["issue"], "DEMO-60");
+    assert_eq!(operations[4]["state"], "Done");
+    assert!(
+        json["warnings"][0]
+            .as_str()
+            .unwrap()
+            .contains("comment on DEMO-50")
+    );
+
+    fs::write(scenario.join("call_log.jsonl"), "").unwrap();
+    track_in(&dir, &scenario)
+        .args(["-o", "json", "apply", "--rollback"])
+        .arg(&state_path)
+        .assert()
+        .success();
+
+    let methods = mock_call_methods(&scenario);
+    assert_eq!(method_count(&methods, "unlink_issues"), 1);
+    assert_eq!(method_count(&methods, "update_issue"), 1);
+    assert_eq!(method_count(&methods, "transition_issue"), 2);
+    assert_eq!(method_count(&methods, "remove_issue_tag"), 1);
+    assert_eq!(method_count(&methods, "delete_issue"), 0);
+
+    let state: Value = serde_json::from_str(&fs::read_to_string(&state_path).unwrap()).unwrap();
+    assert_eq!(state["rolled_back"], true);
+
+    track_in(&dir, &scenario)
+        .args(["-o", "json", "apply", "--rollback"])
+        .arg(&state_path)
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("already been rolled back"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn apply_rollback_deletes_created_issues_with_allow_delete() {
+    let dir = temp_dir();
+    let manifest = base_manifest(
+        r#"
+[[responses]]
+method = "create_issue"
+file = "create_rollback.json"
+[responses.args]
+project = "0-1"
+summary = "Rollback scratch"
+
+[[responses]]
+method = "delete_issue"
+file = "ok.json"
+[responses.args]
+id = "DEMO-60"
+"#,
+    );
+    let mut responses = standard_responses();
+    responses.extend([
+        (
+            "create_rollback.json",
+            issue_json("DEMO-60", "Rollback scratch", "Open", "Normal"),
+        ),
+        ("ok.json", Value::Null),
+    ]);
+    let scenario = write_scenario(&dir, &manifest, &responses);
+    let plan = write_plan(
+        &dir,
+        "plan.json",
+        json!({
+            "version": 1,
+            "defaults": {"project": "DEMO"},
+            "operations": [{"op": "create_issue", "summary": "Rollback scratch"}]
+        }),
+    );
+    let state_path = dir.join("state.json");
+
+    track_in(&dir, &scenario)
+        .args(["apply"])
+        .arg(&plan)
+        .arg("--resume")
+        .arg(&state_path)
+        .assert()
+        .success();
+
+    let output = track_in(&dir, &scenario)
+        .args(["-o", "json", "apply", "--rollback"])
+        .arg(&state_path)
+        .arg("--allow-delete")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json = parse_stdout_json(&output);
+
+    assert_eq!(json["results"][0]["op"], "delete_issue");
+    assert_eq!(json["results"][0]["issue"], "DEMO-60");
+    assert_eq!(json["results"][0]["status"], "deleted");
+    let methods = mock_call_methods(&scenario);
+    assert_eq!(method_count(&methods, "delete_issue"), 1);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn apply_concurrency_runs_independent_operations_and_keeps_result_order() {
+    let dir = temp_dir();
+    let manifest = base_manifest(
+        r#"
+[[responses]]
+method = "create_issue"
+file = "create_slow.json"
+delay_ms = 400
+[responses.args]
+project = "0-1"
+summary = "Slow issue"
+
+[[responses]]
+method = "create_issue"
+file = "create_fast.json"
+[responses.args]
+project = "0-1"
+summary = "Fast issue"
+
+[[responses]]
+method = "add_comment"
+file = "comment_slow.json"
+[responses.args]
+issue_id = "DEMO-100"
+"#,
+    );
+    let mut responses = standard_responses();
+    responses.extend([
+        (
+            "create_slow.json",
+            issue_json("DEMO-100", "Slow issue", "Open", "Normal"),
+        ),
+        (
+            "create_fast.json",
+            issue_json("DEMO-101", "Fast issue", "Open", "Normal"),
+        ),
+        ("comment_slow.json", comment_json("comment-1", "after slow")),
+    ]);
+    let scenario = write_scenario(&dir, &manifest, &responses);
+    let plan = write_plan(
+        &dir,
+        "plan.json",
+        json!({
+            "version": 1,
+            "defaults": {"project": "DEMO"},
+            "operations": [
+                {"ref": "slow", "op": "create_issue", "summary": "Slow issue"},
+                {"ref": "fast", "op": "create_issue", "summary": "Fast issue"},
+                {"op": "comment", "issue": "$slow", "body": "after slow"}
+            ]
+        }),
+    );
+    let state_path = dir.join("state.json");
+
+    let output = track_in(&dir, &scenario)
+        .args(["-o", "json", "apply"])
+        .arg(&plan)
+        .args(["--concurrency", "2", "--resume"])
+        .arg(&state_path)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json = parse_stdout_json(&output);
+
+    let indexes: Vec<u64> = json["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| result["index"].as_u64().unwrap())
+        .collect();
+    assert_eq!(indexes, vec![0, 1, 2]);
+    assert_eq!(json["refs"]["slow"], "DEMO-100");
+    assert_eq!(json["refs"]["fast"], "DEMO-101");
+    assert_eq!(json["results"][2]["issue"], "DEMO-100");
+
+    // The call log records calls as they finish: the fast create did not
+    // wait for the slow one, and the comment waited for its ref.
+    let log = fs::read_to_string(scenario.join("call_log.jsonl")).unwrap();
+    let summaries: Vec<String> = log
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).unwrap())
+        .filter(|entry| entry["method"] != "resolve_project_id")
+        .map(|entry| {
+            entry["args"]["summary"]
+                .as_str()
+                .unwrap_or(entry["method"].as_str().unwrap())
+                .to_string()
+        })
+        .collect();
+    let position = |name: &str| summaries.iter().position(|s| s == name).unwrap();
+    assert!(position("Fast issue") < position("Slow issue"));
+    assert!(position("Slow issue") < position("add_comment"));
+
+    let state: Value = serde_json::from_str(&fs::read_to_string(&state_path).unwrap()).unwrap();
+    assert_eq!(state["completed"], json!([0, 1, 2]));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn sync_file_plans_and_applies_changes_against_keyed_issues() {
+    let dir = temp_dir();
+    let manifest = base_manifest(
+        r#"
+[[responses]]
+method = "search_issues"
+file = "search_synced.json"
+[responses.args]
+query = "project: DEMO"
+
+[[responses]]
+method = "get_issue_links"
+file = "empty_links.json"
+[responses.args]
+issue_id = "DEMO-50"
+
+[[responses]]
+method = "get_issue"
+file = "get_auth.json"
+[responses.args]
+id = "DEMO-50"
+
+[[responses]]
+method = "create_issue"
+file = "create_billing.json"
+[responses.args]
+project = "0-1"
+summary = "Billing"
+
+[[responses]]
+method = "update_issue"
+file = "get_auth.json"
+[responses.args]
+id = "DEMO-50"
+
+[[responses]]
+method = "link_issues"
+file = "ok.json"
+[responses.args]
+source = "DEMO-50"
+target = "DEMO-60"
+"#,
+    );
+    let mut auth = issue_json("DEMO-50", "Auth", "Open", "Normal");
+    auth["description"] = json!("Login work\n\n<!-- track-sync: auth -->");
+    let mut retired = issue_json("DEMO-51", "Old roadmap item", "Open", "Normal");
+    retired["description"] = json!("<!-- track-sync: retired -->");
+    let mut responses = standard_responses();
+    responses.extend([
+        (
+            "search_synced.json",
+            json!([
+                auth.clone(),
+                retired,
+                issue_json("DEMO-52", "Unkeyed", "Open", "Normal")
+            ]),
+        ),
+        ("empty_links.json", json!([])),
+        ("get_auth.json", auth),
+        (
+            "create_billing.json",
+            issue_json("DEMO-60", "Billing", "Open", "Normal"),
+        ),
+        ("ok.json", Value::Null),
+    ]);
+    let scenario = write_scenario(&dir, &manifest, &responses);
+    fs::write(
+        dir.join("roadmap.yaml"),
+        r#"
+query: "project: DEMO"
+project: DEMO
+issues:
+  - key: auth
+    summary: Auth overhaul
+    description: Run ${HOME}/setup for ${vars.region}
+    tags: [roadmap]
+    links:
+      - {target: billing, type: depends}
+  - key: billing
+    summary: Billing
+    description: Invoices
+    parent: auth
+"#,
+    )
+    .unwrap();
+
+    let output = track_in(&dir, &scenario)
+        .args(["-o", "json", "sync-file", "roadmap.yaml", "--plan"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json = parse_stdout_json(&output);
+
+    assert_eq!(json["changes"][0]["key"], "auth");
+    assert_eq!(json["changes"][0]["action"], "update");
+    assert_eq!(json["changes"][0]["changes"][0]["field"], "summary");
+    assert_eq!(json["changes"][0]["changes"][0]["to"], "Auth overhaul");
+    assert_eq!(json["changes"][1]["action"], "create");
+    assert_eq!(
+        json["unmanaged"],
+        json!([{"key": "retired", "issue": "DEMO-51"}])
+    );
+    assert_eq!(
+        json["plan"]["operations"],
+        json!([
+            {
+                "ref": "billing",
+                "op": "create_issue",
+                "project": "DEMO",
+                "summary": "Billing",
+                "description": "Invoices\n\n<!-- track-sync: billing -->",
+                "parent": "DEMO-50"
+            },
+            {
+                "op": "update_issue",
+                "issue": "DEMO-50",
+                "summary": "Auth overhaul",
+                "description": "Run $${HOME}/setup for $${vars.region}\n\n<!-- track-sync: auth -->"
+            },
+            {"op": "add_tag", "issue": "DEMO-50", "tag": "roadmap"},
+            {"op": "link", "source": "DEMO-50", "target": "$billing", "type": "depends"}
+        ])
+    );
+    let methods = mock_call_methods(&scenario);
+    assert_eq!(method_count(&methods, "create_issue"), 0);
+    assert_eq!(method_count(&methods, "update_issue"), 0);
+
+    track_in(&dir, &scenario)
+        .args(["sync-file", "roadmap.yaml"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("+ billing (new issue)"))
+        .stdout(predicates::str::contains(
+            "Plan: 1 to create, 1 to update, 0 unchanged (4 operations).",
+        ));
+
+    let output = track_in(&dir, &scenario)
+        .args(["-o", "json", "sync-file", "roadmap.yaml", "--apply"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json = parse_stdout_json(&output);
+
+    assert_eq!(json["success"], true);
+    assert_eq!(json["refs"]["billing"], "DEMO-60");
+    let update = mock_call_entries(&scenario)
+        .into_iter()
+        .find(|entry| entry["method"] == "update_issue")
+        .expect("update_issue call");
+    assert_eq!(
+        update["args"]["description"],
+        "Run ${HOME}/setup for ${vars.region}\n\n<!-- track-sync: auth -->"
+    );
+    let methods = mock_call_methods(&scenario);
+    assert_eq!(method_count(&methods, "create_issue"), 1);
+    assert_eq!(method_count(&methods, "link_issues"), 1);
+
+    let _ = fs::remove_dir_all(&dir);
+}
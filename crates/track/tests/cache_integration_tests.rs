@@ -22,9 +22,28 @@ fn fixtures_path() -> PathBuf {
         .join("scenarios")
 }
 
-/// Get path to the cache-operations scenario
-fn cache_scenario() -> PathBuf {
-    fixtures_path().join("cache-operations")
+fn copy_dir_recursive(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let source = entry.path();
+        let target = to.join(entry.file_name());
+        if source.is_dir() {
+            copy_dir_recursive(&source, &target);
+        } else {
+            fs::copy(&source, &target).unwrap();
+        }
+    }
+}
+
+/// Copy a scenario into the test's temp directory (once), so the mock's
+/// call log is written there rather than into the fixtures.
+fn copy_scenario(dir: &Path, name: &str) -> PathBuf {
+    let scenario = dir.join("mock-scenarios").join(name);
+    if !scenario.exists() {
+        copy_dir_recursive(&fixtures_path().join(name), &scenario);
+    }
+    scenario
 }
 
 /// Create a unique temp directory for cache isolation.
@@ -63,7 +82,10 @@ fn track_in(dir: &Path) -> Command {
     ensure_project_context(dir);
     let mut cmd = cargo_bin_cmd!("track");
     cmd.current_dir(dir)
-        .env("TRACK_MOCK_DIR", cache_scenario().to_str().unwrap())
+        .env(
+            "TRACK_MOCK_DIR",
+            copy_scenario(dir, "cache-operations").to_str().unwrap(),
+        )
         .args(["--url", "https://mock.test", "--token", "mock-token"]);
     cmd
 }
@@ -278,6 +300,64 @@ fn test_cache_refresh_backend_shard_content() {
     fs::remove_dir_all(&dir).unwrap();
 }
 
+#[test]
+fn test_cache_refresh_includes_saved_queries_from_config() {
+    let dir = temp_dir();
+    fs::write(
+        dir.join(".track.toml"),
+        r#"
+[queries]
+mine = "project: {PROJECT} Assignee: {ME} #Unresolved"
+
+[queries.bugs]
+description = "Open bugs, team flavour"
+query = "project: {PROJECT} Type: Bug State: Open"
+jira = "project = {PROJECT} AND issuetype = Bug"
+"#,
+    )
+    .unwrap();
+    populate_cache(&dir);
+
+    let templates: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(dir.join(".tracker-cache/backend/query_templates.json")).unwrap(),
+    )
+    .unwrap();
+    let templates = templates.as_array().unwrap();
+    let find = |name: &str| {
+        templates
+            .iter()
+            .find(|t| t["name"] == name)
+            .unwrap_or_else(|| panic!("template {name} missing"))
+    };
+
+    assert_eq!(find("mine")["custom"], true);
+    assert_eq!(
+        find("mine")["query"],
+        "project: {PROJECT} Assignee: {ME} #Unresolved"
+    );
+    // Saved queries replace built-ins of the same name, using the shared
+    // query for backends without their own variant.
+    assert_eq!(find("bugs")["description"], "Open bugs, team flavour");
+    assert_eq!(
+        find("bugs")["query"],
+        "project: {PROJECT} Type: Bug State: Open"
+    );
+    assert!(find("unresolved").get("custom").is_none());
+
+    let output = track_in_no_mock(&dir)
+        .args(["cache", "show"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("mine"),
+        "cache show should list saved query"
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
 #[test]
 fn test_cache_refresh_text_output() {
     let dir = temp_dir();
@@ -567,7 +647,7 @@ fn test_issue_access_preserves_other_shards() {
         fs::read_to_string(dir.join(".tracker-cache/projects/DEMO/meta.json")).unwrap();
 
     // Access an issue (triggers record_issue_access + save_runtime)
-    let scenario = fixtures_path().join("basic-workflow");
+    let scenario = copy_scenario(&dir, "basic-workflow");
     let mut cmd = cargo_bin_cmd!("track");
     cmd.current_dir(&dir)
         .env("TRACK_MOCK_DIR", scenario.to_str().unwrap())
@@ -1075,7 +1075,7 @@ fn test_eval_command_help() {
 #[test]
 fn test_issue_shortcut_with_mock() {
     let dir = temp_dir();
-    let scenario = fixtures_path().join("basic-workflow");
+    let scenario = copy_scenario(&dir, "basic-workflow");
 
     let mut cmd = cargo_bin_cmd!("track");
     cmd.current_dir(&dir)
@@ -1083,77 +1083,1016 @@ fn test_issue_shortcut_with_mock() {
         .args(["--url", "https://mock.test", "--token", "mock-token"])
         .arg("DEMO-1")
         .assert()
-        .success()
-        .stdout(predicate::str::contains("DEMO-1"))
-        .stdout(predicate::str::contains("Implement user authentication"));
+        .success()
+        .stdout(predicate::str::contains("DEMO-1"))
+        .stdout(predicate::str::contains("Implement user authentication"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_issue_shortcut_with_full_flag() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+
+    let mut cmd = cargo_bin_cmd!("track");
+    cmd.current_dir(&dir)
+        .env("TRACK_MOCK_DIR", scenario.to_str().unwrap())
+        .args(["--url", "https://mock.test", "--token", "mock-token"])
+        .args(["DEMO-1", "--full"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("DEMO-1"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_issue_shortcut_json_output() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+
+    let output = cargo_bin_cmd!("track")
+        .current_dir(&dir)
+        .env("TRACK_MOCK_DIR", scenario.to_str().unwrap())
+        .args([
+            "-o",
+            "json",
+            "--url",
+            "https://mock.test",
+            "--token",
+            "mock-token",
+        ])
+        .arg("DEMO-1")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["id_readable"], "DEMO-1");
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+// =============================================================================
+// Invalid subcommand rejection
+// =============================================================================
+
+#[test]
+fn test_non_issue_id_rejected() {
+    cargo_bin_cmd!("track")
+        .arg("foobar")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unrecognized subcommand"));
+}
+
+#[test]
+fn test_bare_number_rejected() {
+    cargo_bin_cmd!("track")
+        .arg("123")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unrecognized subcommand"));
+}
+
+// =============================================================================
+// Aliases and plugins
+// =============================================================================
+
+#[test]
+fn test_alias_expands_to_subcommand() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    write_config(
+        &dir,
+        r#"
+[aliases]
+show = "issue get"
+"#,
+    );
+
+    track_in(&dir)
+        .env("TRACK_MOCK_DIR", scenario.to_str().unwrap())
+        .args(["--url", "https://mock.test", "--token", "mock-token"])
+        .args(["show", "DEMO-1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Implement user authentication"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_alias_loop_is_reported() {
+    let dir = temp_dir();
+    write_config(
+        &dir,
+        r#"
+[aliases]
+ping = "pong"
+pong = "ping --verbose"
+"#,
+    );
+
+    track_in(&dir)
+        .arg("ping")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("alias loop: ping -> pong -> ping"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_plugin_runs_with_connection_context() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = temp_dir();
+    let bin = dir.join("bin");
+    fs::create_dir_all(&bin).unwrap();
+    let plugin = bin.join("track-hello");
+    fs::write(
+        &plugin,
+        "#!/bin/sh\necho \"args=$*\"\necho \"backend=$TRACK_BACKEND\"\necho \"url=$TRACK_URL\"\necho \"project=$TRACK_DEFAULT_PROJECT\"\necho \"token=${TRACK_TOKEN:-unset}\"\n",
+    )
+    .unwrap();
+    fs::set_permissions(&plugin, fs::Permissions::from_mode(0o755)).unwrap();
+    write_config(
+        &dir,
+        r#"
+backend = "jira"
+default_project = "DEMO"
+
+[jira]
+url = "https://jira.example.com"
+email = "me@example.com"
+token = "secret"
+"#,
+    );
+
+    let path = std::env::join_paths(std::iter::once(bin.clone()).chain(std::env::split_paths(
+        &std::env::var_os("PATH").unwrap_or_default(),
+    )))
+    .unwrap();
+
+    track_in(&dir)
+        .env("PATH", &path)
+        .args(["hello", "one", "--two"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("args=one --two"))
+        .stdout(predicate::str::contains("backend=jira"))
+        .stdout(predicate::str::contains("url=https://jira.example.com"))
+        .stdout(predicate::str::contains("project=DEMO"))
+        .stdout(predicate::str::contains("token=unset"));
+
+    fs::write(
+        dir.join(".track.toml"),
+        format!(
+            "{}\n[plugins]\npass_token = true\n",
+            fs::read_to_string(dir.join(".track.toml")).unwrap()
+        ),
+    )
+    .unwrap();
+
+    track_in(&dir)
+        .env("PATH", &path)
+        .arg("hello")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("token=secret"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_plugin_exit_code_is_propagated() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = temp_dir();
+    let plugin = dir.join("track-fail");
+    fs::write(&plugin, "#!/bin/sh\nexit 3\n").unwrap();
+    fs::set_permissions(&plugin, fs::Permissions::from_mode(0o755)).unwrap();
+
+    track_in(&dir)
+        .env("PATH", &dir)
+        .arg("fail")
+        .assert()
+        .code(3);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+// =============================================================================
+// Git integration
+// =============================================================================
+
+fn git_in(dir: &Path, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .current_dir(dir)
+        .args(args)
+        .status()
+        .unwrap();
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+/// Create a git repository in `dir` with `branch` checked out (unborn).
+fn git_repo_on_branch(dir: &Path, branch: &str) {
+    git_in(dir, &["init", "--quiet"]);
+    git_in(dir, &["checkout", "--quiet", "-b", branch]);
+}
+
+#[test]
+fn test_issue_get_infers_id_from_branch() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    git_repo_on_branch(&dir, "feature/demo-1-user-auth");
+
+    track_in(&dir)
+        .env("TRACK_MOCK_DIR", scenario.to_str().unwrap())
+        .args(["--url", "https://mock.test", "--token", "mock-token"])
+        .args(["issue", "get"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("DEMO-1"))
+        .stdout(predicate::str::contains("Implement user authentication"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_issue_attach_link_unlink_infer_id_from_branch() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    git_repo_on_branch(&dir, "feature/demo-1-user-auth");
+    fs::write(dir.join("notes.txt"), "notes").unwrap();
+
+    // The mock has no responses for these writes; the errors show which
+    // issue each command resolved.
+    let stderr = |args: &[&str]| {
+        let output = track_in(&dir)
+            .env("TRACK_MOCK_DIR", scenario.to_str().unwrap())
+            .args(["--url", "https://mock.test", "--token", "mock-token"])
+            .args(args)
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stderr).to_string()
+    };
+    assert!(stderr(&["issue", "link", "DEMO-2"]).contains("Failed to link DEMO-1 to DEMO-2"));
+    assert!(
+        stderr(&["issue", "unlink", "link-7"]).contains("Failed to unlink DEMO-1 (link link-7)")
+    );
+    let attach = stderr(&["issue", "attach", "notes.txt"]);
+    assert!(attach.contains("DEMO-1"), "stderr: {attach}");
+    assert!(!attach.contains("No issue ID given"), "stderr: {attach}");
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_issue_get_without_id_off_issue_branch_fails() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    git_repo_on_branch(&dir, "main");
+
+    track_in(&dir)
+        .env("TRACK_MOCK_DIR", scenario.to_str().unwrap())
+        .args(["--url", "https://mock.test", "--token", "mock-token"])
+        .args(["issue", "get"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "none found in the current branch name 'main'",
+        ));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_issue_start_branch_creates_branch_from_template() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    git_repo_on_branch(&dir, "main");
+    write_config(
+        &dir,
+        r#"
+[git]
+branch_template = "work/{id}/{slug}"
+"#,
+    );
+
+    track_in(&dir)
+        .env("TRACK_MOCK_DIR", scenario.to_str().unwrap())
+        .args(["--url", "https://mock.test", "--token", "mock-token"])
+        .args(["issue", "start", "DEMO-1", "--branch"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Switched to a new branch 'work/DEMO-1/implement-user-authentication'",
+        ));
+
+    let head = fs::read_to_string(dir.join(".git/HEAD")).unwrap();
+    assert_eq!(
+        head.trim(),
+        "ref: refs/heads/work/DEMO-1/implement-user-authentication"
+    );
+    assert!(mock_call_methods(&scenario).contains(&"update_issue".to_string()));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_git_hook_install_and_commit_msg_prepend() {
+    let dir = temp_dir();
+    git_repo_on_branch(&dir, "feature/PROJ-42-login");
+
+    track_in(&dir)
+        .args(["git", "hook", "install"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("mode: prepend"));
+    let hook = fs::read_to_string(dir.join(".git/hooks/commit-msg")).unwrap();
+    assert!(hook.contains("track git commit-msg --mode prepend"));
+
+    // Re-installing over our own hook doesn't need --force
+    track_in(&dir)
+        .args(["git", "hook", "install", "--mode", "check"])
+        .assert()
+        .success();
+
+    let msg = dir.join("COMMIT_EDITMSG");
+    fs::write(&msg, "Add login form\n").unwrap();
+    track_in(&dir)
+        .args(["git", "commit-msg"])
+        .arg(&msg)
+        .assert()
+        .success();
+    assert_eq!(
+        fs::read_to_string(&msg).unwrap(),
+        "PROJ-42: Add login form\n"
+    );
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_git_hook_install_refuses_foreign_hook() {
+    let dir = temp_dir();
+    git_repo_on_branch(&dir, "main");
+    fs::create_dir_all(dir.join(".git/hooks")).unwrap();
+    fs::write(dir.join(".git/hooks/commit-msg"), "#!/bin/sh\nexit 0\n").unwrap();
+
+    track_in(&dir)
+        .args(["git", "hook", "install"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--force"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_git_commit_msg_check_rejects_missing_key() {
+    let dir = temp_dir();
+    git_repo_on_branch(&dir, "main");
+    let msg = dir.join("COMMIT_EDITMSG");
+    fs::write(&msg, "Tidy up\n").unwrap();
+
+    track_in(&dir)
+        .args(["git", "commit-msg", "--mode", "check"])
+        .arg(&msg)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must reference an issue key"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+// =============================================================================
+// saved queries
+// =============================================================================
+
+#[test]
+fn test_saved_query_warns_about_unreadable_cache() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    fs::write(
+        dir.join(".track.toml"),
+        "[queries]\nopen = \"project: DEMO #Unresolved\"\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.join(".tracker-cache")).unwrap();
+    fs::write(dir.join(".tracker-cache/index.json"), "{").unwrap();
+
+    track_mock(&dir, &scenario)
+        .args(["issue", "search", "-T", "open"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Warning: Failed to load cache"));
+    let search = mock_call_entries(&scenario)
+        .into_iter()
+        .find(|entry| entry["method"] == "search_issues")
+        .expect("search_issues call");
+    assert_eq!(search["args"]["query"], "project: DEMO #Unresolved");
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+// =============================================================================
+// changelog
+// =============================================================================
+
+fn git_commit(dir: &Path, message: &str) {
+    git_in(
+        dir,
+        &[
+            "-c",
+            "user.name=Test",
+            "-c",
+            "user.email=test@example.com",
+            "commit",
+            "--quiet",
+            "--allow-empty",
+            "-m",
+            message,
+        ],
+    );
+}
+
+#[test]
+fn test_changelog_from_git_range() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    add_second_basic_issue(&scenario);
+    let demo_2 = scenario.join("responses/get_issue_DEMO-2.json");
+    let content = fs::read_to_string(&demo_2).unwrap();
+    fs::write(&demo_2, content.replace("DEMO-1", "DEMO-2")).unwrap();
+    // The batch search returns the full issues, so no per-key lookups are
+    // needed except for keys it doesn't know
+    let demo_1 = fs::read_to_string(scenario.join("responses/get_issue_DEMO-1.json")).unwrap();
+    let demo_2 = fs::read_to_string(&demo_2).unwrap();
+    fs::write(
+        scenario.join("responses/search_issues_DEMO.json"),
+        format!("[{demo_1},{demo_2}]"),
+    )
+    .unwrap();
+    git_repo_on_branch(&dir, "main");
+    git_commit(&dir, "Initial import");
+    git_in(&dir, &["tag", "v1.0.0"]);
+    git_commit(&dir, "DEMO-1: Add login form");
+    git_commit(
+        &dir,
+        "Fix session timeout\n\nRefs DEMO-2, NOPE-9 and DEMO-1",
+    );
+
+    let output = track_mock(&dir, &scenario)
+        .args(["changelog", "--from", "v1.0.0", "--title", "v1.1.0"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("## v1.1.0\n\n### Task\n\n- DEMO-1 "));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("skipped NOPE-9"), "stderr: {stderr}");
+    assert_eq!(
+        mock_call_methods(&scenario),
+        vec!["search_issues", "get_issue"]
+    );
+
+    let output = track_mock(&dir, &scenario)
+        .args(["-o", "json", "changelog", "--from", "v1.0.0"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["sections"][0]["title"], "Task");
+    assert_eq!(json["sections"][0]["issues"].as_array().unwrap().len(), 2);
+    assert_eq!(json["skipped"][0]["id"], "NOPE-9");
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_changelog_query_mode_without_git() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+
+    // Search results carry no Type field, so everything lands in "Other"
+    track_mock(&dir, &scenario)
+        .args(["changelog", "--query", "project: DEMO"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "### Other\n\n- DEMO-1 Implement user authentication\n- DEMO-2 ",
+        ));
+    assert_eq!(mock_call_methods(&scenario), vec!["search_issues"]);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+// =============================================================================
+// watch
+// =============================================================================
+
+#[test]
+fn test_watch_once_with_state_reports_changes_between_runs() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    let watch = |format: &str| {
+        track_mock(&dir, &scenario)
+            .args(["-o", format, "watch", "-q", "project: DEMO"])
+            .args(["--once", "--state", "watch.json"])
+            .output()
+            .unwrap()
+    };
+
+    // First run records the baseline without reporting anything
+    let output = watch("json");
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(dir.join("watch.json").exists());
+
+    let search = scenario.join("responses/search_issues_DEMO.json");
+    let mut issues: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&search).unwrap()).unwrap();
+    issues[0]["updated"] = "2024-01-16T10:00:00Z".into();
+    issues[0]["custom_fields"][0]["State"]["value"] = "In Progress".into();
+    issues.as_array_mut().unwrap().remove(1);
+    fs::write(&search, issues.to_string()).unwrap();
+    let comments = scenario.join("responses/get_comments_DEMO-1.json");
+    fs::write(
+        &comments,
+        r#"[
+            {"id": "c1", "text": "Old", "author": null, "created": "2024-01-10T09:05:00Z"},
+            {"id": "c2", "text": "Ready for review", "author": {"login": "jane", "name": "Jane"}, "created": "2024-01-16T09:59:00Z"}
+        ]"#,
+    )
+    .unwrap();
+
+    let output = watch("json");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let events: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(events.len(), 3, "stdout: {stdout}");
+    assert_eq!(events[0]["change"], "state");
+    assert_eq!(events[0]["from"], "Open");
+    assert_eq!(events[0]["to"], "In Progress");
+    assert_eq!(events[1]["change"], "comment");
+    assert_eq!(events[1]["comment"], "Ready for review");
+    assert_eq!(events[2]["change"], "removed");
+    assert_eq!(events[2]["issue"], "DEMO-2");
+
+    // Nothing changed since the last run
+    let output = watch("text");
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_watch_rejects_sub_second_interval() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    track_mock(&dir, &scenario)
+        .args(["watch", "-q", "project: DEMO", "--interval", "0s"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid --interval"))
+        .stderr(predicate::str::contains("Duration must be positive"));
+    let _ = fs::remove_dir_all(&dir);
+}
+
+// =============================================================================
+// mcp
+// =============================================================================
+
+fn mcp_responses(output: &std::process::Output) -> Vec<serde_json::Value> {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_mcp_lists_tools_and_routes_writes_through_apply() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    let requests = [
+        r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"protocolVersion":"2025-06-18","capabilities":{}}}"#,
+        r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#,
+        r#"{"jsonrpc":"2.0","id":2,"method":"tools/list"}"#,
+        r#"{"jsonrpc":"2.0","id":3,"method":"tools/call","params":{"name":"get_issue","arguments":{"issue":"DEMO-1"}}}"#,
+        r#"{"jsonrpc":"2.0","id":4,"method":"tools/call","params":{"name":"add_comment","arguments":{"issue":"DEMO-1","body":"Looking into it"}}}"#,
+        r#"{"jsonrpc":"2.0","id":5,"method":"tools/call","params":{"name":"delete_issue","arguments":{"issue":"DEMO-1"}}}"#,
+    ];
+
+    let output = track_mock(&dir, &scenario)
+        .arg("mcp")
+        .write_stdin(requests.join("\n"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let responses = mcp_responses(&output);
+    // The notification gets no response
+    assert_eq!(responses.len(), 5);
+
+    assert_eq!(responses[0]["result"]["protocolVersion"], "2025-06-18");
+    assert_eq!(responses[0]["result"]["serverInfo"]["name"], "track");
+
+    let tools: Vec<&str> = responses[1]["result"]["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap())
+        .collect();
+    assert!(tools.contains(&"search_issues"));
+    assert!(tools.contains(&"get_context"));
+    assert!(!tools.contains(&"delete_issue"));
+
+    let issue = &responses[2]["result"]["structuredContent"];
+    assert_eq!(issue["id_readable"], "DEMO-1");
+    assert_eq!(responses[2]["result"]["isError"], false);
+
+    let comment = &responses[3]["result"]["structuredContent"];
+    assert!(comment.is_null(), "write results are text only");
+    let text = responses[3]["result"]["content"][0]["text"]
+        .as_str()
+        .unwrap();
+    let apply_output: serde_json::Value = serde_json::from_str(text).unwrap();
+    assert_eq!(apply_output["results"][0]["status"], "commented");
+
+    assert_eq!(responses[4]["error"]["code"], -32602);
+    assert!(!mock_call_methods(&scenario).contains(&"delete_issue".to_string()));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_mcp_posts_placeholder_text_verbatim() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    let body = "Token ${env.TRACK_VAR_SECRET}, see ${HOME} and $${vars.x}";
+    let request = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "tools/call",
+        "params": {"name": "add_comment", "arguments": {"issue": "DEMO-1", "body": body}}
+    });
+
+    let output = track_mock(&dir, &scenario)
+        .env("TRACK_VAR_SECRET", "hunter2")
+        .arg("mcp")
+        .write_stdin(request.to_string())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(mcp_responses(&output)[0]["result"]["isError"], false);
+
+    let comment = mock_call_entries(&scenario)
+        .into_iter()
+        .find(|entry| entry["method"] == "add_comment")
+        .expect("add_comment call");
+    assert_eq!(comment["args"]["text"], body);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_mcp_update_issue_adds_tags_to_existing_ones() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    let request = r#"{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"update_issue","arguments":{"issue":"DEMO-1","tags":["triaged"]}}}"#;
+
+    let output = track_mock(&dir, &scenario)
+        .arg("mcp")
+        .write_stdin(request)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(mcp_responses(&output)[0]["result"]["isError"], false);
+
+    let updates: Vec<_> = mock_call_entries(&scenario)
+        .into_iter()
+        .filter(|entry| entry["method"] == "update_issue")
+        .collect();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0]["args"]["tags"], "backend,triaged");
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_mcp_read_only_hides_write_tools() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    let output = track_mock(&dir, &scenario)
+        .args(["mcp", "--read-only"])
+        .write_stdin(r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#)
+        .output()
+        .unwrap();
+    let responses = mcp_responses(&output);
+    let tools = responses[0]["result"]["tools"].as_array().unwrap();
+    assert!(!tools.is_empty());
+    assert!(
+        tools
+            .iter()
+            .all(|tool| tool["annotations"]["readOnlyHint"] == true)
+    );
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+// =============================================================================
+// webhook serve
+// =============================================================================
+
+/// Signature of fixtures/webhooks/github-issue-closed.json with secret "s3cret"
+const GITHUB_FIXTURE_SIGNATURE: &str =
+    "sha256=5b507bf0acab5c29256ff05e32649fa97e9d042467b83f78f5aedabcfe078fdb";
+
+/// POST a payload to the local server and return the response status line.
+fn post_webhook(addr: &str, path: &str, headers: &[(&str, &str)], body: &[u8]) -> String {
+    let mut stream = std::net::TcpStream::connect(addr).unwrap();
+    let mut request = format!(
+        "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Length: {}\r\n",
+        path,
+        addr,
+        body.len()
+    );
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).unwrap();
+    stream.write_all(body).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response.lines().next().unwrap_or_default().to_string()
+}
+
+#[cfg(unix)]
+#[test]
+fn test_webhook_serve_verifies_and_emits_events() {
+    use std::io::BufRead;
+    use std::process::{Command, Stdio};
+
+    let dir = temp_dir();
+    fs::write(
+        dir.join(".track.toml"),
+        r#"
+[webhook.secrets]
+github = "s3cret"
+
+[[webhook.hooks]]
+command = 'echo "$TRACK_SOURCE $TRACK_EVENT_KIND $TRACK_ISSUE_ID" >> hooks.log'
+events = ["issue_transitioned"]
+"#,
+    )
+    .unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_track"))
+        .current_dir(&dir)
+        .env("HOME", &dir)
+        .env_remove("TRACKER_CONFIG")
+        .args(["-o", "json", "webhook", "serve", "--port", "0"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut stderr = std::io::BufReader::new(child.stderr.take().unwrap());
+    let mut addr = None;
+    let mut line = String::new();
+    while stderr.read_line(&mut line).unwrap() > 0 {
+        if let Some(url) = line.trim().strip_prefix("Listening on http://") {
+            addr = Some(url.to_string());
+            break;
+        }
+        line.clear();
+    }
+    let addr = addr.expect("server should report its address");
+
+    let payload = fs::read(
+        fixtures_path()
+            .parent()
+            .unwrap()
+            .join("webhooks/github-issue-closed.json"),
+    )
+    .unwrap();
+    let forged = post_webhook(
+        &addr,
+        "/github",
+        &[
+            ("X-GitHub-Event", "issues"),
+            ("X-Hub-Signature-256", "sha256=00"),
+        ],
+        &payload,
+    );
+    assert!(forged.contains("401"), "got: {}", forged);
+
+    let accepted = post_webhook(
+        &addr,
+        "/",
+        &[
+            ("X-GitHub-Event", "issues"),
+            ("X-Hub-Signature-256", GITHUB_FIXTURE_SIGNATURE),
+        ],
+        &payload,
+    );
+    assert!(accepted.contains("200"), "got: {}", accepted);
+
+    let mut stdout = std::io::BufReader::new(child.stdout.take().unwrap());
+    let mut event_line = String::new();
+    stdout.read_line(&mut event_line).unwrap();
+    let event: serde_json::Value = serde_json::from_str(&event_line).unwrap();
+    assert_eq!(event["source"], "github");
+    assert_eq!(event["kind"], "issue_transitioned");
+    assert_eq!(event["issue"], "acme/app#42");
+    assert_eq!(event["to"], "closed");
+
+    // Hooks run after the response, so wait for the log to appear
+    let hook_log = dir.join("hooks.log");
+    for _ in 0..50 {
+        if hook_log.exists() {
+            break;
+        }
+        thread::sleep(std::time::Duration::from_millis(100));
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+    assert_eq!(
+        fs::read_to_string(&hook_log).unwrap().trim(),
+        "github issue_transitioned acme/app#42"
+    );
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+// =============================================================================
+// rules
+// =============================================================================
+
+const STALE_RULES: &str = r#"
+[[rules]]
+name = "close-stale"
+query = "project: DEMO #Unresolved"
+[rules.when]
+state = "Open"
+inactive_for = "30d"
+without_tags = ["keep"]
+[rules.then]
+state = "Won't Fix"
+comment = "Closing after 30 days without activity, per ${vars.policy}."
+"#;
+
+#[test]
+fn test_rules_compile_to_apply_plan_and_run() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    let search = scenario.join("responses/search_issues_DEMO.json");
+    let content = fs::read_to_string(&search).unwrap();
+    let mut issues: serde_json::Value = serde_json::from_str(&content).unwrap();
+    issues[1]["tags"] = serde_json::json!([{"id": "t1", "name": "keep"}]);
+    fs::write(&search, issues.to_string()).unwrap();
+    fs::write(dir.join("rules.toml"), STALE_RULES).unwrap();
+
+    let output = track_mock(&dir, &scenario)
+        .args(["rules", "plan", "rules.toml"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let plan = parse_json_stdout(&output);
+    let operations = plan["operations"].as_array().unwrap();
+    assert_eq!(operations.len(), 2);
+    assert_eq!(operations[0]["op"], "update_issue");
+    assert_eq!(operations[0]["issue"], "DEMO-1");
+    assert_eq!(operations[0]["state"], "Won't Fix");
+    assert_eq!(operations[1]["op"], "comment");
+    assert_eq!(
+        operations[1]["body"],
+        "Closing after 30 days without activity, per $${vars.policy}."
+    );
+
+    let output = track_mock(&dir, &scenario)
+        .args(["-o", "json", "rules", "run", "rules.toml", "--dry-run"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json = parse_json_stdout(&output);
+    assert_eq!(json["dry_run"], true);
+    assert_eq!(json["summary"]["by_status"]["dry_run"], 2);
+
+    fs::write(scenario.join("call_log.jsonl"), "").unwrap();
+    track_mock(&dir, &scenario)
+        .args(["rules", "run", "rules.toml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Apply completed"));
+    assert_eq!(
+        mock_call_methods(&scenario),
+        vec!["search_issues", "update_issue", "add_comment"]
+    );
+    assert_eq!(
+        mock_call_entries(&scenario)[2]["args"]["text"],
+        "Closing after 30 days without activity, per ${vars.policy}."
+    );
+
+    track_mock(&dir, &scenario)
+        .args(["rules", "run", "rules.toml", "--rule", "missing"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No rule named 'missing'"));
 
     let _ = fs::remove_dir_all(&dir);
 }
 
 #[test]
-fn test_issue_shortcut_with_full_flag() {
+fn test_rules_add_tags_keep_existing_tags() {
     let dir = temp_dir();
-    let scenario = fixtures_path().join("basic-workflow");
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    let search = scenario.join("responses/search_issues_DEMO.json");
+    let content = fs::read_to_string(&search).unwrap();
+    let mut issues: serde_json::Value = serde_json::from_str(&content).unwrap();
+    issues[1]["tags"] = serde_json::json!([{"id": "t1", "name": "keep"}]);
+    fs::write(&search, issues.to_string()).unwrap();
+    fs::write(
+        dir.join("rules.toml"),
+        r#"
+[[rules]]
+name = "triage"
+query = "project: DEMO"
+when = { without_tags = ["keep"] }
+then = { add_tags = ["triaged"] }
+"#,
+    )
+    .unwrap();
 
-    let mut cmd = cargo_bin_cmd!("track");
-    cmd.current_dir(&dir)
-        .env("TRACK_MOCK_DIR", scenario.to_str().unwrap())
-        .args(["--url", "https://mock.test", "--token", "mock-token"])
-        .args(["DEMO-1", "--full"])
+    track_mock(&dir, &scenario)
+        .args(["rules", "run", "rules.toml"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("DEMO-1"));
+        .success();
+
+    let updates: Vec<_> = mock_call_entries(&scenario)
+        .into_iter()
+        .filter(|entry| entry["method"] == "update_issue")
+        .collect();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0]["args"]["tags"], "backend,triaged");
 
     let _ = fs::remove_dir_all(&dir);
 }
 
+// =============================================================================
+// report flow
+// =============================================================================
+
 #[test]
-fn test_issue_shortcut_json_output() {
+fn test_report_flow_json_and_csv() {
     let dir = temp_dir();
-    let scenario = fixtures_path().join("basic-workflow");
+    let scenario = copy_scenario(&dir, "flow-report");
 
-    let output = cargo_bin_cmd!("track")
-        .current_dir(&dir)
-        .env("TRACK_MOCK_DIR", scenario.to_str().unwrap())
+    let output = track_mock(&dir, &scenario)
         .args([
             "-o",
             "json",
-            "--url",
-            "https://mock.test",
-            "--token",
-            "mock-token",
+            "report",
+            "flow",
+            "--query",
+            "project: DEMO",
+            "--since",
+            "520w",
         ])
-        .arg("DEMO-1")
         .output()
         .unwrap();
-
     assert!(output.status.success());
-    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
-    assert_eq!(json["id_readable"], "DEMO-1");
-
-    let _ = fs::remove_dir_all(&dir);
-}
-
-// =============================================================================
-// Invalid subcommand rejection
-// =============================================================================
+    let json = parse_json_stdout(&output);
+    assert_eq!(json["issues_analyzed"], 3);
+    assert_eq!(json["completed"], 2);
+    assert_eq!(json["cycle_time"]["p50_days"], 7.0);
+    assert_eq!(json["cycle_time"]["p85_days"], 12.0);
+    assert_eq!(json["lead_time"]["mean_days"], 14.5);
+    assert_eq!(json["time_in_state"][1]["state"], "In Progress");
+    assert_eq!(json["time_in_state"][1]["mean_days"], 7.5);
+    assert_eq!(json["weeks"][0]["week_start"], "2026-03-30");
+    assert_eq!(json["weeks"][2]["completed"], 1);
+    assert_eq!(json["weeks"][2]["wip"], 2);
 
-#[test]
-fn test_non_issue_id_rejected() {
-    cargo_bin_cmd!("track")
-        .arg("foobar")
+    track_mock(&dir, &scenario)
+        .args(["report", "flow", "-q", "project: DEMO", "--csv"])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("unrecognized subcommand"));
-}
+        .success()
+        .stdout(predicate::str::contains(
+            "DEMO-3,Add password reset,In Progress,2026-04-05T09:00:00+00:00,2026-04-14T09:00:00+00:00,,,\n",
+        ));
 
-#[test]
-fn test_bare_number_rejected() {
-    cargo_bin_cmd!("track")
-        .arg("123")
-        .assert()
-        .failure()
-        .stderr(predicate::str::contains("unrecognized subcommand"));
+    let _ = fs::remove_dir_all(&dir);
 }
 
 // =============================================================================
@@ -1493,7 +2432,7 @@ fn test_comment_requires_message_or_body_file() {
 #[test]
 fn test_body_file_reads_file_via_mock() {
     let dir = temp_dir();
-    let scenario = fixtures_path().join("basic-workflow");
+    let scenario = copy_scenario(&dir, "basic-workflow");
 
     // Write a body file
     let body = dir.join("comment.md");
@@ -1522,7 +2461,7 @@ fn test_body_file_reads_file_via_mock() {
 #[test]
 fn test_body_file_error_on_missing_file() {
     let dir = temp_dir();
-    let scenario = fixtures_path().join("basic-workflow");
+    let scenario = copy_scenario(&dir, "basic-workflow");
 
     let mut cmd = cargo_bin_cmd!("track");
     cmd.current_dir(&dir)
@@ -1545,7 +2484,7 @@ fn test_body_file_error_on_missing_file() {
 #[test]
 fn test_body_file_reads_multiline_markdown() {
     let dir = temp_dir();
-    let scenario = fixtures_path().join("basic-workflow");
+    let scenario = copy_scenario(&dir, "basic-workflow");
 
     // Write complex markdown with code blocks, angle brackets, etc.
     let body = dir.join("complex.md");
@@ -1575,7 +2514,7 @@ fn test_body_file_reads_multiline_markdown() {
 #[test]
 fn test_body_file_stdin_via_pipe() {
     let dir = temp_dir();
-    let scenario = fixtures_path().join("basic-workflow");
+    let scenario = copy_scenario(&dir, "basic-workflow");
 
     // Test that --body-file - reads from stdin
     let mut cmd = cargo_bin_cmd!("track");
@@ -1594,7 +2533,7 @@ fn test_body_file_stdin_via_pipe() {
 #[test]
 fn test_body_file_update_satisfies_required_fields() {
     let dir = temp_dir();
-    let scenario = fixtures_path().join("basic-workflow");
+    let scenario = copy_scenario(&dir, "basic-workflow");
 
     let body = dir.join("desc.md");
     fs::write(&body, "Updated description\n").unwrap();
@@ -1696,6 +2635,329 @@ fn test_issue_update_validate_dry_run_does_not_update_batch() {
     let _ = fs::remove_dir_all(&dir);
 }
 
+#[cfg(unix)]
+#[test]
+fn test_issue_edit_sends_only_changed_fields() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    let editor = dir.join("edit.sh");
+    fs::write(
+        &editor,
+        "#!/bin/sh\nsed -e 's/^priority: .*/priority: High/' \"$1\" > \"$1.new\" && mv \"$1.new\" \"$1\"\n",
+    )
+    .unwrap();
+    fs::set_permissions(&editor, fs::Permissions::from_mode(0o755)).unwrap();
+
+    track_mock(&dir, &scenario)
+        .env_remove("VISUAL")
+        .env("EDITOR", &editor)
+        .args(["issue", "edit", "DEMO-1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("DEMO-1"));
+
+    let calls = mock_call_entries(&scenario);
+    let updates: Vec<_> = calls
+        .iter()
+        .filter(|entry| entry["method"] == "update_issue")
+        .collect();
+    assert_eq!(updates.len(), 1, "calls: {:?}", calls);
+    assert_eq!(updates[0]["args"]["custom_fields"], "Priority=High");
+    assert!(
+        !calls
+            .iter()
+            .any(|entry| entry["method"] == "remove_issue_tag"
+                || entry["method"] == "remove_issue_parent")
+    );
+
+    // Saving without changes makes no update.
+    fs::write(scenario.join("call_log.jsonl"), "").unwrap();
+    track_mock(&dir, &scenario)
+        .env_remove("VISUAL")
+        .env("EDITOR", "true")
+        .args(["issue", "edit", "DEMO-1"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("No changes to DEMO-1"));
+    assert!(!mock_call_methods(&scenario).contains(&"update_issue".to_string()));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_issue_edit_keeps_rendered_mentions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    let issue_file = scenario.join("responses/get_issue_DEMO-1.json");
+    let mut issue: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&issue_file).unwrap()).unwrap();
+    issue["description"] = serde_json::json!("Ping @Jane Smith about this.");
+    fs::write(&issue_file, issue.to_string()).unwrap();
+
+    let editor = dir.join("edit.sh");
+    fs::write(
+        &editor,
+        "#!/bin/sh
+printf '\\nOver to @\"John Doe\".\\n' >> \"$1\"\n",
+    )
+    .unwrap();
+    fs::set_permissions(&editor, fs::Permissions::from_mode(0o755)).unwrap();
+
+    track_mock(&dir, &scenario)
+        .env_remove("VISUAL")
+        .env("EDITOR", &editor)
+        .args(["issue", "edit", "DEMO-1"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("did not match").not());
+
+    let updates: Vec<_> = mock_call_entries(&scenario)
+        .into_iter()
+        .filter(|entry| entry["method"] == "update_issue")
+        .collect();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(
+        updates[0]["args"]["description"],
+        "Ping @Jane Smith about this.\n\nOver to @john.doe."
+    );
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_issue_create_from_template_fills_variables() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "issue-create-advanced");
+    let templates = dir.join(".track/templates");
+    fs::create_dir_all(&templates).unwrap();
+    fs::write(
+        templates.join("bug.md"),
+        "---\nproject: DEMO\nsummary: '{{area}} priority bug'\nfields:\n  Type: Bug\ntags: [bug]\n---\n\n## Steps\n\n{{steps}}\n",
+    )
+    .unwrap();
+
+    track_mock(&dir, &scenario)
+        .args([
+            "issue",
+            "create",
+            "--template",
+            "bug",
+            "--var",
+            "area=Checkout",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Missing template variables: steps",
+        ));
+
+    track_mock(&dir, &scenario)
+        .args([
+            "-o",
+            "json",
+            "issue",
+            "create",
+            "--template",
+            "bug",
+            "--var",
+            "area=Checkout",
+            "--var",
+            "steps=Pay twice",
+        ])
+        .assert()
+        .success();
+
+    let creates: Vec<_> = mock_call_entries(&scenario)
+        .into_iter()
+        .filter(|entry| entry["method"] == "create_issue")
+        .collect();
+    assert_eq!(creates.len(), 1);
+    assert_eq!(creates[0]["args"]["summary"], "Checkout priority bug");
+
+    let output = track_mock(&dir, &scenario)
+        .args(["-o", "json", "issue", "template", "list"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let list = parse_json_stdout(&output);
+    assert_eq!(list[0]["name"], "bug");
+    assert_eq!(list[0]["variables"], serde_json::json!(["area", "steps"]));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_issue_comment_resolves_mentions_to_backend_users() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    let message = r#"Over to @jane.smith and @"John Doe", cc @nobody"#;
+
+    // Dry run previews the resolved text without posting
+    let output = track_mock(&dir, &scenario)
+        .args([
+            "-o",
+            "json",
+            "issue",
+            "comment",
+            "DEMO-1",
+            "--dry-run",
+            "-m",
+        ])
+        .arg(message)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let preview = parse_json_stdout(&output);
+    assert_eq!(
+        preview["text"],
+        "Over to @jane.smith and @john.doe, cc @nobody"
+    );
+    assert_eq!(preview["mentions"].as_array().unwrap().len(), 2);
+    assert_eq!(preview["mentions"][1]["user"]["id"], "user-1");
+    assert!(preview["warnings"][0].as_str().unwrap().contains("@nobody"));
+    assert!(!mock_call_methods(&scenario).contains(&"add_comment".to_string()));
+
+    track_mock(&dir, &scenario)
+        .args(["issue", "comment", "DEMO-1", "-m"])
+        .arg(message)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Mention @nobody did not match any user",
+        ));
+
+    let comment = mock_call_entries(&scenario)
+        .into_iter()
+        .find(|entry| entry["method"] == "add_comment")
+        .expect("add_comment call");
+    assert_eq!(
+        comment["args"]["text"],
+        "Over to @jane.smith and @john.doe, cc @nobody"
+    );
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_apply_add_tag_matches_existing_tags_ignoring_case() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    fs::write(
+        dir.join("plan.json"),
+        r#"{"version": 1, "operations": [{"op": "add_tag", "issue": "DEMO-1", "tag": "Backend"}]}"#,
+    )
+    .unwrap();
+
+    let output = track_mock(&dir, &scenario)
+        .args(["-o", "json", "apply", "plan.json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json = parse_json_stdout(&output);
+    assert_eq!(json["results"][0]["status"], "unchanged");
+    assert!(!mock_call_methods(&scenario).contains(&"update_issue".to_string()));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_apply_and_mcp_comments_resolve_mentions() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "basic-workflow");
+    fs::write(
+        dir.join("plan.json"),
+        r#"{"version": 1, "operations": [{"op": "comment", "issue": "DEMO-1", "body": "Over to @\"John Doe\""}]}"#,
+    )
+    .unwrap();
+
+    track_mock(&dir, &scenario)
+        .args(["apply", "plan.json"])
+        .assert()
+        .success();
+
+    let request = r#"{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"add_comment","arguments":{"issue":"DEMO-1","body":"cc @\"Jane Smith\""}}}"#;
+    let output = track_mock(&dir, &scenario)
+        .arg("mcp")
+        .write_stdin(request)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let comments: Vec<_> = mock_call_entries(&scenario)
+        .into_iter()
+        .filter(|entry| entry["method"] == "add_comment")
+        .map(|entry| entry["args"]["text"].clone())
+        .collect();
+    assert_eq!(comments, vec!["Over to @john.doe", "cc @jane.smith"]);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_article_sync_pushes_and_pulls_markdown_directory() {
+    let dir = temp_dir();
+    let scenario = copy_scenario(&dir, "article-workflow");
+    let docs = dir.join("docs");
+    fs::create_dir_all(&docs).unwrap();
+    fs::write(
+        docs.join("getting-started.md"),
+        "<!-- track-article: KB-A-1 -->\n# Getting Started\n\nEdited locally.\n",
+    )
+    .unwrap();
+    fs::write(docs.join("new-page.md"), "# New Page\n\nHello\n").unwrap();
+
+    let output = track_mock(&dir, &scenario)
+        .args(["-o", "json", "article", "sync", "docs", "--project", "DEMO"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let result = parse_json_stdout(&output);
+    let actions: Vec<_> = result["actions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|action| {
+            (
+                action["action"].as_str().unwrap(),
+                action["path"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        actions,
+        [("update", "getting-started.md"), ("create", "new-page.md")]
+    );
+    assert!(
+        fs::read_to_string(docs.join("new-page.md"))
+            .unwrap()
+            .starts_with("<!-- track-article: KB-A-3 -->\n# New Page")
+    );
+    assert!(docs.join(".track-sync.json").exists());
+    let methods = mock_call_methods(&scenario);
+    assert!(methods.contains(&"create_article".to_string()));
+    assert!(methods.contains(&"update_article".to_string()));
+
+    let pulled = dir.join("pulled");
+    fs::create_dir_all(&pulled).unwrap();
+    track_mock(&dir, &scenario)
+        .args(["article", "sync", "pulled", "--project", "DEMO", "--pull"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("pull-new"));
+    assert_eq!(
+        fs::read_to_string(pulled.join("getting-started.md")).unwrap(),
+        "<!-- track-article: KB-A-1 -->\n# Getting Started\n\nWelcome to the project documentation.\n"
+    );
+    assert!(pulled.join("api-reference.md").exists());
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
 #[test]
 fn test_content_file_backward_compat_still_parses() {
     // --content-file should still be accepted on article commands (hidden alias).
@@ -1703,7 +2965,7 @@ fn test_content_file_backward_compat_still_parses() {
     // The actual API call may fail (mock may not support update_article),
     // so we only check that the error is NOT a parse/usage error.
     let dir = temp_dir();
-    let scenario = fixtures_path().join("basic-workflow");
+    let scenario = copy_scenario(&dir, "basic-workflow");
 
     let body = dir.join("article.md");
     fs::write(&body, "Article content from file\n").unwrap();
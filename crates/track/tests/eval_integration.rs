@@ -9,7 +9,7 @@ use assert_cmd::cargo::cargo_bin_cmd;
 use predicates::prelude::*;
 use serial_test::serial;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Get the path to the fixtures directory (relative to workspace root)
 fn fixtures_path() -> PathBuf {
@@ -29,6 +29,36 @@ fn scenario_path(name: &str) -> PathBuf {
     fixtures_path().join(name)
 }
 
+/// Copy a scenario into a fresh temp directory, so the mock's call log is
+/// written there rather than into the fixtures.
+fn scenario_copy(name: &str) -> PathBuf {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!(
+        "track-eval-test-{}-{}/{}",
+        std::process::id(),
+        n,
+        name
+    ));
+    copy_dir_recursive(&scenario_path(name), &dir);
+    dir
+}
+
+fn copy_dir_recursive(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let source = entry.path();
+        let target = to.join(entry.file_name());
+        if source.is_dir() {
+            copy_dir_recursive(&source, &target);
+        } else {
+            fs::copy(&source, &target).unwrap();
+        }
+    }
+}
+
 /// Helper to run track command from workspace root
 fn track() -> Command {
     let mut cmd = cargo_bin_cmd!("track");
@@ -98,7 +128,7 @@ fn test_eval_status_when_disabled() {
 #[test]
 #[serial]
 fn test_eval_clear_creates_empty_log() {
-    let path = scenario_path("basic-workflow");
+    let path = scenario_copy("basic-workflow");
     let log_path = path.join("call_log.jsonl");
 
     // Write something to the log first
@@ -118,7 +148,7 @@ fn test_eval_clear_creates_empty_log() {
 #[test]
 #[serial]
 fn test_eval_run_fails_on_empty_log() {
-    let path = scenario_path("basic-workflow");
+    let path = scenario_copy("basic-workflow");
     let log_path = path.join("call_log.jsonl");
 
     // Ensure log is empty
@@ -134,7 +164,7 @@ fn test_eval_run_fails_on_empty_log() {
 #[test]
 #[serial]
 fn test_mock_mode_get_issue() {
-    let path = scenario_path("basic-workflow");
+    let path = scenario_copy("basic-workflow");
     let log_path = path.join("call_log.jsonl");
 
     // Clear the log first
@@ -160,7 +190,7 @@ fn test_mock_mode_get_issue() {
 #[test]
 #[serial]
 fn test_mock_mode_full_workflow() {
-    let path = scenario_path("basic-workflow");
+    let path = scenario_copy("basic-workflow");
     let log_path = path.join("call_log.jsonl");
 
     // Clear the log
@@ -204,7 +234,7 @@ fn test_mock_mode_full_workflow() {
 #[test]
 #[serial]
 fn test_eval_run_with_min_score_threshold() {
-    let path = scenario_path("basic-workflow");
+    let path = scenario_copy("basic-workflow");
     let log_path = path.join("call_log.jsonl");
 
     // Clear and run minimal workflow
@@ -227,7 +257,7 @@ fn test_eval_run_with_min_score_threshold() {
 #[test]
 #[serial]
 fn test_eval_run_strict_mode_fails_on_incomplete() {
-    let path = scenario_path("basic-workflow");
+    let path = scenario_copy("basic-workflow");
     let log_path = path.join("call_log.jsonl");
 
     // Clear and run only one command (incomplete workflow)
@@ -250,7 +280,7 @@ fn test_eval_run_strict_mode_fails_on_incomplete() {
 #[test]
 #[serial]
 fn test_eval_run_json_output() {
-    let path = scenario_path("basic-workflow");
+    let path = scenario_copy("basic-workflow");
     let log_path = path.join("call_log.jsonl");
 
     // Set up a passing workflow
@@ -286,7 +316,7 @@ fn test_eval_run_json_output() {
 #[test]
 #[serial]
 fn test_mock_mode_error_handling() {
-    let path = scenario_path("error-recovery");
+    let path = scenario_copy("error-recovery");
     let log_path = path.join("call_log.jsonl");
 
     // Clear log
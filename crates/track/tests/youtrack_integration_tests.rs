@@ -399,7 +399,9 @@ fn test_invalid_subcommand() {
 
 #[test]
 fn test_issue_get_requires_id() {
+    // Outside a git repository there is no branch to infer the ID from
     cargo_bin_cmd!("track")
+        .current_dir(std::env::temp_dir())
         .args(["issue", "get"])
         .env("TRACKER_TOKEN", "test")
         .env("TRACKER_URL", "https://test.example.com")
@@ -407,7 +409,7 @@ fn test_issue_get_requires_id() {
         .env_remove("YOUTRACK_TOKEN")
         .assert()
         .failure()
-        .stderr(predicate::str::contains("required"));
+        .stderr(predicate::str::contains("No issue ID given"));
 }
 
 #[test]
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+/// The issue tracker a [`Config`](crate::Config) connects to.
+#[derive(Clone, Debug, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+    /// YouTrack issue tracker
+    #[default]
+    #[cfg_attr(feature = "clap", value(name = "youtrack", alias = "yt"))]
+    YouTrack,
+    /// Jira issue tracker
+    #[cfg_attr(feature = "clap", value(name = "jira", alias = "j"))]
+    Jira,
+    /// GitHub issue tracker
+    #[cfg_attr(feature = "clap", value(name = "github", alias = "gh"))]
+    GitHub,
+    /// GitLab issue tracker
+    #[cfg_attr(feature = "clap", value(name = "gitlab", alias = "gl"))]
+    GitLab,
+    /// Linear issue tracker
+    #[cfg_attr(feature = "clap", value(name = "linear", alias = "lin"))]
+    Linear,
+}
+
+impl Backend {
+    /// All known backends, in stable display order.
+    pub const ALL: [Backend; 5] = [
+        Backend::YouTrack,
+        Backend::Jira,
+        Backend::GitHub,
+        Backend::GitLab,
+        Backend::Linear,
+    ];
+
+    /// Human-readable product name (e.g., "GitHub")
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Backend::YouTrack => "YouTrack",
+            Backend::Jira => "Jira",
+            Backend::GitHub => "GitHub",
+            Backend::GitLab => "GitLab",
+            Backend::Linear => "Linear",
+        }
+    }
+}
+
+impl std::fmt::Display for Backend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Backend::YouTrack => write!(f, "youtrack"),
+            Backend::Jira => write!(f, "jira"),
+            Backend::GitHub => write!(f, "github"),
+            Backend::GitLab => write!(f, "gitlab"),
+            Backend::Linear => write!(f, "linear"),
+        }
+    }
+}
@@ -0,0 +1,313 @@
+use crate::error::{ClientError, Result};
+use crate::{Backend, Config};
+use tracker_core::{IssueTracker, KnowledgeBase};
+
+#[cfg(feature = "github")]
+use crate::config::GitHubScope;
+#[cfg(feature = "jira")]
+use crate::config::JiraDeployment;
+#[cfg(feature = "github")]
+use github_backend::{GitHubClient, RepoFilter};
+#[cfg(feature = "gitlab")]
+use gitlab_backend::GitLabClient;
+#[cfg(feature = "jira")]
+use jira_backend::{ConfluenceClient, JiraClient};
+#[cfg(feature = "linear")]
+use linear_backend::LinearClient;
+#[cfg(feature = "mock")]
+use tracker_mock::MockClient;
+#[cfg(feature = "youtrack")]
+use youtrack_backend::YouTrackClient;
+
+/// A constructed backend client, exposing the issue-tracker and knowledge-base
+/// trait objects it implements. Jira is the only backend that splits the two
+/// across separate clients (Jira issues, Confluence articles).
+pub enum Client {
+    #[cfg(feature = "youtrack")]
+    YouTrack(YouTrackClient),
+    #[cfg(feature = "jira")]
+    Jira {
+        issues: JiraClient,
+        confluence: ConfluenceClient,
+    },
+    #[cfg(feature = "github")]
+    GitHub(GitHubClient),
+    #[cfg(feature = "gitlab")]
+    GitLab(GitLabClient),
+    #[cfg(feature = "linear")]
+    Linear(LinearClient),
+    #[cfg(feature = "mock")]
+    Mock(MockClient),
+}
+
+impl Client {
+    pub fn issue_tracker(&self) -> &dyn IssueTracker {
+        match self {
+            #[cfg(feature = "youtrack")]
+            Client::YouTrack(c) => c,
+            #[cfg(feature = "jira")]
+            Client::Jira { issues, .. } => issues,
+            #[cfg(feature = "github")]
+            Client::GitHub(c) => c,
+            #[cfg(feature = "gitlab")]
+            Client::GitLab(c) => c,
+            #[cfg(feature = "linear")]
+            Client::Linear(c) => c,
+            #[cfg(feature = "mock")]
+            Client::Mock(c) => c,
+        }
+    }
+
+    pub fn knowledge_base(&self) -> &dyn KnowledgeBase {
+        match self {
+            #[cfg(feature = "youtrack")]
+            Client::YouTrack(c) => c,
+            #[cfg(feature = "jira")]
+            Client::Jira { confluence, .. } => confluence,
+            #[cfg(feature = "github")]
+            Client::GitHub(c) => c,
+            #[cfg(feature = "gitlab")]
+            Client::GitLab(c) => c,
+            #[cfg(feature = "linear")]
+            Client::Linear(c) => c,
+            #[cfg(feature = "mock")]
+            Client::Mock(c) => c,
+        }
+    }
+
+    /// Serve responses from a mock scenario directory.
+    #[cfg(feature = "mock")]
+    pub fn mock(scenario_dir: &std::path::Path) -> Result<Self> {
+        MockClient::new(scenario_dir)
+            .map(Client::Mock)
+            .map_err(|e| ClientError::Mock(e.to_string()))
+    }
+
+    /// Build a client for `backend` from an already backend-collapsed [`Config`]
+    /// (i.e. one produced by `Config::load(_, backend)`).
+    ///
+    /// Unlike [`connect_backend`] this neither validates the config nor
+    /// honors mock mode; missing settings surface as errors rather than
+    /// panics so callers like `track doctor` can probe multiple backends
+    /// safely.
+    // A mock-only build has no real backends to build
+    #[cfg_attr(
+        not(any(
+            feature = "youtrack",
+            feature = "jira",
+            feature = "github",
+            feature = "gitlab",
+            feature = "linear"
+        )),
+        allow(unused_variables)
+    )]
+    pub fn build(backend: Backend, config: &Config) -> Result<Self> {
+        match backend {
+            #[cfg(feature = "youtrack")]
+            Backend::YouTrack => {
+                let url = required(&config.url, "YouTrack URL")?;
+                let token = required(&config.token, "YouTrack token")?;
+                let client = YouTrackClient::new(url, token)
+                    .with_link_mappings(config.youtrack.link_mappings.clone());
+                Ok(Client::YouTrack(client))
+            }
+            #[cfg(feature = "jira")]
+            Backend::Jira => {
+                let url = required(&config.url, "Jira URL")?;
+                let token = required(&config.token, "Jira token")?;
+
+                let deployment = match config.jira.deployment() {
+                    JiraDeployment::Cloud => jira_backend::JiraDeployment::Cloud,
+                    JiraDeployment::Server => jira_backend::JiraDeployment::Server,
+                };
+                let issues = match (config.jira.deployment(), config.email.as_deref()) {
+                    (JiraDeployment::Cloud, _) => {
+                        let email = required(&config.email, "Jira email")?;
+                        JiraClient::new(url, email, token)
+                    }
+                    // Server / Data Center: username + password, or a
+                    // personal access token on its own
+                    (JiraDeployment::Server, Some(username)) => {
+                        JiraClient::new(url, username, token).with_deployment(deployment)
+                    }
+                    (JiraDeployment::Server, None) => JiraClient::new_server(url, token),
+                }
+                .with_link_mappings(config.jira.link_mappings.clone());
+                let email = config.email.as_deref().unwrap_or_default();
+                // Only Confluence Cloud is implemented; on Server / Data
+                // Center article operations report that they're unsupported
+                let confluence =
+                    ConfluenceClient::new(url, email, token).with_deployment(deployment);
+                Ok(Client::Jira { issues, confluence })
+            }
+            #[cfg(feature = "github")]
+            Backend::GitHub => {
+                let owner = required(&config.github.owner, "GitHub owner")?;
+                let org_scope = config.github.scope() == GitHubScope::Org;
+                let repo = match config.github.repo.as_deref() {
+                    // Org scope spans the owner's repositories
+                    None if org_scope => "",
+                    _ => required(&config.github.repo, "GitHub repo")?,
+                };
+                let token = required(&config.token, "GitHub token")?;
+                let mut client = if let Some(api_url) = config.url.as_deref() {
+                    GitHubClient::with_base_url(api_url, owner, repo, token)
+                } else {
+                    GitHubClient::new(owner, repo, token)
+                }
+                .with_project_number(config.github.project_number);
+                if org_scope {
+                    let filter = RepoFilter::new(
+                        config.github.repo_topic.clone(),
+                        config.github.repo_pattern.as_deref(),
+                    )
+                    .map_err(|e| {
+                        ClientError::Invalid(format!("Invalid github.repo_pattern: {}", e))
+                    })?;
+                    client = client.with_org_scope(filter);
+                }
+                Ok(Client::GitHub(client))
+            }
+            #[cfg(feature = "gitlab")]
+            Backend::GitLab => {
+                let base_url = required(&config.url, "GitLab URL")?;
+                let token = required(&config.token, "GitLab token")?;
+                let project_id = config.gitlab.project_id.as_deref();
+                let client = GitLabClient::new(base_url, token, project_id)
+                    .with_link_mappings(config.gitlab.link_mappings.clone());
+                Ok(Client::GitLab(client))
+            }
+            #[cfg(feature = "linear")]
+            Backend::Linear => {
+                let token = required(&config.token, "Linear token")?;
+                let api_url = config
+                    .linear
+                    .api_url
+                    .as_deref()
+                    .unwrap_or("https://api.linear.app/graphql");
+                let default_team = config
+                    .linear
+                    .default_team
+                    .clone()
+                    .or_else(|| config.default_project.clone());
+                let client = LinearClient::with_base_url(api_url, token)
+                    .with_defaults(default_team, config.linear.default_linear_project.clone())
+                    .with_link_mappings(config.linear.link_mappings.clone());
+                Ok(Client::Linear(client))
+            }
+            #[allow(unreachable_patterns)]
+            disabled => Err(ClientError::BackendDisabled(disabled)),
+        }
+    }
+}
+
+#[cfg_attr(
+    not(any(
+        feature = "youtrack",
+        feature = "jira",
+        feature = "github",
+        feature = "gitlab",
+        feature = "linear"
+    )),
+    allow(dead_code)
+)]
+fn required<'a>(value: &'a Option<String>, what: &'static str) -> Result<&'a str> {
+    value.as_deref().ok_or(ClientError::NotConfigured(what))
+}
+
+/// Connect to the config's own backend (see [`Config::get_backend`]).
+pub fn connect(config: &Config) -> Result<Client> {
+    connect_backend(config.get_backend(), config)
+}
+
+/// Connect to `backend` using a config loaded for it with [`Config::load`].
+///
+/// When `TRACK_MOCK_DIR` is set (and the `mock` feature is on) the mock
+/// client is returned instead, without requiring real credentials.
+/// Otherwise the config is validated before the client is built.
+pub fn connect_backend(backend: Backend, config: &Config) -> Result<Client> {
+    #[cfg(feature = "mock")]
+    if let Some(mock_dir) = tracker_mock::get_mock_dir() {
+        return Client::mock(&mock_dir);
+    }
+
+    config.validate(backend)?;
+    Client::build(backend, config)
+}
+
+// Every test builds a real backend client
+#[cfg(all(test, any(feature = "github", feature = "jira")))]
+mod tests {
+    use super::*;
+    #[cfg(feature = "github")]
+    use crate::GitHubConfig;
+
+    #[cfg(feature = "github")]
+    #[test]
+    fn build_reports_missing_settings() {
+        let config = Config {
+            token: Some("secret".to_string()),
+            github: GitHubConfig {
+                owner: Some("org".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let err = Client::build(Backend::GitHub, &config).err().unwrap();
+        assert_eq!(err.to_string(), "GitHub repo not configured");
+    }
+
+    #[cfg(feature = "github")]
+    #[test]
+    fn build_github_org_scope_checks_repo_pattern() {
+        let mut config = Config {
+            token: Some("secret".to_string()),
+            github: GitHubConfig {
+                owner: Some("org".to_string()),
+                scope: Some(GitHubScope::Org),
+                repo_pattern: Some("^svc-".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(Client::build(Backend::GitHub, &config).is_ok());
+
+        config.github.repo_pattern = Some("(".to_string());
+        let err = Client::build(Backend::GitHub, &config).err().unwrap();
+        assert!(err.to_string().contains("github.repo_pattern"), "{err}");
+    }
+
+    #[cfg(feature = "jira")]
+    #[test]
+    fn build_exposes_both_traits() {
+        let config = Config {
+            url: Some("https://acme.atlassian.net".to_string()),
+            email: Some("user@example.com".to_string()),
+            token: Some("secret".to_string()),
+            ..Default::default()
+        };
+        let client = Client::build(Backend::Jira, &config).unwrap();
+        assert!(matches!(client, Client::Jira { .. }));
+        let _ = (client.issue_tracker(), client.knowledge_base());
+    }
+
+    #[cfg(feature = "jira")]
+    // Irrefutable when Jira is the only backend compiled in
+    #[allow(irrefutable_let_patterns)]
+    #[test]
+    fn build_jira_server_needs_no_email() {
+        let mut config = Config {
+            url: Some("https://jira.example.com".to_string()),
+            token: Some("pat".to_string()),
+            ..Default::default()
+        };
+        config.jira.deployment = Some(JiraDeployment::Server);
+        let client = Client::build(Backend::Jira, &config).unwrap();
+        let error = client.knowledge_base().get_article("123").unwrap_err();
+        assert!(error.to_string().contains("not supported in Jira Server"));
+        let Client::Jira { issues, .. } = client else {
+            panic!("expected a Jira client");
+        };
+        assert_eq!(issues.deployment(), jira_backend::JiraDeployment::Server);
+    }
+}
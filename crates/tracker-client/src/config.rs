@@ -0,0 +1,944 @@
+//! Connection settings shared by every tool built on tracker-client.
+//!
+//! Settings are merged from `~/.tracker-cli/.track.toml`, `./.track.toml`
+//! (or an explicit path), and the `TRACKER_*`, `YOUTRACK_*`, `JIRA_*`,
+//! `GITHUB_*`, `GITLAB_*` and `LINEAR_*` environment variables, in that order.
+
+use crate::Backend;
+use crate::error::{ClientError, Result};
+use figment::{
+    Figment,
+    providers::{Env, Format, Serialized, Toml},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Connection configuration supporting multiple backends
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct Config {
+    /// Default backend to use (youtrack, jira, github, gitlab, or linear)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backend: Option<Backend>,
+    /// Global URL override (applies to any backend)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Global token override (applies to any backend)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// Email for authentication (required for Jira)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Default project shortName (e.g., "PROJ")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_project: Option<String>,
+    /// YouTrack-specific configuration
+    #[serde(default, skip_serializing_if = "BackendConfig::is_empty")]
+    pub youtrack: BackendConfig,
+    /// Jira-specific configuration
+    #[serde(default, skip_serializing_if = "JiraConfig::is_empty")]
+    pub jira: JiraConfig,
+    /// GitHub-specific configuration
+    #[serde(default, skip_serializing_if = "GitHubConfig::is_empty")]
+    pub github: GitHubConfig,
+    /// GitLab-specific configuration
+    #[serde(default, skip_serializing_if = "GitLabConfig::is_empty")]
+    pub gitlab: GitLabConfig,
+    /// Linear-specific configuration
+    #[serde(default, skip_serializing_if = "LinearConfig::is_empty")]
+    pub linear: LinearConfig,
+}
+
+/// Backend-specific configuration
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct BackendConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub link_mappings: HashMap<String, String>,
+}
+
+impl BackendConfig {
+    pub fn is_empty(&self) -> bool {
+        self.url.is_none() && self.token.is_none() && self.link_mappings.is_empty()
+    }
+
+    /// Connection-relevant keys only: cosmetic settings like link_mappings
+    /// must not make a backend count as configured.
+    pub fn has_connection_config(&self) -> bool {
+        self.url.is_some() || self.token.is_some()
+    }
+}
+
+/// Jira-specific configuration
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct JiraConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// Jira product to talk to (defaults to Cloud)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployment: Option<JiraDeployment>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub link_mappings: HashMap<String, String>,
+}
+
+/// Jira product selected by `jira.deployment`
+#[derive(Debug, Deserialize, Serialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum JiraDeployment {
+    /// Jira Cloud: email + API token, REST API v3
+    #[default]
+    Cloud,
+    /// Jira Server / Data Center: personal access token (or username +
+    /// password), REST API v2
+    #[serde(alias = "datacenter", alias = "data-center", alias = "dc")]
+    Server,
+}
+
+impl JiraConfig {
+    pub fn is_empty(&self) -> bool {
+        self.url.is_none()
+            && self.email.is_none()
+            && self.token.is_none()
+            && self.deployment.is_none()
+            && self.link_mappings.is_empty()
+    }
+
+    /// The configured Jira product, Cloud when unset.
+    pub fn deployment(&self) -> JiraDeployment {
+        self.deployment.unwrap_or_default()
+    }
+
+    /// Connection-relevant keys only (excludes link_mappings).
+    pub fn has_connection_config(&self) -> bool {
+        self.url.is_some() || self.email.is_some() || self.token.is_some()
+    }
+}
+
+/// GitHub-specific configuration
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct GitHubConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo: Option<String>,
+    /// API URL (defaults to https://api.github.com)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_url: Option<String>,
+    /// Projects (v2) board number under `owner` whose fields are exposed as
+    /// custom fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_number: Option<u64>,
+    /// One repository (default) or every repository of `owner`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<GitHubScope>,
+    /// In org scope, only repositories tagged with this topic
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo_topic: Option<String>,
+    /// In org scope, only repositories whose name matches this regex
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo_pattern: Option<String>,
+}
+
+/// Repositories a GitHub connection spans, selected by `github.scope`
+#[derive(Debug, Deserialize, Serialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum GitHubScope {
+    /// The single repository named by `github.repo`
+    #[default]
+    Repo,
+    /// Every repository of `github.owner` (optionally filtered); issue IDs
+    /// take the form `repo#123`
+    #[serde(alias = "organization")]
+    Org,
+}
+
+impl GitHubConfig {
+    pub fn is_empty(&self) -> bool {
+        self.token.is_none()
+            && self.owner.is_none()
+            && self.repo.is_none()
+            && self.api_url.is_none()
+            && self.project_number.is_none()
+            && self.scope.is_none()
+            && self.repo_topic.is_none()
+            && self.repo_pattern.is_none()
+    }
+
+    /// The configured scope, a single repository when unset.
+    pub fn scope(&self) -> GitHubScope {
+        self.scope.unwrap_or_default()
+    }
+
+    /// Connection-relevant keys (GitHub has no cosmetic-only keys today, but
+    /// this keeps backend enumeration uniform across sections).
+    pub fn has_connection_config(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+/// GitLab-specific configuration
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct GitLabConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub link_mappings: HashMap<String, String>,
+}
+
+impl GitLabConfig {
+    pub fn is_empty(&self) -> bool {
+        self.token.is_none()
+            && self.url.is_none()
+            && self.project_id.is_none()
+            && self.namespace.is_none()
+            && self.link_mappings.is_empty()
+    }
+
+    /// Connection-relevant keys only (excludes link_mappings).
+    pub fn has_connection_config(&self) -> bool {
+        self.token.is_some()
+            || self.url.is_some()
+            || self.project_id.is_some()
+            || self.namespace.is_some()
+    }
+}
+
+/// Linear-specific configuration
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct LinearConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// Linear GraphQL API URL (defaults to https://api.linear.app/graphql)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_url: Option<String>,
+    /// Linear workspace/web URL used by `track open` (e.g. https://linear.app/acme)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Default Linear team key/name/id. Falls back to top-level default_project.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_team: Option<String>,
+    /// Default Linear project association for issue creation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_linear_project: Option<String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub link_mappings: HashMap<String, String>,
+}
+
+impl LinearConfig {
+    pub fn is_empty(&self) -> bool {
+        self.token.is_none()
+            && self.api_url.is_none()
+            && self.url.is_none()
+            && self.default_team.is_none()
+            && self.default_linear_project.is_none()
+            && self.link_mappings.is_empty()
+    }
+
+    /// Connection-relevant keys only (excludes link_mappings).
+    pub fn has_connection_config(&self) -> bool {
+        self.token.is_some()
+            || self.api_url.is_some()
+            || self.url.is_some()
+            || self.default_team.is_some()
+            || self.default_linear_project.is_some()
+    }
+}
+
+fn map_youtrack_env_key(key: &str) -> Option<&'static str> {
+    match key.to_ascii_lowercase().as_str() {
+        "url" => Some("youtrack.url"),
+        "token" => Some("youtrack.token"),
+        _ => None,
+    }
+}
+
+fn map_jira_env_key(key: &str) -> Option<&'static str> {
+    match key.to_ascii_lowercase().as_str() {
+        "url" => Some("jira.url"),
+        "email" => Some("jira.email"),
+        "token" => Some("jira.token"),
+        "deployment" => Some("jira.deployment"),
+        _ => None,
+    }
+}
+
+fn map_github_env_key(key: &str) -> Option<&'static str> {
+    match key.to_ascii_lowercase().as_str() {
+        "token" => Some("github.token"),
+        "owner" => Some("github.owner"),
+        "repo" => Some("github.repo"),
+        "api_url" => Some("github.api_url"),
+        "project_number" => Some("github.project_number"),
+        "scope" => Some("github.scope"),
+        "repo_topic" => Some("github.repo_topic"),
+        "repo_pattern" => Some("github.repo_pattern"),
+        _ => None,
+    }
+}
+
+fn map_gitlab_env_key(key: &str) -> Option<&'static str> {
+    match key.to_ascii_lowercase().as_str() {
+        "token" => Some("gitlab.token"),
+        "url" => Some("gitlab.url"),
+        "project_id" => Some("gitlab.project_id"),
+        "namespace" => Some("gitlab.namespace"),
+        _ => None,
+    }
+}
+
+fn map_linear_env_key(key: &str) -> Option<&'static str> {
+    match key.to_ascii_lowercase().as_str() {
+        "token" => Some("linear.token"),
+        "api_url" => Some("linear.api_url"),
+        "url" => Some("linear.url"),
+        "default_team" => Some("linear.default_team"),
+        "default_project" => Some("linear.default_linear_project"),
+        _ => None,
+    }
+}
+
+impl Config {
+    pub fn load(config_path: Option<PathBuf>, backend: Backend) -> Result<Self> {
+        let mut config = Self::load_raw(config_path)?;
+
+        // Merge backend-specific config with global config
+        config.apply_backend_config(backend);
+
+        Ok(config)
+    }
+
+    /// Load the merged configuration (files + env) WITHOUT collapsing any
+    /// backend-specific section into the flat url/token fields.
+    ///
+    /// Use this when the per-backend sections themselves matter, e.g. to
+    /// enumerate which backends are configured (`track doctor --all-backends`).
+    pub fn load_raw(config_path: Option<PathBuf>) -> Result<Self> {
+        figment(config_path.as_deref())?
+            .extract()
+            .map_err(|e| ClientError::Load(Box::new(e)))
+    }
+
+    /// Enumerate backends that have any configuration present.
+    ///
+    /// Must be called on a raw config (see [`Config::load_raw`]): once
+    /// `apply_backend_config` has collapsed a backend's section into the flat
+    /// url/token fields, that section is partially consumed. A backend counts
+    /// as configured when its nested section has connection-relevant keys
+    /// (link_mappings alone don't count), or when it is the default backend
+    /// and only flat url/token settings exist.
+    pub fn configured_backends(&self) -> Vec<Backend> {
+        let mut found = Vec::new();
+        if self.youtrack.has_connection_config() {
+            found.push(Backend::YouTrack);
+        }
+        if self.jira.has_connection_config() {
+            found.push(Backend::Jira);
+        }
+        if self.github.has_connection_config() {
+            found.push(Backend::GitHub);
+        }
+        if self.gitlab.has_connection_config() {
+            found.push(Backend::GitLab);
+        }
+        if self.linear.has_connection_config() {
+            found.push(Backend::Linear);
+        }
+
+        // Single-backend setups often only set the flat url/token keys; those
+        // belong to the default backend.
+        let default = self.get_backend();
+        if !found.contains(&default) && (self.url.is_some() || self.token.is_some()) {
+            found.push(default);
+        }
+
+        Backend::ALL
+            .into_iter()
+            .filter(|b| found.contains(b))
+            .collect()
+    }
+
+    /// Apply backend-specific configuration, falling back to global settings.
+    ///
+    /// [`Config::load`] already does this; call it yourself after extracting
+    /// a config from [`figment`].
+    pub fn apply_backend_config(&mut self, backend: Backend) {
+        match backend {
+            Backend::YouTrack => {
+                if let Some(u) = self.youtrack.url.take() {
+                    self.url = Some(u);
+                }
+                if let Some(t) = self.youtrack.token.take() {
+                    self.token = Some(t);
+                }
+            }
+            Backend::Jira => {
+                if let Some(u) = self.jira.url.take() {
+                    self.url = Some(u);
+                }
+                if let Some(e) = self.jira.email.take() {
+                    self.email = Some(e);
+                }
+                if let Some(t) = self.jira.token.take() {
+                    self.token = Some(t);
+                }
+            }
+            Backend::GitHub => {
+                if let Some(api_url) = self.github.api_url.take() {
+                    self.url = Some(api_url);
+                } else {
+                    // GitHub typically defaults to api.github.com.
+                    // If the global generic URL is set to a completely different service (like YouTrack/GitLab),
+                    // we should disregard it and use the GitHub default to prevent cross-contamination.
+                    let is_github_url = self
+                        .url
+                        .as_deref()
+                        .is_some_and(|u| u.to_lowercase().contains("github"));
+
+                    if !is_github_url {
+                        self.url = Some("https://api.github.com".to_string());
+                    }
+                }
+                if let Some(t) = self.github.token.take() {
+                    self.token = Some(t);
+                }
+            }
+            Backend::GitLab => {
+                if let Some(u) = self.gitlab.url.take() {
+                    self.url = Some(u);
+                }
+                if let Some(t) = self.gitlab.token.take() {
+                    self.token = Some(t);
+                }
+            }
+            Backend::Linear => {
+                if let Some(u) = self.linear.url.take() {
+                    self.url = Some(u);
+                } else {
+                    let is_linear_url = self
+                        .url
+                        .as_deref()
+                        .is_some_and(|u| u.to_lowercase().contains("linear"));
+                    if !is_linear_url {
+                        self.url = None;
+                    }
+                }
+                if let Some(t) = self.linear.token.take() {
+                    self.token = Some(t);
+                }
+                if self.default_project.is_none()
+                    && let Some(team) = self.linear.default_team.clone()
+                {
+                    self.default_project = Some(team);
+                }
+            }
+        }
+    }
+
+    /// Override the collapsed url/token, e.g. with `--url`/`--token` flags.
+    pub fn merge_overrides(&mut self, url: Option<String>, token: Option<String>) {
+        if let Some(url) = url {
+            self.url = Some(url);
+        }
+        if let Some(token) = token {
+            self.token = Some(token);
+        }
+    }
+
+    /// Check that the settings `backend` needs to connect are present.
+    pub fn validate(&self, backend: Backend) -> Result<()> {
+        let backend_name = backend.display_name();
+
+        if backend != Backend::Linear && self.url.is_none() {
+            return Err(ClientError::Invalid(format!(
+                "{} URL not configured. Set via --url, TRACKER_URL env var, or config file",
+                backend_name
+            )));
+        }
+        if self.token.is_none() {
+            return Err(ClientError::Invalid(format!(
+                "{} token not configured. Set via --token, TRACKER_TOKEN env var, or config file",
+                backend_name
+            )));
+        }
+        // Jira Cloud requires email for Basic Auth; Server / Data Center
+        // authenticates with a personal access token alone.
+        if backend == Backend::Jira
+            && self.jira.deployment() == JiraDeployment::Cloud
+            && self.email.is_none()
+        {
+            return Err(ClientError::Invalid(
+                "Jira email not configured. Set via JIRA_EMAIL env var or config file".to_string(),
+            ));
+        }
+        if backend == Backend::GitHub {
+            if self.github.owner.is_none() {
+                return Err(ClientError::Invalid(
+                    "GitHub owner not configured. Set via 'track config set github.owner <OWNER>' or GITHUB_OWNER env var".to_string(),
+                ));
+            }
+            // Org scope spans the owner's repositories; `repo` is optional
+            if self.github.repo.is_none() && self.github.scope() == GitHubScope::Repo {
+                return Err(ClientError::Invalid(
+                    "GitHub repo not configured. Set via 'track config set github.repo <REPO>' or GITHUB_REPO env var".to_string(),
+                ));
+            }
+        }
+        if backend == Backend::GitLab && self.gitlab.project_id.is_none() {
+            return Err(ClientError::Invalid(
+                "GitLab project_id not configured. Set via 'track config set gitlab.project_id <ID>' or GITLAB_PROJECT_ID env var".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Get the configured backend, defaulting to YouTrack
+    pub fn get_backend(&self) -> Backend {
+        self.backend.unwrap_or_default()
+    }
+
+    /// Link-type mappings (canonical keyword -> backend link type name) for
+    /// the given backend. GitHub has no configurable link types.
+    pub fn link_mappings_for(&self, backend: Backend) -> &HashMap<String, String> {
+        static NO_MAPPINGS: std::sync::LazyLock<HashMap<String, String>> =
+            std::sync::LazyLock::new(HashMap::new);
+        match backend {
+            Backend::YouTrack => &self.youtrack.link_mappings,
+            Backend::Jira => &self.jira.link_mappings,
+            Backend::GitHub => &NO_MAPPINGS,
+            Backend::GitLab => &self.gitlab.link_mappings,
+            Backend::Linear => &self.linear.link_mappings,
+        }
+    }
+}
+
+/// The merged config provider: config files followed by environment
+/// variables, with the backend prefixes mapped onto their sections
+/// (`JIRA_URL` -> `jira.url`).
+///
+/// Tools with settings of their own can extract a struct that flattens
+/// [`Config`] from this instead of re-implementing the lookup.
+pub fn figment(config_path: Option<&Path>) -> Result<Figment> {
+    let mut figment = Figment::new().merge(Serialized::defaults(Config::default()));
+
+    if let Some(path) = config_path
+        && !path.exists()
+    {
+        return Err(ClientError::ConfigNotFound(path.to_path_buf()));
+    }
+
+    for path in config_paths(config_path) {
+        if path.exists() {
+            figment = figment.merge(Toml::file(path));
+        }
+    }
+
+    // Support TRACKER_*, YOUTRACK_*, and JIRA_* environment variables
+    Ok(figment
+        .merge(Env::prefixed("TRACKER_"))
+        .merge(Env::prefixed("YOUTRACK_").map(|key| {
+            // Map YOUTRACK_URL -> youtrack.url for nested config
+            if let Some(mapped) = map_youtrack_env_key(key.as_str()) {
+                mapped.into()
+            } else {
+                key.into()
+            }
+        }))
+        .merge(Env::prefixed("JIRA_").map(|key| {
+            // Map JIRA_URL -> jira.url for nested config
+            if let Some(mapped) = map_jira_env_key(key.as_str()) {
+                mapped.into()
+            } else {
+                key.into()
+            }
+        }))
+        .merge(Env::prefixed("GITHUB_").map(|key| {
+            if let Some(mapped) = map_github_env_key(key.as_str()) {
+                mapped.into()
+            } else {
+                key.into()
+            }
+        }))
+        .merge(Env::prefixed("GITLAB_").map(|key| {
+            if let Some(mapped) = map_gitlab_env_key(key.as_str()) {
+                mapped.into()
+            } else {
+                key.into()
+            }
+        }))
+        .merge(Env::prefixed("LINEAR_").map(|key| {
+            if let Some(mapped) = map_linear_env_key(key.as_str()) {
+                mapped.into()
+            } else {
+                key.into()
+            }
+        })))
+}
+
+fn config_paths(explicit: Option<&Path>) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+
+    if let Some(path) = explicit {
+        paths.push(path.to_path_buf());
+        return paths;
+    }
+
+    // Load configs from lowest to highest priority
+    // Later entries override earlier ones in figment merge
+    // 1. Global: ~/.tracker-cli/.track.toml (lowest file priority)
+    if let Some(path) = global_config_path() {
+        paths.push(path);
+    }
+    // 2. Project: ./.track.toml (highest file priority)
+    if let Some(path) = local_config_path()
+        && !paths.contains(&path)
+    {
+        paths.push(path);
+    }
+
+    paths
+}
+
+/// Returns the per-user directory (~/.tracker-cli)
+pub fn config_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(".tracker-cli"))
+}
+
+/// Returns the path to the global config (~/.tracker-cli/.track.toml)
+pub fn global_config_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(".track.toml"))
+}
+
+/// Returns the path to the local .track.toml file in the current directory
+pub fn local_config_path() -> Option<PathBuf> {
+    std::env::current_dir()
+        .ok()
+        .map(|dir| dir.join(".track.toml"))
+}
+
+/// Load backend from the full config chain (global -> project -> env)
+/// without requiring a backend argument.
+pub fn resolve_backend() -> Backend {
+    let mut figment = Figment::new().merge(Serialized::defaults(Config::default()));
+    for path in config_paths(None) {
+        if path.exists() {
+            figment = figment.merge(Toml::file(path));
+        }
+    }
+    figment = figment.merge(Env::prefixed("TRACKER_"));
+    figment
+        .extract::<Config>()
+        .ok()
+        .and_then(|c| c.backend)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_with_link_mappings() {
+        let toml_str = r#"
+backend = "jira"
+[jira]
+url = "https://test.atlassian.net"
+email = "user@example.com"
+token = "secret"
+
+[jira.link_mappings]
+depends = "Requires"
+required = "Requires"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config.jira.link_mappings.get("depends"),
+            Some(&"Requires".to_string())
+        );
+        assert_eq!(
+            config.jira.link_mappings.get("required"),
+            Some(&"Requires".to_string())
+        );
+        assert!(!config.jira.is_empty());
+    }
+
+    #[test]
+    fn test_config_without_link_mappings() {
+        let toml_str = r#"
+backend = "jira"
+[jira]
+url = "https://test.atlassian.net"
+email = "user@example.com"
+token = "secret"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.jira.link_mappings.is_empty());
+    }
+
+    #[test]
+    fn test_config_youtrack_link_mappings() {
+        let toml_str = r#"
+backend = "youtrack"
+[youtrack]
+url = "https://yt.example.com"
+token = "secret"
+
+[youtrack.link_mappings]
+depends = "Custom Depend"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config.youtrack.link_mappings.get("depends"),
+            Some(&"Custom Depend".to_string())
+        );
+    }
+
+    #[test]
+    fn test_config_gitlab_link_mappings() {
+        let toml_str = r#"
+backend = "gitlab"
+[gitlab]
+url = "https://gitlab.com/api/v4"
+token = "secret"
+project_id = "123"
+
+[gitlab.link_mappings]
+duplicates = "blocks"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config.gitlab.link_mappings.get("duplicates"),
+            Some(&"blocks".to_string())
+        );
+    }
+
+    #[test]
+    fn test_config_link_mappings_serialization_roundtrip() {
+        let mut config = Config::default();
+        config
+            .jira
+            .link_mappings
+            .insert("depends".to_string(), "Requires".to_string());
+        config.jira.url = Some("https://test.atlassian.net".to_string());
+
+        let toml_str = toml::to_string_pretty(&config).unwrap();
+        let parsed: Config = toml::from_str(&toml_str).unwrap();
+        assert_eq!(
+            parsed.jira.link_mappings.get("depends"),
+            Some(&"Requires".to_string())
+        );
+    }
+
+    #[test]
+    fn test_backend_env_key_mappers_are_case_insensitive() {
+        assert_eq!(map_youtrack_env_key("URL"), Some("youtrack.url"));
+        assert_eq!(map_youtrack_env_key("Token"), Some("youtrack.token"));
+        assert_eq!(map_jira_env_key("EMAIL"), Some("jira.email"));
+        assert_eq!(map_github_env_key("OWNER"), Some("github.owner"));
+        assert_eq!(map_github_env_key("API_URL"), Some("github.api_url"));
+        assert_eq!(
+            map_github_env_key("PROJECT_NUMBER"),
+            Some("github.project_number")
+        );
+        assert_eq!(map_gitlab_env_key("PROJECT_ID"), Some("gitlab.project_id"));
+        assert_eq!(map_linear_env_key("TOKEN"), Some("linear.token"));
+        assert_eq!(map_linear_env_key("API_URL"), Some("linear.api_url"));
+        assert_eq!(
+            map_linear_env_key("DEFAULT_TEAM"),
+            Some("linear.default_team")
+        );
+        assert_eq!(
+            map_linear_env_key("DEFAULT_PROJECT"),
+            Some("linear.default_linear_project")
+        );
+        assert_eq!(map_linear_env_key("UNKNOWN"), None);
+    }
+
+    #[test]
+    fn test_validate_jira_server_needs_no_email() {
+        let mut config = Config {
+            url: Some("https://jira.example.com".to_string()),
+            token: Some("pat".to_string()),
+            ..Default::default()
+        };
+        let err = config.validate(Backend::Jira).unwrap_err();
+        assert!(err.to_string().contains("Jira email not configured"));
+
+        config.jira = toml::from_str("deployment = \"datacenter\"").unwrap();
+        assert_eq!(config.jira.deployment(), JiraDeployment::Server);
+        config.validate(Backend::Jira).unwrap();
+        assert_eq!(map_jira_env_key("DEPLOYMENT"), Some("jira.deployment"));
+    }
+
+    #[test]
+    fn test_validate_github_requires_owner_and_repo() {
+        let config = Config {
+            url: Some("https://api.github.com".to_string()),
+            token: Some("secret".to_string()),
+            github: GitHubConfig {
+                repo: Some("repo".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let err = config.validate(Backend::GitHub).unwrap_err();
+        assert!(
+            err.to_string().contains("GitHub owner not configured"),
+            "expected missing owner error, got: {err}"
+        );
+
+        let config = Config {
+            url: Some("https://api.github.com".to_string()),
+            token: Some("secret".to_string()),
+            github: GitHubConfig {
+                owner: Some("org".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let err = config.validate(Backend::GitHub).unwrap_err();
+        assert!(
+            err.to_string().contains("GitHub repo not configured"),
+            "expected missing repo error, got: {err}"
+        );
+    }
+
+    #[test]
+    fn test_validate_github_org_scope_needs_no_repo() {
+        let mut config = Config {
+            url: Some("https://api.github.com".to_string()),
+            token: Some("secret".to_string()),
+            github: toml::from_str("owner = \"org\"\nscope = \"organization\"").unwrap(),
+            ..Default::default()
+        };
+        assert_eq!(config.github.scope(), GitHubScope::Org);
+        config.validate(Backend::GitHub).unwrap();
+
+        config.github.scope = Some(GitHubScope::Repo);
+        assert!(config.validate(Backend::GitHub).is_err());
+        assert_eq!(map_github_env_key("REPO_TOPIC"), Some("github.repo_topic"));
+    }
+
+    #[test]
+    fn test_configured_backends_from_sections() {
+        let toml_str = r#"
+backend = "youtrack"
+[youtrack]
+url = "https://yt.example.com"
+token = "yt-secret"
+
+[gitlab]
+url = "https://gitlab.com/api/v4"
+token = "gl-secret"
+project_id = "123"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config.configured_backends(),
+            vec![Backend::YouTrack, Backend::GitLab]
+        );
+    }
+
+    #[test]
+    fn test_configured_backends_flat_keys_count_for_default_backend() {
+        let toml_str = r#"
+backend = "jira"
+url = "https://test.atlassian.net"
+token = "secret"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.configured_backends(), vec![Backend::Jira]);
+    }
+
+    #[test]
+    fn test_configured_backends_flat_keys_default_to_youtrack() {
+        let toml_str = r#"
+url = "https://yt.example.com"
+token = "secret"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.configured_backends(), vec![Backend::YouTrack]);
+    }
+
+    #[test]
+    fn test_configured_backends_empty_config() {
+        let config = Config::default();
+        assert!(config.configured_backends().is_empty());
+    }
+
+    #[test]
+    fn test_configured_backends_ignores_link_mappings_only_sections() {
+        // A cosmetic link_mappings table alone must not enumerate the backend
+        // (it has no url/token, so auditing it would always fail).
+        let toml_str = r#"
+[youtrack.link_mappings]
+"parent for" = "subtask of"
+
+[gitlab]
+url = "https://gitlab.com/api/v4"
+token = "gl-secret"
+project_id = "123"
+
+[linear.link_mappings]
+blocks = "blocked by"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.configured_backends(), vec![Backend::GitLab]);
+    }
+
+    #[test]
+    fn test_configured_backends_stable_order() {
+        let toml_str = r#"
+[linear]
+token = "lin-secret"
+
+[jira]
+url = "https://test.atlassian.net"
+email = "user@example.com"
+token = "j-secret"
+
+[github]
+token = "gh-secret"
+owner = "org"
+repo = "repo"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config.configured_backends(),
+            vec![Backend::Jira, Backend::GitHub, Backend::Linear]
+        );
+    }
+
+    #[test]
+    fn test_validate_gitlab_requires_project_id() {
+        let config = Config {
+            url: Some("https://gitlab.com/api/v4".to_string()),
+            token: Some("secret".to_string()),
+            ..Default::default()
+        };
+
+        let err = config.validate(Backend::GitLab).unwrap_err();
+        assert!(
+            err.to_string().contains("GitLab project_id not configured"),
+            "expected missing project_id error, got: {err}"
+        );
+    }
+}
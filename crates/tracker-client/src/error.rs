@@ -0,0 +1,27 @@
+use crate::Backend;
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("Config file not found: {}", .0.display())]
+    ConfigNotFound(PathBuf),
+
+    #[error("Failed to load config: {0}")]
+    Load(#[from] Box<figment::Error>),
+
+    /// A setting `validate` requires is missing; the message says where to set it
+    #[error("{0}")]
+    Invalid(String),
+
+    #[error("{0} not configured")]
+    NotConfigured(&'static str),
+
+    #[error("{} support is not enabled; build tracker-client with the `{}` feature", .0.display_name(), .0)]
+    BackendDisabled(Backend),
+
+    #[error("Failed to initialize mock client: {0}")]
+    Mock(String),
+}
+
+pub type Result<T> = std::result::Result<T, ClientError>;
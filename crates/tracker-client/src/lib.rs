@@ -0,0 +1,55 @@
+//! Config loading and client construction for tracker-cli backends.
+//!
+//! This crate is what the `track` binary uses to turn `.track.toml` files and
+//! environment variables into a connected [`IssueTracker`]/[`KnowledgeBase`]
+//! client. Other tools can use it to pick up the same configuration, link
+//! mappings and mock mode without shelling out to `track`.
+//!
+//! # Usage
+//!
+//! ```no_run
+//! use tracker_client::{Config, connect_backend, resolve_backend};
+//!
+//! # fn main() -> tracker_client::Result<()> {
+//! let backend = resolve_backend();
+//! let config = Config::load(None, backend)?;
+//! let client = connect_backend(backend, &config)?;
+//! let issue = client.issue_tracker().get_issue("PROJ-1");
+//! # let _ = issue;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Features
+//!
+//! Each backend (`youtrack`, `jira`, `github`, `gitlab`, `linear`) and the
+//! `mock` client sit behind a feature of the same name; all are enabled by
+//! default. Config for a disabled backend still parses, but connecting to it
+//! returns [`ClientError::BackendDisabled`]. The `clap` feature derives
+//! `clap::ValueEnum` for [`Backend`].
+//!
+//! [`IssueTracker`]: tracker_core::IssueTracker
+//! [`KnowledgeBase`]: tracker_core::KnowledgeBase
+
+#[cfg(not(any(
+    feature = "youtrack",
+    feature = "jira",
+    feature = "github",
+    feature = "gitlab",
+    feature = "linear",
+    feature = "mock"
+)))]
+compile_error!("tracker-client needs at least one backend feature enabled");
+
+pub mod backend;
+pub mod client;
+pub mod config;
+pub mod error;
+
+pub use backend::Backend;
+pub use client::{Client, connect, connect_backend};
+pub use config::{
+    BackendConfig, Config, GitHubConfig, GitHubScope, GitLabConfig, JiraConfig, JiraDeployment,
+    LinearConfig, resolve_backend,
+};
+pub use error::{ClientError, Result};
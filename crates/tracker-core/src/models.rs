@@ -4,6 +4,7 @@ use std::path::PathBuf;
 
 /// Common issue representation across all backends
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Issue {
     /// Internal ID
     pub id: String,
@@ -34,6 +35,7 @@ pub struct Issue {
 
 /// Reference to a project (minimal fields)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ProjectRef {
     pub id: String,
     pub name: Option<String>,
@@ -59,6 +61,7 @@ pub struct ProjectRef {
 /// externally-tagged JSON encoding is stable and additive: existing variant
 /// encodings never change, and `Unknown` only gains an optional `value` key.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum CustomField {
     SingleEnum {
         name: String,
@@ -95,6 +98,7 @@ pub enum CustomField {
 
 /// Tag on an issue
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Tag {
     pub id: String,
     pub name: String,
@@ -102,6 +106,7 @@ pub struct Tag {
 
 /// Full project representation
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Project {
     pub id: String,
     pub name: String,
@@ -111,6 +116,7 @@ pub struct Project {
 
 /// Custom field definition for a project
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ProjectCustomField {
     pub id: String,
     pub name: String,
@@ -126,6 +132,7 @@ pub struct ProjectCustomField {
 
 /// State value with workflow metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct StateValueInfo {
     /// State name (e.g., "Open", "In Progress", "Done")
     pub name: String,
@@ -139,6 +146,7 @@ pub struct StateValueInfo {
 
 /// User that can be assigned to issues
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct User {
     pub id: String,
     pub login: Option<String>,
@@ -158,6 +166,7 @@ pub struct CreateProject {
 
 /// Issue tag (full representation with optional metadata)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct IssueTag {
     pub id: String,
     pub name: String,
@@ -167,6 +176,7 @@ pub struct IssueTag {
 
 /// Tag color information
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TagColor {
     pub id: String,
     pub background: Option<String>,
@@ -186,6 +196,7 @@ pub struct CreateTag {
 
 /// Issue link type definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct IssueLinkType {
     pub id: String,
     pub name: String,
@@ -198,6 +209,7 @@ pub struct IssueLinkType {
 
 /// Link between two issues
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct IssueLink {
     pub id: String,
     pub direction: Option<String>,
@@ -207,6 +219,7 @@ pub struct IssueLink {
 
 /// A linked issue reference
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct LinkedIssue {
     pub id: String,
     pub id_readable: Option<String>,
@@ -215,6 +228,7 @@ pub struct LinkedIssue {
 
 /// Comment on an issue
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Comment {
     pub id: String,
     pub text: String,
@@ -224,6 +238,7 @@ pub struct Comment {
 
 /// Comment author information
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CommentAuthor {
     pub login: String,
     pub name: Option<String>,
@@ -236,6 +251,7 @@ pub struct CommentAuthor {
 /// this shape. The lowest-common-denominator representation is one field
 /// transition: who changed `field` from `from` to `to`, and when.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct IssueHistoryEvent {
     /// When the change was recorded.
     pub at: DateTime<Utc>,
@@ -287,6 +303,7 @@ pub struct AttachmentUploadFile {
 
 /// Attachment on an issue or issue comment.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct IssueAttachment {
     pub id: String,
     pub name: String,
@@ -322,6 +339,37 @@ pub struct UpdateIssue {
     pub parent: Option<String>,
 }
 
+/// A workflow transition to apply to an issue
+#[derive(Debug, Clone, Default)]
+pub struct IssueTransition {
+    /// Target state name (e.g., "Done")
+    pub state: String,
+    /// Resolution to record with the transition (e.g., "Won't Do"). Only
+    /// backends with a separate resolution field (Jira) accept one.
+    pub resolution: Option<String>,
+}
+
+/// Time spent on an issue, for [`crate::IssueTracker::log_work`]
+#[derive(Debug, Clone, Default)]
+pub struct LogWork {
+    /// Duration in minutes
+    pub minutes: u64,
+    /// Work description
+    pub text: Option<String>,
+    /// Day the work was done (defaults to today on the backend)
+    pub date: Option<chrono::NaiveDate>,
+}
+
+/// A recorded work log entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WorkItem {
+    /// Backend ID of the entry, when the backend reports one
+    pub id: Option<String>,
+    pub minutes: u64,
+    pub text: Option<String>,
+}
+
 /// Custom field update value
 #[derive(Debug, Clone)]
 pub enum CustomFieldUpdate {
@@ -331,12 +379,92 @@ pub enum CustomFieldUpdate {
     SingleUser { name: String, login: String },
 }
 
+// ============================================================================
+// Webhook Event Models
+// ============================================================================
+
+/// Kind of change reported by a tracker webhook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TrackerEventKind {
+    IssueCreated,
+    IssueUpdated,
+    IssueCommented,
+    /// The issue's workflow state changed (including close/reopen)
+    IssueTransitioned,
+}
+
+impl TrackerEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrackerEventKind::IssueCreated => "issue_created",
+            TrackerEventKind::IssueUpdated => "issue_updated",
+            TrackerEventKind::IssueCommented => "issue_commented",
+            TrackerEventKind::IssueTransitioned => "issue_transitioned",
+        }
+    }
+}
+
+/// A webhook payload normalized across backends.
+///
+/// Each backend crate's `webhook` module maps its native payloads onto this
+/// shape; anything that isn't an issue change is dropped there.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TrackerEvent {
+    /// Backend that sent the event ("github", "jira", ...)
+    pub source: String,
+    pub kind: TrackerEventKind,
+    /// Human-readable issue ID in the backend's own format
+    pub issue: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// Login or display name of whoever caused the event
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub at: Option<DateTime<Utc>>,
+    /// Previous state, for transitions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// New state, for transitions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    /// Comment body, for comment events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl TrackerEvent {
+    pub fn new(source: &str, kind: TrackerEventKind, issue: impl Into<String>) -> Self {
+        Self {
+            source: source.to_string(),
+            kind,
+            issue: issue.into(),
+            summary: None,
+            project: None,
+            actor: None,
+            at: None,
+            from: None,
+            to: None,
+            comment: None,
+            url: None,
+        }
+    }
+}
+
 // ============================================================================
 // Knowledge Base / Article Models
 // ============================================================================
 
 /// Knowledge base article representation across all backends
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Article {
     /// Internal ID
     pub id: String,
@@ -364,6 +492,7 @@ pub struct Article {
 
 /// Reference to an article (minimal fields for links/hierarchy)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ArticleRef {
     pub id: String,
     pub id_readable: Option<String>,
@@ -398,6 +527,7 @@ pub struct UpdateArticle {
 
 /// Attachment on an article
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ArticleAttachment {
     pub id: String,
     pub name: String,
@@ -413,6 +543,7 @@ pub struct ArticleAttachment {
 
 /// Type of custom field
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum CustomFieldType {
     /// Single enum value selection
     SingleEnum,
@@ -495,6 +626,7 @@ impl std::fmt::Display for CustomFieldType {
 
 /// Type of bundle for storing field values
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum BundleType {
     /// Enumeration values
     Enum,
@@ -609,6 +741,7 @@ pub struct AttachFieldToProject {
 
 /// Global custom field definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CustomFieldDefinition {
     /// Field ID
     pub id: String,
@@ -623,6 +756,7 @@ pub struct CustomFieldDefinition {
 
 /// Bundle definition (collection of values for enum/state fields)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct BundleDefinition {
     /// Bundle ID
     pub id: String,
@@ -636,6 +770,7 @@ pub struct BundleDefinition {
 
 /// Single value in a bundle
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct BundleValueDefinition {
     /// Value ID
     pub id: String,
@@ -655,6 +790,7 @@ pub struct BundleValueDefinition {
 /// YouTrack chains a count call before the search to get it.
 /// `total` is `None` if the backend cannot report a count (e.g., count timed out).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SearchResult<T> {
     /// The items returned for this page
     pub items: Vec<T>,
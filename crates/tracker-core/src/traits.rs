@@ -49,6 +49,50 @@ pub trait IssueTracker: Send + Sync {
     /// Delete an issue
     fn delete_issue(&self, id: &str) -> Result<()>;
 
+    /// Move an issue through its workflow. The default sets the State field;
+    /// backends with a separate resolution field override this.
+    fn transition_issue(&self, id: &str, transition: &IssueTransition) -> Result<Issue> {
+        if transition.resolution.is_some() {
+            return Err(crate::error::TrackerError::InvalidInput(
+                "Transition resolutions are not supported by this backend".to_string(),
+            ));
+        }
+        self.update_issue(
+            id,
+            &UpdateIssue {
+                custom_fields: vec![CustomFieldUpdate::State {
+                    name: "State".to_string(),
+                    value: transition.state.clone(),
+                }],
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Remove a tag (label) from an issue.
+    fn remove_issue_tag(&self, issue_id: &str, tag: &str) -> Result<()> {
+        let _ = (issue_id, tag);
+        Err(crate::error::TrackerError::InvalidInput(
+            "Removing tags is not supported by this backend".to_string(),
+        ))
+    }
+
+    /// Detach an issue from its parent, if it has one.
+    fn remove_issue_parent(&self, issue_id: &str) -> Result<()> {
+        let _ = issue_id;
+        Err(crate::error::TrackerError::InvalidInput(
+            "Removing a parent is not supported by this backend".to_string(),
+        ))
+    }
+
+    /// Record time spent on an issue.
+    fn log_work(&self, issue_id: &str, work: &LogWork) -> Result<WorkItem> {
+        let _ = (issue_id, work);
+        Err(crate::error::TrackerError::InvalidInput(
+            "Work logging is not supported by this backend".to_string(),
+        ))
+    }
+
     // ========== Attachment Operations ==========
 
     /// List attachments on an issue.
@@ -113,6 +157,12 @@ pub trait IssueTracker: Send + Sync {
         Ok(Vec::new())
     }
 
+    /// Render a mention of `user` in this backend's native text syntax, so
+    /// the user is notified when it appears in a comment or description.
+    fn format_mention(&self, user: &User) -> String {
+        format!("@{}", user.login.as_deref().unwrap_or(&user.display_name))
+    }
+
     // ========== Custom Field Admin Operations ==========
 
     /// List all custom field definitions (instance-wide)
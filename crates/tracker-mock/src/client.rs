@@ -12,8 +12,9 @@ use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use tracker_core::{
     Article, ArticleAttachment, Comment, CreateArticle, CreateIssue, CreateProject, Issue,
-    IssueHistoryEvent, IssueLink, IssueLinkType, IssueTag, IssueTracker, KnowledgeBase, Project,
-    ProjectCustomField, Result, SearchResult, TrackerError, UpdateArticle, UpdateIssue, User,
+    IssueHistoryEvent, IssueLink, IssueLinkType, IssueTag, IssueTracker, IssueTransition,
+    KnowledgeBase, LogWork, Project, ProjectCustomField, Result, SearchResult, TrackerError,
+    UpdateArticle, UpdateIssue, User, WorkItem,
 };
 
 /// A mock client that reads responses from fixture files
@@ -310,6 +311,12 @@ impl IssueTracker for MockClient {
         if !custom_fields.is_empty() {
             args.insert("custom_fields".to_string(), custom_fields);
         }
+        if !update.tags.is_empty() {
+            args.insert("tags".to_string(), update.tags.join(","));
+        }
+        if let Some(description) = &update.description {
+            args.insert("description".to_string(), description.clone());
+        }
         // Note: UpdateIssue doesn't implement Serialize, so we just pass None for body
         self.get_response("update_issue", args, None)
     }
@@ -430,6 +437,49 @@ impl IssueTracker for MockClient {
         self.get_response("link_subtask", args, None)
     }
 
+    fn transition_issue(&self, id: &str, transition: &IssueTransition) -> Result<Issue> {
+        let mut args: HashMap<String, String> = [
+            ("id".to_string(), id.to_string()),
+            ("state".to_string(), transition.state.clone()),
+        ]
+        .into_iter()
+        .collect();
+        if let Some(resolution) = &transition.resolution {
+            args.insert("resolution".to_string(), resolution.clone());
+        }
+        self.get_response("transition_issue", args, None)
+    }
+
+    fn remove_issue_tag(&self, issue_id: &str, tag: &str) -> Result<()> {
+        let args = [
+            ("issue_id".to_string(), issue_id.to_string()),
+            ("tag".to_string(), tag.to_string()),
+        ]
+        .into_iter()
+        .collect();
+        self.get_response("remove_issue_tag", args, None)
+    }
+
+    fn remove_issue_parent(&self, issue_id: &str) -> Result<()> {
+        let args = [("issue_id".to_string(), issue_id.to_string())]
+            .into_iter()
+            .collect();
+        self.get_response("remove_issue_parent", args, None)
+    }
+
+    fn log_work(&self, issue_id: &str, work: &LogWork) -> Result<WorkItem> {
+        let mut args: HashMap<String, String> = [
+            ("issue_id".to_string(), issue_id.to_string()),
+            ("minutes".to_string(), work.minutes.to_string()),
+        ]
+        .into_iter()
+        .collect();
+        if let Some(date) = work.date {
+            args.insert("date".to_string(), date.to_string());
+        }
+        self.get_response("log_work", args, work.text.as_deref())
+    }
+
     fn add_comment(&self, issue_id: &str, text: &str) -> Result<Comment> {
         let args = [
             ("issue_id".to_string(), issue_id.to_string()),
@@ -621,6 +621,42 @@ impl YouTrackClient {
         Ok(updated_tag)
     }
 
+    /// Remove a tag from an issue by tag ID
+    pub fn remove_issue_tag(&self, issue_id: &str, tag_id: &str) -> Result<()> {
+        let url = format!("{}/api/issues/{}/tags/{}", self.base_url, issue_id, tag_id);
+
+        let response = self
+            .agent
+            .delete(&url)
+            .header("Authorization", &self.auth_header())
+            .call()
+            .map_err(|e| self.handle_error(e))?;
+
+        self.check_response(response)?;
+        Ok(())
+    }
+
+    /// Add a time tracking work item to an issue
+    pub fn add_work_item(&self, issue_id: &str, item: &CreateWorkItem) -> Result<IssueWorkItem> {
+        let url = format!(
+            "{}/api/issues/{}/timeTracking/workItems?fields=id,duration(minutes),text",
+            self.base_url, issue_id
+        );
+
+        let response = self
+            .agent
+            .post(&url)
+            .header("Authorization", &self.auth_header())
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .send_json(item)
+            .map_err(|e| self.handle_error(e))?;
+
+        let mut response = self.check_response(response)?;
+        let work_item: IssueWorkItem = response.body_mut().read_json()?;
+        Ok(work_item)
+    }
+
     /// List all available issue link types
     pub fn list_link_types(&self) -> Result<Vec<IssueLinkType>> {
         let url = format!(
@@ -758,6 +794,25 @@ impl YouTrackClient {
         Ok(())
     }
 
+    /// Remove the child's "subtask of" links, detaching it from its parent.
+    pub fn remove_parent(&self, child_issue_id: &str) -> Result<()> {
+        for link in self.get_issue_links(child_issue_id)? {
+            let is_parent_link = unicode_eq_ignore_case(&link.link_type.name, "Subtask")
+                && link
+                    .direction
+                    .as_deref()
+                    .is_some_and(|dir| dir.eq_ignore_ascii_case("INWARD"));
+            if !is_parent_link {
+                continue;
+            }
+            for parent in &link.issues {
+                let parent_id = parent.id_readable.as_deref().unwrap_or(&parent.id);
+                self.remove_issue_from_link(child_issue_id, &link.id, parent_id)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Link two issues together with the specified link type.
     /// `link_type` is a canonical name (e.g. "relates", "depends") that gets
     /// resolved to the YouTrack-native name via `resolve_link_type`.
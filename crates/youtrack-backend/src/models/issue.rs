@@ -364,6 +364,31 @@ pub struct IssueComment {
     pub created: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+/// Time tracking work item
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct IssueWorkItem {
+    pub id: String,
+    pub duration: WorkDuration,
+    #[serde(default)]
+    pub text: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct WorkDuration {
+    pub minutes: u64,
+}
+
+/// Create a time tracking work item
+#[derive(Debug, Serialize)]
+pub struct CreateWorkItem {
+    pub duration: WorkDuration,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Epoch milliseconds of the work day
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<i64>,
+}
+
 /// Issue attachment
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
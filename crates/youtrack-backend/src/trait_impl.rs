@@ -4,15 +4,16 @@ use crate::client::YouTrackClient;
 use crate::convert;
 use crate::models::{
     AttachFieldRequest, BundleRef, CreateBundleRequest, CreateBundleValueRequest,
-    CreateCustomFieldRequest, CreateIssueTagRequest, CustomFieldRef, FieldTypeRef, TagColorRequest,
+    CreateCustomFieldRequest, CreateIssueTagRequest, CreateWorkItem, CustomFieldRef, FieldTypeRef,
+    TagColorRequest, WorkDuration,
 };
 use tracker_core::{
     Article, ArticleAttachment, AttachFieldToProject, AttachmentUpload, BundleDefinition,
     BundleType, BundleValueDefinition, Comment, CreateArticle, CreateBundle, CreateBundleValue,
     CreateCustomField, CreateIssue, CreateProject, CreateTag, CustomFieldDefinition, Issue,
     IssueAttachment, IssueHistoryEvent, IssueLink, IssueLinkType, IssueTag, IssueTracker,
-    KnowledgeBase, Project, ProjectCustomField, Result, SearchResult, TrackerError, UpdateArticle,
-    UpdateIssue, User,
+    KnowledgeBase, LogWork, Project, ProjectCustomField, Result, SearchResult, TrackerError,
+    UpdateArticle, UpdateIssue, User, WorkItem,
 };
 
 impl IssueTracker for YouTrackClient {
@@ -235,6 +236,39 @@ impl IssueTracker for YouTrackClient {
         Ok(self.link_subtask(child, parent)?)
     }
 
+    fn remove_issue_parent(&self, issue_id: &str) -> Result<()> {
+        Ok(self.remove_parent(issue_id)?)
+    }
+
+    fn remove_issue_tag(&self, issue_id: &str, tag: &str) -> Result<()> {
+        let tags = self.list_tags()?;
+        let tag = tags
+            .into_iter()
+            .find(|t| t.name == tag)
+            .ok_or_else(|| TrackerError::NotFound(format!("Tag '{}' not found", tag)))?;
+        Ok(self.remove_issue_tag(issue_id, &tag.id)?)
+    }
+
+    fn log_work(&self, issue_id: &str, work: &LogWork) -> Result<WorkItem> {
+        let item = CreateWorkItem {
+            duration: WorkDuration {
+                minutes: work.minutes,
+            },
+            text: work.text.clone(),
+            date: work.date.map(|date| {
+                date.and_time(chrono::NaiveTime::MIN)
+                    .and_utc()
+                    .timestamp_millis()
+            }),
+        };
+        let created = self.add_work_item(issue_id, &item)?;
+        Ok(WorkItem {
+            id: Some(created.id),
+            minutes: created.duration.minutes,
+            text: created.text,
+        })
+    }
+
     fn add_comment(&self, issue_id: &str, text: &str) -> Result<Comment> {
         Ok(self.add_comment(issue_id, text)?.into())
     }
@@ -0,0 +1,80 @@
+//! Normalize webhook payloads sent from YouTrack workflows.
+//!
+//! YouTrack has no built-in webhooks; a workflow rule posts JSON with the
+//! `http` module instead. The expected body mirrors the REST API's naming:
+//!
+//! ```json
+//! {
+//!   "event": "transitioned",
+//!   "issue": { "idReadable": "PROJ-12", "summary": "...", "project": { "shortName": "PROJ" } },
+//!   "author": { "login": "jane" },
+//!   "from": "Open",
+//!   "to": "In Progress",
+//!   "comment": { "text": "..." },
+//!   "timestamp": 1777636800000
+//! }
+//! ```
+//!
+//! `event` is one of `created`, `updated`, `commented`, or `transitioned`.
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use tracker_core::{TrackerEvent, TrackerEventKind};
+
+const SOURCE: &str = "youtrack";
+
+/// Convert a workflow payload into a [`TrackerEvent`]. Unknown event names
+/// yield `None`.
+pub fn parse_webhook(payload: &Value) -> Option<TrackerEvent> {
+    let kind = match payload["event"].as_str()? {
+        "created" => TrackerEventKind::IssueCreated,
+        "updated" => TrackerEventKind::IssueUpdated,
+        "commented" => TrackerEventKind::IssueCommented,
+        "transitioned" => TrackerEventKind::IssueTransitioned,
+        _ => return None,
+    };
+
+    let issue = &payload["issue"];
+    let mut event = TrackerEvent::new(SOURCE, kind, issue["idReadable"].as_str()?);
+    event.summary = issue["summary"].as_str().map(String::from);
+    event.project = issue["project"]["shortName"].as_str().map(String::from);
+    event.actor = payload["author"]["login"].as_str().map(String::from);
+    event.url = issue["url"].as_str().map(String::from);
+    event.at = payload["timestamp"]
+        .as_i64()
+        .and_then(DateTime::<Utc>::from_timestamp_millis);
+    event.from = payload["from"].as_str().map(String::from);
+    event.to = payload["to"].as_str().map(String::from);
+    event.comment = payload["comment"]["text"].as_str().map(String::from);
+    Some(event)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn maps_workflow_payload() {
+        let payload = json!({
+            "event": "transitioned",
+            "issue": {
+                "idReadable": "PROJ-12",
+                "summary": "Crash on start",
+                "project": { "shortName": "PROJ" }
+            },
+            "author": { "login": "jane" },
+            "from": "Open",
+            "to": "In Progress",
+            "timestamp": 1777636800000i64
+        });
+        let event = parse_webhook(&payload).unwrap();
+        assert_eq!(event.kind, TrackerEventKind::IssueTransitioned);
+        assert_eq!(event.issue, "PROJ-12");
+        assert_eq!(event.project.as_deref(), Some("PROJ"));
+        assert_eq!(event.from.as_deref(), Some("Open"));
+        assert_eq!(event.at, "2026-05-01T12:00:00Z".parse().ok());
+
+        assert!(parse_webhook(&json!({ "event": "deleted", "issue": {} })).is_none());
+    }
+}